@@ -0,0 +1,95 @@
+// Compares the serde data format of rtps-cdr with the generated CdrEncode and CdrDecode
+// impls on the submessages of RtpsCore.idl
+extern crate criterion;
+extern crate rtps_cdr;
+
+mod generated {
+    include!("../files/test-vectors/rtps_submessages/expected.rs");
+}
+
+use std::convert::TryFrom;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use rtps_cdr::{decode_from_slice, encode_to_vec, from_slice_with_encoding, to_vec_with_encoding, CdrDecode, CdrEncode,
+               Encoding, Endianness};
+use generated::DDS::RTPS::{AckNackSubmessage, Count_t, DataSubmessage, EntityId_t, GUID_t, InfoReplySubmessage,
+                           Locator_t, ParticipantMessageData, SequenceNumberSet, SequenceNumber_t, SubmessageHeader};
+
+const ENDIANNESS: Endianness = Endianness::Little;
+const ENCODING: Encoding = Encoding::Xcdr1;
+
+fn header(submessage_id: u8) -> SubmessageHeader {
+    SubmessageHeader { submessageId: submessage_id, flags: 1, submessageLength: 0 }
+}
+
+fn entity_id(kind: u8) -> EntityId_t {
+    EntityId_t { entityKey: [0, 0, 1], entityKind: kind }
+}
+
+fn ack_nack() -> AckNackSubmessage {
+    AckNackSubmessage {
+        smHeader: header(0x06),
+        readerId: entity_id(0xc7),
+        writerId: entity_id(0xc2),
+        readerSNState: SequenceNumberSet {
+            bitmapBase: SequenceNumber_t { high: 0, low: 17 },
+            numBits: 64,
+            bitmap: TryFrom::try_from(vec![-1, 0x5555]).unwrap(),
+        },
+        count: Count_t { value: 3 },
+    }
+}
+
+fn data() -> DataSubmessage {
+    DataSubmessage {
+        smHeader: header(0x15),
+        extraFlags: 0,
+        octetsToInlineQos: 16,
+        readerId: entity_id(0),
+        writerId: entity_id(0x02),
+        writerSN: SequenceNumber_t { high: 0, low: 42 },
+        inlineQos: (0..=255).collect(),
+    }
+}
+
+fn info_reply() -> InfoReplySubmessage {
+    let locator = |port| Locator_t { kind: 1, _port: port, address: [192; 16] };
+    InfoReplySubmessage {
+        smHeader: header(0x0f),
+        unicastLocatorList: TryFrom::try_from(vec![locator(7410), locator(7411)]).unwrap(),
+        multicastLocatorList: TryFrom::try_from(vec![locator(7400)]).unwrap(),
+    }
+}
+
+fn participant_message() -> ParticipantMessageData {
+    ParticipantMessageData {
+        participantGuid: GUID_t { guidPrefix: [7; 12], entityId: entity_id(0xc1) },
+        data: vec![0xab; 4096],
+    }
+}
+
+/// Benchmark encoding and decoding the value by serde and by the generated impls
+fn compare<T>(c: &mut Criterion, name: &str, value: &T)
+    where T: CdrEncode + CdrDecode + serde::Serialize + serde::de::DeserializeOwned
+{
+    let bytes = encode_to_vec(value, ENDIANNESS, ENCODING).unwrap();
+    assert_eq!(bytes, to_vec_with_encoding(value, ENDIANNESS, ENCODING).unwrap());
+
+    let mut group = c.benchmark_group(name);
+    group.bench_function("serialize", |b| b.iter(|| to_vec_with_encoding(black_box(value), ENDIANNESS, ENCODING)));
+    group.bench_function("encode", |b| b.iter(|| encode_to_vec(black_box(value), ENDIANNESS, ENCODING)));
+    group.bench_function("deserialize", |b| {
+        b.iter(|| from_slice_with_encoding::<T>(black_box(&bytes), ENDIANNESS, ENCODING))
+    });
+    group.bench_function("decode", |b| b.iter(|| decode_from_slice::<T>(black_box(&bytes), ENDIANNESS, ENCODING)));
+    group.finish();
+}
+
+fn submessages(c: &mut Criterion) {
+    compare(c, "AckNackSubmessage", &ack_nack());
+    compare(c, "DataSubmessage", &data());
+    compare(c, "InfoReplySubmessage", &info_reply());
+    compare(c, "ParticipantMessageData", &participant_message());
+}
+
+criterion_group!(benches, submessages);
+criterion_main!(benches);
@@ -0,0 +1,563 @@
+// Copyright (C) 2019  Frank Rehberger
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>
+
+//! Direct encoding of CDR, bypassing serde's data model
+//!
+//! The traits are implemented by the Rust generator, if configured, for the structs,
+//! unions and enums of the IDL, and here for primitives, strings, sequences, arrays and the
+//! runtime types of `rtps_types`. The encoding is the one of the serde data format;
+//! sequences and arrays of primitives are copied in bulk if the byte order is native.
+//! Generated types tell the bounds of their encoded size by `SerializedSize`.
+use alloc::{borrow::ToOwned, boxed::Box, string::String, vec::Vec};
+#[cfg(target_has_atomic = "ptr")]
+use alloc::sync::Arc;
+use core::convert::{TryFrom, TryInto};
+use core::{mem, ptr, slice};
+use rtps_types::{BoundError, BoundedString, BoundedVec, BoundedWString, BoundedWideString, Char, FixedString,
+                 FixedVec, LongDouble, WChar, WString};
+use crate::{Deserializer, Encoding, Endianness, Error, Result, Serializer};
+
+/// A type encoding itself as CDR
+pub trait CdrEncode {
+    fn encode(&self, ser: &mut Serializer<'_>) -> Result<()>;
+
+    /// Encode the elements of a sequence or array
+    fn encode_slice(values: &[Self], ser: &mut Serializer<'_>) -> Result<()>
+        where Self: Sized
+    {
+        values.iter().try_for_each(|value| value.encode(ser))
+    }
+}
+
+/// A type decoding itself from CDR
+pub trait CdrDecode: Sized {
+    fn decode(de: &mut Deserializer<'_>) -> Result<Self>;
+
+    /// Decode exactly `len` elements of a sequence or array
+    fn decode_vec(de: &mut Deserializer<'_>, len: usize) -> Result<Vec<Self>> {
+        // the capacity is limited by the remaining bytes, as the length is not trusted
+        let mut values = Vec::with_capacity(len.min(de.remaining().len()));
+        for _ in 0..len {
+            values.push(Self::decode(de)?);
+        }
+        Ok(values)
+    }
+
+    /// Decode the elements of an array
+    fn decode_array<const N: usize>(de: &mut Deserializer<'_>) -> Result<[Self; N]> {
+        Self::decode_vec(de, N)?.try_into().map_err(|_| Error::Eof)
+    }
+
+    /// Read past the value without keeping it, such as a member preceding those of a view
+    fn skip(de: &mut Deserializer<'_>) -> Result<()> {
+        Self::decode(de).map(drop)
+    }
+
+    /// Read past exactly `len` elements of a sequence or array
+    fn skip_slice(de: &mut Deserializer<'_>, len: usize) -> Result<()> {
+        (0..len).try_for_each(|_| Self::skip(de))
+    }
+}
+
+/// A final or appendable union, encoding its discriminator followed by the element of the
+/// selected case; the discriminator keys the element apart from the union, such as the
+/// parameter identifier of a parameter list
+pub trait CdrUnion: Sized {
+    /// The primitive encoding the discriminator
+    type Discriminator: CdrEncode + CdrDecode;
+
+    /// The case label of the union's case, the first one if several
+    fn discriminator(&self) -> Self::Discriminator;
+
+    /// Encode the element, without the discriminator
+    fn encode_element(&self, ser: &mut Serializer<'_>) -> Result<()>;
+
+    /// Decode the element of the case the discriminator selects
+    fn decode_element(discriminator: Self::Discriminator, de: &mut Deserializer<'_>) -> Result<Self>;
+}
+
+/// Bounds of the encoded size of a type, encoding the value from an aligned position; the
+/// maximum is `None` if unbounded
+pub trait SerializedSize {
+    const MAX_SIZE_XCDR1: Option<usize>;
+    const MIN_SIZE_XCDR1: usize;
+    const MAX_SIZE_XCDR2: Option<usize>;
+    const MIN_SIZE_XCDR2: usize;
+
+    /// The largest size in the encoding, `None` if unbounded
+    fn max_size(encoding: Encoding) -> Option<usize> {
+        match encoding {
+            Encoding::Xcdr1 => Self::MAX_SIZE_XCDR1,
+            Encoding::Xcdr2 => Self::MAX_SIZE_XCDR2,
+        }
+    }
+
+    /// The smallest size in the encoding
+    fn min_size(encoding: Encoding) -> usize {
+        match encoding {
+            Encoding::Xcdr1 => Self::MIN_SIZE_XCDR1,
+            Encoding::Xcdr2 => Self::MIN_SIZE_XCDR2,
+        }
+    }
+}
+
+/// A collection of non-primitive elements, which XCDR2 precedes by a DHEADER; the
+/// counterpart of `rtps_types::xtypes::Delimited`
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Delimited<T>(pub T);
+
+impl<T: CdrEncode> CdrEncode for Delimited<T> {
+    fn encode(&self, ser: &mut Serializer<'_>) -> Result<()> {
+        ser.delimited(|ser| self.0.encode(ser))
+    }
+}
+
+impl<T: CdrDecode> CdrDecode for Delimited<T> {
+    fn decode(de: &mut Deserializer<'_>) -> Result<Delimited<T>> {
+        de.delimited(T::decode).map(Delimited)
+    }
+
+    fn skip(de: &mut Deserializer<'_>) -> Result<()> {
+        de.delimited(T::skip)
+    }
+}
+
+/// Primitives are aligned to their size, a sequence of these once
+macro_rules! primitive {
+    ($($typ:ty),*) => {$(
+        impl CdrEncode for $typ {
+            #[inline]
+            fn encode(&self, ser: &mut Serializer<'_>) -> Result<()> {
+                let bytes = match ser.endianness() {
+                    Endianness::Big => self.to_be_bytes(),
+                    Endianness::Little => self.to_le_bytes(),
+                };
+                ser.write_aligned(&bytes)
+            }
+
+            fn encode_slice(values: &[$typ], ser: &mut Serializer<'_>) -> Result<()> {
+                if values.is_empty() || ser.endianness() != Endianness::native() {
+                    return values.iter().try_for_each(|value| value.encode(ser));
+                }
+                ser.align(mem::size_of::<$typ>())?;
+                // SAFETY: the primitive has no padding, its bytes being in native byte order
+                let bytes = unsafe {
+                    slice::from_raw_parts(values.as_ptr() as *const u8, mem::size_of_val(values))
+                };
+                ser.write(bytes)
+            }
+        }
+
+        impl CdrDecode for $typ {
+            #[inline]
+            fn decode(de: &mut Deserializer<'_>) -> Result<$typ> {
+                let bytes = de.read_aligned(mem::size_of::<$typ>())?.try_into().map_err(|_| Error::Eof)?;
+                Ok(match de.endianness() {
+                    Endianness::Big => <$typ>::from_be_bytes(bytes),
+                    Endianness::Little => <$typ>::from_le_bytes(bytes),
+                })
+            }
+
+            fn decode_vec(de: &mut Deserializer<'_>, len: usize) -> Result<Vec<$typ>> {
+                if len == 0 {
+                    return Ok(Vec::new());
+                }
+                de.align(mem::size_of::<$typ>())?;
+                let size = len.checked_mul(mem::size_of::<$typ>()).ok_or(Error::Eof)?;
+                let bytes = de.read(size)?;
+                if de.endianness() == Endianness::native() {
+                    let mut values = Vec::<$typ>::with_capacity(len);
+                    // SAFETY: the capacity holds `len` values, any bit pattern being valid
+                    unsafe {
+                        ptr::copy_nonoverlapping(bytes.as_ptr(), values.as_mut_ptr() as *mut u8, size);
+                        values.set_len(len);
+                    }
+                    return Ok(values);
+                }
+                let from_bytes = match de.endianness() {
+                    Endianness::Big => <$typ>::from_be_bytes,
+                    Endianness::Little => <$typ>::from_le_bytes,
+                };
+                Ok(bytes.chunks_exact(mem::size_of::<$typ>())
+                    .map(|chunk| from_bytes(chunk.try_into().expect("chunk of the primitive's size")))
+                    .collect())
+            }
+
+            fn decode_array<const N: usize>(de: &mut Deserializer<'_>) -> Result<[$typ; N]> {
+                if N == 0 {
+                    return Ok([0 as $typ; N]);
+                }
+                de.align(mem::size_of::<$typ>())?;
+                let bytes = de.read(N * mem::size_of::<$typ>())?;
+                let from_bytes = match de.endianness() {
+                    Endianness::Big => <$typ>::from_be_bytes,
+                    Endianness::Little => <$typ>::from_le_bytes,
+                };
+                let mut chunks = bytes.chunks_exact(mem::size_of::<$typ>());
+                Ok(core::array::from_fn(|_| {
+                    from_bytes(chunks.next().and_then(|chunk| chunk.try_into().ok()).expect("chunk of the primitive's size"))
+                }))
+            }
+
+            #[inline]
+            fn skip(de: &mut Deserializer<'_>) -> Result<()> {
+                de.read_aligned(mem::size_of::<$typ>()).map(drop)
+            }
+
+            fn skip_slice(de: &mut Deserializer<'_>, len: usize) -> Result<()> {
+                if len == 0 {
+                    return Ok(());
+                }
+                de.align(mem::size_of::<$typ>())?;
+                de.read(len.checked_mul(mem::size_of::<$typ>()).ok_or(Error::Eof)?).map(drop)
+            }
+        }
+    )*};
+}
+
+primitive!(u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, f32, f64);
+
+impl CdrEncode for bool {
+    fn encode(&self, ser: &mut Serializer<'_>) -> Result<()> {
+        ser.write(&[u8::from(*self)])
+    }
+}
+
+impl CdrDecode for bool {
+    fn decode(de: &mut Deserializer<'_>) -> Result<bool> {
+        de.read_bool()
+    }
+}
+
+/// `char` is a single octet, holding Latin-1
+impl CdrEncode for char {
+    fn encode(&self, ser: &mut Serializer<'_>) -> Result<()> {
+        let code = u32::from(*self);
+        let octet = u8::try_from(code).map_err(|_| Error::InvalidChar(code))?;
+        ser.write(&[octet])
+    }
+}
+
+impl CdrDecode for char {
+    fn decode(de: &mut Deserializer<'_>) -> Result<char> {
+        Ok(char::from(de.read_u8()?))
+    }
+}
+
+impl CdrEncode for str {
+    fn encode(&self, ser: &mut Serializer<'_>) -> Result<()> {
+        ser.write_str(self)
+    }
+}
+
+impl CdrEncode for String {
+    fn encode(&self, ser: &mut Serializer<'_>) -> Result<()> {
+        ser.write_str(self)
+    }
+}
+
+impl CdrDecode for String {
+    fn decode(de: &mut Deserializer<'_>) -> Result<String> {
+        de.read_str().map(str::to_owned)
+    }
+
+    fn skip(de: &mut Deserializer<'_>) -> Result<()> {
+        de.skip_str()
+    }
+}
+
+/// Sequences are prefixed by their element count
+impl<T: CdrEncode> CdrEncode for [T] {
+    fn encode(&self, ser: &mut Serializer<'_>) -> Result<()> {
+        ser.write_len(self.len())?;
+        T::encode_slice(self, ser)
+    }
+}
+
+impl<T: CdrEncode> CdrEncode for Vec<T> {
+    fn encode(&self, ser: &mut Serializer<'_>) -> Result<()> {
+        self.as_slice().encode(ser)
+    }
+}
+
+impl<T: CdrDecode> CdrDecode for Vec<T> {
+    fn decode(de: &mut Deserializer<'_>) -> Result<Vec<T>> {
+        let len = de.read_len()?;
+        T::decode_vec(de, len)
+    }
+
+    fn skip(de: &mut Deserializer<'_>) -> Result<()> {
+        let len = de.read_len()?;
+        T::skip_slice(de, len)
+    }
+}
+
+/// Arrays have a fixed length, which is not encoded
+impl<T: CdrEncode, const N: usize> CdrEncode for [T; N] {
+    fn encode(&self, ser: &mut Serializer<'_>) -> Result<()> {
+        T::encode_slice(self, ser)
+    }
+}
+
+impl<T: CdrDecode, const N: usize> CdrDecode for [T; N] {
+    fn decode(de: &mut Deserializer<'_>) -> Result<[T; N]> {
+        T::decode_array(de)
+    }
+
+    fn skip(de: &mut Deserializer<'_>) -> Result<()> {
+        T::skip_slice(de, N)
+    }
+}
+
+/// Options are preceded by a `boolean` telling their presence, as the optional members of
+/// XCDR2 are; `Serializer::optional` precedes those of XCDR1 by a parameter header
+impl<T: CdrEncode> CdrEncode for Option<T> {
+    fn encode(&self, ser: &mut Serializer<'_>) -> Result<()> {
+        self.is_some().encode(ser)?;
+        match self {
+            Some(value) => value.encode(ser),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<T: CdrDecode> CdrDecode for Option<T> {
+    fn decode(de: &mut Deserializer<'_>) -> Result<Option<T>> {
+        if de.read_bool()? {
+            T::decode(de).map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn skip(de: &mut Deserializer<'_>) -> Result<()> {
+        if de.read_bool()? {
+            T::skip(de)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Tuples encode their elements in order, like structs
+macro_rules! tuple {
+    ($($name:ident)+) => {
+        impl<$($name: CdrEncode),+> CdrEncode for ($($name,)+) {
+            #[allow(non_snake_case)]
+            fn encode(&self, ser: &mut Serializer<'_>) -> Result<()> {
+                let ($($name,)+) = self;
+                $($name.encode(ser)?;)+
+                Ok(())
+            }
+        }
+
+        impl<$($name: CdrDecode),+> CdrDecode for ($($name,)+) {
+            fn decode(de: &mut Deserializer<'_>) -> Result<($($name,)+)> {
+                Ok(($($name::decode(de)?,)+))
+            }
+        }
+    };
+}
+
+tuple!(A);
+tuple!(A B);
+tuple!(A B C);
+tuple!(A B C D);
+tuple!(A B C D E);
+tuple!(A B C D E F);
+
+impl<T: CdrEncode + ?Sized> CdrEncode for &T {
+    fn encode(&self, ser: &mut Serializer<'_>) -> Result<()> {
+        (**self).encode(ser)
+    }
+}
+
+impl<T: CdrEncode + ?Sized> CdrEncode for Box<T> {
+    fn encode(&self, ser: &mut Serializer<'_>) -> Result<()> {
+        (**self).encode(ser)
+    }
+}
+
+impl<T: CdrDecode> CdrDecode for Box<T> {
+    fn decode(de: &mut Deserializer<'_>) -> Result<Box<T>> {
+        T::decode(de).map(Box::new)
+    }
+
+    fn skip(de: &mut Deserializer<'_>) -> Result<()> {
+        T::skip(de)
+    }
+}
+
+#[cfg(target_has_atomic = "ptr")]
+impl<T: CdrEncode + ?Sized> CdrEncode for Arc<T> {
+    fn encode(&self, ser: &mut Serializer<'_>) -> Result<()> {
+        (**self).encode(ser)
+    }
+}
+
+#[cfg(target_has_atomic = "ptr")]
+impl<T: CdrDecode> CdrDecode for Arc<T> {
+    fn decode(de: &mut Deserializer<'_>) -> Result<Arc<T>> {
+        T::decode(de).map(Arc::new)
+    }
+
+    fn skip(de: &mut Deserializer<'_>) -> Result<()> {
+        T::skip(de)
+    }
+}
+
+/// The length of a bounded string or sequence, failing if exceeding the bound
+fn read_bounded_len(de: &mut Deserializer<'_>, bound: usize) -> Result<usize> {
+    match de.read_len()? {
+        len if len > bound => Err(Error::from(BoundError { bound, len })),
+        len => Ok(len),
+    }
+}
+
+impl<const N: usize> CdrEncode for BoundedString<N> {
+    fn encode(&self, ser: &mut Serializer<'_>) -> Result<()> {
+        ser.write_str(self)
+    }
+}
+
+impl<const N: usize> CdrDecode for BoundedString<N> {
+    fn decode(de: &mut Deserializer<'_>) -> Result<BoundedString<N>> {
+        Ok(BoundedString::try_from(de.read_str()?)?)
+    }
+}
+
+impl<const N: usize> CdrEncode for BoundedWideString<N> {
+    fn encode(&self, ser: &mut Serializer<'_>) -> Result<()> {
+        ser.write_str(self)
+    }
+}
+
+impl<const N: usize> CdrDecode for BoundedWideString<N> {
+    fn decode(de: &mut Deserializer<'_>) -> Result<BoundedWideString<N>> {
+        Ok(BoundedWideString::try_from(de.read_str()?)?)
+    }
+}
+
+impl<T: CdrEncode, const N: usize> CdrEncode for BoundedVec<T, N> {
+    fn encode(&self, ser: &mut Serializer<'_>) -> Result<()> {
+        self.as_slice().encode(ser)
+    }
+}
+
+/// The bound is verified before decoding the elements
+impl<T: CdrDecode, const N: usize> CdrDecode for BoundedVec<T, N> {
+    fn decode(de: &mut Deserializer<'_>) -> Result<BoundedVec<T, N>> {
+        let len = read_bounded_len(de, N)?;
+        Ok(BoundedVec::try_from(T::decode_vec(de, len)?)?)
+    }
+
+    fn skip(de: &mut Deserializer<'_>) -> Result<()> {
+        let len = read_bounded_len(de, N)?;
+        T::skip_slice(de, len)
+    }
+}
+
+impl<const N: usize> CdrEncode for FixedString<N> {
+    fn encode(&self, ser: &mut Serializer<'_>) -> Result<()> {
+        ser.write_str(self)
+    }
+}
+
+impl<const N: usize> CdrDecode for FixedString<N> {
+    fn decode(de: &mut Deserializer<'_>) -> Result<FixedString<N>> {
+        Ok(FixedString::try_from(de.read_str()?)?)
+    }
+}
+
+impl<T: CdrEncode, const N: usize, const P: usize> CdrEncode for FixedVec<T, N, P> {
+    fn encode(&self, ser: &mut Serializer<'_>) -> Result<()> {
+        self.as_slice().encode(ser)
+    }
+}
+
+/// The bound is verified before decoding the elements
+impl<T: CdrDecode + Default, const N: usize, const P: usize> CdrDecode for FixedVec<T, N, P> {
+    fn decode(de: &mut Deserializer<'_>) -> Result<FixedVec<T, N, P>> {
+        let len = read_bounded_len(de, N)?;
+        let mut vec = FixedVec::new();
+        for _ in 0..len {
+            vec.push(T::decode(de)?)?;
+        }
+        Ok(vec)
+    }
+
+    fn skip(de: &mut Deserializer<'_>) -> Result<()> {
+        let len = read_bounded_len(de, N)?;
+        T::skip_slice(de, len)
+    }
+}
+
+impl CdrEncode for Char {
+    fn encode(&self, ser: &mut Serializer<'_>) -> Result<()> {
+        self.0.encode(ser)
+    }
+}
+
+impl CdrDecode for Char {
+    fn decode(de: &mut Deserializer<'_>) -> Result<Char> {
+        u8::decode(de).map(Char)
+    }
+}
+
+impl CdrEncode for WChar {
+    fn encode(&self, ser: &mut Serializer<'_>) -> Result<()> {
+        self.0.encode(ser)
+    }
+}
+
+impl CdrDecode for WChar {
+    fn decode(de: &mut Deserializer<'_>) -> Result<WChar> {
+        u16::decode(de).map(WChar)
+    }
+}
+
+/// `wstring` is a sequence of UTF-16 code units
+impl CdrEncode for WString {
+    fn encode(&self, ser: &mut Serializer<'_>) -> Result<()> {
+        self.as_units().encode(ser)
+    }
+}
+
+impl CdrDecode for WString {
+    fn decode(de: &mut Deserializer<'_>) -> Result<WString> {
+        Vec::<u16>::decode(de).map(WString::from_units)
+    }
+
+    fn skip(de: &mut Deserializer<'_>) -> Result<()> {
+        Vec::<u16>::skip(de)
+    }
+}
+
+impl<const N: usize> CdrEncode for BoundedWString<N> {
+    fn encode(&self, ser: &mut Serializer<'_>) -> Result<()> {
+        self.as_wstring().encode(ser)
+    }
+}
+
+impl<const N: usize> CdrDecode for BoundedWString<N> {
+    fn decode(de: &mut Deserializer<'_>) -> Result<BoundedWString<N>> {
+        let len = read_bounded_len(de, N)?;
+        let units = u16::decode_vec(de, len)?;
+        Ok(BoundedWString::try_from(WString::from_units(units))?)
+    }
+}
+
+/// `long double` is encoded by its 16 bytes
+impl CdrEncode for LongDouble {
+    fn encode(&self, ser: &mut Serializer<'_>) -> Result<()> {
+        self.to_bits().encode(ser)
+    }
+}
+
+impl CdrDecode for LongDouble {
+    fn decode(de: &mut Deserializer<'_>) -> Result<LongDouble> {
+        u128::decode(de).map(LongDouble::from_bits)
+    }
+}
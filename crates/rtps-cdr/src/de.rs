@@ -0,0 +1,626 @@
+// Copyright (C) 2019  Frank Rehberger
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>
+
+use core::convert::TryInto;
+use serde::de::{self, DeserializeSeed, IntoDeserializer, Visitor};
+use rtps_types::xtypes::{DELIMITED, MEMBER_ID_MASK, MUST_UNDERSTAND, MUTABLE, OPTIONAL};
+use crate::ser::{PID_EXTENDED, PID_IGNORE, PID_LIST_END, PID_MASK, PID_MUST_UNDERSTAND};
+use crate::{CdrDecode, Encoding, Endianness, Error, Result};
+
+/// Deserializer reading CDR from a slice, aligning relative to its first byte
+#[derive(Clone)]
+pub struct Deserializer<'de> {
+    input: &'de [u8],
+    endianness: Endianness,
+    encoding: Encoding,
+    pos: usize,
+    origin: usize,
+    /// End of the delimited value or member being read
+    limit: usize,
+    /// The next map holds the members of a mutable type
+    mutable: bool,
+    /// End of the delimited value, if the next struct is its appendable type
+    struct_end: Option<usize>,
+    /// Reading the value of a member, which may be skipped
+    in_member: bool,
+    /// End of the optional member of XCDR1, if the next option is its value
+    optional_end: Option<usize>,
+}
+
+/// Read the primitive in the byte order of the deserializer
+macro_rules! read {
+    ($de:expr, $typ:ty) => {{
+        let bytes = $de.read_aligned(core::mem::size_of::<$typ>())?
+            .try_into()
+            .map_err(|_| Error::Eof)?;
+        match $de.endianness {
+            Endianness::Big => <$typ>::from_be_bytes(bytes),
+            Endianness::Little => <$typ>::from_le_bytes(bytes),
+        }
+    }};
+}
+
+impl<'de> Deserializer<'de> {
+    /// Deserializer of XCDR1
+    pub fn new(input: &'de [u8], endianness: Endianness) -> Deserializer<'de> {
+        Deserializer::with_encoding(input, endianness, Encoding::Xcdr1)
+    }
+
+    pub fn with_encoding(input: &'de [u8], endianness: Endianness, encoding: Encoding) -> Deserializer<'de> {
+        Deserializer {
+            input,
+            endianness,
+            encoding,
+            pos: 0,
+            origin: 0,
+            limit: input.len(),
+            mutable: false,
+            struct_end: None,
+            in_member: false,
+            optional_end: None,
+        }
+    }
+
+    /// The number of bytes read
+    pub fn position(&self) -> usize {
+        self.pos
+    }
+
+    /// The bytes not read yet
+    pub fn remaining(&self) -> &'de [u8] {
+        &self.input[self.pos..]
+    }
+
+    /// Byte order of the encoded primitives
+    #[inline]
+    pub fn endianness(&self) -> Endianness {
+        self.endianness
+    }
+
+    /// Version of the extended CDR being decoded
+    pub fn encoding(&self) -> Encoding {
+        self.encoding
+    }
+
+    /// The deserializer continuing at `pos`, a position this one has read up to
+    pub(crate) fn at(&self, pos: usize) -> Deserializer<'de> {
+        Deserializer { pos, ..self.clone() }
+    }
+
+    #[inline]
+    pub(crate) fn read(&mut self, len: usize) -> Result<&'de [u8]> {
+        let end = self.pos.checked_add(len).filter(|&end| end <= self.limit).ok_or(Error::Eof)?;
+        let bytes = &self.input[self.pos..end];
+        self.pos = end;
+        Ok(bytes)
+    }
+
+    /// Skip the padding up to the alignment of a primitive of `size` bytes
+    #[inline]
+    pub(crate) fn align(&mut self, size: usize) -> Result<()> {
+        let align = size.min(self.encoding.max_align());
+        let pad = (align - (self.pos - self.origin) % align) % align;
+        self.read(pad).map(|_| ())
+    }
+
+    #[inline]
+    pub(crate) fn read_aligned(&mut self, size: usize) -> Result<&'de [u8]> {
+        self.align(size)?;
+        self.read(size)
+    }
+
+    pub(crate) fn read_u8(&mut self) -> Result<u8> {
+        Ok(self.read(1)?[0])
+    }
+
+    pub(crate) fn read_bool(&mut self) -> Result<bool> {
+        match self.read_u8()? {
+            0 => Ok(false),
+            1 => Ok(true),
+            val => Err(Error::InvalidBool(val)),
+        }
+    }
+
+    pub(crate) fn read_len(&mut self) -> Result<usize> {
+        Ok(read!(self, u32) as usize)
+    }
+
+    /// The end of a value of `len` bytes starting at the current position
+    fn end_of(&self, len: usize) -> Result<usize> {
+        self.pos.checked_add(len).filter(|&end| end <= self.limit).ok_or(Error::Eof)
+    }
+
+    /// Read the value up to `end`, then continue at `end`
+    fn read_until<T>(&mut self, end: usize, read: impl FnOnce(&mut Self) -> Result<T>) -> Result<T> {
+        let limit = core::mem::replace(&mut self.limit, end);
+        let value = read(self);
+        self.limit = limit;
+        self.pos = end;
+        value
+    }
+
+    /// Read the value preceded by a DHEADER, if XCDR2
+    fn read_delimited<T>(&mut self, appendable: bool, read: impl FnOnce(&mut Self) -> Result<T>) -> Result<T> {
+        match self.encoding {
+            Encoding::Xcdr1 => read(self),
+            Encoding::Xcdr2 => {
+                let len = self.read_len()?;
+                let end = self.end_of(len)?;
+                self.struct_end = if appendable { Some(end) } else { None };
+                self.read_until(end, read)
+            }
+        }
+    }
+
+    /// The parameter header of an optional member of XCDR1, the member header and the end
+    /// of its value
+    fn read_parameter_header(&mut self) -> Result<(u32, usize)> {
+        self.read_member_header()?.ok_or(Error::MissingParameterHeader)
+    }
+
+    /// Decode the value by `decode`, preceded by a DHEADER if XCDR2; the bytes following
+    /// the value within its length are skipped
+    pub fn delimited<T>(&mut self, decode: impl FnOnce(&mut Self) -> Result<T>) -> Result<T> {
+        self.read_delimited(false, decode)
+    }
+
+    /// Decode a member of an appendable struct, which takes its default value past the end
+    /// of the struct, if encoded by an earlier version of the type
+    pub fn appended<T: CdrDecode + Default>(&mut self) -> Result<T> {
+        self.appended_with(T::decode)
+    }
+
+    /// Decode a member of an appendable struct by `decode`, see `appended()`
+    pub fn appended_with<T: Default>(&mut self, decode: impl FnOnce(&mut Self) -> Result<T>) -> Result<T> {
+        if self.encoding == Encoding::Xcdr2 && self.pos >= self.limit {
+            Ok(T::default())
+        } else {
+            decode(self)
+        }
+    }
+
+    /// Decode an optional member of a final or appendable type: XCDR1 precedes it by a
+    /// parameter header, of length 0 if absent, XCDR2 by a `boolean` telling its presence
+    pub fn optional<T: CdrDecode>(&mut self) -> Result<Option<T>> {
+        self.read_optional(T::decode)
+    }
+
+    /// Read past an optional member, see `optional()`
+    pub fn skip_optional<T: CdrDecode>(&mut self) -> Result<()> {
+        self.read_optional(T::skip).map(drop)
+    }
+
+    /// Read an optional member by `read`, if present
+    fn read_optional<T>(&mut self, read: impl FnOnce(&mut Self) -> Result<T>) -> Result<Option<T>> {
+        match self.encoding {
+            Encoding::Xcdr1 => {
+                let (_, end) = self.read_parameter_header()?;
+                if self.pos == end {
+                    Ok(None)
+                } else {
+                    self.read_member(end, read).map(Some)
+                }
+            }
+            Encoding::Xcdr2 => {
+                if self.read_bool()? {
+                    read(self).map(Some)
+                } else {
+                    Ok(None)
+                }
+            }
+        }
+    }
+
+    /// Decode the members of a mutable type, `decode` being passed the member header of
+    /// each and returning whether it knows the member; unknown members are skipped unless
+    /// flagged must-understand
+    pub fn members(&mut self, mut decode: impl FnMut(&mut Self, u32) -> Result<bool>) -> Result<()> {
+        self.delimited(|de| {
+            while let Some((header, end)) = de.read_member_header()? {
+                if !de.read_member(end, |de| decode(de, header))? && header & MUST_UNDERSTAND != 0 {
+                    return Err(Error::UnknownMember(header & MEMBER_ID_MASK));
+                }
+            }
+            Ok(())
+        })
+    }
+
+    /// Read the value of a member up to its end, relative to which XCDR1 aligns
+    fn read_member<T>(&mut self, end: usize, read: impl FnOnce(&mut Self) -> Result<T>) -> Result<T> {
+        let origin = self.origin;
+        if self.encoding == Encoding::Xcdr1 {
+            self.origin = self.pos;
+        }
+        self.in_member = true;
+        let value = self.read_until(end, read);
+        self.in_member = false;
+        self.origin = origin;
+        value
+    }
+
+    /// The header of the next member of a mutable type and the end of its value, or `None`
+    /// past the last member
+    fn read_member_header(&mut self) -> Result<Option<(u32, usize)>> {
+        match self.encoding {
+            Encoding::Xcdr2 => {
+                if self.pos >= self.limit {
+                    return Ok(None);
+                }
+                let emheader: u32 = read!(self, u32);
+                let len = match emheader >> 28 & 0x7 {
+                    code @ 0..=3 => 1 << code,
+                    4 => self.read_len()?,
+                    // the length is the first word of the value, counting elements
+                    code => {
+                        let count = self.read_len()?;
+                        self.pos -= 4;
+                        let size = [1, 4, 8][code as usize - 5];
+                        count.checked_mul(size).and_then(|len| len.checked_add(4)).ok_or(Error::Eof)?
+                    }
+                };
+                let header = (emheader & MUST_UNDERSTAND) | (emheader & MEMBER_ID_MASK);
+                Ok(Some((header, self.end_of(len)?)))
+            }
+            Encoding::Xcdr1 => loop {
+                // parameter headers are aligned to 4 bytes, as the serializer writes them
+                self.align(4)?;
+                let pid: u16 = read!(self, u16);
+                let len: u16 = read!(self, u16);
+                let header = match pid & PID_MASK {
+                    PID_LIST_END => return Ok(None),
+                    PID_IGNORE => {
+                        self.read(usize::from(len))?;
+                        continue;
+                    }
+                    PID_EXTENDED => {
+                        let header: u32 = read!(self, u32);
+                        let len = read!(self, u32) as usize;
+                        return Ok(Some((header & (MUST_UNDERSTAND | MEMBER_ID_MASK), self.end_of(len)?)));
+                    }
+                    id if pid & PID_MUST_UNDERSTAND != 0 => MUST_UNDERSTAND | u32::from(id),
+                    id => u32::from(id),
+                };
+                return Ok(Some((header, self.end_of(usize::from(len))?)));
+            },
+        }
+    }
+
+    /// Read past a string, not verifying its contents
+    pub(crate) fn skip_str(&mut self) -> Result<()> {
+        let len = self.read_len()?;
+        self.read(len).map(drop)
+    }
+
+    /// A string without its terminating NUL
+    pub(crate) fn read_str(&mut self) -> Result<&'de str> {
+        let len = self.read_len()?;
+        if len == 0 {
+            // tolerated for an empty string
+            return Ok("");
+        }
+        let bytes = self.read(len)?;
+        match bytes.split_last() {
+            Some((0, text)) => core::str::from_utf8(text).map_err(|_| Error::InvalidUtf8),
+            _ => Err(Error::MissingNul),
+        }
+    }
+}
+
+impl<'de> de::Deserializer<'de> for &mut Deserializer<'de> {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, _visitor: V) -> Result<V::Value> {
+        Err(Error::NotSelfDescribing)
+    }
+
+    fn deserialize_bool<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_bool(self.read_bool()?)
+    }
+
+    fn deserialize_i8<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_i8(self.read_u8()? as i8)
+    }
+
+    fn deserialize_i16<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_i16(read!(self, i16))
+    }
+
+    fn deserialize_i32<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_i32(read!(self, i32))
+    }
+
+    fn deserialize_i64<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_i64(read!(self, i64))
+    }
+
+    fn deserialize_i128<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_i128(read!(self, i128))
+    }
+
+    fn deserialize_u8<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_u8(self.read_u8()?)
+    }
+
+    fn deserialize_u16<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_u16(read!(self, u16))
+    }
+
+    fn deserialize_u32<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_u32(read!(self, u32))
+    }
+
+    fn deserialize_u64<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_u64(read!(self, u64))
+    }
+
+    fn deserialize_u128<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_u128(read!(self, u128))
+    }
+
+    fn deserialize_f32<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_f32(read!(self, f32))
+    }
+
+    fn deserialize_f64<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_f64(read!(self, f64))
+    }
+
+    /// `char` is a single octet, holding Latin-1
+    fn deserialize_char<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_char(char::from(self.read_u8()?))
+    }
+
+    fn deserialize_str<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_borrowed_str(self.read_str()?)
+    }
+
+    fn deserialize_string<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.deserialize_str(visitor)
+    }
+
+    fn deserialize_bytes<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        let len = self.read_len()?;
+        visitor.visit_borrowed_bytes(self.read(len)?)
+    }
+
+    fn deserialize_byte_buf<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.deserialize_bytes(visitor)
+    }
+
+    /// Options are preceded by a `boolean` telling their presence, optional members of
+    /// XCDR1 by a parameter header
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.struct_end = None;
+        if let Some(end) = self.optional_end.take() {
+            if self.pos == end {
+                visitor.visit_none()
+            } else {
+                self.read_member(end, |de| visitor.visit_some(de))
+            }
+        } else if self.read_bool()? {
+            visitor.visit_some(self)
+        } else {
+            visitor.visit_none()
+        }
+    }
+
+    fn deserialize_unit<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_unit_struct<V: Visitor<'de>>(self, _name: &'static str, visitor: V) -> Result<V::Value> {
+        visitor.visit_unit()
+    }
+
+    /// The newtypes of `rtps_types::xtypes` control the encoding of extensible types
+    fn deserialize_newtype_struct<V: Visitor<'de>>(self, name: &'static str, visitor: V)
+                                                   -> Result<V::Value> {
+        self.struct_end = None;
+        if name == DELIMITED {
+            self.read_delimited(true, |de| visitor.visit_newtype_struct(de))
+        } else if name == MUTABLE {
+            self.mutable = true;
+            let value = self.read_delimited(false, |de| visitor.visit_newtype_struct(de));
+            self.mutable = false;
+            value
+        } else if name == OPTIONAL {
+            let (header, end) = match self.encoding {
+                Encoding::Xcdr1 => self.read_parameter_header().map(|(header, end)| (header, Some(end)))?,
+                Encoding::Xcdr2 => (0, None),
+            };
+            visitor.visit_seq(Optional { de: self, header, end, index: 0 })
+        } else {
+            visitor.visit_newtype_struct(self)
+        }
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.struct_end = None;
+        let len = self.read_len()?;
+        visitor.visit_seq(Access { de: self, len, end: None })
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(self, len: usize, visitor: V) -> Result<V::Value> {
+        self.struct_end = None;
+        visitor.visit_seq(Access { de: self, len, end: None })
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(self, _name: &'static str, len: usize, visitor: V)
+                                                 -> Result<V::Value> {
+        self.deserialize_tuple(len, visitor)
+    }
+
+    fn deserialize_map<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.struct_end = None;
+        if core::mem::replace(&mut self.mutable, false) {
+            return visitor.visit_map(Members { de: self, end: 0 });
+        }
+        let len = self.read_len()?;
+        visitor.visit_map(Access { de: self, len, end: None })
+    }
+
+    /// The members of an appendable struct not encoded take their default value
+    fn deserialize_struct<V: Visitor<'de>>(self, _name: &'static str, fields: &'static [&'static str],
+                                           visitor: V) -> Result<V::Value> {
+        let end = self.struct_end.take();
+        visitor.visit_seq(Access { de: self, len: fields.len(), end })
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(self, _name: &'static str, _variants: &'static [&'static str],
+                                         visitor: V) -> Result<V::Value> {
+        self.struct_end = None;
+        visitor.visit_enum(self)
+    }
+
+    /// The index of a variant
+    fn deserialize_identifier<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.deserialize_u32(visitor)
+    }
+
+    /// Only the value of a member of a mutable type can be skipped
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        if self.in_member {
+            self.pos = self.limit;
+            visitor.visit_unit()
+        } else {
+            Err(Error::NotSelfDescribing)
+        }
+    }
+
+    fn is_human_readable(&self) -> bool {
+        false
+    }
+}
+
+/// The elements of a sequence, tuple, struct or map of known length, ending early at the
+/// end of an appendable struct
+struct Access<'a, 'de> {
+    de: &'a mut Deserializer<'de>,
+    len: usize,
+    end: Option<usize>,
+}
+
+impl<'de, 'a> de::SeqAccess<'de> for Access<'a, 'de> {
+    type Error = Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(&mut self, seed: T) -> Result<Option<T::Value>> {
+        if self.len == 0 || self.end.is_some_and(|end| self.de.pos >= end) {
+            return Ok(None);
+        }
+        self.len -= 1;
+        seed.deserialize(&mut *self.de).map(Some)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.len)
+    }
+}
+
+impl<'de, 'a> de::MapAccess<'de> for Access<'a, 'de> {
+    type Error = Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(&mut self, seed: K) -> Result<Option<K::Value>> {
+        if self.len == 0 {
+            return Ok(None);
+        }
+        self.len -= 1;
+        seed.deserialize(&mut *self.de).map(Some)
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value> {
+        seed.deserialize(&mut *self.de)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.len)
+    }
+}
+
+/// The members of a mutable type, keyed by their member header
+struct Members<'a, 'de> {
+    de: &'a mut Deserializer<'de>,
+    end: usize,
+}
+
+impl<'de, 'a> de::MapAccess<'de> for Members<'a, 'de> {
+    type Error = Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(&mut self, seed: K) -> Result<Option<K::Value>> {
+        match self.de.read_member_header()? {
+            Some((header, end)) => {
+                self.end = end;
+                seed.deserialize(IntoDeserializer::<Error>::into_deserializer(header)).map(Some)
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value> {
+        self.de.read_member(self.end, |de| seed.deserialize(&mut *de))
+    }
+}
+
+/// The member header and option of an optional member, XCDR1 reading the header and the
+/// end of the value from the parameter header; XCDR2 encodes no header, yielding 0
+struct Optional<'a, 'de> {
+    de: &'a mut Deserializer<'de>,
+    header: u32,
+    end: Option<usize>,
+    /// The number of elements read
+    index: usize,
+}
+
+impl<'de, 'a> de::SeqAccess<'de> for Optional<'a, 'de> {
+    type Error = Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(&mut self, seed: T) -> Result<Option<T::Value>> {
+        self.index += 1;
+        match self.index {
+            1 => seed.deserialize(IntoDeserializer::<Error>::into_deserializer(self.header)).map(Some),
+            2 => {
+                self.de.optional_end = self.end;
+                seed.deserialize(&mut *self.de).map(Some)
+            }
+            _ => Ok(None),
+        }
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(2usize.saturating_sub(self.index))
+    }
+}
+
+/// The variant index as `unsigned long`, followed by the members of the variant
+impl<'de> de::EnumAccess<'de> for &mut Deserializer<'de> {
+    type Error = Error;
+    type Variant = Self;
+
+    fn variant_seed<V: DeserializeSeed<'de>>(self, seed: V) -> Result<(V::Value, Self)> {
+        let index: u32 = read!(self, u32);
+        let value = seed.deserialize(IntoDeserializer::<Error>::into_deserializer(index))?;
+        Ok((value, self))
+    }
+}
+
+impl<'de> de::VariantAccess<'de> for &mut Deserializer<'de> {
+    type Error = Error;
+
+    fn unit_variant(self) -> Result<()> {
+        Ok(())
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(self, seed: T) -> Result<T::Value> {
+        seed.deserialize(self)
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(self, len: usize, visitor: V) -> Result<V::Value> {
+        de::Deserializer::deserialize_tuple(self, len, visitor)
+    }
+
+    fn struct_variant<V: Visitor<'de>>(self, fields: &'static [&'static str], visitor: V)
+                                       -> Result<V::Value> {
+        de::Deserializer::deserialize_tuple(self, fields.len(), visitor)
+    }
+}
@@ -0,0 +1,182 @@
+// Copyright (C) 2019  Frank Rehberger
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>
+
+//! Encapsulation header of a serialized payload
+//!
+//! The payload starts with the encapsulation identifier and the options, two octets each,
+//! followed by the encoded value and padding up to a multiple of 4 bytes. The identifier
+//! selects the byte order and the encoding, which depends on the extensibility of the
+//! type; the two least significant bits of the options hold the length of the padding.
+use alloc::vec::Vec;
+use core::fmt;
+use rtps_types::xtypes::Extensibility;
+use crate::{Encoding, Endianness, Error, Result};
+
+/// Mask of the options holding the length of the padding
+const PADDING_MASK: u16 = 0x0003;
+
+/// Encapsulation identifier, as defined by DDS-XTypes
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum EncapsulationKind {
+    /// Final and appendable types in XCDR1
+    CdrBe,
+    CdrLe,
+    /// Mutable types in XCDR1, as parameter list
+    PlCdrBe,
+    PlCdrLe,
+    /// Final types in XCDR2
+    Cdr2Be,
+    Cdr2Le,
+    /// Appendable types in XCDR2, preceded by a DHEADER
+    DCdr2Be,
+    DCdr2Le,
+    /// Mutable types in XCDR2, preceded by a DHEADER
+    PlCdr2Be,
+    PlCdr2Le,
+}
+
+impl EncapsulationKind {
+    /// The encapsulation of a type of the extensibility
+    pub fn new(extensibility: Extensibility, endianness: Endianness, encoding: Encoding) -> EncapsulationKind {
+        use self::EncapsulationKind::*;
+        let (big, little) = match (encoding, extensibility) {
+            (Encoding::Xcdr1, Extensibility::Mutable) => (PlCdrBe, PlCdrLe),
+            (Encoding::Xcdr1, _) => (CdrBe, CdrLe),
+            (Encoding::Xcdr2, Extensibility::Final) => (Cdr2Be, Cdr2Le),
+            (Encoding::Xcdr2, Extensibility::Appendable) => (DCdr2Be, DCdr2Le),
+            (Encoding::Xcdr2, Extensibility::Mutable) => (PlCdr2Be, PlCdr2Le),
+        };
+        match endianness {
+            Endianness::Big => big,
+            Endianness::Little => little,
+        }
+    }
+
+    /// The encapsulation of the identifier
+    pub fn from_id(id: u16) -> Result<EncapsulationKind> {
+        use self::EncapsulationKind::*;
+        Ok(match id {
+            0x0000 => CdrBe,
+            0x0001 => CdrLe,
+            0x0002 => PlCdrBe,
+            0x0003 => PlCdrLe,
+            0x0010 => Cdr2Be,
+            0x0011 => Cdr2Le,
+            0x0012 => PlCdr2Be,
+            0x0013 => PlCdr2Le,
+            0x0014 => DCdr2Be,
+            0x0015 => DCdr2Le,
+            _ => return Err(Error::UnknownEncapsulation(id)),
+        })
+    }
+
+    /// The encapsulation identifier
+    pub fn id(self) -> u16 {
+        use self::EncapsulationKind::*;
+        match self {
+            CdrBe => 0x0000,
+            CdrLe => 0x0001,
+            PlCdrBe => 0x0002,
+            PlCdrLe => 0x0003,
+            Cdr2Be => 0x0010,
+            Cdr2Le => 0x0011,
+            PlCdr2Be => 0x0012,
+            PlCdr2Le => 0x0013,
+            DCdr2Be => 0x0014,
+            DCdr2Le => 0x0015,
+        }
+    }
+
+    /// Byte order of the encoded primitives, the least significant bit of the identifier
+    pub fn endianness(self) -> Endianness {
+        if self.id() & 1 == 0 {
+            Endianness::Big
+        } else {
+            Endianness::Little
+        }
+    }
+
+    /// Version of the extended CDR, XCDR2 for the identifiers from 0x0010
+    pub fn encoding(self) -> Encoding {
+        if self.id() < 0x0010 {
+            Encoding::Xcdr1
+        } else {
+            Encoding::Xcdr2
+        }
+    }
+
+    /// Whether the encapsulation encodes types of the extensibility
+    pub fn encodes(self, extensibility: Extensibility) -> bool {
+        EncapsulationKind::new(extensibility, self.endianness(), self.encoding()) == self
+    }
+}
+
+impl fmt::Display for EncapsulationKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use self::EncapsulationKind::*;
+        f.write_str(match self {
+            CdrBe => "CDR_BE",
+            CdrLe => "CDR_LE",
+            PlCdrBe => "PL_CDR_BE",
+            PlCdrLe => "PL_CDR_LE",
+            Cdr2Be => "CDR2_BE",
+            Cdr2Le => "CDR2_LE",
+            DCdr2Be => "D_CDR2_BE",
+            DCdr2Le => "D_CDR2_LE",
+            PlCdr2Be => "PL_CDR2_BE",
+            PlCdr2Le => "PL_CDR2_LE",
+        })
+    }
+}
+
+/// Encapsulation header preceding the encoded value
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Encapsulation {
+    pub kind: EncapsulationKind,
+    /// The options, big endian as the identifier
+    pub options: u16,
+}
+
+impl Encapsulation {
+    /// Length of the header
+    pub const LEN: usize = 4;
+
+    /// Header without options
+    pub fn new(kind: EncapsulationKind) -> Encapsulation {
+        Encapsulation { kind, options: 0 }
+    }
+
+    /// The number of padding bytes ending the payload
+    pub fn padding(&self) -> usize {
+        usize::from(self.options & PADDING_MASK)
+    }
+
+    /// Read the header of the payload, returning the encoded value without the padding
+    pub fn read(payload: &[u8]) -> Result<(Encapsulation, &[u8])> {
+        if payload.len() < Encapsulation::LEN {
+            return Err(Error::Eof);
+        }
+        let kind = EncapsulationKind::from_id(u16::from_be_bytes([payload[0], payload[1]]))?;
+        let header = Encapsulation { kind, options: u16::from_be_bytes([payload[2], payload[3]]) };
+        let end = payload.len().checked_sub(header.padding())
+            .filter(|&end| end >= Encapsulation::LEN)
+            .ok_or(Error::Eof)?;
+        Ok((header, &payload[Encapsulation::LEN..end]))
+    }
+
+    /// Write the payload of the value encoded by `encode`, padded to a multiple of 4 bytes
+    /// noted in the options
+    pub fn write(mut self, output: &mut Vec<u8>, encode: impl FnOnce(&mut Vec<u8>) -> Result<()>) -> Result<()> {
+        let start = output.len();
+        output.extend_from_slice(&[0; Encapsulation::LEN]);
+        encode(output)?;
+        let padding = (4 - (output.len() - start) % 4) % 4;
+        output.resize(output.len() + padding, 0);
+        self.options = (self.options & !PADDING_MASK) | padding as u16;
+        output[start..start + 2].copy_from_slice(&self.kind.id().to_be_bytes());
+        output[start + 2..start + 4].copy_from_slice(&self.options.to_be_bytes());
+        Ok(())
+    }
+}
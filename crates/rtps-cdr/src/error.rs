@@ -0,0 +1,131 @@
+// Copyright (C) 2019  Frank Rehberger
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>
+
+use alloc::string::{String, ToString};
+use core::fmt;
+#[cfg(feature = "std")]
+use std::io;
+use crate::{EncapsulationKind, Encoding};
+
+/// Failure encoding or decoding CDR
+#[derive(Debug)]
+pub enum Error {
+    /// Writing the encoded data failed
+    #[cfg(feature = "std")]
+    Io(io::Error),
+    /// The data ends within a value
+    Eof,
+    /// A sequence or map of unknown length, which cannot be prefixed by its length
+    UnknownLength,
+    /// A length exceeding `unsigned long`
+    LengthOverflow(usize),
+    /// A `boolean` other than 0 and 1
+    InvalidBool(u8),
+    /// A character not representable by the octet of `char`
+    InvalidChar(u32),
+    /// A string holding invalid UTF-8
+    InvalidUtf8,
+    /// A string lacking the terminating NUL
+    MissingNul,
+    /// Deserializing a type which relies on a self-describing format
+    NotSelfDescribing,
+    /// A member of a mutable type not keyed by its `unsigned long` member header
+    InvalidMemberHeader,
+    /// An optional member of XCDR1 not preceded by its parameter header
+    MissingParameterHeader,
+    /// A member of a mutable type, unknown to the reader, is flagged must-understand
+    UnknownMember(u32),
+    /// A union whose discriminator selects none of its cases, named by the union
+    InvalidDiscriminator(i128, &'static str),
+    /// An encapsulation identifier not defined by DDS-XTypes
+    UnknownEncapsulation(u16),
+    /// The encapsulation of a payload does not encode the extensibility of the type
+    EncapsulationMismatch(EncapsulationKind),
+    /// A parameter whose length is not a multiple of 4 or exceeds `unsigned short`
+    InvalidParameterLength(u16, usize),
+    /// A parameter unknown to the reader is flagged incompatible
+    IncompatibleParameter(u16),
+    /// A view reading other than XCDR1
+    ViewEncoding(Encoding),
+    /// Custom error of a `Serialize`, `Deserialize` or `CdrDecode` impl
+    Message(String),
+}
+
+/// Result of encoding or decoding CDR
+pub type Result<T> = core::result::Result<T, Error>;
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            #[cfg(feature = "std")]
+            Error::Io(ref err) => write!(f, "io error: {}", err),
+            Error::Eof => f.write_str("unexpected end of data"),
+            Error::UnknownLength => f.write_str("sequences and maps require a known length"),
+            Error::LengthOverflow(len) => write!(f, "length {} exceeds an unsigned long", len),
+            Error::InvalidBool(val) => write!(f, "invalid boolean {}", val),
+            Error::InvalidChar(code) => write!(f, "character U+{:04X} is not representable", code),
+            Error::InvalidUtf8 => f.write_str("string is not valid UTF-8"),
+            Error::MissingNul => f.write_str("string lacks the terminating NUL"),
+            Error::NotSelfDescribing => f.write_str("CDR is not self-describing"),
+            Error::InvalidMemberHeader => f.write_str("member of a mutable type lacks its member header"),
+            Error::MissingParameterHeader => f.write_str("optional member lacks its parameter header"),
+            Error::UnknownMember(id) => write!(f, "unknown member {} must be understood", id),
+            Error::InvalidDiscriminator(label, union) => write!(f, "invalid discriminator {} of {}", label, union),
+            Error::UnknownEncapsulation(id) => write!(f, "unknown encapsulation identifier {:#06x}", id),
+            Error::EncapsulationMismatch(kind) => write!(f, "{} does not encode the type's extensibility", kind),
+            Error::InvalidParameterLength(id, len) => write!(f, "invalid length {} of parameter {:#06x}", len, id),
+            Error::IncompatibleParameter(id) => write!(f, "unknown parameter {:#06x} must be understood", id),
+            Error::ViewEncoding(encoding) => write!(f, "views read XCDR1, not {:?}", encoding),
+            Error::Message(ref mesg) => f.write_str(mesg),
+        }
+    }
+}
+
+impl core::error::Error for Error {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            #[cfg(feature = "std")]
+            Error::Io(ref err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Error {
+        Error::Io(err)
+    }
+}
+
+impl From<rtps_types::BoundError> for Error {
+    fn from(err: rtps_types::BoundError) -> Error {
+        Error::Message(err.to_string())
+    }
+}
+
+impl From<rtps_types::EnumError> for Error {
+    fn from(err: rtps_types::EnumError) -> Error {
+        Error::Message(err.to_string())
+    }
+}
+
+impl From<rtps_types::ValidationError> for Error {
+    fn from(err: rtps_types::ValidationError) -> Error {
+        Error::Message(err.to_string())
+    }
+}
+
+impl serde::ser::Error for Error {
+    fn custom<T: fmt::Display>(msg: T) -> Error {
+        Error::Message(msg.to_string())
+    }
+}
+
+impl serde::de::Error for Error {
+    fn custom<T: fmt::Display>(msg: T) -> Error {
+        Error::Message(msg.to_string())
+    }
+}
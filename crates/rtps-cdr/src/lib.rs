@@ -0,0 +1,174 @@
+// Copyright (C) 2019  Frank Rehberger
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>
+
+//! Serde data format of the OMG Common Data Representation (CDR)
+//!
+//! Values are encoded as plain CDR (XCDR1) or as XCDR2, aligning primitives relative to
+//! the start of the encoded data, which follows the encapsulation header of an RTPS
+//! submessage. The extensible types of `rtps_types::xtypes` are encoded by parameter lists
+//! in XCDR1, and by the DHEADER and EMHEADER in XCDR2.
+//!
+//! The traits `CdrEncode` and `CdrDecode` encode the same without serde, as generated for
+//! hot paths. Serialized payloads precede the encoded value by an `Encapsulation` header
+//! telling the byte order and encoding, as selected by the extensibility of the type.
+//!
+//! The discovery data and inline QoS of RTPS are `ParameterList`s, keyed by the `PID_*`
+//! constants of RtpsCore.idl rather than by member IDs, and typed by a union switched by
+//! the identifier as `CdrUnion`.
+//!
+//! Generated views read structs in place by `CdrView`, borrowing strings and octets.
+//!
+//! Without the default feature `std` the crate is `no_std`, requiring `alloc` only;
+//! `to_writer` and `Error::Io` require `std`.
+#![no_std]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::io;
+use rtps_types::xtypes::Extensible;
+
+mod codec;
+mod de;
+mod encapsulation;
+mod error;
+mod parameter_list;
+mod ser;
+mod view;
+
+pub use crate::codec::{CdrDecode, CdrEncode, CdrUnion, Delimited, SerializedSize};
+pub use crate::de::Deserializer;
+pub use crate::encapsulation::{Encapsulation, EncapsulationKind};
+pub use crate::error::{Error, Result};
+pub use crate::parameter_list::{Parameter, ParameterList, ParameterListWriter, PIDMASK_INCOMPATIBLE,
+                                PIDMASK_VENDOR_SPECIFIC, PID_PAD, PID_SENTINEL};
+pub use crate::ser::Serializer;
+pub use crate::view::{CdrView, ViewMembers};
+
+/// Byte order of the encoded primitives
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Endianness {
+    Big,
+    Little,
+}
+
+impl Endianness {
+    /// The byte order of the target
+    pub fn native() -> Endianness {
+        if cfg!(target_endian = "big") {
+            Endianness::Big
+        } else {
+            Endianness::Little
+        }
+    }
+}
+
+/// Version of the extended CDR
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Encoding {
+    /// Plain CDR, aligning primitives up to 8 bytes; mutable types are parameter lists
+    Xcdr1,
+    /// Aligning primitives up to 4 bytes; appendable and mutable types are preceded by a
+    /// DHEADER, and the members of mutable types by an EMHEADER
+    Xcdr2,
+}
+
+impl Encoding {
+    /// Maximum alignment of primitives
+    fn max_align(self) -> usize {
+        match self {
+            Encoding::Xcdr1 => 8,
+            Encoding::Xcdr2 => 4,
+        }
+    }
+}
+
+/// Encode the value as XCDR1 into a new buffer
+pub fn to_vec<T: serde::Serialize + ?Sized>(value: &T, endianness: Endianness) -> Result<Vec<u8>> {
+    to_vec_with_encoding(value, endianness, Encoding::Xcdr1)
+}
+
+/// Encode the value into a new buffer
+pub fn to_vec_with_encoding<T: serde::Serialize + ?Sized>(value: &T, endianness: Endianness,
+                                                          encoding: Encoding) -> Result<Vec<u8>> {
+    let mut bytes = Vec::new();
+    value.serialize(&mut Serializer::with_encoding(&mut bytes, endianness, encoding))?;
+    Ok(bytes)
+}
+
+/// Encode the value as XCDR1 into the writer, the value starting at its first byte
+#[cfg(feature = "std")]
+pub fn to_writer<W: io::Write, T: serde::Serialize + ?Sized>(mut writer: W, value: &T, endianness: Endianness)
+                                                             -> Result<()> {
+    writer.write_all(&to_vec(value, endianness)?)?;
+    Ok(())
+}
+
+/// Decode a value from XCDR1 bytes; trailing bytes, such as padding, are ignored
+pub fn from_slice<'de, T: serde::Deserialize<'de>>(bytes: &'de [u8], endianness: Endianness) -> Result<T> {
+    from_slice_with_encoding(bytes, endianness, Encoding::Xcdr1)
+}
+
+/// Decode a value from the bytes; trailing bytes, such as padding, are ignored
+pub fn from_slice_with_encoding<'de, T: serde::Deserialize<'de>>(bytes: &'de [u8], endianness: Endianness,
+                                                                 encoding: Encoding) -> Result<T> {
+    let mut deserializer = Deserializer::with_encoding(bytes, endianness, encoding);
+    T::deserialize(&mut deserializer)
+}
+
+/// Encode the value by its `CdrEncode` impl into a new buffer
+pub fn encode_to_vec<T: CdrEncode + ?Sized>(value: &T, endianness: Endianness, encoding: Encoding) -> Result<Vec<u8>> {
+    let mut bytes = Vec::new();
+    value.encode(&mut Serializer::with_encoding(&mut bytes, endianness, encoding))?;
+    Ok(bytes)
+}
+
+/// Decode a value by its `CdrDecode` impl from the bytes; trailing bytes are ignored
+pub fn decode_from_slice<T: CdrDecode>(bytes: &[u8], endianness: Endianness, encoding: Encoding) -> Result<T> {
+    T::decode(&mut Deserializer::with_encoding(bytes, endianness, encoding))
+}
+
+/// Encode the value as serialized payload, preceded by the encapsulation header of the
+/// type's extensibility
+pub fn to_payload<T: serde::Serialize + Extensible + ?Sized>(value: &T, endianness: Endianness,
+                                                             encoding: Encoding) -> Result<Vec<u8>> {
+    let mut bytes = Vec::new();
+    Encapsulation::new(EncapsulationKind::new(T::EXTENSIBILITY, endianness, encoding))
+        .write(&mut bytes, |output| value.serialize(&mut Serializer::with_encoding(output, endianness, encoding)))?;
+    Ok(bytes)
+}
+
+/// Decode a value from the serialized payload in the byte order and encoding of its
+/// encapsulation header
+pub fn from_payload<'de, T: serde::Deserialize<'de> + Extensible>(payload: &'de [u8]) -> Result<T> {
+    let (header, bytes) = read_payload::<T>(payload)?;
+    from_slice_with_encoding(bytes, header.kind.endianness(), header.kind.encoding())
+}
+
+/// Encode the value by its `CdrEncode` impl as serialized payload, see `to_payload`
+pub fn encode_payload<T: CdrEncode + Extensible + ?Sized>(value: &T, endianness: Endianness,
+                                                          encoding: Encoding) -> Result<Vec<u8>> {
+    let mut bytes = Vec::new();
+    Encapsulation::new(EncapsulationKind::new(T::EXTENSIBILITY, endianness, encoding))
+        .write(&mut bytes, |output| value.encode(&mut Serializer::with_encoding(output, endianness, encoding)))?;
+    Ok(bytes)
+}
+
+/// Decode a value by its `CdrDecode` impl from the serialized payload, see `from_payload`
+pub fn decode_payload<T: CdrDecode + Extensible>(payload: &[u8]) -> Result<T> {
+    let (header, bytes) = read_payload::<T>(payload)?;
+    decode_from_slice(bytes, header.kind.endianness(), header.kind.encoding())
+}
+
+/// Read the encapsulation header, which must encode the extensibility of the type
+fn read_payload<T: Extensible>(payload: &[u8]) -> Result<(Encapsulation, &[u8])> {
+    let (header, bytes) = Encapsulation::read(payload)?;
+    if !header.kind.encodes(T::EXTENSIBILITY) {
+        return Err(Error::EncapsulationMismatch(header.kind));
+    }
+    Ok((header, bytes))
+}
@@ -0,0 +1,249 @@
+// Copyright (C) 2019  Frank Rehberger
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>
+
+//! Parameter lists of the RTPS discovery data and inline QoS
+//!
+//! Each parameter is its `ParameterId_t` and the length of its value, an `unsigned short`
+//! each, followed by the value encoded as XCDR1 and padded to a multiple of 4 bytes; the
+//! list ends with `PID_SENTINEL`. The identifiers are the `PID_*` constants of RtpsCore.idl:
+//! those flagged `PIDMASK_VENDOR_SPECIFIC` are defined by the vendor, and unknown ones
+//! flagged `PIDMASK_INCOMPATIBLE` must be understood by the reader.
+//!
+//! The values are typed by a union switched by the identifier, whose cases give the type
+//! of the parameters they list; generated with `Configuration::cdr`, it implements
+//! `CdrUnion` for `get_typed` and `put_typed`.
+use alloc::vec::Vec;
+use crate::{CdrDecode, CdrEncode, CdrUnion, Deserializer, Encapsulation, EncapsulationKind, Encoding, Endianness, Error,
+            Result, Serializer};
+
+/// Padding, ignored by the reader
+pub const PID_PAD: u16 = 0x0000;
+/// End of the parameter list
+pub const PID_SENTINEL: u16 = 0x0001;
+/// Parameters defined by the vendor
+pub const PIDMASK_VENDOR_SPECIFIC: u16 = 0x8000;
+/// Parameters the reader must understand
+pub const PIDMASK_INCOMPATIBLE: u16 = 0x4000;
+
+/// Length of the identifier and the length preceding each value
+const PARAMETER_HEADER_LEN: usize = 4;
+
+/// A parameter of the list, its value including the padding
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Parameter<'a> {
+    pub id: u16,
+    pub value: &'a [u8],
+}
+
+impl<'a> Parameter<'a> {
+    /// Whether the parameter is defined by the vendor
+    pub fn is_vendor_specific(&self) -> bool {
+        self.id & PIDMASK_VENDOR_SPECIFIC != 0
+    }
+
+    /// Whether the reader must understand the parameter
+    pub fn is_incompatible(&self) -> bool {
+        self.id & PIDMASK_INCOMPATIBLE != 0
+    }
+}
+
+/// Parameters read from a parameter list, borrowing their values
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParameterList<'a> {
+    endianness: Endianness,
+    parameters: Vec<Parameter<'a>>,
+}
+
+impl<'a> ParameterList<'a> {
+    /// Read the parameters up to the sentinel, returning the bytes following it
+    pub fn read(bytes: &'a [u8], endianness: Endianness) -> Result<(ParameterList<'a>, &'a [u8])> {
+        let mut de = Deserializer::new(bytes, endianness);
+        let mut parameters = Vec::new();
+        loop {
+            let id = u16::decode(&mut de)?;
+            let len = u16::decode(&mut de)?;
+            if usize::from(len) % 4 != 0 {
+                return Err(Error::InvalidParameterLength(id, usize::from(len)));
+            }
+            let value = de.read(usize::from(len))?;
+            match id {
+                PID_SENTINEL => break,
+                PID_PAD => {}
+                _ => parameters.push(Parameter { id, value }),
+            }
+        }
+        Ok((ParameterList { endianness, parameters }, de.remaining()))
+    }
+
+    /// Read the parameters of a serialized payload, preceded by the encapsulation PL_CDR_BE
+    /// or PL_CDR_LE
+    pub fn from_payload(payload: &'a [u8]) -> Result<ParameterList<'a>> {
+        let (header, bytes) = Encapsulation::read(payload)?;
+        match header.kind {
+            EncapsulationKind::PlCdrBe | EncapsulationKind::PlCdrLe => {
+                ParameterList::read(bytes, header.kind.endianness()).map(|(list, _)| list)
+            }
+            kind => Err(Error::EncapsulationMismatch(kind)),
+        }
+    }
+
+    /// Byte order of the values
+    pub fn endianness(&self) -> Endianness {
+        self.endianness
+    }
+
+    /// The parameters in the order read, without padding and sentinel
+    pub fn parameters(&self) -> &[Parameter<'a>] {
+        &self.parameters
+    }
+
+    /// Whether the list holds the parameter
+    pub fn contains(&self, id: u16) -> bool {
+        self.parameters.iter().any(|parameter| parameter.id == id)
+    }
+
+    /// Decode the value of the first parameter of the identifier
+    pub fn get<T: CdrDecode>(&self, id: u16) -> Result<Option<T>> {
+        self.parameters.iter()
+            .find(|parameter| parameter.id == id)
+            .map(|parameter| self.decode(parameter, T::decode))
+            .transpose()
+    }
+
+    /// Decode the values of all parameters of the identifier, such as the locators
+    pub fn get_all<T: CdrDecode>(&self, id: u16) -> Result<Vec<T>> {
+        self.parameters.iter()
+            .filter(|parameter| parameter.id == id)
+            .map(|parameter| self.decode(parameter, T::decode))
+            .collect()
+    }
+
+    /// Decode the value of the first parameter of the identifier as the case of the union
+    /// it selects, typed by the union
+    pub fn get_typed<P: CdrUnion<Discriminator = u16>>(&self, id: u16) -> Result<Option<P>> {
+        self.parameters.iter()
+            .find(|parameter| parameter.id == id)
+            .map(|parameter| self.decode(parameter, |de| P::decode_element(id, de)))
+            .transpose()
+    }
+
+    /// Decode the values of all parameters of the identifier as the case of the union it
+    /// selects
+    pub fn get_all_typed<P: CdrUnion<Discriminator = u16>>(&self, id: u16) -> Result<Vec<P>> {
+        self.parameters.iter()
+            .filter(|parameter| parameter.id == id)
+            .map(|parameter| self.decode(parameter, |de| P::decode_element(id, de)))
+            .collect()
+    }
+
+    /// Fail on the first parameter flagged incompatible which is not `understood`, unless
+    /// it is vendor specific; the other unknown parameters are ignored
+    pub fn check(&self, understood: impl Fn(u16) -> bool) -> Result<()> {
+        match self.parameters.iter()
+            .find(|parameter| parameter.is_incompatible() && !parameter.is_vendor_specific()
+                && !understood(parameter.id)) {
+            Some(parameter) => Err(Error::IncompatibleParameter(parameter.id)),
+            None => Ok(()),
+        }
+    }
+
+    /// Decode the value by `decode`, aligned relative to its start
+    fn decode<T>(&self, parameter: &Parameter<'a>, decode: impl FnOnce(&mut Deserializer<'a>) -> Result<T>)
+                 -> Result<T> {
+        decode(&mut Deserializer::with_encoding(parameter.value, self.endianness, Encoding::Xcdr1))
+    }
+}
+
+/// Writer of a parameter list, terminated by `finish`
+#[derive(Clone, Debug)]
+pub struct ParameterListWriter {
+    endianness: Endianness,
+    bytes: Vec<u8>,
+}
+
+impl ParameterListWriter {
+    /// Empty list, encoding the values in the byte order
+    pub fn new(endianness: Endianness) -> ParameterListWriter {
+        ParameterListWriter { endianness, bytes: Vec::new() }
+    }
+
+    /// Append the parameter, its value encoded by the `CdrEncode` impl
+    pub fn put<T: CdrEncode + ?Sized>(&mut self, id: u16, value: &T) -> Result<()> {
+        let endianness = self.endianness;
+        self.append(id, |bytes| value.encode(&mut Serializer::with_encoding(bytes, endianness, Encoding::Xcdr1)))
+    }
+
+    /// Append the parameter of the union's case, identified by its case label and its value
+    /// encoded by the element; a case of several labels is written by the first
+    pub fn put_typed<P: CdrUnion<Discriminator = u16>>(&mut self, parameter: &P) -> Result<()> {
+        let endianness = self.endianness;
+        self.append(parameter.discriminator(), |bytes| {
+            parameter.encode_element(&mut Serializer::with_encoding(bytes, endianness, Encoding::Xcdr1))
+        })
+    }
+
+    /// Append the parameter of the encoded value, such as one not known to the writer
+    pub fn put_raw(&mut self, id: u16, value: &[u8]) -> Result<()> {
+        self.append(id, |bytes| {
+            bytes.extend_from_slice(value);
+            Ok(())
+        })
+    }
+
+    /// Terminate the list by the sentinel, returning the encoded parameters
+    pub fn finish(mut self) -> Vec<u8> {
+        self.header(PID_SENTINEL, 0);
+        self.bytes
+    }
+
+    /// Terminate the list, returning it as serialized payload preceded by the encapsulation
+    /// PL_CDR_BE or PL_CDR_LE
+    pub fn into_payload(self) -> Vec<u8> {
+        let kind = match self.endianness {
+            Endianness::Big => EncapsulationKind::PlCdrBe,
+            Endianness::Little => EncapsulationKind::PlCdrLe,
+        };
+        // the list keeps a multiple of 4 bytes, so the options note no padding
+        let list = self.finish();
+        let mut payload = Vec::with_capacity(Encapsulation::LEN + list.len());
+        payload.extend_from_slice(&kind.id().to_be_bytes());
+        payload.extend_from_slice(&[0, 0]);
+        payload.extend_from_slice(&list);
+        payload
+    }
+
+    /// Append the header and the value written by `encode`, padded to a multiple of 4 bytes
+    fn append(&mut self, id: u16, encode: impl FnOnce(&mut Vec<u8>) -> Result<()>) -> Result<()> {
+        let start = self.bytes.len();
+        self.header(id, 0);
+        if let Err(err) = encode(&mut self.bytes) {
+            self.bytes.truncate(start);
+            return Err(err);
+        }
+        let len = (self.bytes.len() - start - PARAMETER_HEADER_LEN + 3) & !3;
+        if len > usize::from(u16::MAX) {
+            self.bytes.truncate(start);
+            return Err(Error::InvalidParameterLength(id, len));
+        }
+        self.bytes.resize(start + PARAMETER_HEADER_LEN + len, 0);
+        let len = u16_bytes(len as u16, self.endianness);
+        self.bytes[start + 2..start + PARAMETER_HEADER_LEN].copy_from_slice(&len);
+        Ok(())
+    }
+
+    /// Append the identifier and the length of the value
+    fn header(&mut self, id: u16, len: u16) {
+        self.bytes.extend_from_slice(&u16_bytes(id, self.endianness));
+        self.bytes.extend_from_slice(&u16_bytes(len, self.endianness));
+    }
+}
+
+/// The `unsigned short` in the byte order
+fn u16_bytes(v: u16, endianness: Endianness) -> [u8; 2] {
+    match endianness {
+        Endianness::Big => v.to_be_bytes(),
+        Endianness::Little => v.to_le_bytes(),
+    }
+}
@@ -0,0 +1,535 @@
+// Copyright (C) 2019  Frank Rehberger
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>
+
+use alloc::vec::Vec;
+use core::convert::{TryFrom, TryInto};
+use serde::ser::{self, Serialize};
+use rtps_types::xtypes::{DELIMITED, MEMBER_ID_MASK, MUST_UNDERSTAND, MUTABLE, OPTIONAL};
+use crate::{CdrEncode, Encoding, Endianness, Error, Result};
+
+/// Parameter ID of a member header carrying a 32 bit member ID and length
+pub(crate) const PID_EXTENDED: u16 = 0x3f01;
+/// Parameter ID terminating a parameter list
+pub(crate) const PID_LIST_END: u16 = 0x3f02;
+/// Parameter ID of a member to be ignored
+pub(crate) const PID_IGNORE: u16 = 0x3f03;
+/// Flag of a parameter ID, the reader failing if not knowing the member
+pub(crate) const PID_MUST_UNDERSTAND: u16 = 0x4000;
+/// Mask of the parameter ID, without its flags
+pub(crate) const PID_MASK: u16 = 0x3fff;
+/// Largest member ID of a short parameter header
+const PID_MAX: u32 = 0x3eff;
+/// Length code of an EMHEADER followed by the length of the member
+const LC_NEXTINT: u32 = 4;
+
+/// Progress through the newtype `OPTIONAL`, holding the member header and the option
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Optional {
+    Outside,
+    Header,
+    Value(u32),
+}
+
+/// Serializer appending CDR to a buffer, aligning relative to the first byte appended
+pub struct Serializer<'a> {
+    output: &'a mut Vec<u8>,
+    endianness: Endianness,
+    encoding: Encoding,
+    start: usize,
+    origin: usize,
+    mutable: bool,
+    optional: Optional,
+}
+
+/// The bytes of the primitive in the byte order of the serializer
+macro_rules! bytes {
+    ($ser:expr, $val:expr) => {
+        match $ser.endianness {
+            Endianness::Big => $val.to_be_bytes(),
+            Endianness::Little => $val.to_le_bytes(),
+        }
+    };
+}
+
+impl<'a> Serializer<'a> {
+    /// Serializer of XCDR1
+    pub fn new(output: &'a mut Vec<u8>, endianness: Endianness) -> Serializer<'a> {
+        Serializer::with_encoding(output, endianness, Encoding::Xcdr1)
+    }
+
+    pub fn with_encoding(output: &'a mut Vec<u8>, endianness: Endianness, encoding: Encoding) -> Serializer<'a> {
+        let start = output.len();
+        Serializer { output, endianness, encoding, start, origin: start, mutable: false, optional: Optional::Outside }
+    }
+
+    /// The number of bytes written
+    pub fn position(&self) -> usize {
+        self.output.len() - self.start
+    }
+
+    /// Byte order of the encoded primitives
+    #[inline]
+    pub fn endianness(&self) -> Endianness {
+        self.endianness
+    }
+
+    /// Version of the extended CDR being encoded
+    pub fn encoding(&self) -> Encoding {
+        self.encoding
+    }
+
+    #[inline]
+    pub(crate) fn write(&mut self, bytes: &[u8]) -> Result<()> {
+        self.output.extend_from_slice(bytes);
+        Ok(())
+    }
+
+    /// Pad with zeros up to the alignment of a primitive of `size` bytes
+    #[inline]
+    pub(crate) fn align(&mut self, size: usize) -> Result<()> {
+        let align = size.min(self.encoding.max_align());
+        let pad = (align - (self.output.len() - self.origin) % align) % align;
+        let len = self.output.len() + pad;
+        self.output.resize(len, 0);
+        Ok(())
+    }
+
+    /// Write the primitive aligned to its size
+    #[inline]
+    pub(crate) fn write_aligned(&mut self, bytes: &[u8]) -> Result<()> {
+        self.align(bytes.len())?;
+        self.write(bytes)
+    }
+
+    /// Write the length prefix of a string, sequence or map
+    pub(crate) fn write_len(&mut self, len: usize) -> Result<()> {
+        let len = u32::try_from(len).map_err(|_| Error::LengthOverflow(len))?;
+        self.write_aligned(&bytes!(self, len))
+    }
+
+    /// Write the string, prefixed by its length and terminated by NUL
+    pub(crate) fn write_str(&mut self, v: &str) -> Result<()> {
+        self.write_len(v.len() + 1)?;
+        self.write(v.as_bytes())?;
+        self.write(&[0])
+    }
+
+    /// Reserve an `unsigned long` length, returning its offset
+    fn reserve_len(&mut self) -> Result<usize> {
+        self.align(4)?;
+        let at = self.output.len();
+        self.write(&[0; 4])?;
+        Ok(at)
+    }
+
+    /// Set the reserved length to the number of bytes following it
+    fn patch_len(&mut self, at: usize) -> Result<()> {
+        let len = self.output.len() - at - 4;
+        let len = u32::try_from(len).map_err(|_| Error::LengthOverflow(len))?;
+        let bytes = bytes!(self, len);
+        self.output[at..at + 4].copy_from_slice(&bytes);
+        Ok(())
+    }
+
+    /// Encode the value by `encode`, preceded by a DHEADER if XCDR2
+    pub fn delimited(&mut self, encode: impl FnOnce(&mut Self) -> Result<()>) -> Result<()> {
+        match self.encoding {
+            Encoding::Xcdr1 => encode(self),
+            Encoding::Xcdr2 => {
+                let at = self.reserve_len()?;
+                encode(&mut *self)?;
+                self.patch_len(at)
+            }
+        }
+    }
+
+    /// Encode the members of a mutable type by `encode`, each by `member()`
+    pub fn members(&mut self, encode: impl FnOnce(&mut Self) -> Result<()>) -> Result<()> {
+        self.delimited(|ser| {
+            encode(&mut *ser)?;
+            ser.end_members()
+        })
+    }
+
+    /// Terminate the parameter list of a mutable type, if XCDR1
+    fn end_members(&mut self) -> Result<()> {
+        if self.encoding == Encoding::Xcdr1 {
+            self.align(4)?;
+            self.write(&bytes!(self, PID_LIST_END | PID_MUST_UNDERSTAND))?;
+            self.write(&[0; 2])?;
+        }
+        Ok(())
+    }
+
+    /// Encode an optional member of a final or appendable type given its member header:
+    /// XCDR1 precedes it by a parameter header, of length 0 if absent, XCDR2 by a `boolean`
+    /// telling its presence
+    pub fn optional<T: CdrEncode>(&mut self, header: u32, value: Option<T>) -> Result<()> {
+        match self.encoding {
+            Encoding::Xcdr1 => self.member(header, |ser| match value {
+                Some(value) => value.encode(ser),
+                None => Ok(()),
+            }),
+            Encoding::Xcdr2 => value.encode(self),
+        }
+    }
+
+    /// Encode a member of a mutable type by `encode`, preceded by its EMHEADER or parameter
+    /// header given the member header, which is the member ID and the must-understand flag
+    pub fn member(&mut self, header: u32, encode: impl FnOnce(&mut Self) -> Result<()>) -> Result<()> {
+        let id = header & MEMBER_ID_MASK;
+        let must_understand = header & MUST_UNDERSTAND != 0;
+        self.align(4)?;
+        match self.encoding {
+            Encoding::Xcdr2 => {
+                let emheader = (header & MUST_UNDERSTAND) | LC_NEXTINT << 28 | id;
+                self.write(&bytes!(self, emheader))?;
+                let at = self.reserve_len()?;
+                encode(&mut *self)?;
+                self.patch_len(at)
+            }
+            Encoding::Xcdr1 => {
+                let at = self.output.len();
+                let extended = id > PID_MAX;
+                if extended {
+                    self.write_extended_header(header, 0)?;
+                } else {
+                    let flag = if must_understand { PID_MUST_UNDERSTAND } else { 0 };
+                    self.write(&bytes!(self, id as u16 | flag))?;
+                    self.write(&[0; 2])?;
+                }
+                // the value is aligned relative to its first byte
+                let origin = core::mem::replace(&mut self.origin, self.output.len());
+                let result = encode(&mut *self).and_then(|_| self.align(4));
+                self.origin = origin;
+                result?;
+                let value_at = at + if extended { 12 } else { 4 };
+                let len = self.output.len() - value_at;
+                match u16::try_from(len) {
+                    Ok(len) if !extended => {
+                        let bytes = bytes!(self, len);
+                        self.output[at + 2..at + 4].copy_from_slice(&bytes);
+                        Ok(())
+                    }
+                    _ => {
+                        let len = u32::try_from(len).map_err(|_| Error::LengthOverflow(len))?;
+                        let mut header_bytes = Vec::with_capacity(12);
+                        Serializer::new(&mut header_bytes, self.endianness).write_extended_header(header, len)?;
+                        self.output.splice(at..value_at, header_bytes);
+                        Ok(())
+                    }
+                }
+            }
+        }
+    }
+
+    /// The parameter header of a member whose ID or length exceeds 16 bits
+    fn write_extended_header(&mut self, header: u32, len: u32) -> Result<()> {
+        self.write(&bytes!(self, PID_EXTENDED | PID_MUST_UNDERSTAND))?;
+        self.write(&bytes!(self, 8u16))?;
+        self.write(&bytes!(self, header))?;
+        self.write(&bytes!(self, len))
+    }
+}
+
+impl<'s, 'a> ser::Serializer for &'s mut Serializer<'a> {
+    type Ok = ();
+    type Error = Error;
+    type SerializeSeq = Self;
+    type SerializeTuple = Self;
+    type SerializeTupleStruct = Self;
+    type SerializeTupleVariant = Self;
+    type SerializeMap = MapSerializer<'s, 'a>;
+    type SerializeStruct = Self;
+    type SerializeStructVariant = Self;
+
+    fn serialize_bool(self, v: bool) -> Result<()> {
+        self.write(&[u8::from(v)])
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<()> {
+        self.write(&v.to_be_bytes())
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<()> {
+        self.write_aligned(&bytes!(self, v))
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<()> {
+        self.write_aligned(&bytes!(self, v))
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<()> {
+        self.write_aligned(&bytes!(self, v))
+    }
+
+    fn serialize_i128(self, v: i128) -> Result<()> {
+        self.write_aligned(&bytes!(self, v))
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<()> {
+        self.write(&[v])
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<()> {
+        self.write_aligned(&bytes!(self, v))
+    }
+
+    /// The member header of an optional member is encoded by its value
+    fn serialize_u32(self, v: u32) -> Result<()> {
+        if self.optional == Optional::Header {
+            self.optional = Optional::Value(v);
+            return Ok(());
+        }
+        self.write_aligned(&bytes!(self, v))
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<()> {
+        self.write_aligned(&bytes!(self, v))
+    }
+
+    /// `long double`, as held by `rtps_types::LongDouble`
+    fn serialize_u128(self, v: u128) -> Result<()> {
+        self.write_aligned(&bytes!(self, v))
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<()> {
+        self.write_aligned(&bytes!(self, v))
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<()> {
+        self.write_aligned(&bytes!(self, v))
+    }
+
+    /// `char` is a single octet, holding Latin-1
+    fn serialize_char(self, v: char) -> Result<()> {
+        let octet = u8::try_from(u32::from(v)).map_err(|_| Error::InvalidChar(u32::from(v)))?;
+        self.write(&[octet])
+    }
+
+    fn serialize_str(self, v: &str) -> Result<()> {
+        self.write_str(v)
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<()> {
+        self.write_len(v.len())?;
+        self.write(v)
+    }
+
+    /// Options are preceded by a `boolean` telling their presence, optional members of
+    /// XCDR1 by a parameter header
+    fn serialize_none(self) -> Result<()> {
+        match core::mem::replace(&mut self.optional, Optional::Outside) {
+            Optional::Value(header) if self.encoding == Encoding::Xcdr1 => self.member(header, |_| Ok(())),
+            _ => self.serialize_bool(false),
+        }
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<()> {
+        match core::mem::replace(&mut self.optional, Optional::Outside) {
+            Optional::Value(header) if self.encoding == Encoding::Xcdr1 => {
+                self.member(header, |ser| value.serialize(ser))
+            }
+            _ => {
+                self.serialize_bool(true)?;
+                value.serialize(self)
+            }
+        }
+    }
+
+    fn serialize_unit(self) -> Result<()> {
+        Ok(())
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<()> {
+        Ok(())
+    }
+
+    fn serialize_unit_variant(self, _name: &'static str, variant_index: u32, _variant: &'static str)
+                              -> Result<()> {
+        self.serialize_u32(variant_index)
+    }
+
+    /// The newtypes of `rtps_types::xtypes` control the encoding of extensible types
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(self, name: &'static str, value: &T) -> Result<()> {
+        if name == DELIMITED {
+            self.delimited(|ser| value.serialize(ser))
+        } else if name == MUTABLE {
+            self.mutable = true;
+            let result = self.delimited(|ser| value.serialize(ser));
+            self.mutable = false;
+            result
+        } else if name == OPTIONAL {
+            self.optional = Optional::Header;
+            value.serialize(self)
+        } else {
+            value.serialize(self)
+        }
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(self, _name: &'static str, variant_index: u32,
+                                                         _variant: &'static str, value: &T) -> Result<()> {
+        self.serialize_u32(variant_index)?;
+        value.serialize(self)
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<Self> {
+        self.write_len(len.ok_or(Error::UnknownLength)?)?;
+        Ok(self)
+    }
+
+    /// Tuples and arrays have a fixed length, which is not encoded
+    fn serialize_tuple(self, _len: usize) -> Result<Self> {
+        Ok(self)
+    }
+
+    fn serialize_tuple_struct(self, _name: &'static str, _len: usize) -> Result<Self> {
+        Ok(self)
+    }
+
+    fn serialize_tuple_variant(self, _name: &'static str, variant_index: u32, _variant: &'static str,
+                               _len: usize) -> Result<Self> {
+        self.serialize_u32(variant_index)?;
+        Ok(self)
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<MapSerializer<'s, 'a>> {
+        let members = core::mem::replace(&mut self.mutable, false);
+        if !members {
+            self.write_len(len.ok_or(Error::UnknownLength)?)?;
+        }
+        Ok(MapSerializer { ser: self, members, header: None })
+    }
+
+    fn serialize_struct(self, _name: &'static str, _len: usize) -> Result<Self> {
+        Ok(self)
+    }
+
+    fn serialize_struct_variant(self, _name: &'static str, variant_index: u32, _variant: &'static str,
+                                _len: usize) -> Result<Self> {
+        self.serialize_u32(variant_index)?;
+        Ok(self)
+    }
+
+    fn is_human_readable(&self) -> bool {
+        false
+    }
+}
+
+impl ser::SerializeSeq for &mut Serializer<'_> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl ser::SerializeTuple for &mut Serializer<'_> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl ser::SerializeTupleStruct for &mut Serializer<'_> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl ser::SerializeTupleVariant for &mut Serializer<'_> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// Serializer of a map, or of the members of a mutable type keyed by their member header
+pub struct MapSerializer<'s, 'a> {
+    ser: &'s mut Serializer<'a>,
+    members: bool,
+    header: Option<u32>,
+}
+
+impl ser::SerializeMap for MapSerializer<'_, '_> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<()> {
+        if !self.members {
+            return key.serialize(&mut *self.ser);
+        }
+        let mut bytes = Vec::with_capacity(4);
+        key.serialize(&mut Serializer::new(&mut bytes, Endianness::Big))?;
+        let bytes = bytes.as_slice().try_into().map_err(|_| Error::InvalidMemberHeader)?;
+        self.header = Some(u32::from_be_bytes(bytes));
+        Ok(())
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        if !self.members {
+            return value.serialize(&mut *self.ser);
+        }
+        let header = self.header.take().ok_or(Error::InvalidMemberHeader)?;
+        self.ser.member(header, |ser| value.serialize(ser))
+    }
+
+    fn end(self) -> Result<()> {
+        if self.members {
+            self.ser.end_members()?;
+        }
+        Ok(())
+    }
+}
+
+impl ser::SerializeStruct for &mut Serializer<'_> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, _key: &'static str, value: &T) -> Result<()> {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl ser::SerializeStructVariant for &mut Serializer<'_> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, _key: &'static str, value: &T) -> Result<()> {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<()> {
+        Ok(())
+    }
+}
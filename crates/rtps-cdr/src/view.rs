@@ -0,0 +1,106 @@
+// Copyright (C) 2019  Frank Rehberger
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>
+
+//! Zero-copy views of XCDR1 data
+//!
+//! A view borrows the encoded bytes, decoding a member only if accessed: strings as `&str`,
+//! sequences of octets as `&[u8]` and structs as their views, the other members as owned
+//! values by `CdrDecode`. Reading a view records where its members start, skipping strings,
+//! sequences and primitives without allocating; the bounds of strings and sequences are
+//! verified by decoding only.
+use core::fmt;
+use crate::{CdrDecode, Deserializer, Encoding, Error, Result};
+
+/// A value read in place from XCDR1 data, borrowing its strings and octets
+pub trait CdrView<'a>: Sized {
+    /// Read the view at the position of the deserializer, continuing past the value
+    fn view(de: &mut Deserializer<'a>) -> Result<Self>;
+
+    /// Read past the value, verifying what the view does
+    fn skip(de: &mut Deserializer<'a>) -> Result<()> {
+        Self::view(de).map(drop)
+    }
+}
+
+/// A string without its terminating NUL
+impl<'a> CdrView<'a> for &'a str {
+    fn view(de: &mut Deserializer<'a>) -> Result<&'a str> {
+        de.read_str()
+    }
+
+    /// The UTF-8 is verified when accessed
+    fn skip(de: &mut Deserializer<'a>) -> Result<()> {
+        de.skip_str()
+    }
+}
+
+/// A sequence of octets
+impl<'a> CdrView<'a> for &'a [u8] {
+    fn view(de: &mut Deserializer<'a>) -> Result<&'a [u8]> {
+        let len = de.read_len()?;
+        de.read(len)
+    }
+}
+
+/// The start of a struct view and the positions of its `N` members
+#[derive(Clone)]
+pub struct ViewMembers<'a, const N: usize> {
+    start: Deserializer<'a>,
+    offsets: [usize; N],
+    /// The number of members read past
+    len: usize,
+}
+
+impl<'a, const N: usize> ViewMembers<'a, N> {
+    /// Begin the view of a struct at the position of the deserializer, which reads XCDR1
+    pub fn begin(de: &Deserializer<'a>) -> Result<ViewMembers<'a, N>> {
+        if de.encoding() != Encoding::Xcdr1 {
+            return Err(Error::ViewEncoding(de.encoding()));
+        }
+        Ok(ViewMembers { start: de.clone(), offsets: [0; N], len: 0 })
+    }
+
+    /// Record the start of the next member, reading past it by the `CdrView` impl
+    pub fn skip_view<T: CdrView<'a>>(&mut self, de: &mut Deserializer<'a>) -> Result<()> {
+        self.push(de.position());
+        T::skip(de)
+    }
+
+    /// Record the start of the next member, reading past it by the `CdrDecode` impl
+    pub fn skip_decoded<T: CdrDecode>(&mut self, de: &mut Deserializer<'a>) -> Result<()> {
+        self.push(de.position());
+        T::skip(de)
+    }
+
+    /// Record the start of the next member, an optional one, reading past its parameter
+    /// header and the `CdrDecode` impl of the type held if present
+    pub fn skip_optional<T: CdrDecode>(&mut self, de: &mut Deserializer<'a>) -> Result<()> {
+        self.push(de.position());
+        de.skip_optional::<T>()
+    }
+
+    /// The deserializer at the start of the struct
+    pub fn start(&self) -> Deserializer<'a> {
+        self.start.clone()
+    }
+
+    /// The deserializer at the start of the member; panics if `index` is no member
+    pub fn member(&self, index: usize) -> Deserializer<'a> {
+        self.start.at(self.offsets[index])
+    }
+
+    fn push(&mut self, offset: usize) {
+        assert!(self.len < N, "more than {} members", N);
+        self.offsets[self.len] = offset;
+        self.len += 1;
+    }
+}
+
+/// The encoded bytes are omitted
+impl<'a, const N: usize> fmt::Debug for ViewMembers<'a, N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ViewMembers").field("offsets", &self.offsets).finish()
+    }
+}
@@ -0,0 +1,149 @@
+extern crate rtps_cdr;
+
+use std::convert::TryFrom;
+use rtps_cdr::{decode_from_slice, encode_to_vec, to_vec_with_encoding, CdrDecode, CdrEncode, Delimited,
+               Deserializer, Encoding, Endianness, Serializer};
+use rtps_types::xtypes::{MEMBER_ID_MASK, MUST_UNDERSTAND};
+use rtps_types::{BoundedString, BoundedVec, BoundedWString, BoundedWideString, Char, FixedString, FixedVec, LongDouble,
+                 WString};
+
+const ENDIANNESSES: [Endianness; 2] = [Endianness::Big, Endianness::Little];
+const ENCODINGS: [Encoding; 2] = [Encoding::Xcdr1, Encoding::Xcdr2];
+
+/// Encode the value by its `CdrEncode` impl, verifying the serde data format agrees
+fn encode<T: CdrEncode + serde::Serialize>(value: &T, endianness: Endianness, encoding: Encoding) -> Vec<u8> {
+    let bytes = encode_to_vec(value, endianness, encoding).unwrap();
+    assert_eq!(bytes, to_vec_with_encoding(value, endianness, encoding).unwrap());
+    bytes
+}
+
+fn round_trip<T>(value: T)
+    where T: CdrEncode + CdrDecode + serde::Serialize + PartialEq + std::fmt::Debug
+{
+    for &endianness in &ENDIANNESSES {
+        for &encoding in &ENCODINGS {
+            let bytes = encode(&value, endianness, encoding);
+            assert_eq!(decode_from_slice::<T>(&bytes, endianness, encoding).unwrap(), value);
+        }
+    }
+}
+
+#[test]
+fn primitives() {
+    round_trip((true, 1u8, -2i8));
+    round_trip((1u8, 0x0102u16, -3i32, 4u64, 5i128));
+    round_trip((0.5f32, 1u8, -1.25f64, 'a'));
+    round_trip((Char(b'x'), LongDouble::from_f64(2.5)));
+    assert!(encode_to_vec(&'€', Endianness::Big, Encoding::Xcdr1).is_err());
+}
+
+#[test]
+fn sequences_in_bulk() {
+    // a sequence of primitives is aligned once, then copied in either byte order
+    round_trip((1u8, vec![1u16, 2, 3], vec![-1i64, 2], [0.5f32; 3]));
+    round_trip((1u8, Vec::<u64>::new(), vec![0u8, 1, 2], [[1i32, 2], [3, 4]]));
+    let bytes = encode(&(1u8, vec![7u64]), Endianness::Big, Encoding::Xcdr2);
+    assert_eq!(bytes, [1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 7]);
+
+    // the length is not trusted
+    let bytes = [0xff, 0xff, 0xff, 0xff, 1, 2];
+    assert!(decode_from_slice::<Vec<u32>>(&bytes, Endianness::Big, Encoding::Xcdr1).is_err());
+    assert!(decode_from_slice::<Vec<String>>(&bytes, Endianness::Big, Encoding::Xcdr1).is_err());
+}
+
+#[test]
+fn strings_and_options() {
+    round_trip(("abc".to_owned(), String::new(), vec!["x".to_owned()]));
+    round_trip((Some(3u32), None::<u64>, Some("a".to_owned())));
+    round_trip((WString::from("wide"), Box::new(1u16)));
+}
+
+#[test]
+fn bounds() {
+    let name = BoundedString::<3>::try_from("abc").unwrap();
+    let values = BoundedVec::<u16, 2>::try_from(vec![1, 2]).unwrap();
+    let wide = BoundedWString::<4>::try_from("wide").unwrap();
+    round_trip((name, values, wide));
+
+    let bytes = encode_to_vec(&vec![1u16, 2, 3], Endianness::Big, Encoding::Xcdr1).unwrap();
+    let err = decode_from_slice::<BoundedVec<u16, 2>>(&bytes, Endianness::Big, Encoding::Xcdr1).unwrap_err();
+    assert_eq!(err.to_string(), "length 3 exceeds the bound 2");
+    let bytes = encode_to_vec("abcd", Endianness::Big, Encoding::Xcdr1).unwrap();
+    assert!(decode_from_slice::<BoundedString<3>>(&bytes, Endianness::Big, Encoding::Xcdr1).is_err());
+    // a wide string of Rust characters bounds the characters of its UTF-8 octets
+    let bytes = encode_to_vec("\u{e4}\u{f6}\u{fc}", Endianness::Big, Encoding::Xcdr1).unwrap();
+    assert!(decode_from_slice::<BoundedWideString<3>>(&bytes, Endianness::Big, Encoding::Xcdr1).is_ok());
+    assert!(decode_from_slice::<BoundedWideString<2>>(&bytes, Endianness::Big, Encoding::Xcdr1).is_err());
+
+    // fixed containers encode as the bounded ones
+    let fixed = (FixedString::<3>::try_from("ab").unwrap(), FixedVec::<u64, 2, 4>::try_from(vec![7]).unwrap());
+    round_trip(fixed);
+    assert!(decode_from_slice::<FixedString<3>>(&bytes, Endianness::Big, Encoding::Xcdr1).is_err());
+}
+
+#[test]
+fn delimited() {
+    let names = vec!["a".to_owned()];
+    let bytes = encode_to_vec(&Delimited(&names), Endianness::Big, Encoding::Xcdr2).unwrap();
+    assert_eq!(bytes, [0, 0, 0, 10, 0, 0, 0, 1, 0, 0, 0, 2, b'a', 0]);
+    let decoded: Delimited<Vec<String>> = decode_from_slice(&bytes, Endianness::Big, Encoding::Xcdr2).unwrap();
+    assert_eq!(decoded.0, names);
+}
+
+/// A mutable type of `unsigned long long` members, as given by their header
+#[derive(Debug, Default, PartialEq)]
+struct Entries(Vec<(u32, u64)>);
+
+impl CdrEncode for Entries {
+    fn encode(&self, ser: &mut Serializer<'_>) -> rtps_cdr::Result<()> {
+        ser.members(|ser| self.0.iter().try_for_each(|(header, value)| ser.member(*header, |ser| value.encode(ser))))
+    }
+}
+
+/// A mutable type knowing the `unsigned short` member 1 only
+#[derive(Debug, Default, PartialEq)]
+struct Known(u16);
+
+impl CdrDecode for Known {
+    fn decode(de: &mut Deserializer<'_>) -> rtps_cdr::Result<Known> {
+        let mut known = Known::default();
+        de.members(|de, header| {
+            match header & MEMBER_ID_MASK {
+                1 => known.0 = u16::decode(de)?,
+                _ => return Ok(false),
+            }
+            Ok(true)
+        })?;
+        Ok(known)
+    }
+}
+
+#[test]
+fn members() {
+    let entries = Entries(vec![(MUST_UNDERSTAND | 1, 7), (2, 8)]);
+    let bytes = encode_to_vec(&entries, Endianness::Big, Encoding::Xcdr2).unwrap();
+    assert_eq!(bytes, [
+        0, 0, 0, 32, // DHEADER
+        0xc0, 0, 0, 1, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 7, // M flag, LC 4, id 1
+        0x40, 0, 0, 2, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 8,
+    ]);
+    let bytes = encode_to_vec(&entries, Endianness::Big, Encoding::Xcdr1).unwrap();
+    assert_eq!(bytes, [
+        0x40, 1, 0, 8, 0, 0, 0, 0, 0, 0, 0, 7, // M flag, id 1
+        0, 2, 0, 8, 0, 0, 0, 0, 0, 0, 0, 8,
+        0x7f, 2, 0, 0, // PID_LIST_END
+    ]);
+
+    for &encoding in &ENCODINGS {
+        let bytes = encode_to_vec(&Entries(vec![(3, 1), (1, 2)]), Endianness::Little, encoding).unwrap();
+        assert_eq!(decode_from_slice::<Known>(&bytes, Endianness::Little, encoding).unwrap(), Known(2));
+        let bytes = encode_to_vec(&Entries(vec![(MUST_UNDERSTAND | 2, 2)]), Endianness::Little, encoding).unwrap();
+        let err = decode_from_slice::<Known>(&bytes, Endianness::Little, encoding).unwrap_err();
+        assert_eq!(err.to_string(), "unknown member 2 must be understood");
+    }
+
+    // the parameter list following an octet starts aligned to 4 bytes
+    let bytes = encode_to_vec(&(9u8, Entries(vec![(1, 5)])), Endianness::Little, Encoding::Xcdr1).unwrap();
+    assert_eq!(bytes[..8], [9, 0, 0, 0, 1, 0, 8, 0]);
+    assert_eq!(decode_from_slice::<(u8, Known)>(&bytes, Endianness::Little, Encoding::Xcdr1).unwrap(), (9, Known(5)));
+}
@@ -0,0 +1,116 @@
+extern crate rtps_cdr;
+#[macro_use]
+extern crate serde_derive;
+
+use rtps_cdr::{decode_payload, encode_payload, from_payload, to_payload, CdrDecode, CdrEncode, Deserializer,
+               Encapsulation, EncapsulationKind, Encoding, Endianness, Serializer};
+use rtps_types::xtypes::{Extensibility, Extensible};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Point {
+    x: u16,
+    flag: bool,
+}
+
+impl Extensible for Point {
+    const EXTENSIBILITY: Extensibility = Extensibility::Final;
+}
+
+impl CdrEncode for Point {
+    fn encode(&self, ser: &mut Serializer<'_>) -> rtps_cdr::Result<()> {
+        self.x.encode(ser)?;
+        self.flag.encode(ser)
+    }
+}
+
+impl CdrDecode for Point {
+    fn decode(de: &mut Deserializer<'_>) -> rtps_cdr::Result<Point> {
+        Ok(Point { x: u16::decode(de)?, flag: bool::decode(de)? })
+    }
+}
+
+/// A point encoded with a DHEADER in XCDR2
+#[derive(Debug, PartialEq)]
+struct Appendable(Point);
+
+impl Extensible for Appendable {
+    const EXTENSIBILITY: Extensibility = Extensibility::Appendable;
+}
+
+impl CdrEncode for Appendable {
+    fn encode(&self, ser: &mut Serializer<'_>) -> rtps_cdr::Result<()> {
+        ser.delimited(|ser| self.0.encode(ser))
+    }
+}
+
+impl CdrDecode for Appendable {
+    fn decode(de: &mut Deserializer<'_>) -> rtps_cdr::Result<Appendable> {
+        de.delimited(Point::decode).map(Appendable)
+    }
+}
+
+#[test]
+fn kinds() {
+    let kinds = [
+        (Extensibility::Final, Encoding::Xcdr1, 0x0000, "CDR_BE"),
+        (Extensibility::Appendable, Encoding::Xcdr1, 0x0000, "CDR_BE"),
+        (Extensibility::Mutable, Encoding::Xcdr1, 0x0002, "PL_CDR_BE"),
+        (Extensibility::Final, Encoding::Xcdr2, 0x0010, "CDR2_BE"),
+        (Extensibility::Mutable, Encoding::Xcdr2, 0x0012, "PL_CDR2_BE"),
+        (Extensibility::Appendable, Encoding::Xcdr2, 0x0014, "D_CDR2_BE"),
+    ];
+    for &(extensibility, encoding, id, name) in &kinds {
+        let big = EncapsulationKind::new(extensibility, Endianness::Big, encoding);
+        let little = EncapsulationKind::new(extensibility, Endianness::Little, encoding);
+        assert_eq!((big.id(), little.id(), big.to_string()), (id, id | 1, name.to_owned()));
+        assert_eq!(EncapsulationKind::from_id(little.id()).unwrap(), little);
+        assert_eq!((little.endianness(), little.encoding()), (Endianness::Little, encoding));
+        assert!(little.encodes(extensibility));
+    }
+    assert!(!EncapsulationKind::Cdr2Le.encodes(Extensibility::Appendable));
+    assert_eq!(EncapsulationKind::from_id(0x0004).unwrap_err().to_string(), "unknown encapsulation identifier 0x0004");
+}
+
+#[test]
+fn padding() {
+    // the 3 bytes of the point are padded by 1, noted in the options
+    let point = Point { x: 0x0102, flag: true };
+    let payload = to_payload(&point, Endianness::Big, Encoding::Xcdr1).unwrap();
+    assert_eq!(payload, [0, 0, 0, 1, 1, 2, 1, 0]);
+    assert_eq!(encode_payload(&point, Endianness::Big, Encoding::Xcdr1).unwrap(), payload);
+    let (header, bytes) = Encapsulation::read(&payload).unwrap();
+    assert_eq!((header.kind, header.padding(), bytes), (EncapsulationKind::CdrBe, 1, &[1, 2, 1][..]));
+
+    // the other options are kept
+    let mut output = vec![9];
+    let header = Encapsulation { kind: EncapsulationKind::Cdr2Le, options: 0x0100 };
+    header.write(&mut output, |output| {
+        output.push(7);
+        Ok(())
+    }).unwrap();
+    assert_eq!(output, [9, 0, 0x11, 1, 3, 7, 0, 0, 0]);
+
+    // padding beyond the payload
+    assert!(Encapsulation::read(&[0, 1, 0, 3, 1, 2]).is_err());
+    assert!(Encapsulation::read(&[0, 1, 0]).is_err());
+}
+
+#[test]
+fn dispatch() {
+    let point = Point { x: 0x0102, flag: false };
+    for &endianness in &[Endianness::Big, Endianness::Little] {
+        for &encoding in &[Encoding::Xcdr1, Encoding::Xcdr2] {
+            let payload = to_payload(&point, endianness, encoding).unwrap();
+            assert_eq!(from_payload::<Point>(&payload).unwrap(), point);
+            assert_eq!(decode_payload::<Point>(&payload).unwrap(), point);
+        }
+    }
+    let payload = encode_payload(&Appendable(point), Endianness::Little, Encoding::Xcdr2).unwrap();
+    assert_eq!(payload, [0, 0x15, 0, 1, 3, 0, 0, 0, 2, 1, 0, 0]);
+    assert_eq!(decode_payload::<Appendable>(&payload).unwrap(), Appendable(Point { x: 0x0102, flag: false }));
+
+    // the encapsulation of a final type does not encode an appendable one
+    let payload = encode_payload(&Point { x: 1, flag: true }, Endianness::Little, Encoding::Xcdr2).unwrap();
+    let err = decode_payload::<Appendable>(&payload).unwrap_err();
+    assert_eq!(err.to_string(), "CDR2_LE does not encode the type's extensibility");
+}
@@ -0,0 +1,97 @@
+extern crate rtps_cdr;
+
+use rtps_cdr::{Endianness, Parameter, ParameterList, ParameterListWriter, PIDMASK_INCOMPATIBLE, PIDMASK_VENDOR_SPECIFIC};
+
+const PID_TOPIC_NAME: u16 = 0x0005;
+const PID_UNICAST_LOCATOR: u16 = 0x002f;
+const PID_EXPECTS_INLINE_QOS: u16 = 0x0043;
+const PID_KEY_HASH: u16 = 0x0070;
+
+#[test]
+fn parameters_on_the_wire() {
+    let mut writer = ParameterListWriter::new(Endianness::Little);
+    writer.put(PID_TOPIC_NAME, "Square").unwrap();
+    writer.put(PID_EXPECTS_INLINE_QOS, &true).unwrap();
+    writer.put(PID_KEY_HASH, &[7u8; 16]).unwrap();
+    let bytes = writer.finish();
+    let mut expected = vec![0x05, 0, 12, 0, 7, 0, 0, 0];
+    expected.extend_from_slice(b"Square\0\0");
+    expected.extend_from_slice(&[0x43, 0, 4, 0, 1, 0, 0, 0]);
+    expected.extend_from_slice(&[0x70, 0, 16, 0]);
+    expected.extend_from_slice(&[7; 16]);
+    expected.extend_from_slice(&[1, 0, 0, 0]);
+    assert_eq!(bytes, expected);
+
+    let (list, rest) = ParameterList::read(&bytes, Endianness::Little).unwrap();
+    assert!(rest.is_empty());
+    assert_eq!(list.parameters().len(), 3);
+    assert_eq!(list.get::<String>(PID_TOPIC_NAME).unwrap().unwrap(), "Square");
+    assert_eq!(list.get::<bool>(PID_EXPECTS_INLINE_QOS).unwrap(), Some(true));
+    assert_eq!(list.get::<[u8; 16]>(PID_KEY_HASH).unwrap(), Some([7; 16]));
+    assert_eq!(list.get::<u32>(PID_UNICAST_LOCATOR).unwrap(), None);
+}
+
+#[test]
+fn repeated_parameters() {
+    for &endianness in &[Endianness::Big, Endianness::Little] {
+        let mut writer = ParameterListWriter::new(endianness);
+        writer.put(PID_UNICAST_LOCATOR, &(1i32, 7410u32, [192u8; 16])).unwrap();
+        writer.put(PID_UNICAST_LOCATOR, &(1i32, 7411u32, [10u8; 16])).unwrap();
+        let payload = writer.into_payload();
+        assert_eq!(payload[..4], [0, 2 | (endianness == Endianness::Little) as u8, 0, 0]);
+
+        let list = ParameterList::from_payload(&payload).unwrap();
+        assert_eq!(list.endianness(), endianness);
+        let locators = list.get_all::<(i32, u32, [u8; 16])>(PID_UNICAST_LOCATOR).unwrap();
+        assert_eq!(locators, [(1, 7410, [192; 16]), (1, 7411, [10; 16])]);
+    }
+}
+
+#[test]
+fn padding_and_trailing_bytes() {
+    // PID_PAD is skipped, the bytes following the sentinel are returned
+    let bytes = [0, 0, 0, 4, 0, 0, 0, 0, 0, 0x05, 0, 8, 0, 0, 0, 2, b'a', 0, 0, 0, 0, 1, 0, 0, 9, 9];
+    let (list, rest) = ParameterList::read(&bytes, Endianness::Big).unwrap();
+    assert_eq!(list.parameters(), [Parameter { id: PID_TOPIC_NAME, value: &[0, 0, 0, 2, b'a', 0, 0, 0] }]);
+    assert_eq!(list.get::<String>(PID_TOPIC_NAME).unwrap().unwrap(), "a");
+    assert_eq!(rest, [9, 9]);
+
+    // unaligned lengths and missing sentinels are rejected
+    let err = ParameterList::read(&[0, 0x05, 0, 3, 0, 0, 0], Endianness::Big).unwrap_err();
+    assert_eq!(err.to_string(), "invalid length 3 of parameter 0x0005");
+    assert!(ParameterList::read(&[0, 0x05, 0, 0], Endianness::Big).is_err());
+
+    // the payload of a final type is no parameter list
+    assert!(ParameterList::from_payload(&[0, 1, 0, 0, 1, 0, 0, 0]).is_err());
+}
+
+#[test]
+fn incompatible_parameters() {
+    let vendor = PIDMASK_VENDOR_SPECIFIC | PIDMASK_INCOMPATIBLE | 0x3001;
+    let incompatible = PIDMASK_INCOMPATIBLE | 0x0100;
+    let mut writer = ParameterListWriter::new(Endianness::Big);
+    writer.put(PID_TOPIC_NAME, "t").unwrap();
+    writer.put_raw(vendor, &[1, 2]).unwrap();
+    writer.put_raw(0x0200, &[]).unwrap();
+    let bytes = writer.finish();
+    let (list, _) = ParameterList::read(&bytes, Endianness::Big).unwrap();
+    assert!(list.parameters()[1].is_vendor_specific() && list.parameters()[1].is_incompatible());
+    assert_eq!(list.parameters()[1].value, [1, 2, 0, 0]);
+
+    // unknown parameters are ignored unless incompatible, vendor specific ones always
+    assert!(list.check(|id| id == PID_TOPIC_NAME).is_ok());
+
+    let mut writer = ParameterListWriter::new(Endianness::Big);
+    writer.put_raw(incompatible, &[]).unwrap();
+    let bytes = writer.finish();
+    let (list, _) = ParameterList::read(&bytes, Endianness::Big).unwrap();
+    let err = list.check(|id| id == PID_TOPIC_NAME).unwrap_err();
+    assert_eq!(err.to_string(), "unknown parameter 0x4100 must be understood");
+    assert!(list.check(|id| id == incompatible).is_ok());
+    assert!(list.contains(incompatible));
+
+    // values exceeding an unsigned short are not written
+    let mut writer = ParameterListWriter::new(Endianness::Big);
+    assert!(writer.put_raw(0x0200, &[0; 0x10000]).is_err());
+    assert_eq!(writer.finish(), [0, 1, 0, 0]);
+}
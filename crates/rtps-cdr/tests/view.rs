@@ -0,0 +1,47 @@
+extern crate rtps_cdr;
+
+use rtps_cdr::{encode_to_vec, CdrDecode, CdrView, Deserializer, Encoding, Endianness, Error, ViewMembers};
+
+/// A struct of a string, an octet, a sequence of octets and a sequence of `short`
+type Record = (String, u8, Vec<u8>, Vec<i16>);
+
+fn view<'a>(de: &mut Deserializer<'a>) -> rtps_cdr::Result<ViewMembers<'a, 4>> {
+    let mut members = ViewMembers::begin(de)?;
+    members.skip_view::<&str>(de)?;
+    members.skip_decoded::<u8>(de)?;
+    members.skip_view::<&[u8]>(de)?;
+    members.skip_decoded::<Vec<i16>>(de)?;
+    Ok(members)
+}
+
+#[test]
+fn borrowed_members() {
+    let record: Record = ("abc".to_owned(), 7, vec![1, 2], vec![-1, 300]);
+    for &endianness in &[Endianness::Big, Endianness::Little] {
+        let bytes = encode_to_vec(&record, endianness, Encoding::Xcdr1).unwrap();
+        let mut de = Deserializer::new(&bytes, endianness);
+        let members = view(&mut de).unwrap();
+        assert!(de.remaining().is_empty());
+
+        assert_eq!(<&str>::view(&mut members.member(0)).unwrap(), "abc");
+        assert_eq!(u8::decode(&mut members.member(1)).unwrap(), 7);
+        assert_eq!(<&[u8]>::view(&mut members.member(2)).unwrap(), [1, 2]);
+        // the sequence of `short` is aligned relative to the start
+        assert_eq!(Vec::<i16>::decode(&mut members.member(3)).unwrap(), [-1, 300]);
+        assert_eq!(Record::decode(&mut members.start()).unwrap(), record);
+    }
+}
+
+#[test]
+fn skipped_members() {
+    // strings are verified when accessed, lengths when skipped
+    let mut bytes = encode_to_vec(&("é".to_owned(), 1u8, vec![3u8], Vec::<i16>::new()), Endianness::Little,
+                                  Encoding::Xcdr1).unwrap();
+    bytes[4] = 0xff;
+    let members = view(&mut Deserializer::new(&bytes, Endianness::Little)).unwrap();
+    assert!(matches!(<&str>::view(&mut members.member(0)), Err(Error::InvalidUtf8)));
+    assert!(matches!(view(&mut Deserializer::new(&bytes[..bytes.len() - 1], Endianness::Little)), Err(Error::Eof)));
+
+    let err = view(&mut Deserializer::with_encoding(&bytes, Endianness::Little, Encoding::Xcdr2)).unwrap_err();
+    assert_eq!(err.to_string(), "views read XCDR1, not Xcdr2");
+}
@@ -0,0 +1,143 @@
+extern crate rtps_cdr;
+#[macro_use]
+extern crate serde_derive;
+
+use std::convert::TryFrom;
+use rtps_cdr::{from_slice, to_vec, Endianness, Error};
+use rtps_types::{BoundedString, Char, LongDouble};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Sample {
+    flag: bool,
+    value: i64,
+    short: u16,
+    name: String,
+    values: Vec<u16>,
+    octets: [u8; 3],
+    ratio: f32,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+enum Union {
+    Short { short: i16 },
+    Text { text: String },
+}
+
+fn sample() -> Sample {
+    Sample {
+        flag: true,
+        value: -2,
+        short: 0x0102,
+        name: "abc".to_owned(),
+        values: vec![7, 8],
+        octets: [1, 2, 3],
+        ratio: 1.5,
+    }
+}
+
+#[test]
+fn alignment_big_endian() {
+    let bytes = to_vec(&sample(), Endianness::Big).unwrap();
+    assert_eq!(bytes, [
+        1, 0, 0, 0, 0, 0, 0, 0, // flag, padding up to 8
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xfe, // value
+        1, 2, 0, 0, // short, padding up to 4
+        0, 0, 0, 4, b'a', b'b', b'c', 0, // name, length including NUL
+        0, 0, 0, 2, 0, 7, 0, 8, // values
+        1, 2, 3, 0, // octets, no length, padding up to 4
+        0x3f, 0xc0, 0, 0, // ratio
+    ]);
+    assert_eq!(from_slice::<Sample>(&bytes, Endianness::Big).unwrap(), sample());
+}
+
+#[test]
+fn alignment_little_endian() {
+    let bytes = to_vec(&sample(), Endianness::Little).unwrap();
+    assert_eq!(&bytes[8..20], &[0xfe, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 2, 1, 0, 0]);
+    assert_eq!(&bytes[20..24], &[4, 0, 0, 0]);
+    assert_eq!(from_slice::<Sample>(&bytes, Endianness::Little).unwrap(), sample());
+    assert!(from_slice::<Sample>(&bytes, Endianness::Big).is_err());
+}
+
+#[test]
+fn unions_and_options() {
+    let union = Union::Text { text: String::new() };
+    let bytes = to_vec(&union, Endianness::Little).unwrap();
+    assert_eq!(bytes, [1, 0, 0, 0, 1, 0, 0, 0, 0]);
+    assert_eq!(from_slice::<Union>(&bytes, Endianness::Little).unwrap(), union);
+    assert!(from_slice::<Union>(&[2, 0, 0, 0], Endianness::Little).is_err());
+
+    let bytes = to_vec(&(Some(3u16), None::<u16>), Endianness::Big).unwrap();
+    assert_eq!(bytes, [1, 0, 0, 3, 0]);
+    assert_eq!(from_slice::<(Option<u16>, Option<u16>)>(&bytes, Endianness::Big).unwrap(),
+               (Some(3), None));
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Reading {
+    id: u8,
+    #[serde(default, serialize_with = "rtps_types::xtypes::optional::serialize::<1, _, _>",
+            deserialize_with = "rtps_types::xtypes::optional::deserialize")]
+    value: Option<u64>,
+    #[serde(default, serialize_with = "rtps_types::xtypes::optional::serialize::<0x8000_4000, _, _>",
+            deserialize_with = "rtps_types::xtypes::optional::deserialize")]
+    unit: Option<u16>,
+}
+
+#[test]
+fn optional_members() {
+    let absent = Reading { id: 5, value: None, unit: None };
+    let bytes = to_vec(&absent, Endianness::Big).unwrap();
+    assert_eq!(bytes, [
+        5, 0, 0, 0, // id, padding up to 4
+        0, 1, 0, 0, // parameter header of value, length 0
+        0x7f, 0x01, 0, 8, 0x80, 0, 0x40, 0, 0, 0, 0, 0, // extended header of unit, must understand
+    ]);
+    assert_eq!(from_slice::<Reading>(&bytes, Endianness::Big).unwrap(), absent);
+
+    let present = Reading { id: 5, value: Some(9), unit: Some(7) };
+    let bytes = to_vec(&present, Endianness::Little).unwrap();
+    assert_eq!(bytes, [
+        5, 0, 0, 0, // id, padding up to 4
+        1, 0, 8, 0, // parameter header of value
+        9, 0, 0, 0, 0, 0, 0, 0, // value, aligned relative to its first byte
+        0x01, 0x7f, 8, 0, 0, 0x40, 0, 0x80, 4, 0, 0, 0, // extended header of unit
+        7, 0, 0, 0, // unit, padded to 4
+    ]);
+    assert_eq!(from_slice::<Reading>(&bytes, Endianness::Little).unwrap(), present);
+    assert!(matches!(from_slice::<Reading>(&[5, 0, 0, 0, 0x3f, 0x02, 0, 0], Endianness::Big),
+                     Err(Error::MissingParameterHeader)));
+}
+
+#[test]
+fn runtime_types() {
+    let value = (Char::from_char('a'), LongDouble::from_f64(1.0),
+                 BoundedString::<4>::try_from("ab").unwrap());
+    let bytes = to_vec(&value, Endianness::Big).unwrap();
+    assert_eq!(bytes.len(), 8 + 16 + 4 + 3);
+    assert_eq!(&bytes[8..10], &[0x3f, 0xff]);
+    let (c, ld, s): (Char, LongDouble, BoundedString<4>) = from_slice(&bytes, Endianness::Big).unwrap();
+    assert_eq!((c, ld.to_f64(), s.as_str()), (value.0, 1.0, "ab"));
+
+    // oversize strings are rejected
+    let bytes = to_vec("abcde", Endianness::Big).unwrap();
+    assert!(from_slice::<BoundedString<4>>(&bytes, Endianness::Big).is_err());
+}
+
+#[test]
+fn errors() {
+    assert!(matches!(to_vec(&'€', Endianness::Big), Err(Error::InvalidChar(0x20ac))));
+    assert!(matches!(from_slice::<u32>(&[0, 0], Endianness::Big), Err(Error::Eof)));
+    assert!(matches!(from_slice::<bool>(&[2], Endianness::Big), Err(Error::InvalidBool(2))));
+    assert!(matches!(from_slice::<String>(&[0, 0, 0, 1, b'a'], Endianness::Big), Err(Error::MissingNul)));
+    assert!(matches!(from_slice::<std::collections::HashMap<u8, u8>>(&[0, 0, 0, 0], Endianness::Big),
+                     Ok(ref map) if map.is_empty()));
+
+    struct Any;
+    impl<'de> serde::Deserialize<'de> for Any {
+        fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Any, D::Error> {
+            serde::de::IgnoredAny::deserialize(deserializer).map(|_| Any)
+        }
+    }
+    assert!(matches!(from_slice::<Any>(&[], Endianness::Big), Err(Error::NotSelfDescribing)));
+}
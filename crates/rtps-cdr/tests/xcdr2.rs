@@ -0,0 +1,205 @@
+extern crate rtps_cdr;
+#[macro_use]
+extern crate serde_derive;
+
+use serde::de::{Deserialize, Deserializer, MapAccess};
+use serde::ser::{Serialize, SerializeMap, Serializer};
+use rtps_cdr::{from_slice_with_encoding, to_vec_with_encoding, Encoding, Endianness};
+use rtps_types::xtypes::{self, Delimited, DelimitedValue, Members, MembersVisitor};
+
+/// A mutable type of `unsigned long long` members, as given by their header
+#[derive(Debug, Default, PartialEq)]
+struct Entries(Vec<(u32, u64)>);
+
+impl xtypes::SerializeMembers for Entries {
+    fn member_count(&self) -> usize {
+        self.0.len()
+    }
+
+    fn serialize_members<M: SerializeMap>(&self, map: &mut M) -> Result<(), M::Error> {
+        self.0.iter().try_for_each(|(header, value)| map.serialize_entry(header, value))
+    }
+}
+
+impl<'de> xtypes::DeserializeMembers<'de> for Entries {
+    fn deserialize_members<A: MapAccess<'de>>(mut map: A) -> Result<Entries, A::Error> {
+        let mut entries = Entries::default();
+        while let Some(header) = map.next_key::<u32>()? {
+            entries.0.push((header, map.next_value()?));
+        }
+        Ok(entries)
+    }
+}
+
+impl Serialize for Entries {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_newtype_struct(xtypes::MUTABLE, &Members(self))
+    }
+}
+
+impl<'de> Deserialize<'de> for Entries {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Entries, D::Error> {
+        deserializer.deserialize_newtype_struct(xtypes::MUTABLE, MembersVisitor::default())
+    }
+}
+
+/// A mutable type knowing the `unsigned short` member 1 only
+#[derive(Debug, Default, PartialEq)]
+struct Known(u16);
+
+impl<'de> xtypes::DeserializeMembers<'de> for Known {
+    fn deserialize_members<A: MapAccess<'de>>(mut map: A) -> Result<Known, A::Error> {
+        let mut known = Known::default();
+        while let Some(header) = map.next_key::<u32>()? {
+            match header & xtypes::MEMBER_ID_MASK {
+                1 => known.0 = map.next_value()?,
+                _ => xtypes::skip_member(&mut map, header)?,
+            }
+        }
+        Ok(known)
+    }
+}
+
+impl<'de> Deserialize<'de> for Known {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Known, D::Error> {
+        deserializer.deserialize_newtype_struct(xtypes::MUTABLE, MembersVisitor::default())
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, PartialEq)]
+#[serde(default)]
+struct Version2 {
+    a: u8,
+    b: u32,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Version1 {
+    a: u8,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Reading {
+    id: u8,
+    #[serde(default, serialize_with = "xtypes::optional::serialize::<1, _, _>",
+            deserialize_with = "xtypes::optional::deserialize")]
+    value: Option<u64>,
+    #[serde(default, serialize_with = "xtypes::optional::serialize::<2, _, _>",
+            deserialize_with = "xtypes::optional::deserialize")]
+    unit: Option<u16>,
+}
+
+fn encode<T: Serialize + ?Sized>(value: &T, encoding: Encoding) -> Vec<u8> {
+    to_vec_with_encoding(value, Endianness::Big, encoding).unwrap()
+}
+
+fn decode<'de, T: Deserialize<'de>>(bytes: &'de [u8], encoding: Encoding) -> rtps_cdr::Result<T> {
+    from_slice_with_encoding(bytes, Endianness::Big, encoding)
+}
+
+#[test]
+fn alignment() {
+    let value = (1u8, 2u64);
+    assert_eq!(encode(&value, Encoding::Xcdr2), [1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2]);
+    assert_eq!(encode(&value, Encoding::Xcdr1).len(), 16);
+    assert_eq!(decode::<(u8, u64)>(&encode(&value, Encoding::Xcdr2), Encoding::Xcdr2).unwrap(), value);
+}
+
+#[test]
+fn delimited() {
+    let names = vec!["a".to_owned()];
+    assert_eq!(encode(&Delimited(&names), Encoding::Xcdr2), [0, 0, 0, 10, 0, 0, 0, 1, 0, 0, 0, 2, b'a', 0]);
+    assert_eq!(encode(&Delimited(&names), Encoding::Xcdr1), encode(&names, Encoding::Xcdr1));
+
+    // members appended to a struct are skipped by earlier versions, and default in later ones
+    let bytes = encode(&(Delimited(&Version2 { a: 1, b: 2 }), 3u8), Encoding::Xcdr2);
+    assert_eq!(bytes, [0, 0, 0, 8, 1, 0, 0, 0, 0, 0, 0, 2, 3]);
+    let (version1, next): (DelimitedValue<Version1>, u8) = decode(&bytes, Encoding::Xcdr2).unwrap();
+    assert_eq!((version1.0, next), (Version1 { a: 1 }, 3));
+    let bytes = encode(&(Delimited(&Version1 { a: 1 }), 3u8), Encoding::Xcdr2);
+    let (version2, next): (DelimitedValue<Version2>, u8) = decode(&bytes, Encoding::Xcdr2).unwrap();
+    assert_eq!((version2.0, next), (Version2 { a: 1, b: 0 }, 3));
+
+    // the delimiter must not exceed the data
+    assert!(decode::<DelimitedValue<Version1>>(&[0, 0, 0, 8, 1], Encoding::Xcdr2).is_err());
+}
+
+#[test]
+fn emheader() {
+    let entries = Entries(vec![(xtypes::MUST_UNDERSTAND | 1, 7), (2, 8)]);
+    let bytes = encode(&entries, Encoding::Xcdr2);
+    assert_eq!(bytes, [
+        0, 0, 0, 32, // DHEADER
+        0xc0, 0, 0, 1, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 7, // M flag, LC 4, id 1
+        0x40, 0, 0, 2, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 8,
+    ]);
+    assert_eq!(decode::<Entries>(&bytes, Encoding::Xcdr2).unwrap(), entries);
+
+    // the length codes 0 to 3 and 5 to 7 imply the length of the member
+    let bytes = [
+        0, 0, 0, 36,
+        0x10, 0, 0, 1, 0, 3, 0, 0, // LC 1, two bytes
+        0x30, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 9, // LC 3, eight bytes
+        0x60, 0, 0, 3, 0, 0, 0, 2, 0, 0, 0, 1, 0, 0, 0, 2, // LC 6, two words following the length
+    ];
+    assert_eq!(decode::<Known>(&bytes, Encoding::Xcdr2).unwrap(), Known(3));
+}
+
+#[test]
+fn parameter_list() {
+    let entries = Entries(vec![(xtypes::MUST_UNDERSTAND | 1, 7), (0x3f00, 8)]);
+    let bytes = encode(&entries, Encoding::Xcdr1);
+    assert_eq!(bytes, [
+        0x40, 1, 0, 8, 0, 0, 0, 0, 0, 0, 0, 7, // M flag, id 1
+        0x7f, 1, 0, 8, 0, 0, 0x3f, 0, 0, 0, 0, 8, // PID_EXTENDED
+        0, 0, 0, 0, 0, 0, 0, 8,
+        0x7f, 2, 0, 0, // PID_LIST_END
+    ]);
+    assert_eq!(decode::<Entries>(&bytes, Encoding::Xcdr1).unwrap(), entries);
+
+    // values exceeding 16 bit lengths take the extended header
+    let long = vec![0u8; 0x10000];
+    let mut bytes = Vec::new();
+    let mut serializer = rtps_cdr::Serializer::new(&mut bytes, Endianness::Big);
+    struct Long<'a>(&'a Vec<u8>);
+    impl xtypes::SerializeMembers for Long<'_> {
+        fn member_count(&self) -> usize {
+            1
+        }
+        fn serialize_members<M: SerializeMap>(&self, map: &mut M) -> Result<(), M::Error> {
+            map.serialize_entry(&2u32, self.0)
+        }
+    }
+    serializer.serialize_newtype_struct(xtypes::MUTABLE, &Members(&Long(&long))).unwrap();
+    assert_eq!(&bytes[..16], &[0x7f, 1, 0, 8, 0, 0, 0, 2, 0, 1, 0, 4, 0, 1, 0, 0]);
+    assert_eq!(bytes.len(), 12 + 4 + 0x10000 + 4);
+
+    // PID_IGNORE and unknown members are skipped
+    let bytes = [0x3f, 3, 0, 4, 9, 9, 9, 9, 0, 7, 0, 0, 0, 1, 0, 4, 0, 5, 0, 0, 0x3f, 2, 0, 0];
+    assert_eq!(decode::<Known>(&bytes, Encoding::Xcdr1).unwrap(), Known(5));
+}
+
+#[test]
+fn must_understand() {
+    for &encoding in &[Encoding::Xcdr1, Encoding::Xcdr2] {
+        let bytes = encode(&Entries(vec![(3, 1), (2, 2)]), encoding);
+        assert_eq!(decode::<Known>(&bytes, encoding).unwrap(), Known(0));
+        let bytes = encode(&Entries(vec![(xtypes::MUST_UNDERSTAND | 2, 2)]), encoding);
+        let err = decode::<Known>(&bytes, encoding).unwrap_err();
+        assert_eq!(err.to_string(), "unknown member 2 must be understood");
+    }
+}
+
+#[test]
+fn optional_members() {
+    // XCDR2 encodes the presence instead of a parameter header
+    let absent = Reading { id: 5, value: None, unit: None };
+    let bytes = encode(&absent, Encoding::Xcdr2);
+    assert_eq!(bytes, [5, 0, 0]);
+    assert_eq!(decode::<Reading>(&bytes, Encoding::Xcdr2).unwrap(), absent);
+
+    let present = Reading { id: 5, value: Some(9), unit: Some(7) };
+    let bytes = encode(&present, Encoding::Xcdr2);
+    assert_eq!(bytes, [5, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 1, 0, 0, 7]);
+    assert_eq!(decode::<Reading>(&bytes, Encoding::Xcdr2).unwrap(), present);
+}
@@ -21,7 +21,7 @@ fn main() -> Result<(), Error> {
     let out_dir = env::var("OUT_DIR").unwrap();
     let dest_path = Path::new(&out_dir).join(RUST_OUTFILE);
     let search_path = vec![IDL_DIR.to_owned()];
-    let config = Configuration::default();
+    let config = Configuration { cdr: true, no_std: true, ..Configuration::default() };
     let mut out = File::create(dest_path)?;
     let mut data = String::new();
 
@@ -2,7 +2,11 @@
 //
 // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
 // http://www.apache.org/licenses/LICENSE-2.0>
-extern crate serde_derive;
+
+//! The types are generated `no_std`, requiring `alloc` only; without the default feature
+//! `std` the crate serves participants lacking the standard library.
+#![no_std]
+extern crate alloc;
 extern crate serde;
 
 include!(concat!(env!("OUT_DIR"), "/DdsCollection.rs"));
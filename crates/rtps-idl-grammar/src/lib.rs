@@ -19,3 +19,6 @@ extern crate pest_derive;
 #[derive(Parser)]
 #[grammar = "grammar/idl_v4.pest"]
 pub struct IdlParser;
+
+// Rebuild the parser whenever the grammar is modified
+const _GRAMMAR: &str = include_str!("grammar/idl_v4.pest");
@@ -2,11 +2,16 @@
 //
 // Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
 // http://www.apache.org/licenses/LICENSE-2.0>
+
+//! Abstract syntax tree of an IDL specification
+//!
+//! The AST is produced by `parse_with_loader()`; it is owned, and declarations
+//! stemming from included files are merged into the module hierarchy of the
+//! including file. Declarations carry their annotations and source spans.
+use std::convert::TryFrom;
 use linked_hash_map::LinkedHashMap;
-use std::io::Write;
-use std::io::Error;
 
-///
+/// Unary operator of a constant expression
 #[derive(Clone, Debug)]
 pub enum UnaryOp {
     Neg,
@@ -14,27 +19,7 @@ pub enum UnaryOp {
     Inverse,
 }
 
-const INDENTION: usize = 4;
-const ATTR_ALLOW_DEADCODE: &str = "#[allow(dead_code)]";
-const ATTR_DERIVE_SERDE: &str = "#[derive(Serialize, Deserialize)]";
-const ATTR_DERIVE_CLONE_DEBUG: &str = "#[derive(Clone, Debug)]";
-const ATTR_ALLOW_NON_CAMEL_CASE_TYPES: &str = "#[allow(non_camel_case_types)]";
-const ATTR_ALLOW_NON_SNAKE_CASE: &str = "#[allow(non_snake_case)]";
-const IMPORT_SERDE: &str = "use serde_derive::{Serialize, Deserialize};";
-const ATTR_ALLOW_UNUSED_IMPORTS: &str = "#[allow(unused_imports)]";
-
-impl UnaryOp {
-    pub fn write<W: Write>(&self, out: &mut W) -> Result<(), Error> {
-        let _ = match self {
-            UnaryOp::Neg => write!(out, "-"),
-            UnaryOp::Pos => write!(out, "+"),
-            UnaryOp::Inverse => write!(out, "~"),
-        };
-        Ok(())
-    }
-}
-
-///
+/// Binary operator of a constant expression, the left operand precedes
 #[derive(Clone, Debug)]
 pub enum BinaryOp {
     Add,
@@ -49,48 +34,75 @@ pub enum BinaryOp {
     And,
 }
 
-
-impl BinaryOp {
-    pub fn write<W: Write>(&self, out: &mut W) -> Result<(), Error> {
-        let _ = match self {
-            BinaryOp::Add => write!(out, "+"),
-            BinaryOp::Sub => write!(out, "-"),
-            BinaryOp::Mul => write!(out, "*"),
-            BinaryOp::Div => write!(out, "/"),
-            BinaryOp::Mod => write!(out, "%"),
-            BinaryOp::LShift => write!(out, "<<"),
-            BinaryOp::RShift => write!(out, ">>"),
-            BinaryOp::Or => write!(out, "|"),
-            BinaryOp::Xor => write!(out, "^"),
-            BinaryOp::And => write!(out, "&"),
-        };
-        Ok(())
-    }
+/// Location of a declaration within the IDL source
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Span {
+    /// Included file the declaration stems from, `None` for the top level input
+    pub file: Option<String>,
+    /// Byte offset of the start
+    pub start: usize,
+    /// Byte offset of the end
+    pub end: usize,
+    /// Line of the start, counting from 1
+    pub line: usize,
+    /// Column of the start, counting from 1
+    pub column: usize,
 }
 
-///
+/// Scoped name, the components and whether it is absolute (prefixed by `::`)
 #[derive(Clone, Debug)]
 pub struct IdlScopedName(pub Vec<String>, pub bool);
 
-impl IdlScopedName {
-    pub fn write<W: Write>(&self, out: &mut W) -> Result<(), Error> {
-        let is_absolute_path = self.1;
-        let components = &self.0;
-        for (idx, comp) in components.iter().enumerate() {
-            // TODO, use paths according to "crate::" or "super::"
-            if idx == 0 && !is_absolute_path {
-                let _ = write!(out, "{}", comp);
-            } else if idx == 0 && is_absolute_path {
-                let _ = write!(out, "crate::{}", comp);
-            } else {
-                let _ = write!(out, "::{}", comp);
-            }
-        }
-        Ok(())
+/// Parameter of an annotation application
+#[derive(Clone, Debug)]
+pub struct IdlAnnotationParam {
+    /// Name of the parameter, `None` for the short form `@name(value)`
+    pub id: Option<String>,
+    pub value: Box<IdlValueExpr>,
+}
+
+/// Annotation application, such as `@key` or `@range(min = 0, max = 10)`
+#[derive(Clone, Debug)]
+pub struct IdlAnnotation {
+    pub name: IdlScopedName,
+    pub params: Vec<IdlAnnotationParam>,
+}
+
+impl IdlAnnotation {
+    /// Name of the annotation, ignoring the scope
+    pub fn id(&self) -> &str {
+        self.name.0.last().map(|s| s.as_str()).unwrap_or("")
+    }
+
+    /// Value of the parameter `id`; the short form value is regarded as parameter `value`
+    pub fn param(&self, id: &str) -> Option<&IdlValueExpr> {
+        self.params.iter()
+            .find(|p| match p.id {
+                Some(ref param_id) => param_id == id,
+                None => id == "value",
+            })
+            .map(|p| p.value.as_ref())
+    }
+
+    /// Value of the single parameter, either the short form or named `value`
+    pub fn value(&self) -> Option<&IdlValueExpr> {
+        self.param("value")
     }
 }
 
-///
+/// Find the annotation `id` among the annotations of a declaration
+pub fn find_annotation<'a>(annotations: &'a [IdlAnnotation], id: &str) -> Option<&'a IdlAnnotation> {
+    annotations.iter().find(|a| a.id() == id)
+}
+
+/// Whether the boolean annotation `id`, such as `@optional` or `@key(TRUE)`, is applied;
+/// the parameter defaults to `TRUE`
+pub fn has_annotation(annotations: &[IdlAnnotation], id: &str) -> bool {
+    matches!(find_annotation(annotations, id).map(|a| a.value()),
+             Some(None) | Some(Some(IdlValueExpr::BooleanLiteral(true))))
+}
+
+/// Constant expression, as found in constant declarations, bounds, labels and annotations
 #[derive(Clone, Debug)]
 pub enum IdlValueExpr {
     None,
@@ -110,115 +122,45 @@ pub enum IdlValueExpr {
     ScopedName(IdlScopedName),
 }
 
-impl IdlValueExpr {
-    pub fn write<W: Write>(&self, out: &mut W) -> Result<(), Error> {
-        let _ = match self {
-            IdlValueExpr::None => write!(out, ""),
-            IdlValueExpr::DecLiteral(ref val) => write!(out, "{}", val),
-            IdlValueExpr::HexLiteral(ref val) => write!(out, "{}", val),
-            IdlValueExpr::OctLiteral(ref val) => write!(out, "{}", val),
-            IdlValueExpr::CharLiteral(ref val) => write!(out, "{}", val),
-            IdlValueExpr::WideCharLiteral(ref val) => write!(out, "{}", val),
-            IdlValueExpr::StringLiteral(ref val) => write!(out, "{}", val),
-            IdlValueExpr::WideStringLiteral(ref val) => write!(out, "{}", val),
-            IdlValueExpr::BooleanLiteral(val) => write!(out, "{}", val),
-            //            FloatLiteral(ref integ => write!(out, "{}", val), ref fract, ref expo, ref suffix) => write!(out, "{}", val),
-            IdlValueExpr::UnaryOp(op, ref expr) => op.write(out).and_then(|_| expr.write(out)),
-            IdlValueExpr::BinaryOp(op, ref expr) => op.write(out).and_then(|_| expr.write(out)),
-            IdlValueExpr::Expr(ref expr1, ref expr2) => expr1.write(out).and_then(|_| expr2.write(out)),
-            IdlValueExpr::Brace(ref expr) => write!(out, "{}", "(")
-                .and_then(|_| expr.write(out))
-                .and_then(|_| write!(out, "{}", ")")),
-            IdlValueExpr::FloatLiteral(ref integral, ref fraction, ref exponent, ref suffix) => {
-                integral.as_ref().and_then(|i| write!(out, "{}", i).err());
-                fraction.as_ref().and_then(|f| write!(out, ".{}", f).err());
-                exponent.as_ref().and_then(|e| write!(out, "e{}", e).err());
-                suffix.as_ref().and_then(|s| write!(out, "{}", s).err());
-                Ok(())
-            }
-            IdlValueExpr::ScopedName(ref name) => name.write(out),
-            //_ => unimplemented!(),
-        };
-        Ok(())
-    }
-}
-
 ///
 impl Default for IdlValueExpr {
     fn default() -> IdlValueExpr { IdlValueExpr::None }
 }
 
-///
+/// Member of a struct, declarators `long a, b;` result in a member each
 #[derive(Clone, Debug)]
 pub struct IdlStructMember {
     pub id: String,
     pub type_spec: Box<IdlTypeSpec>,
+    pub annotations: Vec<IdlAnnotation>,
+    pub span: Span,
 }
 
-///
-impl IdlStructMember {
-    ///
-    pub fn write<W: Write>(&self, out: &mut W, _level: usize) -> Result<(), Error> {
-        write!(out, "{}: ", self.id)
-            .and_then(|_| self.type_spec.write(out))
-            .and_then(|_| write!(out, ","))
-    }
-}
-
-///
+/// Element of a union branch
 #[derive(Clone, Debug)]
 pub struct IdlSwitchElement {
     pub id: String,
     pub type_spec: Box<IdlTypeSpec>,
+    pub annotations: Vec<IdlAnnotation>,
+    pub span: Span,
 }
 
-///
-impl IdlSwitchElement {
-    ///
-    pub fn write<W: Write>(&self, out: &mut W, _level: usize) -> Result<(), Error> {
-        write!(out, "{}: ", self.id)
-            .and_then(|_| self.type_spec.write(out))
-            .and_then(|_| write!(out, ","))
-    }
-}
-
-///
+/// Label of a union branch, `case <expr>:` or `default:`
 #[derive(Clone, Debug)]
 pub enum IdlSwitchLabel {
     Label(Box<IdlValueExpr>),
     Default,
 }
 
-///
+/// Branch of a union, one or more labels selecting the element
 #[derive(Clone, Debug)]
 pub struct IdlSwitchCase {
     pub labels: Vec<IdlSwitchLabel>,
     pub elem_spec: Box<IdlSwitchElement>,
+    pub span: Span,
 }
 
-///
-impl IdlSwitchCase {
-    ///
-    pub fn write<W: Write>(&self, out: &mut W, level: usize) -> Result<(), Error> {
-        for label in &self.labels {
-            match label {
-                IdlSwitchLabel::Label(ref val_expr) =>
-                    write!(out, "{:indent$}", "", indent = level * INDENTION)
-                        .and_then(|_| val_expr.write(out))
-                        .and_then(|_| write!(out, "{}", "{"))
-                        .and_then(|_| self.elem_spec.write(out, level + 1))
-                        .and_then(|_| writeln!(out, "{}", "},"))?,
-                IdlSwitchLabel::Default =>
-                    write!(out, "{:indent$}default{}", "", "{", indent = level * INDENTION)
-                        .and_then(|_| self.elem_spec.write(out, level + 1))
-                        .and_then(|_| writeln!(out, "{}", "},"))?,
-            }
-        }
-        Ok(())
-    }
-}
-
-///
+/// Type specification of members, typedefs, constants and template arguments
 #[derive(Clone, Debug)]
 pub enum IdlTypeSpec {
     None,
@@ -249,68 +191,47 @@ pub enum IdlTypeSpec {
     ScopedName(IdlScopedName),
 }
 
-
-///
-impl IdlTypeSpec {
-    ///
-    pub fn write<W: Write>(&self, out: &mut W) -> Result<(), Error> {
-        let _ = match self {
-            IdlTypeSpec::F32Type => write!(out, "f32"),
-            IdlTypeSpec::F64Type => write!(out, "f64"),
-            IdlTypeSpec::F128Type => write!(out, "f128"),
-            IdlTypeSpec::I16Type => write!(out, "i16"),
-            IdlTypeSpec::I32Type => write!(out, "i32"),
-            IdlTypeSpec::I64Type => write!(out, "i64"),
-            IdlTypeSpec::U16Type => write!(out, "u16"),
-            IdlTypeSpec::U32Type => write!(out, "u32"),
-            IdlTypeSpec::U64Type => write!(out, "u64"),
-            IdlTypeSpec::CharType => write!(out, "char"),
-            IdlTypeSpec::WideCharType => write!(out, "char"),
-            IdlTypeSpec::BooleanType => write!(out, "bool"),
-            IdlTypeSpec::OctetType => write!(out, "u8"),
-            IdlTypeSpec::StringType(None) => write!(out, "String"),
-            IdlTypeSpec::WideStringType(None) => write!(out, "String"),
-            // TODO implement String/Sequence bounds
-            IdlTypeSpec::StringType(_) => write!(out, "String"),
-            // TODO implement String/Sequence bounds for serializer and deserialzer
-            IdlTypeSpec::WideStringType(_) => write!(out, "String"),
-            IdlTypeSpec::SequenceType(typ_expr, _) => {
-                write!(out, "Vec<")
-                    .and_then(|_| typ_expr.as_ref().write(out))
-                    .and_then(|_| write!(out, ">"))
-            }
-            IdlTypeSpec::ArrayType(typ_expr, dim_expr_list) => {
-                for _ in dim_expr_list { let _ = write!(out, "["); }
-                let _ = typ_expr.as_ref().write(out);
-                for dim_expr in dim_expr_list {
-                    // TODO return result
-                    let _ = write!(out, ";")
-                        .and_then(|_| dim_expr.as_ref().write(out))
-                        .and_then(|_| write!(out, "]"));
-                }
-                Ok(())
-            }
-            IdlTypeSpec::ScopedName(ref name) => name.write(out),
-            _ => unimplemented!(),
-        };
-
-        Ok(())
-    }
-}
-
 ///
 impl Default for IdlTypeSpec {
     fn default() -> IdlTypeSpec { IdlTypeSpec::None }
 }
 
-///
+/// Enumerator of an enum declaration
+#[derive(Clone, Debug)]
+pub struct IdlEnumerator {
+    pub id: String,
+    pub annotations: Vec<IdlAnnotation>,
+    pub span: Span,
+}
+
+/// Kind of a type declaration, the first field is the declared identifier
 #[derive(Clone, Debug)]
 pub enum IdlTypeDclKind {
     None,
+    /// `typedef` of the type specification
     TypeDcl(String, Box<IdlTypeSpec>),
+    /// `struct` and its members
     StructDcl(String, Vec<Box<IdlStructMember>>),
+    /// `union`, the switch type and branches
     UnionDcl(String, Box<IdlTypeSpec>, Vec<IdlSwitchCase>),
-    EnumDcl(String,  Vec<String>),
+    /// `enum` and its enumerators
+    EnumDcl(String, Vec<IdlEnumerator>),
+    /// `native` type, implemented by a user-provided Rust type
+    NativeDcl(String),
+}
+
+impl IdlTypeDclKind {
+    /// The declared identifier
+    pub fn id(&self) -> Option<&str> {
+        match self {
+            IdlTypeDclKind::None => None,
+            IdlTypeDclKind::TypeDcl(ref id, _)
+            | IdlTypeDclKind::StructDcl(ref id, _)
+            | IdlTypeDclKind::UnionDcl(ref id, _, _)
+            | IdlTypeDclKind::EnumDcl(ref id, _)
+            | IdlTypeDclKind::NativeDcl(ref id) => Some(id),
+        }
+    }
 }
 
 ///
@@ -318,93 +239,17 @@ impl Default for IdlTypeDclKind {
     fn default() -> IdlTypeDclKind { IdlTypeDclKind::None }
 }
 
-///
+/// Type declaration with annotations applied
 #[derive(Clone,
 Debug,
 Default)]
-pub struct IdlTypeDcl(pub IdlTypeDclKind);
-
-///
-impl IdlTypeDcl {
-    ///
-    ///
-    pub fn write<W: Write>(&mut self, out: &mut W, level: usize) -> Result<(), Error> {
-        match self.0 {
-            IdlTypeDclKind::TypeDcl(ref id, ref type_spec) => {
-                // TODO collect/return result
-                let _ = writeln!(out, "");
-                let _ = writeln!(out, "{:indent$}//", "", indent = level * INDENTION);
-                let _ = writeln!(out, "{:indent$}//", "", indent = level * INDENTION);
-                let _ = writeln!(out, "{:indent$}{}", "", ATTR_ALLOW_DEADCODE, indent = level * INDENTION);
-                let _ = writeln!(out, "{:indent$}{}", "", ATTR_ALLOW_NON_CAMEL_CASE_TYPES, indent = level * INDENTION);
-                let _ = write!(out, "{:indent$}pub type {} = ", "", id, indent = level * INDENTION);
-                let _ = type_spec.as_ref().write(out);
-                let _ = writeln!(out, ";");
-                Ok(())
-            }
-            IdlTypeDclKind::StructDcl(ref id, ref type_spec) => {
-                // TODO collect/return result
-                let _ = writeln!(out, "");
-                let _ = writeln!(out, "{:indent$}//", "", indent = level * INDENTION);
-                let _ = writeln!(out, "{:indent$}//", "", indent = level * INDENTION);
-                let _ = writeln!(out, "{:indent$}{}", "", ATTR_ALLOW_DEADCODE, indent = level * INDENTION);
-                let _ = writeln!(out, "{:indent$}{}", "", ATTR_ALLOW_NON_CAMEL_CASE_TYPES, indent = level * INDENTION);
-                let _ = writeln!(out, "{:indent$}{}", "", ATTR_DERIVE_SERDE, indent = level * INDENTION);
-                let _ = writeln!(out, "{:indent$}{}", "", ATTR_DERIVE_CLONE_DEBUG, indent = level * INDENTION);
-                let _ = writeln!(out, "{:indent$}pub struct {} {}", "", id, "{", indent = level * INDENTION);
-                for member in type_spec {
-                    let _ = write!(out, "{:indent$}pub ", "", indent = (level +1) * INDENTION)
-                        .and_then(|_| member.as_ref().write(out, level + 1))
-                        .and_then(|_| writeln!(out));
-                }
-                let _ = writeln!(out, "{:indent$}{}", "", "}", indent = level * INDENTION);
-                Ok(())
-            }
-
-            IdlTypeDclKind::EnumDcl(ref id, ref enums) => {
-                // TODO collect/return result
-                let _ = writeln!(out, "");
-                let _ = writeln!(out, "{:indent$}//", "", indent = level * INDENTION);
-                let _ = writeln!(out, "{:indent$}//", "", indent = level * INDENTION);
-                let _ = writeln!(out, "{:indent$}{}", "", ATTR_ALLOW_DEADCODE, indent = level * INDENTION);
-                let _ = writeln!(out, "{:indent$}{}", "", ATTR_ALLOW_NON_CAMEL_CASE_TYPES, indent = level * INDENTION);
-                let _ = writeln!(out, "{:indent$}{}", "", ATTR_DERIVE_SERDE, indent = level * INDENTION);
-                let _ = writeln!(out, "{:indent$}{}", "", ATTR_DERIVE_CLONE_DEBUG, indent = level * INDENTION);
-                let _ = writeln!(out, "{:indent$}pub enum {} {}", "", id, "{", indent = level * INDENTION);
-                for variant in enums {
-                    let _ = writeln!(out, "{:indent$}{},", "", variant, indent = (level +1) * INDENTION);
-                }
-                let _ = writeln!(out, "{:indent$}{}", "", "}", indent = level * INDENTION);
-                Ok(())
-            }
-
-            IdlTypeDclKind::UnionDcl(ref id, ref _type_spec, ref switch_cases) => {
-                // TODO collect/return result
-                let _ = writeln!(out, "");
-                let _ = writeln!(out, "{:indent$}//", "", indent = level * INDENTION);
-                let _ = writeln!(out, "{:indent$}//", "", indent = level * INDENTION);
-                let _ = writeln!(out, "{:indent$}{}", "", ATTR_ALLOW_DEADCODE, indent = level * INDENTION);
-                let _ = writeln!(out, "{:indent$}{}", "", ATTR_ALLOW_NON_CAMEL_CASE_TYPES, indent = level * INDENTION);
-                let _ = writeln!(out, "{:indent$}{}", "", ATTR_DERIVE_SERDE, indent = level * INDENTION);
-                let _ = writeln!(out, "{:indent$}{}", "", ATTR_DERIVE_CLONE_DEBUG, indent = level * INDENTION);
-                let _ = writeln!(out, "{:indent$}pub enum {} {}", "", id, "{", indent = level * INDENTION);
-                for case in switch_cases {
-                    let _ = case.write(out, level + 1);
-                }
-                let _ = writeln!(out, "{:indent$}{}", "", "}", indent = level * INDENTION);
-
-                let _ = writeln!(out, "{:indent$}//", "", indent = level * INDENTION);
-                let _ = writeln!(out, "{:indent$}// TODO custom de-/serializer", "", indent = level * INDENTION);
-                let _ = writeln!(out, "{:indent$}//", "", indent = level * INDENTION);
-
-                Ok(())
-            }
-            _ => Ok(())
-        }
-    }
+pub struct IdlTypeDcl {
+    pub kind: IdlTypeDclKind,
+    pub annotations: Vec<IdlAnnotation>,
+    pub span: Span,
 }
 
-///
+/// Constant declaration
 #[derive(Clone,
 Default,
 Debug)]
@@ -412,82 +257,178 @@ pub struct IdlConstDcl {
     pub id: String,
     pub typedcl: Box<IdlTypeSpec>,
     pub value: Box<IdlValueExpr>,
+    pub annotations: Vec<IdlAnnotation>,
+    pub span: Span,
 }
 
-///
-impl IdlConstDcl {
-    ///
-    ///
-    pub fn write<W: Write>(&mut self, out: &mut W, level: usize) -> Result<(), Error> {
-        writeln!(out, "{:indent$}{}", "", ATTR_ALLOW_DEADCODE, indent = level * INDENTION)
-            .and_then(|_| write!(out, "{:indent$}pub const {}", "", self.id, indent = level * INDENTION))
-            .and_then(|_| write!(out, ": "))
-            .and_then(|_| self.typedcl.write(out))
-            .and_then(|_| write!(out, " = "))
-            .and_then(|_| self.value.write(out))
-            .and_then(|_| writeln!(out, ";"))
-    }
-}
-
-///
+/// Module, the declarations of a reopened module are merged, in order of appearance
 #[derive(Clone,
 Default, Debug)]
 pub struct IdlModule {
+    /// Identifier, `None` for the root module
     pub id: Option<String>,
+    /// Nesting depth
     pub level: usize,
     pub modules: LinkedHashMap<String, Box<IdlModule>>,
     pub types: LinkedHashMap<String, Box<IdlTypeDcl>>,
     pub constants: LinkedHashMap<String, Box<IdlConstDcl>>,
+    /// Span of the first module declaration
+    pub span: Span,
 }
 
-
 ///
 impl IdlModule {
     pub fn new(id: Option<String>, level: usize) -> IdlModule {
         IdlModule {
-            id: id,
-            level: level,
+            id,
+            level,
             modules: LinkedHashMap::default(),
             types: LinkedHashMap::default(),
             constants: LinkedHashMap::default(),
+            span: Span::default(),
         }
     }
 
-    pub fn write<W: Write>(&mut self, out: &mut W, level: usize) -> Result<(), Error> {
-        let _prolog = match self.id {
-            Some(ref id_str) =>
-                writeln!(out, "{:indent$}{}", "",
-                         ATTR_ALLOW_NON_SNAKE_CASE, indent = level * INDENTION)
-                    .and_then(|_| writeln!(out, "{:indent$}pub mod {} {}", "", id_str, "{", indent = level * INDENTION)),
+    /// The submodule at the relative path
+    pub fn module(&self, path: &[String]) -> Option<&IdlModule> {
+        path.iter().try_fold(self, |module, id| module.modules.get(id).map(|m| m.as_ref()))
+    }
+}
 
-            _ => write!(out, ""),
-        };
+/// Parsed IDL specification, the root module holds the top level declarations
+#[derive(Clone, Default, Debug)]
+pub struct Specification {
+    pub root: IdlModule,
+}
 
-        let add: usize = if self.id.is_some() { 1 } else { 0 };
+impl Specification {
+    /// Resolve the scoped name of a type, as seen from within the module `scope`.
+    ///
+    /// Relative names are looked up in the enclosing scopes, from the innermost
+    /// to the root module. Returns the path of the declaring module and the declaration.
+    pub fn resolve_type(&self, scope: &[String], name: &IdlScopedName) -> Option<(Vec<String>, &IdlTypeDcl)> {
+        let (id, prefix) = name.0.split_last()?;
+        let depth = if name.1 { 0 } else { scope.len() };
+
+        (0..=depth).rev()
+            .find_map(|level| {
+                let mut path = scope[..level].to_vec();
+                path.extend_from_slice(prefix);
+                self.root.module(&path)
+                    .and_then(|module| module.types.get(id))
+                    .map(|type_dcl| (path, type_dcl.as_ref()))
+            })
+    }
 
-        let _ = writeln!(out, "{:indent$}{}", "",
-                         ATTR_ALLOW_UNUSED_IMPORTS, indent = (level + add) * INDENTION)
-            .and_then(|_| writeln!(out, "{:indent$}{}", "",
-                                   IMPORT_SERDE, indent = (level + add) * INDENTION));
+    /// Resolve the scoped name of a constant, as seen from within the module `scope`
+    pub fn resolve_const(&self, scope: &[String], name: &IdlScopedName) -> Option<(Vec<String>, &IdlConstDcl)> {
+        let (id, prefix) = name.0.split_last()?;
+        let depth = if name.1 { 0 } else { scope.len() };
+
+        (0..=depth).rev()
+            .find_map(|level| {
+                let mut path = scope[..level].to_vec();
+                path.extend_from_slice(prefix);
+                self.root.module(&path)
+                    .and_then(|module| module.constants.get(id))
+                    .map(|const_dcl| (path, const_dcl.as_ref()))
+            })
+    }
 
-        for typ in self.types.entries() {
-            typ.into_mut().write(out, level + add)?;
+    /// Evaluate an integer constant expression, as seen from within the module `scope`.
+    ///
+    /// Constants are resolved and binary operators evaluated with the precedence of the IDL,
+    /// associating to the left. Returns `None` if the expression is no integer, or on overflow.
+    pub fn eval_int(&self, scope: &[String], expr: &IdlValueExpr) -> Option<i128> {
+        // bounds the resolution of (cyclic) constants
+        const MAX_DEPTH: usize = 64;
+
+        fn precedence(op: &BinaryOp) -> u8 {
+            match op {
+                BinaryOp::Or => 0,
+                BinaryOp::Xor => 1,
+                BinaryOp::And => 2,
+                BinaryOp::LShift | BinaryOp::RShift => 3,
+                BinaryOp::Add | BinaryOp::Sub => 4,
+                BinaryOp::Mul | BinaryOp::Div | BinaryOp::Mod => 5,
+            }
         }
 
-        for module in self.modules.entries() {
-            module.into_mut().write(out, level + add)?;
+        // applies the topmost operator to the two topmost values
+        fn reduce(values: &mut Vec<i128>, ops: &mut Vec<&BinaryOp>) -> Option<()> {
+            let op = ops.pop()?;
+            let rhs = values.pop()?;
+            let lhs = values.pop()?;
+            values.push(match op {
+                BinaryOp::Add => lhs.checked_add(rhs),
+                BinaryOp::Sub => lhs.checked_sub(rhs),
+                BinaryOp::Mul => lhs.checked_mul(rhs),
+                BinaryOp::Div => lhs.checked_div(rhs),
+                BinaryOp::Mod => lhs.checked_rem(rhs),
+                BinaryOp::LShift => u32::try_from(rhs).ok().and_then(|rhs| lhs.checked_shl(rhs)),
+                BinaryOp::RShift => u32::try_from(rhs).ok().and_then(|rhs| lhs.checked_shr(rhs)),
+                BinaryOp::Or => Some(lhs | rhs),
+                BinaryOp::Xor => Some(lhs ^ rhs),
+                BinaryOp::And => Some(lhs & rhs),
+            }?);
+            Some(())
         }
 
-        for cnst in self.constants.entries() {
-            cnst.into_mut().write(out, level + add)?;
+        fn eval(spec: &Specification, scope: &[String], expr: &IdlValueExpr, depth: usize) -> Option<i128> {
+            if depth > MAX_DEPTH {
+                return None;
+            }
+            match expr {
+                IdlValueExpr::DecLiteral(ref val) => val.parse().ok(),
+                IdlValueExpr::HexLiteral(ref val) => i128::from_str_radix(&val[2..], 16).ok(),
+                IdlValueExpr::OctLiteral(ref val) => i128::from_str_radix(val, 8).ok(),
+                IdlValueExpr::Brace(ref expr) => eval(spec, scope, expr, depth + 1),
+                IdlValueExpr::UnaryOp(ref op, ref expr) => {
+                    let val = eval(spec, scope, expr, depth + 1)?;
+                    match op {
+                        UnaryOp::Neg => val.checked_neg(),
+                        UnaryOp::Pos => Some(val),
+                        UnaryOp::Inverse => Some(!val),
+                    }
+                }
+                IdlValueExpr::Expr(..) => {
+                    // the operator chain, parsed right-recursive, is reduced with the precedence of
+                    // the IDL, operators of equal precedence associating to the left
+                    let mut values = Vec::new();
+                    let mut ops: Vec<&BinaryOp> = Vec::new();
+                    let mut expr = expr;
+                    loop {
+                        match expr {
+                            IdlValueExpr::Expr(ref lhs, ref rhs) => match rhs.as_ref() {
+                                IdlValueExpr::BinaryOp(ref op, ref rhs) => {
+                                    values.push(eval(spec, scope, lhs, depth + 1)?);
+                                    while ops.last().is_some_and(|top| precedence(top) >= precedence(op)) {
+                                        reduce(&mut values, &mut ops)?;
+                                    }
+                                    ops.push(op);
+                                    expr = rhs;
+                                }
+                                _ => return None,
+                            },
+                            _ => {
+                                values.push(eval(spec, scope, expr, depth + 1)?);
+                                break;
+                            }
+                        }
+                    }
+                    while !ops.is_empty() {
+                        reduce(&mut values, &mut ops)?;
+                    }
+                    values.pop()
+                }
+                IdlValueExpr::ScopedName(ref name) => {
+                    let (path, const_dcl) = spec.resolve_const(scope, name)?;
+                    eval(spec, &path, &const_dcl.value, depth + 1)
+                }
+                _ => None,
+            }
         }
 
-        let _epilog = match self.id {
-            Some(_) => writeln!(out, "{:indent$}{}", "", "}", indent = level * INDENTION),
-            _ => write!(out, ""),
-        };
-
-        Ok(())
+        eval(self, scope, expr, 0)
     }
 }
-
@@ -0,0 +1,138 @@
+// Copyright (C) 2019  Frank Rehberger
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>
+
+//! Generator configuration files
+//!
+//! ```toml
+//! naming = "rust"
+//! derives = ["Clone", "Debug", "PartialEq"]
+//!
+//! [types]
+//! "DDS::Time_t" = "my_time::Timestamp"
+//! "sequence<octet>" = "bytes::Bytes"
+//! "DDS::Handle" = "my_dds::Handle"
+//!
+//! [[type_rules]]
+//! pattern = "DDS::*"
+//! derives = ["Hash"]
+//! ```
+use std::collections::HashMap;
+use serde::Deserialize;
+use crate::generator::rust::check_keyword_suffix;
+use crate::{CharMapping, Configuration, ExternalMapping, IdlError, NamingConvention, TypeRule};
+
+/// Contents of a configuration file, all keys being optional
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct ConfigFile {
+    naming: Option<String>,
+    chars: Option<String>,
+    keyword_suffix: Option<String>,
+    native_path: Option<String>,
+    serde: Option<bool>,
+    bounded: Option<bool>,
+    external: Option<String>,
+    validate: Option<bool>,
+    cdr: Option<bool>,
+    views: Option<bool>,
+    fixed_layout: Option<bool>,
+    no_std: Option<bool>,
+    heapless: Option<bool>,
+    type_objects: Option<bool>,
+    derives: Option<Vec<String>>,
+    attributes: Vec<String>,
+    types: HashMap<String, String>,
+    type_rules: Vec<TypeRuleFile>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct TypeRuleFile {
+    pattern: String,
+    derives: Vec<String>,
+    attributes: Vec<String>,
+}
+
+impl Configuration {
+    /// Read the configuration from TOML; keys not present keep their default values.
+    /// The type mapping is validated against the IDL specification when generating.
+    pub fn from_toml(text: &str) -> Result<Configuration, IdlError> {
+        let file: ConfigFile = toml::from_str(text)
+            .map_err(|e| IdlError::ErrorMesg(format!("configuration: {}", e)))?;
+
+        let mut config = Configuration::default();
+        if let Some(naming) = file.naming {
+            config.naming = match naming.as_str() {
+                "idl" => NamingConvention::Idl,
+                "rust" => NamingConvention::Rust,
+                _ => return Err(IdlError::ErrorMesg(
+                    format!("configuration: unknown naming convention '{}'", naming))),
+            };
+        }
+        if let Some(chars) = file.chars {
+            config.chars = match chars.as_str() {
+                "rust" => CharMapping::Rust,
+                "wire" => CharMapping::Wire,
+                _ => return Err(IdlError::ErrorMesg(
+                    format!("configuration: unknown char mapping '{}'", chars))),
+            };
+        }
+        if let Some(ref suffix) = file.keyword_suffix {
+            check_keyword_suffix(suffix)?;
+        }
+        config.keyword_suffix = file.keyword_suffix;
+        config.native_path = file.native_path;
+        if let Some(serde) = file.serde {
+            config.serde = serde;
+        }
+        if let Some(bounded) = file.bounded {
+            config.bounded = bounded;
+        }
+        if let Some(external) = file.external {
+            config.external = match external.as_str() {
+                "box" => ExternalMapping::Box,
+                "arc" => ExternalMapping::Arc,
+                _ => return Err(IdlError::ErrorMesg(
+                    format!("configuration: unknown external mapping '{}'", external))),
+            };
+        }
+        if let Some(validate) = file.validate {
+            config.validate = validate;
+        }
+        if let Some(cdr) = file.cdr {
+            config.cdr = cdr;
+        }
+        // views decode by the CdrDecode impls
+        if let Some(views) = file.views {
+            config.views = views;
+            config.cdr |= views;
+        }
+        if let Some(fixed_layout) = file.fixed_layout {
+            config.fixed_layout = fixed_layout;
+        }
+        if let Some(no_std) = file.no_std {
+            config.no_std = no_std;
+        }
+        if let Some(heapless) = file.heapless {
+            config.heapless = heapless;
+        }
+        if let Some(type_objects) = file.type_objects {
+            config.type_objects = type_objects;
+        }
+        if let Some(derives) = file.derives {
+            config.derives = derives;
+        }
+        config.attributes = file.attributes;
+        config.type_map = file.types;
+        config.type_rules = file.type_rules.into_iter()
+            .map(|rule| TypeRule {
+                pattern: rule.pattern,
+                derives: rule.derives,
+                attributes: rule.attributes,
+            })
+            .collect();
+        Ok(config)
+    }
+}
@@ -0,0 +1,37 @@
+// Copyright (C) 2019  Frank Rehberger
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>
+
+//! Code generation backends
+//!
+//! A backend implements the trait `Generator`, receiving the AST with includes
+//! resolved and reopened modules merged. The Rust backend is the default one.
+use std::io::Write;
+use crate::ast::Specification;
+use crate::{Configuration, IdlError};
+
+pub mod rust;
+
+pub use self::rust::RustGenerator;
+
+/// Backend emitting the output for a parsed IDL specification
+pub trait Generator {
+    /// Name selecting the backend, e.g. by `rtps-gen --lang <name>`
+    fn name(&self) -> &str;
+
+    /// Emit the output for the specification
+    fn generate(&self, out: &mut dyn Write, spec: &Specification, config: &Configuration)
+                -> Result<(), IdlError>;
+}
+
+/// Names of the backends provided by this crate
+pub const GENERATORS: &[&str] = &["rust"];
+
+/// Look up the backend by name
+pub fn by_name(name: &str) -> Option<Box<dyn Generator>> {
+    match name {
+        "rust" => Some(Box::new(RustGenerator)),
+        _ => None,
+    }
+}
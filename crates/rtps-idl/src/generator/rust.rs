@@ -0,0 +1,895 @@
+// Copyright (C) 2019  Frank Rehberger
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>
+
+//! Rust backend, emitting a module hierarchy of serde-enabled Rust types
+//!
+//! The items are composed as token streams by `generate_tokens()`, which may be
+//! invoked from a procedural macro as well; `RustGenerator` pretty-prints these.
+use std::io::Write;
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::str::FromStr;
+use heck::{ToSnakeCase, ToUpperCamelCase, ToShoutySnakeCase};
+use proc_macro2::{Ident, Literal, TokenStream};
+use quote::{quote, ToTokens};
+use crate::ast::*;
+use crate::generator::Generator;
+use crate::{CharMapping, Configuration, ExternalMapping, IdlError, NamingConvention};
+use self::derives::{Derives, derive_name, scoped_name};
+use self::type_map::TypeMap;
+
+mod cdr;
+mod defaults;
+mod derives;
+mod enums;
+mod layout;
+mod size;
+mod type_map;
+mod type_object;
+mod unions;
+mod validate;
+mod view;
+mod xtypes;
+
+/// The Rust code generator
+#[derive(Clone, Debug, Default)]
+pub struct RustGenerator;
+
+impl Generator for RustGenerator {
+    fn name(&self) -> &str {
+        "rust"
+    }
+
+    fn generate(&self, out: &mut dyn Write, spec: &Specification, config: &Configuration)
+                -> Result<(), IdlError> {
+        let file = generate_file(spec, config)?;
+        out.write_all(file.as_bytes()).map_err(|_| IdlError::InternalError)
+    }
+}
+
+/// Compose the Rust items of the specification
+pub fn generate_tokens(spec: &Specification, config: &Configuration) -> Result<TokenStream, IdlError> {
+    if let Some(ref suffix) = config.keyword_suffix {
+        check_keyword_suffix(suffix)?;
+    }
+    spec.root.check_names("", config)?;
+
+    let type_map = TypeMap::new(config, spec)?;
+    let ctx = Emitter {
+        config,
+        spec,
+        type_map: &type_map,
+        derives: Derives::new(config, spec, &type_map),
+    };
+    let root = spec.root.tokens(&ctx, &mut Vec::new())?;
+    // without std, each module imports the prelude of alloc
+    if config.no_std {
+        return Ok(root);
+    }
+    Ok(quote! {
+        #[allow(unused_imports)]
+        use std::vec::Vec;
+        #root
+    })
+}
+
+/// Compose the Rust items of the specification and format these as a source file
+pub fn generate_file(spec: &Specification, config: &Configuration) -> Result<String, IdlError> {
+    let tokens = generate_tokens(spec, config)?;
+    let file = syn::parse2::<syn::File>(tokens)
+        .map_err(|e| IdlError::ErrorMesg(format!("invalid Rust code: {}", e)))?;
+    Ok(prettyplease::unparse(&file))
+}
+
+/// State of the emission, shared by the items
+pub(crate) struct Emitter<'a> {
+    config: &'a Configuration,
+    spec: &'a Specification,
+    type_map: &'a TypeMap<'a>,
+    derives: Derives<'a>,
+}
+
+impl<'a> Emitter<'a> {
+    /// Outer attributes, the configured ones and those of matching type rules
+    fn attributes(&self, scoped_name: &str) -> Result<TokenStream, IdlError> {
+        let rules = self.config.type_rules.iter()
+            .filter(|rule| rule.matches(scoped_name))
+            .flat_map(|rule| rule.attributes.iter());
+        let attrs = self.config.attributes.iter().chain(rules)
+            .map(|attr| {
+                let attr = attr.trim();
+                if attr.starts_with('#') {
+                    literal(attr)
+                } else {
+                    literal(&format!("#[{}]", attr))
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(quote!(#(#attrs)*))
+    }
+
+    /// The bound of a string or sequence declared in module `path`, as `usize` literal
+    fn bound(&self, path: &[String], bound: &IdlValueExpr) -> Result<Literal, IdlError> {
+        const_value(self.spec, path, bound)
+            .and_then(|bound| usize::try_from(bound).ok())
+            .map(Literal::usize_unsuffixed)
+            .ok_or_else(|| IdlError::ErrorMesg(
+                format!("bound {:?} is no non-negative integer constant", bound)))
+    }
+
+    /// The derive attributes of a struct, union or enum declared in module `path`, `Default`
+    /// being derived unless implemented
+    fn derives(&self, path: &[String], type_dcl: &IdlTypeDcl, derive_default: bool)
+               -> Result<TokenStream, IdlError> {
+        // enums implement serde by their value
+        let is_enum = matches!(type_dcl.kind, IdlTypeDclKind::EnumDcl(..));
+        let serde = if self.config.serde && !is_enum {
+            quote!(#[derive(Serialize, Deserialize)])
+        } else {
+            TokenStream::new()
+        };
+        let mut derives = self.derives.of(path, type_dcl);
+        if !derive_default {
+            derives.retain(|derive| derive_name(derive) != "Default");
+        } else if !derives.iter().any(|derive| derive_name(derive) == "Default") {
+            derives.push("Default".to_owned());
+        }
+        let derives = derives.iter()
+            .map(|derive| literal(derive))
+            .collect::<Result<Vec<_>, _>>()?;
+        if derives.is_empty() {
+            Ok(serde)
+        } else {
+            Ok(quote! {
+                #serde
+                #[derive(#(#derives),*)]
+            })
+        }
+    }
+}
+
+const DEFAULT_KEYWORD_SUFFIX: &str = "_";
+
+/// Rust keywords (strict and reserved), these may be used as raw identifiers `r#..`
+const RUST_KEYWORDS: &[&str] = &[
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue",
+    "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if",
+    "impl", "in", "let", "loop", "macro", "match", "mod", "move", "mut", "override",
+    "priv", "pub", "ref", "return", "static", "struct", "trait", "true", "try", "type",
+    "typeof", "unsafe", "unsized", "use", "virtual", "where", "while", "yield",
+];
+
+/// Rust keywords which are not permitted as raw identifiers
+const RUST_PATH_KEYWORDS: &[&str] = &["crate", "self", "Self", "super", "_"];
+
+/// Map an IDL identifier onto a valid Rust identifier.
+///
+/// Rust keywords are emitted as raw identifiers, or extended by the
+/// configured suffix; keywords that cannot be raw identifiers (`self`,
+/// `Self`, `super`, `crate`) are always extended by a suffix.
+pub(crate) fn rust_ident(id: &str, config: &Configuration) -> String {
+    let suffix = config.keyword_suffix.as_deref();
+    if RUST_PATH_KEYWORDS.contains(&id) {
+        format!("{}{}", id, suffix.unwrap_or(DEFAULT_KEYWORD_SUFFIX))
+    } else if RUST_KEYWORDS.contains(&id) {
+        match suffix {
+            Some(suffix) => format!("{}{}", id, suffix),
+            None => format!("r#{}", id),
+        }
+    } else {
+        id.to_owned()
+    }
+}
+
+/// Verify the suffix extends each Rust keyword onto an identifier which is no keyword,
+/// such as `type_`; a suffix like `-` or `of` (forming `typeof`) is rejected.
+pub fn check_keyword_suffix(suffix: &str) -> Result<(), IdlError> {
+    let keywords = || RUST_KEYWORDS.iter().chain(RUST_PATH_KEYWORDS);
+    match keywords().map(|keyword| format!("{}{}", keyword, suffix)).find(|id| {
+        keywords().any(|keyword| keyword == id) || syn::parse_str::<syn::Ident>(id).is_err()
+    }) {
+        Some(id) => Err(IdlError::ErrorMesg(
+            format!("keyword suffix '{}' forms the invalid identifier '{}'", suffix, id))),
+        None => Ok(()),
+    }
+}
+
+/// Kind of a named item, selecting the naming convention being applied
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum NameKind {
+    Module,
+    Type,
+    Member,
+    Variant,
+    Constant,
+}
+
+/// Map an IDL identifier of the given kind onto a Rust identifier, according
+/// to the configured naming convention and escaping Rust keywords.
+pub(crate) fn rust_name(id: &str, kind: NameKind, config: &Configuration) -> String {
+    let name = match config.naming {
+        NamingConvention::Idl => id.to_owned(),
+        NamingConvention::Rust => match kind {
+            NameKind::Module | NameKind::Member => id.to_snake_case(),
+            NameKind::Type | NameKind::Variant => id.to_upper_camel_case(),
+            NameKind::Constant => id.to_shouty_snake_case(),
+        },
+    };
+    // identifiers consisting of underscores only are kept as is
+    if name.is_empty() {
+        rust_ident(id, config)
+    } else {
+        rust_ident(&name, config)
+    }
+}
+
+/// Token of the Rust identifier, as returned by `rust_ident()`
+pub(crate) fn ident(id: &str) -> Ident {
+    let span = proc_macro2::Span::call_site();
+    if let Some(raw) = id.strip_prefix("r#") {
+        Ident::new_raw(raw, span)
+    } else {
+        Ident::new(id, span)
+    }
+}
+
+/// Token of the Rust identifier for an IDL identifier of the given kind
+pub(crate) fn name_ident(id: &str, kind: NameKind, config: &Configuration) -> Ident {
+    ident(&rust_name(id, kind, config))
+}
+
+/// Verify that distinct IDL identifiers are not mapped onto the same Rust identifier
+fn check_collisions<'a, I>(scope: &str, ids: I, config: &Configuration) -> Result<(), IdlError>
+    where I: Iterator<Item=(&'a str, NameKind)>
+{
+    let mut seen: HashMap<String, &str> = HashMap::new();
+    for (id, kind) in ids {
+        let name = rust_name(id, kind, config);
+        match seen.get(&name) {
+            Some(other) if *other != id =>
+                return Err(IdlError::ErrorMesg(
+                    format!("naming collision in '{}': '{}' and '{}' are mapped onto '{}'",
+                            scope, other, id, name))),
+            _ => { seen.insert(name, id); }
+        }
+    }
+    Ok(())
+}
+
+/// `#[allow(..)]` for the item, if IDL names are copied verbatim
+fn allow_naming(lint: &str, config: &Configuration) -> TokenStream {
+    match config.naming {
+        NamingConvention::Idl => {
+            let lint = ident(lint);
+            quote!(#[allow(#lint)])
+        }
+        NamingConvention::Rust => TokenStream::new(),
+    }
+}
+
+/// `#[serde(rename)]` if the Rust identifier differs from the IDL identifier
+fn serde_rename(id: &str, rust_id: &Ident, config: &Configuration) -> TokenStream {
+    if config.serde && rust_id != id {
+        quote!(#[serde(rename = #id)])
+    } else {
+        TokenStream::new()
+    }
+}
+
+/// `#[serde(default)]` of `@optional` members, these may be absent
+fn serde_optional(annotations: &[IdlAnnotation], config: &Configuration) -> TokenStream {
+    if config.serde && has_annotation(annotations, "optional") {
+        quote!(#[serde(default)])
+    } else {
+        TokenStream::new()
+    }
+}
+
+/// The type of a struct member or union element, `@external` ones being held by pointer
+/// and `@optional` ones wrapped into `Option`
+fn member_type(ctx: &Emitter, path: &[String], type_spec: &IdlTypeSpec, annotations: &[IdlAnnotation])
+               -> Result<TokenStream, IdlError> {
+    let typ = held_type(ctx, path, type_spec, annotations)?;
+    if has_annotation(annotations, "optional") {
+        Ok(quote!(Option<#typ>))
+    } else {
+        Ok(typ)
+    }
+}
+
+/// The type of a struct member or union element if present, `@external` ones being held
+/// by pointer
+fn held_type(ctx: &Emitter, path: &[String], type_spec: &IdlTypeSpec, annotations: &[IdlAnnotation])
+             -> Result<TokenStream, IdlError> {
+    let typ = type_spec.tokens(ctx, path)?;
+    if !has_annotation(annotations, "external") {
+        return Ok(typ);
+    }
+    Ok(match ctx.config.external {
+        ExternalMapping::Box => quote!(Box<#typ>),
+        ExternalMapping::Arc => {
+            let alloc = alloc_crate(ctx.config);
+            quote!(#alloc::sync::Arc<#typ>)
+        }
+    })
+}
+
+/// Whether characters and wide strings are emitted as the wire types of `rtps_types`,
+/// as configured or required by a fixed layout
+fn wire_chars(config: &Configuration) -> bool {
+    config.chars == CharMapping::Wire || config.fixed_layout
+}
+
+/// The crate providing the items of `core`, being `::std` unless configured `no_std`
+fn core_crate(config: &Configuration) -> TokenStream {
+    if config.no_std { quote!(::core) } else { quote!(::std) }
+}
+
+/// The crate providing the items of `alloc`, being `::std` unless configured `no_std`
+fn alloc_crate(config: &Configuration) -> TokenStream {
+    if config.no_std { quote!(::alloc) } else { quote!(::std) }
+}
+
+/// Whether bounded strings and sequences are stored in place, as `rtps_types::FixedString`
+/// and `rtps_types::FixedVec`
+fn in_place(config: &Configuration) -> bool {
+    config.fixed_layout || config.heapless
+}
+
+/// Literal token of the given Rust source text
+fn literal(text: &str) -> Result<TokenStream, IdlError> {
+    TokenStream::from_str(text)
+        .map_err(|_| IdlError::ErrorMesg(format!("invalid literal '{}'", text)))
+}
+
+/// Value of a non-negative integer constant expression, such as a bound, within the module `path`
+pub(crate) fn const_value(spec: &Specification, path: &[String], expr: &IdlValueExpr) -> Option<u64> {
+    spec.eval_int(path, expr).and_then(|val| u64::try_from(val).ok())
+}
+
+impl UnaryOp {
+    pub(crate) fn tokens(&self) -> TokenStream {
+        match self {
+            UnaryOp::Neg => quote!(-),
+            // Rust does not know the unary plus
+            UnaryOp::Pos => TokenStream::new(),
+            UnaryOp::Inverse => quote!(!),
+        }
+    }
+}
+
+impl BinaryOp {
+    pub(crate) fn tokens(&self) -> TokenStream {
+        match self {
+            BinaryOp::Add => quote!(+),
+            BinaryOp::Sub => quote!(-),
+            BinaryOp::Mul => quote!(*),
+            BinaryOp::Div => quote!(/),
+            BinaryOp::Mod => quote!(%),
+            BinaryOp::LShift => quote!(<<),
+            BinaryOp::RShift => quote!(>>),
+            BinaryOp::Or => quote!(|),
+            BinaryOp::Xor => quote!(^),
+            BinaryOp::And => quote!(&),
+        }
+    }
+}
+
+impl IdlScopedName {
+    /// The path, the leading components denote modules, the last one an item of `kind`
+    pub(crate) fn tokens(&self, kind: NameKind, config: &Configuration) -> TokenStream {
+        let is_absolute_path = self.1;
+        let components = &self.0;
+        let last = components.len().saturating_sub(1);
+        let idents = components.iter().enumerate()
+            .map(|(idx, comp)| {
+                let comp_kind = if idx == last { kind } else { NameKind::Module };
+                name_ident(comp, comp_kind, config)
+            });
+        // TODO, use paths according to "super::"
+        if is_absolute_path {
+            quote!(crate #(:: #idents)*)
+        } else {
+            quote!(#(#idents)::*)
+        }
+    }
+}
+
+impl IdlValueExpr {
+    pub(crate) fn tokens(&self, config: &Configuration) -> Result<TokenStream, IdlError> {
+        match self {
+            IdlValueExpr::None => Ok(TokenStream::new()),
+            IdlValueExpr::DecLiteral(ref val) => literal(val),
+            IdlValueExpr::HexLiteral(ref val) => literal(&format!("0x{}", &val[2..])),
+            IdlValueExpr::OctLiteral(ref val) => match val.trim_start_matches('0') {
+                "" => literal("0"),
+                digits => literal(&format!("0o{}", digits)),
+            },
+            IdlValueExpr::CharLiteral(ref val) => literal(val),
+            IdlValueExpr::WideCharLiteral(ref val) => literal(val.trim_start_matches('L')),
+            IdlValueExpr::StringLiteral(ref val) => literal(val),
+            IdlValueExpr::WideStringLiteral(ref val) => literal(val.trim_start_matches('L')),
+            IdlValueExpr::BooleanLiteral(true) => Ok(quote!(true)),
+            IdlValueExpr::BooleanLiteral(false) => Ok(quote!(false)),
+            IdlValueExpr::FloatLiteral(ref integral, ref fraction, ref exponent, _) => {
+                // the suffix 'd' or 'f' is given by the type of the constant
+                let mut val = format!("{}.{}",
+                                      integral.as_deref().unwrap_or("0"),
+                                      fraction.as_deref().unwrap_or("0"));
+                if let Some(exponent) = exponent {
+                    val.push('e');
+                    val.push_str(exponent);
+                }
+                literal(&val)
+            }
+            IdlValueExpr::UnaryOp(op, ref expr) => {
+                let op = op.tokens();
+                let expr = expr.tokens(config)?;
+                Ok(quote!(#op #expr))
+            }
+            IdlValueExpr::BinaryOp(op, ref expr) => {
+                let op = op.tokens();
+                let expr = expr.tokens(config)?;
+                Ok(quote!(#op #expr))
+            }
+            IdlValueExpr::Expr(ref expr1, ref expr2) => {
+                let expr1 = expr1.tokens(config)?;
+                let expr2 = expr2.tokens(config)?;
+                Ok(quote!(#expr1 #expr2))
+            }
+            IdlValueExpr::Brace(ref expr) => {
+                let expr = expr.tokens(config)?;
+                Ok(quote!((#expr)))
+            }
+            IdlValueExpr::ScopedName(ref name) => Ok(name.tokens(NameKind::Constant, config)),
+        }
+    }
+}
+
+///
+impl IdlStructMember {
+    ///
+    pub(crate) fn tokens(&self, ctx: &Emitter, path: &[String], member_id: u32) -> Result<TokenStream, IdlError> {
+        let config = ctx.config;
+        let rust_id = name_ident(&self.id, NameKind::Member, config);
+        let rename = serde_rename(&self.id, &rust_id, config);
+        let optional = serde_optional(&self.annotations, config);
+        let delimited = xtypes::serde_with(ctx, path, member_id, &self.type_spec, &self.annotations);
+        let type_spec = member_type(ctx, path, &self.type_spec, &self.annotations)?;
+        Ok(quote! {
+            #rename
+            #optional
+            #delimited
+            pub #rust_id: #type_spec
+        })
+    }
+}
+
+///
+impl IdlSwitchElement {
+    ///
+    pub(crate) fn tokens(&self, ctx: &Emitter, path: &[String], member_id: u32) -> Result<TokenStream, IdlError> {
+        let config = ctx.config;
+        let rust_id = name_ident(&self.id, NameKind::Member, config);
+        let rename = serde_rename(&self.id, &rust_id, config);
+        let optional = serde_optional(&self.annotations, config);
+        let delimited = xtypes::serde_with(ctx, path, member_id, &self.type_spec, &self.annotations);
+        let type_spec = member_type(ctx, path, &self.type_spec, &self.annotations)?;
+        Ok(quote! {
+            #rename
+            #optional
+            #delimited
+            #rust_id: #type_spec
+        })
+    }
+}
+
+/// Name of the variant selected by the label, if the label is an enumerator or `default`
+fn label_id(label: &IdlSwitchLabel) -> Option<&str> {
+    match label {
+        IdlSwitchLabel::Label(ref expr) => match expr.as_ref() {
+            IdlValueExpr::ScopedName(ref name) => name.0.last().map(|l| l.as_str()),
+            _ => None,
+        },
+        IdlSwitchLabel::Default => Some("default"),
+    }
+}
+
+///
+impl IdlSwitchCase {
+    /// The IDL name of the variant, the first label naming it; branches selected by
+    /// numeric labels only are named after the element
+    pub(crate) fn variant_id(&self) -> &str {
+        self.labels.iter()
+            .filter_map(label_id)
+            .next()
+            .unwrap_or(&self.elem_spec.id)
+    }
+
+    /// The branch forms a single variant, further labels are accepted as alias
+    pub(crate) fn tokens(&self, ctx: &Emitter, path: &[String], member_id: u32) -> Result<TokenStream, IdlError> {
+        let config = ctx.config;
+        let id = self.variant_id();
+        let rust_id = name_ident(id, NameKind::Variant, config);
+        let rename = serde_rename(id, &rust_id, config);
+        let aliases = self.labels.iter()
+            .filter_map(label_id)
+            .filter(|alias| config.serde && *alias != id)
+            .map(|alias| quote!(#[serde(alias = #alias)]));
+        let elem = self.elem_spec.tokens(ctx, path, member_id)?;
+        Ok(quote! {
+            #rename
+            #(#aliases)*
+            #rust_id { #elem }
+        })
+    }
+}
+
+///
+impl IdlTypeSpec {
+    /// The Rust type as seen from within the module `path`, the configured type mapping
+    /// taking precedence; declarations of mapped types are aliases, so named types are kept
+    pub(crate) fn tokens(&self, ctx: &Emitter, path: &[String]) -> Result<TokenStream, IdlError> {
+        match self {
+            IdlTypeSpec::ScopedName(_) => self.rust_tokens(ctx, path),
+            _ => match ctx.type_map.type_spec(path, self) {
+                Some(rust_type) => Ok(rust_type.clone()),
+                None => self.rust_tokens(ctx, path),
+            },
+        }
+    }
+
+    /// The Rust type, ignoring the type mapping for this type but not for its elements
+    fn rust_tokens(&self, ctx: &Emitter, path: &[String]) -> Result<TokenStream, IdlError> {
+        let config = ctx.config;
+        match self {
+            IdlTypeSpec::F32Type => Ok(quote!(f32)),
+            IdlTypeSpec::F64Type => Ok(quote!(f64)),
+            IdlTypeSpec::F128Type => Ok(quote!(rtps_types::LongDouble)),
+            IdlTypeSpec::I16Type => Ok(quote!(i16)),
+            IdlTypeSpec::I32Type => Ok(quote!(i32)),
+            IdlTypeSpec::I64Type => Ok(quote!(i64)),
+            IdlTypeSpec::U16Type => Ok(quote!(u16)),
+            IdlTypeSpec::U32Type => Ok(quote!(u32)),
+            IdlTypeSpec::U64Type => Ok(quote!(u64)),
+            IdlTypeSpec::CharType if wire_chars(config) =>
+                Ok(quote!(rtps_types::Char)),
+            IdlTypeSpec::CharType => Ok(quote!(char)),
+            IdlTypeSpec::WideCharType if wire_chars(config) =>
+                Ok(quote!(rtps_types::WChar)),
+            IdlTypeSpec::WideCharType => Ok(quote!(char)),
+            IdlTypeSpec::BooleanType => Ok(quote!(bool)),
+            IdlTypeSpec::OctetType => Ok(quote!(u8)),
+            IdlTypeSpec::StringType(Some(ref bound)) if in_place(config) => {
+                let bound = ctx.bound(path, bound)?;
+                Ok(quote!(rtps_types::FixedString<#bound>))
+            }
+            IdlTypeSpec::SequenceType(typ_expr, Some(ref bound)) if in_place(config) => {
+                let typ = typ_expr.tokens(ctx, path)?;
+                let bound = ctx.bound(path, bound)?;
+                // without fixed layout, any padding is implicit
+                let padding = if config.fixed_layout { layout::sequence_padding(ctx, path, typ_expr)? } else { 0 };
+                match padding {
+                    0 => Ok(quote!(rtps_types::FixedVec<#typ, #bound>)),
+                    padding => {
+                        let padding = Literal::usize_unsuffixed(padding);
+                        Ok(quote!(rtps_types::FixedVec<#typ, #bound, #padding>))
+                    }
+                }
+            }
+            IdlTypeSpec::WideStringType(Some(ref bound))
+                if config.bounded && wire_chars(config) => {
+                let bound = ctx.bound(path, bound)?;
+                Ok(quote!(rtps_types::BoundedWString<#bound>))
+            }
+            IdlTypeSpec::WideStringType(_) if wire_chars(config) => Ok(quote!(rtps_types::WString)),
+            IdlTypeSpec::StringType(Some(ref bound)) if config.bounded => {
+                let bound = ctx.bound(path, bound)?;
+                Ok(quote!(rtps_types::BoundedString<#bound>))
+            }
+            // the bound of a `wstring` counts characters, not the bytes of a `BoundedString`
+            IdlTypeSpec::WideStringType(Some(ref bound)) if config.bounded => {
+                let bound = ctx.bound(path, bound)?;
+                Ok(quote!(rtps_types::BoundedWideString<#bound>))
+            }
+            IdlTypeSpec::StringType(_) => Ok(quote!(String)),
+            IdlTypeSpec::WideStringType(_) => Ok(quote!(String)),
+            IdlTypeSpec::SequenceType(typ_expr, Some(ref bound)) if config.bounded => {
+                let typ = typ_expr.tokens(ctx, path)?;
+                let bound = ctx.bound(path, bound)?;
+                Ok(quote!(rtps_types::BoundedVec<#typ, #bound>))
+            }
+            IdlTypeSpec::SequenceType(typ_expr, _) => {
+                let typ = typ_expr.tokens(ctx, path)?;
+                Ok(quote!(Vec<#typ>))
+            }
+            IdlTypeSpec::ArrayType(typ_expr, dim_expr_list) => {
+                // the last dimension is the innermost one
+                let mut typ = typ_expr.tokens(ctx, path)?;
+                for dim_expr in dim_expr_list.iter().rev() {
+                    let dim = dim_expr.tokens(config)?;
+                    typ = quote!([#typ; #dim]);
+                }
+                Ok(typ)
+            }
+            IdlTypeSpec::ScopedName(ref name) => Ok(name.tokens(NameKind::Type, config)),
+            IdlTypeSpec::None => Err(IdlError::InternalError),
+        }
+    }
+}
+
+///
+impl IdlTypeDcl {
+    ///
+    pub(crate) fn tokens(&self, ctx: &Emitter, path: &[String]) -> Result<TokenStream, IdlError> {
+        let config = ctx.config;
+        layout::check(ctx, path, self)?;
+        let allow_naming = allow_naming("non_camel_case_types", config);
+        let default_impl = defaults::default_impl(ctx, path, self)?;
+        let validate_impl = validate::validate_tokens(ctx, path, self)?;
+        let serde_attrs = xtypes::serde_attributes(ctx, path, self)?;
+        let extensible_impl = xtypes::extensible_tokens(ctx, path, self)?;
+        let serde_impl = xtypes::serde_tokens(ctx, path, self)?;
+        let cdr_impl = cdr::cdr_tokens(ctx, path, self)?;
+        let size_impl = size::size_tokens(ctx, path, self)?;
+        let view = view::view_tokens(ctx, path, self)?;
+        let layout_impl = layout::layout_tokens(ctx, path, self)?;
+        let type_object = type_object::type_object_tokens(ctx, path, self)?;
+        let (derives, attributes) = match self.kind.id() {
+            Some(id) => (ctx.derives(path, self, default_impl.is_none())?, ctx.attributes(&scoped_name(path, id))?),
+            None => (TokenStream::new(), TokenStream::new()),
+        };
+        let mapped = self.kind.id().and_then(|id| ctx.type_map.declaration(path, id));
+        if let (Some(id), Some(rust_type)) = (self.kind.id(), mapped) {
+            let rust_id = name_ident(id, NameKind::Type, config);
+            return Ok(quote! {
+                #[allow(dead_code)]
+                #allow_naming
+                pub type #rust_id = #rust_type;
+            });
+        }
+        match self.kind {
+            IdlTypeDclKind::TypeDcl(ref id, ref type_spec) => {
+                let rust_id = name_ident(id, NameKind::Type, config);
+                let type_spec = type_spec.tokens(ctx, path)?;
+                Ok(quote! {
+                    #[allow(dead_code)]
+                    #allow_naming
+                    pub type #rust_id = #type_spec;
+                })
+            }
+            IdlTypeDclKind::StructDcl(ref id, ref members) => {
+                let rust_id = name_ident(id, NameKind::Type, config);
+                let rename = serde_rename(id, &rust_id, config);
+                // the member IDs start at 0
+                let ids = xtypes::struct_member_ids(ctx, path, self, id, members)?;
+                let members = members.iter().zip(ids)
+                    .map(|(member, member_id)| member.tokens(ctx, path, member_id))
+                    .collect::<Result<Vec<_>, _>>()?;
+                let fields = layout::struct_fields(ctx, path, self, members)?;
+                let repr = if config.fixed_layout { quote!(#[repr(C)]) } else { TokenStream::new() };
+                Ok(quote! {
+                    #[allow(dead_code)]
+                    #allow_naming
+                    #derives
+                    #attributes
+                    #rename
+                    #serde_attrs
+                    #repr
+                    pub struct #rust_id {
+                        #(#fields,)*
+                    }
+                    #default_impl
+                    #validate_impl
+                    #extensible_impl
+                    #serde_impl
+                    #cdr_impl
+                    #size_impl
+                    #view
+                    #layout_impl
+                    #type_object
+                })
+            }
+            IdlTypeDclKind::EnumDcl(ref id, ref enums) => {
+                let outer_attrs = quote! {
+                    #[allow(dead_code)]
+                    #allow_naming
+                    #derives
+                    #attributes
+                };
+                let enum_tokens = enums::enum_tokens(ctx, path, self, id, enums, outer_attrs)?;
+                Ok(quote! {
+                    #enum_tokens
+                    #layout_impl
+                    #type_object
+                })
+            }
+            IdlTypeDclKind::UnionDcl(ref id, ref _type_spec, ref switch_cases) => {
+                let rust_id = name_ident(id, NameKind::Type, config);
+                let rename = serde_rename(id, &rust_id, config);
+                let ids = xtypes::union_member_ids(ctx, path, self, id, switch_cases)?;
+                let cases = switch_cases.iter().zip(ids)
+                    .map(|(case, member_id)| case.tokens(ctx, path, member_id))
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok(quote! {
+                    #[allow(dead_code)]
+                    #allow_naming
+                    #derives
+                    #attributes
+                    #rename
+                    #serde_attrs
+                    pub enum #rust_id {
+                        #(#cases,)*
+                    }
+                    #default_impl
+                    #validate_impl
+                    #extensible_impl
+                    #serde_impl
+                    #cdr_impl
+                    #size_impl
+                    #type_object
+                })
+            }
+            IdlTypeDclKind::NativeDcl(ref id) => {
+                let native_path = config.native_path.as_ref().ok_or_else(|| IdlError::ErrorMesg(
+                    format!("native type '{}' requires a type mapping", scoped_name(path, id))))?;
+                let native_path = syn::parse_str::<syn::Path>(native_path)
+                    .map_err(|e| IdlError::ErrorMesg(
+                        format!("native path: '{}' is not a Rust path: {}", native_path, e)))?;
+                let rust_id = name_ident(id, NameKind::Type, config);
+                Ok(quote! {
+                    #[allow(unused_imports)]
+                    pub use #native_path::#rust_id;
+                })
+            }
+            IdlTypeDclKind::None => Ok(TokenStream::new()),
+        }
+    }
+}
+
+///
+impl IdlTypeDcl {
+    /// Verify the naming convention does not map distinct members onto the same Rust name
+    pub(crate) fn check_names(&self, path: &str, config: &Configuration) -> Result<(), IdlError> {
+        match self.kind {
+            IdlTypeDclKind::StructDcl(ref id, ref members) =>
+                check_collisions(&format!("{}::{}", path, id),
+                                 members.iter().map(|m| (m.id.as_str(), NameKind::Member)),
+                                 config),
+            IdlTypeDclKind::EnumDcl(ref id, ref enums) =>
+                check_collisions(&format!("{}::{}", path, id),
+                                 enums.iter().map(|e| (e.id.as_str(), NameKind::Variant)),
+                                 config),
+            IdlTypeDclKind::UnionDcl(ref id, _, ref switch_cases) => {
+                let variants = switch_cases.iter()
+                    .map(|case| (case.variant_id(), NameKind::Variant));
+                check_collisions(&format!("{}::{}", path, id), variants, config)
+            }
+            _ => Ok(())
+        }
+    }
+}
+
+///
+impl IdlConstDcl {
+    /// The constant keeps its IDL type, as a literal does not initialize a mapped type;
+    /// string constants are string slices
+    pub(crate) fn tokens(&self, ctx: &Emitter, path: &[String]) -> Result<TokenStream, IdlError> {
+        let config = ctx.config;
+        let rust_id = name_ident(&self.id, NameKind::Constant, config);
+        let allow_naming = allow_naming("non_upper_case_globals", config);
+        let value = self.value.tokens(config)?;
+        let (typ, value) = match self.typedcl.as_ref() {
+            IdlTypeSpec::StringType(_) | IdlTypeSpec::WideStringType(_) => (quote!(&str), value),
+            IdlTypeSpec::CharType if wire_chars(config) =>
+                (quote!(rtps_types::Char), quote!(rtps_types::Char::from_char(#value))),
+            IdlTypeSpec::WideCharType if wire_chars(config) =>
+                (quote!(rtps_types::WChar), quote!(rtps_types::WChar::from_char(#value))),
+            IdlTypeSpec::F128Type => {
+                // integer values are converted by the generator, as `from_f64` is a const fn
+                let value = match ctx.spec.eval_int(path, &self.value) {
+                    Some(val) => Literal::f64_unsuffixed(val as f64).into_token_stream(),
+                    None => value,
+                };
+                (quote!(rtps_types::LongDouble), quote!(rtps_types::LongDouble::from_f64(#value)))
+            }
+            typedcl => (typedcl.rust_tokens(ctx, path)?, value),
+        };
+        Ok(quote! {
+            #[allow(dead_code)]
+            #allow_naming
+            pub const #rust_id: #typ = #value;
+        })
+    }
+}
+
+///
+impl IdlModule {
+    /// Verify the naming convention does not map distinct IDL names onto the same Rust name,
+    /// checking types and modules, constants, and the members of each type.
+    pub(crate) fn check_names(&self, path: &str, config: &Configuration) -> Result<(), IdlError> {
+        let path = match self.id {
+            Some(ref id) if path.is_empty() => id.to_owned(),
+            Some(ref id) => format!("{}::{}", path, id),
+            None => path.to_owned(),
+        };
+        let type_names = self.modules.keys().map(|id| (id.as_str(), NameKind::Module))
+            .chain(self.types.keys().map(|id| (id.as_str(), NameKind::Type)));
+        check_collisions(&path, type_names, config)?;
+        check_collisions(&path, self.constants.keys().map(|id| (id.as_str(), NameKind::Constant)),
+                         config)?;
+        for typ in self.types.values() {
+            typ.check_names(&path, config)?;
+        }
+        for module in self.modules.values() {
+            module.check_names(&path, config)?;
+        }
+        Ok(())
+    }
+
+    ///
+    pub(crate) fn tokens(&self, ctx: &Emitter, path: &mut Vec<String>) -> Result<TokenStream, IdlError> {
+        let config = ctx.config;
+        if let Some(ref id) = self.id {
+            path.push(id.clone());
+        }
+        let types = self.types.values()
+            .map(|typ| typ.tokens(ctx, path))
+            .collect::<Result<Vec<_>, _>>()?;
+        let modules = self.modules.values()
+            .map(|module| module.tokens(ctx, path))
+            .collect::<Result<Vec<_>, _>>()?;
+        let constants = self.constants.values()
+            .map(|cnst| cnst.tokens(ctx, path))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if self.id.is_some() {
+            path.pop();
+        }
+
+        let import_serde = if config.serde && config.no_std {
+            quote! {
+                #[allow(unused_imports)]
+                use serde::{Serialize, Deserialize};
+            }
+        } else if config.serde {
+            quote! {
+                #[allow(unused_imports)]
+                use serde_derive::{Serialize, Deserialize};
+            }
+        } else {
+            TokenStream::new()
+        };
+        let import_alloc = if config.no_std {
+            quote! {
+                #[allow(unused_imports)]
+                use ::alloc::{borrow::ToOwned, boxed::Box, string::String, vec::Vec};
+            }
+        } else {
+            TokenStream::new()
+        };
+        let body = quote! {
+            #import_alloc
+            #import_serde
+            #(#types)*
+            #(#modules)*
+            #(#constants)*
+        };
+
+        match self.id {
+            Some(ref id) => {
+                let allow_naming = allow_naming("non_snake_case", config);
+                let rust_id = name_ident(id, NameKind::Module, config);
+                Ok(quote! {
+                    #allow_naming
+                    pub mod #rust_id {
+                        #body
+                    }
+                })
+            }
+            None => Ok(body),
+        }
+    }
+}
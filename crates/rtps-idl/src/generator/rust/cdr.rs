@@ -0,0 +1,378 @@
+// Copyright (C) 2019  Frank Rehberger
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>
+
+//! Direct CDR encoding, bypassing serde
+//!
+//! With `Configuration::cdr` structs and unions implement `rtps_cdr::CdrEncode` and
+//! `rtps_cdr::CdrDecode`, encoding as their serde impls do (see `xtypes`), and enums their
+//! value (see `enums`). Typedefs are type aliases, encoded by the impls of the aliased
+//! type; the Rust types of mapped and native declarations must implement the traits.
+use proc_macro2::{Ident, Literal, TokenStream};
+use quote::quote;
+use crate::ast::*;
+use crate::IdlError;
+use super::{Emitter, NameKind, layout, name_ident, unions};
+use super::derives::scoped_name;
+use super::xtypes::{self, Extensibility, is_delimited, member_header, struct_member_ids, union_member_ids};
+
+/// The body of `decode()`
+enum Decoded {
+    /// An expression of the decoded type
+    Value(TokenStream),
+    /// An expression of the result of decoding
+    Result(TokenStream),
+    /// Statements decoding the members of the mutable `value`
+    Members(TokenStream),
+}
+
+impl Decoded {
+    /// Return the decoded value, validating it first if `validate` is not empty
+    fn tokens(self, validate: TokenStream) -> TokenStream {
+        match (self, validate.is_empty()) {
+            (Decoded::Value(value), true) => quote!(Ok(#value)),
+            (Decoded::Result(result), true) => result,
+            (Decoded::Value(value), false) => quote!(let value = #value; #validate Ok(value)),
+            (Decoded::Result(result), false) => quote!(let value = #result?; #validate Ok(value)),
+            (Decoded::Members(members), _) => quote!(#members #validate Ok(value)),
+        }
+    }
+}
+
+/// A struct member or union element, holding a collection of non-primitive elements which
+/// is delimited, if `delimited`; `header` is its member header
+struct Value {
+    delimited: bool,
+    optional: bool,
+    header: TokenStream,
+}
+
+impl Value {
+    fn new(ctx: &Emitter, path: &[String], member_id: u32, type_spec: &IdlTypeSpec,
+           annotations: &[IdlAnnotation]) -> Value {
+        Value {
+            delimited: is_delimited(ctx, path, type_spec),
+            optional: has_annotation(annotations, "optional"),
+            header: member_header(member_id, annotations),
+        }
+    }
+
+    /// Encode the value of a final or appendable type, given by the expression `place` and
+    /// the reference `access` to it; optional ones are preceded by a parameter header in
+    /// XCDR1
+    fn encode(&self, place: TokenStream, access: TokenStream) -> TokenStream {
+        let header = &self.header;
+        match (self.delimited, self.optional) {
+            (false, false) => quote!(rtps_cdr::CdrEncode::encode(#access, ser)?;),
+            (true, false) => quote!(rtps_cdr::CdrEncode::encode(&rtps_cdr::Delimited(#access), ser)?;),
+            (false, true) => quote!(ser.optional(#header, #place.as_ref())?;),
+            (true, true) => quote!(ser.optional(#header, #place.as_ref().map(rtps_cdr::Delimited))?;),
+        }
+    }
+
+    /// Decode the value of a final or appendable type; the members appended to a struct
+    /// default if not encoded
+    fn decode(&self, appended: bool) -> TokenStream {
+        let (decode, unwrap) = match (self.delimited, self.optional) {
+            (false, false) if appended => return quote!(de.appended()?),
+            (false, false) => return quote!(rtps_cdr::CdrDecode::decode(de)?),
+            (true, false) if appended => return quote!(de.appended::<rtps_cdr::Delimited<_>>()?.0),
+            (true, false) => return quote!(<rtps_cdr::Delimited<_> as rtps_cdr::CdrDecode>::decode(de)?.0),
+            (false, true) => (quote!(de.optional()), TokenStream::new()),
+            (true, true) => (quote!(de.optional::<rtps_cdr::Delimited<_>>()), quote!(.map(|value| value.0))),
+        };
+        if appended {
+            quote!(de.appended_with(|de| #decode)?#unwrap)
+        } else {
+            quote!(#decode?#unwrap)
+        }
+    }
+
+    /// Encode the member of a mutable type, which is absent if optional and `None`
+    fn encode_member(&self, access: TokenStream) -> TokenStream {
+        let header = &self.header;
+        let encode = |access| if self.delimited {
+            quote!(ser.member(#header, |ser| rtps_cdr::CdrEncode::encode(&rtps_cdr::Delimited(#access), ser))?;)
+        } else {
+            quote!(ser.member(#header, |ser| rtps_cdr::CdrEncode::encode(#access, ser))?;)
+        };
+        if self.optional {
+            let encode = encode(quote!(value));
+            quote! {
+                if let Some(value) = #access {
+                    #encode
+                }
+            }
+        } else {
+            encode(access)
+        }
+    }
+
+    /// Decode the member of a mutable type
+    fn decode_member(&self) -> TokenStream {
+        let decode = if self.delimited {
+            quote!(<rtps_cdr::Delimited<_> as rtps_cdr::CdrDecode>::decode(de)?.0)
+        } else {
+            quote!(rtps_cdr::CdrDecode::decode(de)?)
+        };
+        if self.optional {
+            quote!(Some(#decode))
+        } else {
+            decode
+        }
+    }
+}
+
+/// The impls of `CdrEncode` and `CdrDecode` of a struct or union
+pub(crate) fn cdr_tokens(ctx: &Emitter, path: &[String], type_dcl: &IdlTypeDcl) -> Result<TokenStream, IdlError> {
+    let config = ctx.config;
+    if !config.cdr {
+        return Ok(TokenStream::new());
+    }
+    let extensibility = xtypes::extensibility(path, type_dcl)?;
+    let (id, encode, decode) = match type_dcl.kind {
+        IdlTypeDclKind::StructDcl(ref id, ref members) if extensibility == Extensibility::Mutable => {
+            let (encode, decode) = struct_members(ctx, path, type_dcl, id, members)?;
+            (id, encode, decode)
+        }
+        IdlTypeDclKind::StructDcl(ref id, ref members) => {
+            let paddings = layout::padding_fields(ctx, path, type_dcl)?;
+            let (encode, decode) = struct_tokens(ctx, path, type_dcl, id, members, &paddings, extensibility)?;
+            (id, encode, decode)
+        }
+        IdlTypeDclKind::UnionDcl(ref id, ref switch_type, ref cases) if extensibility == Extensibility::Mutable => {
+            let (encode, decode) = union_members(ctx, path, type_dcl, id, switch_type, cases)?;
+            (id, encode, decode)
+        }
+        IdlTypeDclKind::UnionDcl(ref id, _, _) => {
+            let (encode, decode) = union_tokens(extensibility);
+            (id, encode, decode)
+        }
+        _ => return Ok(TokenStream::new()),
+    };
+    let union_impl = match type_dcl.kind {
+        IdlTypeDclKind::UnionDcl(ref id, ref switch_type, ref cases) if extensibility != Extensibility::Mutable =>
+            union_impl(ctx, path, type_dcl, id, switch_type, cases)?,
+        _ => TokenStream::new(),
+    };
+    let rust_id = name_ident(id, NameKind::Type, config);
+    let validate = if config.validate {
+        quote!(rtps_types::Validate::validate(&value)?;)
+    } else {
+        TokenStream::new()
+    };
+    let decode = decode.tokens(validate);
+    Ok(quote! {
+        impl rtps_cdr::CdrEncode for #rust_id {
+            fn encode(&self, ser: &mut rtps_cdr::Serializer<'_>) -> rtps_cdr::Result<()> {
+                #encode
+            }
+        }
+        impl rtps_cdr::CdrDecode for #rust_id {
+            fn decode(de: &mut rtps_cdr::Deserializer<'_>) -> rtps_cdr::Result<#rust_id> {
+                #decode
+            }
+        }
+        #union_impl
+    })
+}
+
+/// Final structs encode their members in order, appendable ones preceded by a DHEADER;
+/// the padding fields of a fixed layout are not encoded
+fn struct_tokens(ctx: &Emitter, path: &[String], type_dcl: &IdlTypeDcl, id: &str, members: &[Box<IdlStructMember>],
+                 paddings: &[Ident], extensibility: Extensibility) -> Result<(TokenStream, Decoded), IdlError> {
+    let config = ctx.config;
+    let rust_id = name_ident(id, NameKind::Type, config);
+    let appendable = extensibility == Extensibility::Appendable;
+    let ids = struct_member_ids(ctx, path, type_dcl, id, members)?;
+    let mut encoded = Vec::new();
+    let mut decoded = Vec::new();
+    for (member, member_id) in members.iter().zip(ids) {
+        let field = name_ident(&member.id, NameKind::Member, config);
+        let value = Value::new(ctx, path, member_id, &member.type_spec, &member.annotations);
+        encoded.push(value.encode(quote!(self.#field), quote!(&self.#field)));
+        let decode = value.decode(appendable);
+        decoded.push(quote!(#field: #decode));
+    }
+    decoded.extend(paddings.iter().map(|padding| quote!(#padding: Default::default())));
+    Ok(match (appendable, members.is_empty()) {
+        (false, false) => (quote!(#(#encoded)* Ok(())), Decoded::Value(quote!(#rust_id { #(#decoded,)* }))),
+        (false, true) => (quote!(let _ = ser; Ok(())), Decoded::Value(quote!({ let _ = de; #rust_id {} }))),
+        (true, false) => (
+            quote!(ser.delimited(|ser| { #(#encoded)* Ok(()) })),
+            Decoded::Result(quote!(de.delimited(|de| Ok(#rust_id { #(#decoded,)* })))),
+        ),
+        (true, true) => (
+            quote!(ser.delimited(|_| Ok(()))),
+            Decoded::Result(quote!(de.delimited(|_| Ok(#rust_id {})))),
+        ),
+    })
+}
+
+/// Unions encode the discriminator as case label (see `unions`), followed by the element,
+/// appendable ones preceded by a DHEADER; the discriminator and element are encoded by the
+/// impl of `rtps_cdr::CdrUnion`
+fn union_tokens(extensibility: Extensibility) -> (TokenStream, Decoded) {
+    let encode = quote! {
+        rtps_cdr::CdrEncode::encode(&rtps_cdr::CdrUnion::discriminator(self), ser)?;
+        rtps_cdr::CdrUnion::encode_element(self, ser)
+    };
+    let decode = quote! {
+        let discriminator = rtps_cdr::CdrDecode::decode(de)?;
+        rtps_cdr::CdrUnion::decode_element(discriminator, de)
+    };
+    if extensibility == Extensibility::Appendable {
+        (quote!(ser.delimited(|ser| { #encode })), Decoded::Result(quote!(de.delimited(|de| { #decode }))))
+    } else {
+        (encode, Decoded::Result(quote!({ #decode })))
+    }
+}
+
+/// The impl of `rtps_cdr::CdrUnion` of a final or appendable union
+fn union_impl(ctx: &Emitter, path: &[String], type_dcl: &IdlTypeDcl, id: &str, switch_type: &IdlTypeSpec,
+              cases: &[IdlSwitchCase]) -> Result<TokenStream, IdlError> {
+    let config = ctx.config;
+    let rust_id = name_ident(id, NameKind::Type, config);
+    let discriminator = unions::discriminator(ctx, path, id, switch_type, cases)?;
+    let ids = union_member_ids(ctx, path, type_dcl, id, cases)?;
+    let mut labels = Vec::new();
+    let mut encoded = Vec::new();
+    let mut decoded = Vec::new();
+    for ((case, member_id), label) in cases.iter().zip(ids).zip(&discriminator.labels) {
+        let elem = &case.elem_spec;
+        let variant = name_ident(case.variant_id(), NameKind::Variant, config);
+        let field = name_ident(&elem.id, NameKind::Member, config);
+        let binding = if field == "value" { quote!(value) } else { quote!(#field: value) };
+        let value = Value::new(ctx, path, member_id, &elem.type_spec, &elem.annotations);
+        let encode = value.encode(quote!(value), quote!(value));
+        let decode = value.decode(false);
+        labels.push(quote!(#rust_id::#variant { .. } => #label,));
+        encoded.push(quote! {
+            #rust_id::#variant { #binding } => {
+                #encode
+            }
+        });
+        decoded.push(quote!(#rust_id::#variant { #field: #decode }));
+    }
+    let typ = &discriminator.typ;
+    let name = format!("union {}", scoped_name(path, id));
+    let arms = discriminator.arms(decoded, quote! {
+        return Err(rtps_cdr::Error::InvalidDiscriminator(i128::from(label), #name))
+    });
+    Ok(quote! {
+        impl rtps_cdr::CdrUnion for #rust_id {
+            type Discriminator = #typ;
+            fn discriminator(&self) -> #typ {
+                match self {
+                    #(#labels)*
+                }
+            }
+            fn encode_element(&self, ser: &mut rtps_cdr::Serializer<'_>) -> rtps_cdr::Result<()> {
+                match self {
+                    #(#encoded)*
+                }
+                Ok(())
+            }
+            fn decode_element(discriminator: #typ, de: &mut rtps_cdr::Deserializer<'_>)
+                              -> rtps_cdr::Result<#rust_id> {
+                Ok(match discriminator {
+                    #arms
+                })
+            }
+        }
+    })
+}
+
+/// Mutable structs encode each member preceded by its member header
+fn struct_members(ctx: &Emitter, path: &[String], type_dcl: &IdlTypeDcl, id: &str, members: &[Box<IdlStructMember>])
+                  -> Result<(TokenStream, Decoded), IdlError> {
+    let config = ctx.config;
+    let rust_id = name_ident(id, NameKind::Type, config);
+    let ids = struct_member_ids(ctx, path, type_dcl, id, members)?;
+    let mut encoded = Vec::new();
+    let mut arms = Vec::new();
+    for (member, member_id) in members.iter().zip(ids) {
+        let field = name_ident(&member.id, NameKind::Member, config);
+        let value = Value::new(ctx, path, member_id, &member.type_spec, &member.annotations);
+        encoded.push(value.encode_member(quote!(&self.#field)));
+        let key = Literal::u32_unsuffixed(member_id);
+        let decode = value.decode_member();
+        arms.push(quote!(#key => value.#field = #decode,));
+    }
+    if members.is_empty() {
+        return Ok((quote!(ser.members(|_| Ok(()))),
+                   Decoded::Members(quote! {
+                       let value = #rust_id {};
+                       de.members(|_, _| Ok(false))?;
+                   })));
+    }
+    Ok((members_encode(quote!(#(#encoded)*)), members_decode(&rust_id, quote!(#(#arms)*))))
+}
+
+/// Mutable unions encode the discriminator as member 0, followed by the element, whose
+/// member IDs start at 1
+fn union_members(ctx: &Emitter, path: &[String], type_dcl: &IdlTypeDcl, id: &str, switch_type: &IdlTypeSpec,
+                 cases: &[IdlSwitchCase]) -> Result<(TokenStream, Decoded), IdlError> {
+    let config = ctx.config;
+    let rust_id = name_ident(id, NameKind::Type, config);
+    let discriminator = unions::discriminator(ctx, path, id, switch_type, cases)?;
+    let ids = union_member_ids(ctx, path, type_dcl, id, cases)?;
+    let mut encoded = Vec::new();
+    let mut arms = Vec::new();
+    for ((case, member_id), label) in cases.iter().zip(ids).zip(&discriminator.labels) {
+        let elem = &case.elem_spec;
+        let variant = name_ident(case.variant_id(), NameKind::Variant, config);
+        let field = name_ident(&elem.id, NameKind::Member, config);
+        let binding = if field == "value" { quote!(value) } else { quote!(#field: value) };
+        let value = Value::new(ctx, path, member_id, &elem.type_spec, &elem.annotations);
+        let encode = value.encode_member(quote!(value));
+        encoded.push(quote! {
+            #rust_id::#variant { #binding } => {
+                ser.member(rtps_types::xtypes::MUST_UNDERSTAND, |ser| rtps_cdr::CdrEncode::encode(&#label, ser))?;
+                #encode
+            }
+        });
+        let key = Literal::u32_unsuffixed(member_id);
+        let decode = value.decode_member();
+        arms.push(quote!(#key => value = #rust_id::#variant { #field: #decode },));
+    }
+    let encode = members_encode(quote! {
+        match self {
+            #(#encoded)*
+        }
+    });
+    let typ = &discriminator.typ;
+    let decode = members_decode(&rust_id, quote! {
+        0 => {
+            <#typ as rtps_cdr::CdrDecode>::decode(de)?;
+        }
+        #(#arms)*
+    });
+    Ok((encode, decode))
+}
+
+/// Encode the members of a mutable type by the statements `members`
+fn members_encode(members: TokenStream) -> TokenStream {
+    quote! {
+        ser.members(|ser| {
+            #members
+            Ok(())
+        })
+    }
+}
+
+/// Decode the members of a mutable type by the match `arms` of their member ID, skipping
+/// unknown members
+fn members_decode(rust_id: &Ident, arms: TokenStream) -> Decoded {
+    Decoded::Members(quote! {
+        let mut value = #rust_id::default();
+        de.members(|de, header| {
+            match header & rtps_types::xtypes::MEMBER_ID_MASK {
+                #arms
+                _ => return Ok(false),
+            }
+            Ok(true)
+        })?;
+    })
+}
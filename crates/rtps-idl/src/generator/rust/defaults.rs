@@ -0,0 +1,264 @@
+// Copyright (C) 2019  Frank Rehberger
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>
+
+//! Default values of the generated types
+//!
+//! Members default to zero, empty, `false` or the default of their type, unless
+//! annotated by `@default`; `@optional` members are absent. Structs derive `Default` if
+//! that suffices, otherwise these and unions implement it: arrays longer than 32 elements
+//! are built by `std::array::from_fn`, and a union selects the branch of the default
+//! discriminator, falling back to the `default` branch and then the first one.
+use proc_macro2::{Literal, TokenStream};
+use quote::{quote, ToTokens};
+use crate::ast::*;
+use crate::{ExternalMapping, IdlError};
+use super::{Emitter, NameKind, alloc_crate, const_value, core_crate, in_place, name_ident, wire_chars};
+use super::derives::scoped_name;
+use super::layout;
+
+/// Arrays longer than this do not implement `Default`
+const MAX_DEFAULT_ARRAY_LEN: u64 = 32;
+
+/// The `impl Default` of a struct whose members cannot derive it, and of a union
+pub(crate) fn default_impl(ctx: &Emitter, path: &[String], type_dcl: &IdlTypeDcl)
+                           -> Result<Option<TokenStream>, IdlError> {
+    let config = ctx.config;
+    match type_dcl.kind {
+        IdlTypeDclKind::StructDcl(ref id, ref members) => {
+            let values = members.iter()
+                .map(|member| member_default(ctx, path, id, &member.id, &member.type_spec, &member.annotations))
+                .collect::<Result<Vec<_>, _>>()?;
+            if values.iter().all(Option::is_none) {
+                return Ok(None);
+            }
+            let rust_id = name_ident(id, NameKind::Type, config);
+            let members = members.iter()
+                .map(|member| name_ident(&member.id, NameKind::Member, config));
+            let values = values.into_iter()
+                .map(|value| value.unwrap_or_else(|| quote!(Default::default())));
+            let paddings = layout::padding_fields(ctx, path, type_dcl)?;
+            Ok(Some(quote! {
+                impl Default for #rust_id {
+                    fn default() -> #rust_id {
+                        #rust_id {
+                            #(#members: #values,)*
+                            #(#paddings: Default::default(),)*
+                        }
+                    }
+                }
+            }))
+        }
+        IdlTypeDclKind::UnionDcl(ref id, ref switch_type, ref cases) => {
+            let case = match default_case(ctx, path, switch_type, cases) {
+                Some(case) => case,
+                None => return Ok(None),
+            };
+            let elem = &case.elem_spec;
+            let rust_id = name_ident(id, NameKind::Type, config);
+            let variant = name_ident(case.variant_id(), NameKind::Variant, config);
+            let member = name_ident(&elem.id, NameKind::Member, config);
+            let value = member_default(ctx, path, id, &elem.id, &elem.type_spec, &elem.annotations)?
+                .unwrap_or_else(|| quote!(Default::default()));
+            Ok(Some(quote! {
+                impl Default for #rust_id {
+                    fn default() -> #rust_id {
+                        #rust_id::#variant { #member: #value }
+                    }
+                }
+            }))
+        }
+        _ => Ok(None),
+    }
+}
+
+/// The default of a member, `None` if `Default::default()` provides it; `@optional`
+/// members are absent
+fn member_default(ctx: &Emitter, path: &[String], type_id: &str, id: &str, type_spec: &IdlTypeSpec,
+                  annotations: &[IdlAnnotation]) -> Result<Option<TokenStream>, IdlError> {
+    if has_annotation(annotations, "optional") {
+        return Ok(None);
+    }
+    let value = match find_annotation(annotations, "default").and_then(|annotation| annotation.value()) {
+        Some(value) => typed_value(ctx, path, type_spec, value)
+            .map(Some)
+            .map_err(|mesg| IdlError::ErrorMesg(
+                format!("@default of '{}::{}': {}", scoped_name(path, type_id), id, mesg)))?,
+        None => type_default(ctx, path, type_spec),
+    };
+    match value {
+        Some(value) if has_annotation(annotations, "external") => match ctx.config.external {
+            ExternalMapping::Box => Ok(Some(quote!(Box::new(#value)))),
+            ExternalMapping::Arc => {
+                let alloc = alloc_crate(ctx.config);
+                Ok(Some(quote!(#alloc::sync::Arc::new(#value))))
+            }
+        },
+        value => Ok(value),
+    }
+}
+
+/// The default of the type, `None` if `Default::default()` provides it
+fn type_default(ctx: &Emitter, path: &[String], type_spec: &IdlTypeSpec) -> Option<TokenStream> {
+    // mapped types are assumed to implement Default
+    if ctx.type_map.type_spec(path, type_spec).is_some() {
+        return None;
+    }
+    match type_spec {
+        IdlTypeSpec::ArrayType(ref type_spec, ref dims) => {
+            let elem = type_default(ctx, path, type_spec);
+            let short = dims.iter()
+                .all(|dim| const_value(ctx.spec, path, dim).is_some_and(|len| len <= MAX_DEFAULT_ARRAY_LEN));
+            if elem.is_none() && short {
+                return None;
+            }
+            let mut value = elem.unwrap_or_else(|| quote!(Default::default()));
+            let core = core_crate(ctx.config);
+            for _ in dims {
+                value = quote!(#core::array::from_fn(|_| #value));
+            }
+            Some(value)
+        }
+        IdlTypeSpec::ScopedName(ref name) => match ctx.spec.resolve_type(path, name) {
+            Some((dcl_path, type_dcl)) => match type_dcl.kind {
+                IdlTypeDclKind::TypeDcl(ref id, ref type_spec)
+                    if ctx.type_map.declaration(&dcl_path, id).is_none() =>
+                    type_default(ctx, &dcl_path, type_spec),
+                _ => None,
+            },
+            None => None,
+        },
+        _ => None,
+    }
+}
+
+/// The type specification a typedef chain resolves to, and its module
+pub(crate) fn resolve<'s>(ctx: &Emitter<'s>, path: &[String], type_spec: &'s IdlTypeSpec)
+                          -> Result<(Vec<String>, &'s IdlTypeSpec, Option<&'s IdlTypeDcl>), String> {
+    let mut path = path.to_vec();
+    let mut type_spec = type_spec;
+    loop {
+        if ctx.type_map.type_spec(&path, type_spec).is_some() {
+            return Err("mapped types take no constant value".to_owned());
+        }
+        let name = match type_spec {
+            IdlTypeSpec::ScopedName(ref name) => name,
+            _ => return Ok((path, type_spec, None)),
+        };
+        let (dcl_path, type_dcl) = ctx.spec.resolve_type(&path, name)
+            .ok_or_else(|| format!("unknown type '{}'", name.0.join("::")))?;
+        if let Some(id) = type_dcl.kind.id() {
+            if ctx.type_map.declaration(&dcl_path, id).is_some() {
+                return Err("mapped types take no constant value".to_owned());
+            }
+        }
+        match type_dcl.kind {
+            IdlTypeDclKind::TypeDcl(_, ref aliased) => {
+                path = dcl_path;
+                type_spec = aliased;
+            }
+            _ => return Ok((dcl_path, type_spec, Some(type_dcl))),
+        }
+    }
+}
+
+/// The value of the type, given by the constant expression of an annotation
+pub(crate) fn typed_value(ctx: &Emitter, path: &[String], type_spec: &IdlTypeSpec, value: &IdlValueExpr)
+                          -> Result<TokenStream, String> {
+    let config = ctx.config;
+    let core = core_crate(config);
+    let (dcl_path, resolved, type_dcl) = resolve(ctx, path, type_spec)?;
+    let tokens = value.tokens(config).map_err(|_| "invalid value".to_owned())?;
+    // the float literal of an integer value
+    let float = || match ctx.spec.eval_int(path, value) {
+        Some(val) => Literal::f64_unsuffixed(val as f64).into_token_stream(),
+        None => tokens.clone(),
+    };
+    match resolved {
+        IdlTypeSpec::I16Type | IdlTypeSpec::I32Type | IdlTypeSpec::I64Type
+        | IdlTypeSpec::U16Type | IdlTypeSpec::U32Type | IdlTypeSpec::U64Type
+        | IdlTypeSpec::OctetType => match ctx.spec.eval_int(path, value) {
+            Some(_) => Ok(tokens),
+            None => Err("an integer constant is required".to_owned()),
+        },
+        IdlTypeSpec::BooleanType => Ok(tokens),
+        IdlTypeSpec::F32Type | IdlTypeSpec::F64Type => Ok(float()),
+        IdlTypeSpec::F128Type => {
+            let value = float();
+            Ok(quote!(rtps_types::LongDouble::from_f64(#value)))
+        }
+        IdlTypeSpec::CharType if wire_chars(config) =>
+            Ok(quote!(rtps_types::Char::from_char(#tokens))),
+        IdlTypeSpec::WideCharType if wire_chars(config) =>
+            Ok(quote!(rtps_types::WChar::from_char(#tokens))),
+        IdlTypeSpec::CharType | IdlTypeSpec::WideCharType => Ok(tokens),
+        IdlTypeSpec::StringType(ref bound) | IdlTypeSpec::WideStringType(ref bound) => {
+            let wide = matches!(resolved, IdlTypeSpec::WideStringType(_));
+            match bound {
+                Some(ref bound) if config.bounded || in_place(config) => {
+                    let text = syn::parse2::<syn::LitStr>(tokens.clone())
+                        .map_err(|_| "a string literal is required".to_owned())?
+                        .value();
+                    let len = match wide {
+                        true if wire_chars(config) => text.encode_utf16().count(),
+                        true => text.chars().count(),
+                        false => text.len(),
+                    };
+                    let bound = const_value(ctx.spec, &dcl_path, bound)
+                        .ok_or_else(|| "the bound is no constant".to_owned())?;
+                    if len as u64 > bound {
+                        return Err(format!("length {} exceeds the bound {}", len, bound));
+                    }
+                    Ok(quote!(#core::convert::TryFrom::try_from(#tokens).expect("default within the bound")))
+                }
+                _ => Ok(quote!(#core::convert::From::from(#tokens))),
+            }
+        }
+        IdlTypeSpec::ScopedName(_) => match type_dcl.map(|type_dcl| &type_dcl.kind) {
+            Some(IdlTypeDclKind::EnumDcl(_, ref enums)) => {
+                let enumerator = match value {
+                    IdlValueExpr::ScopedName(ref name) => name.0.last()
+                        .filter(|id| enums.iter().any(|enumerator| &enumerator.id == *id)),
+                    _ => None,
+                };
+                let enumerator = enumerator.ok_or_else(|| "an enumerator is required".to_owned())?;
+                let typ = type_spec.tokens(ctx, path).map_err(|_| "invalid type".to_owned())?;
+                let variant = name_ident(enumerator, NameKind::Variant, config);
+                Ok(quote!(#typ::#variant))
+            }
+            _ => Err("only primitive types, strings and enums take a constant value".to_owned()),
+        },
+        _ => Err("only primitive types, strings and enums take a constant value".to_owned()),
+    }
+}
+
+/// The value of a union label, if an integer or boolean constant
+pub(crate) fn label_value(ctx: &Emitter, path: &[String], expr: &IdlValueExpr) -> Option<i128> {
+    match expr {
+        IdlValueExpr::BooleanLiteral(val) => Some(i128::from(*val)),
+        expr => ctx.spec.eval_int(path, expr),
+    }
+}
+
+/// The branch selected by the default of the discriminator, zero or the default enumerator
+fn default_case<'s>(ctx: &Emitter, path: &[String], switch_type: &IdlTypeSpec, cases: &'s [IdlSwitchCase])
+                    -> Option<&'s IdlSwitchCase> {
+    let enumerator = match resolve(ctx, path, switch_type) {
+        Ok((_, _, Some(IdlTypeDcl { kind: IdlTypeDclKind::EnumDcl(_, ref enums), .. }))) => enums.iter()
+            .find(|enumerator| find_annotation(&enumerator.annotations, "default_literal").is_some())
+            .or_else(|| enums.first()),
+        _ => None,
+    };
+    let selects = |label: &IdlSwitchLabel| match (label, enumerator) {
+        (IdlSwitchLabel::Label(ref expr), Some(enumerator)) => match expr.as_ref() {
+            IdlValueExpr::ScopedName(ref name) => name.0.last() == Some(&enumerator.id),
+            _ => false,
+        },
+        (IdlSwitchLabel::Label(ref expr), None) => label_value(ctx, path, expr) == Some(0),
+        (IdlSwitchLabel::Default, _) => false,
+    };
+    cases.iter().find(|case| case.labels.iter().any(selects))
+        .or_else(|| cases.iter().find(|case| case.labels.iter().any(|label| matches!(label, IdlSwitchLabel::Default))))
+        .or_else(|| cases.first())
+}
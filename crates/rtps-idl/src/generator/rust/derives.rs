@@ -0,0 +1,139 @@
+// Copyright (C) 2019  Frank Rehberger
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>
+
+//! Derives of the generated types
+//!
+//! The derives requested for a type are filtered by the derives its members
+//! support: floats are neither `Eq`, `Ord` nor `Hash`, strings, sequences and
+//! `@external` members are not `Copy`, and a member of a constructed type supports
+//! the derives remaining for that type. `Default` is implemented by any type, whether
+//! derived is decided by the emission of the type. Types replaced by the type mapping
+//! are assumed to support any derive.
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use crate::ast::*;
+use crate::Configuration;
+use super::type_map::TypeMap;
+
+/// Scoped IDL name of the type `id` declared in the module `path`
+pub(crate) fn scoped_name(path: &[String], id: &str) -> String {
+    path.iter().map(|s| s.as_str()).chain(Some(id)).collect::<Vec<_>>().join("::")
+}
+
+/// Derives of the generated types, computed on demand
+pub(crate) struct Derives<'a> {
+    config: &'a Configuration,
+    spec: &'a Specification,
+    type_map: &'a TypeMap<'a>,
+    cache: RefCell<HashMap<String, Vec<String>>>,
+    visiting: RefCell<HashSet<String>>,
+}
+
+impl<'a> Derives<'a> {
+    pub(crate) fn new(config: &'a Configuration, spec: &'a Specification,
+                      type_map: &'a TypeMap<'a>) -> Derives<'a> {
+        Derives {
+            config,
+            spec,
+            type_map,
+            cache: RefCell::new(HashMap::new()),
+            visiting: RefCell::new(HashSet::new()),
+        }
+    }
+
+    /// The derives of the type declared in the module `path`
+    pub(crate) fn of(&self, path: &[String], type_dcl: &IdlTypeDcl) -> Vec<String> {
+        let id = match type_dcl.kind.id() {
+            Some(id) => id,
+            None => return Vec::new(),
+        };
+        let key = scoped_name(path, id);
+        if let Some(derives) = self.cache.borrow().get(&key) {
+            return derives.clone();
+        }
+
+        let requested = self.requested(&key);
+        // recursive types assume the requested derives
+        if !self.visiting.borrow_mut().insert(key.clone()) {
+            return requested;
+        }
+        let derives = requested.into_iter()
+            .filter(|derive| self.is_legal(derive_name(derive), path, type_dcl))
+            .collect::<Vec<_>>();
+        self.visiting.borrow_mut().remove(&key);
+
+        self.cache.borrow_mut().insert(key, derives.clone());
+        derives
+    }
+
+    /// The configured derives and those of matching type rules, without duplicates
+    fn requested(&self, scoped_name: &str) -> Vec<String> {
+        let rules = self.config.type_rules.iter()
+            .filter(|rule| rule.matches(scoped_name))
+            .flat_map(|rule| rule.derives.iter());
+        let mut derives: Vec<String> = Vec::new();
+        for derive in self.config.derives.iter().chain(rules) {
+            if !derives.contains(derive) {
+                derives.push(derive.clone());
+            }
+        }
+        derives
+    }
+
+    fn is_legal(&self, derive: &str, path: &[String], type_dcl: &IdlTypeDcl) -> bool {
+        if derive == "Default" {
+            return true;
+        }
+        match type_dcl.kind {
+            // native types are assumed to implement the derives
+            IdlTypeDclKind::None | IdlTypeDclKind::NativeDcl(_) => true,
+            IdlTypeDclKind::TypeDcl(_, ref type_spec) => self.is_legal_spec(derive, path, type_spec),
+            IdlTypeDclKind::StructDcl(_, ref members) =>
+                members.iter().all(|member| self.is_legal_member(derive, path, &member.type_spec,
+                                                                 &member.annotations)),
+            IdlTypeDclKind::EnumDcl(..) => true,
+            IdlTypeDclKind::UnionDcl(_, _, ref cases) =>
+                cases.iter().all(|case| self.is_legal_member(derive, path, &case.elem_spec.type_spec,
+                                                             &case.elem_spec.annotations)),
+        }
+    }
+
+    /// `@external` members are held by pointer, which is not `Copy`
+    fn is_legal_member(&self, derive: &str, path: &[String], type_spec: &IdlTypeSpec,
+                       annotations: &[IdlAnnotation]) -> bool {
+        (derive != "Copy" || !has_annotation(annotations, "external"))
+            && self.is_legal_spec(derive, path, type_spec)
+    }
+
+    fn is_legal_spec(&self, derive: &str, path: &[String], type_spec: &IdlTypeSpec) -> bool {
+        // the derives of mapped types are unknown, these are assumed to be supported
+        if self.type_map.type_spec(path, type_spec).is_some() {
+            return true;
+        }
+        match type_spec {
+            IdlTypeSpec::F32Type | IdlTypeSpec::F64Type | IdlTypeSpec::F128Type =>
+                !["Eq", "Ord", "Hash"].contains(&derive),
+            IdlTypeSpec::StringType(_) | IdlTypeSpec::WideStringType(_) => derive != "Copy",
+            IdlTypeSpec::SequenceType(ref type_spec, _) =>
+                derive != "Copy" && self.is_legal_spec(derive, path, type_spec),
+            IdlTypeSpec::ArrayType(ref type_spec, _) => self.is_legal_spec(derive, path, type_spec),
+            IdlTypeSpec::ScopedName(ref name) => match self.spec.resolve_type(path, name) {
+                Some((dcl_path, type_dcl)) => match type_dcl.kind {
+                    // typedefs are transparent
+                    IdlTypeDclKind::TypeDcl(_, ref type_spec) =>
+                        self.is_legal_spec(derive, &dcl_path, type_spec),
+                    _ => self.of(&dcl_path, type_dcl).iter().any(|d| derive_name(d) == derive),
+                },
+                None => true,
+            },
+            _ => true,
+        }
+    }
+}
+
+/// The name of the derive macro, without path
+pub(crate) fn derive_name(derive: &str) -> &str {
+    derive.rsplit("::").next().unwrap_or(derive).trim()
+}
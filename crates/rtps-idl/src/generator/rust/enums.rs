@@ -0,0 +1,227 @@
+// Copyright (C) 2019  Frank Rehberger
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>
+
+//! Enumerations, represented by their integer value
+//!
+//! The values follow `@value`, an enumerator without it taking the value of its predecessor
+//! plus one, starting at 0. `@bit_bound` selects the representation, `u32` by default, and
+//! `@default_literal` the default, the first enumerator otherwise. Serde and the CDR codec
+//! encode the value.
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use proc_macro2::{Ident, Literal, TokenStream};
+use quote::quote;
+use crate::ast::*;
+use crate::IdlError;
+use super::{Emitter, NameKind, core_crate, name_ident, size};
+use super::derives::scoped_name;
+
+/// The representation of the enum, as given by `@bit_bound`
+fn repr(ctx: &Emitter, path: &[String], id: &str, annotations: &[IdlAnnotation])
+        -> Result<(Ident, u32), IdlError> {
+    let bit_bound = match find_annotation(annotations, "bit_bound") {
+        Some(annotation) => annotation.value()
+            .and_then(|expr| ctx.spec.eval_int(path, expr))
+            .filter(|bits| (1..=32).contains(bits))
+            .ok_or_else(|| IdlError::ErrorMesg(
+                format!("enum '{}': @bit_bound must be within 1..32", scoped_name(path, id))))? as u32,
+        None => 32,
+    };
+    Ok((Ident::new(&format!("u{}", repr_bits(bit_bound)), proc_macro2::Span::call_site()), bit_bound))
+}
+
+/// The bits of the smallest unsigned integer holding the bit bound
+fn repr_bits(bit_bound: u32) -> u32 {
+    match bit_bound {
+        1..=8 => 8,
+        9..=16 => 16,
+        _ => 32,
+    }
+}
+
+/// The number of bytes encoding the enum
+pub(crate) fn encoded_len(ctx: &Emitter, path: &[String], id: &str, annotations: &[IdlAnnotation])
+                          -> Result<usize, IdlError> {
+    let (_, bit_bound) = repr(ctx, path, id, annotations)?;
+    Ok(repr_bits(bit_bound) as usize / 8)
+}
+
+/// The bit bound and the values of the enumerators of the enum
+pub(crate) fn literals(ctx: &Emitter, path: &[String], id: &str, annotations: &[IdlAnnotation],
+                       enums: &[IdlEnumerator]) -> Result<(u32, Vec<u32>), IdlError> {
+    let (_, bit_bound) = repr(ctx, path, id, annotations)?;
+    Ok((bit_bound, values(ctx, path, id, enums, bit_bound)?))
+}
+
+/// The values of the enumerators, verified to be distinct and within the bit bound
+fn values(ctx: &Emitter, path: &[String], id: &str, enums: &[IdlEnumerator], bit_bound: u32)
+          -> Result<Vec<u32>, IdlError> {
+    let error = |mesg: String| IdlError::ErrorMesg(format!("enum '{}': {}", scoped_name(path, id), mesg));
+    let max = (1u64 << bit_bound) - 1;
+    let mut next = 0i128;
+    let mut seen = HashMap::new();
+    let mut values = Vec::new();
+    for enumerator in enums {
+        let value = match find_annotation(&enumerator.annotations, "value") {
+            Some(annotation) => annotation.value()
+                .and_then(|expr| ctx.spec.eval_int(path, expr))
+                .ok_or_else(|| error(format!("@value of '{}' is no integer constant", enumerator.id)))?,
+            None => next,
+        };
+        let value = u32::try_from(value).ok()
+            .filter(|value| u64::from(*value) <= max)
+            .ok_or_else(|| error(format!("value {} of '{}' exceeds the bit bound {}",
+                                         value, enumerator.id, bit_bound)))?;
+        if let Some(other) = seen.insert(value, &enumerator.id) {
+            return Err(error(format!("'{}' and '{}' share the value {}", other, enumerator.id, value)));
+        }
+        values.push(value);
+        next = i128::from(value) + 1;
+    }
+    Ok(values)
+}
+
+/// The enum declaration and its conversions
+pub(crate) fn enum_tokens(ctx: &Emitter, path: &[String], type_dcl: &IdlTypeDcl, id: &str,
+                          enums: &[IdlEnumerator], outer_attrs: TokenStream) -> Result<TokenStream, IdlError> {
+    let config = ctx.config;
+    let core = core_crate(config);
+    let rust_id = name_ident(id, NameKind::Type, config);
+    let (repr, bit_bound) = repr(ctx, path, id, &type_dcl.annotations)?;
+    let values = values(ctx, path, id, enums, bit_bound)?;
+    let variants = enums.iter()
+        .map(|enumerator| name_ident(&enumerator.id, NameKind::Variant, config))
+        .collect::<Vec<_>>();
+    let names = enums.iter().map(|enumerator| enumerator.id.as_str()).collect::<Vec<_>>();
+    let values = values.into_iter().map(Literal::u32_unsuffixed).collect::<Vec<_>>();
+
+    let default = enums.iter()
+        .position(|enumerator| find_annotation(&enumerator.annotations, "default_literal").is_some())
+        .unwrap_or(0);
+    let declared = variants.iter().enumerate()
+        .map(|(idx, variant)| if idx == default { quote!(#[default] #variant) } else { quote!(#variant) })
+        .collect::<Vec<_>>();
+
+    // into u32, and from and into the representation if narrower
+    let narrow = if bit_bound > 16 {
+        quote! {
+            impl From<#rust_id> for u32 {
+                fn from(value: #rust_id) -> u32 {
+                    value.value()
+                }
+            }
+        }
+    } else {
+        quote! {
+            impl From<#rust_id> for u32 {
+                fn from(value: #rust_id) -> u32 {
+                    u32::from(value.value())
+                }
+            }
+            impl From<#rust_id> for #repr {
+                fn from(value: #rust_id) -> #repr {
+                    value.value()
+                }
+            }
+            impl #core::convert::TryFrom<#repr> for #rust_id {
+                type Error = rtps_types::EnumError;
+
+                fn try_from(value: #repr) -> Result<#rust_id, rtps_types::EnumError> {
+                    <#rust_id as #core::convert::TryFrom<u32>>::try_from(u32::from(value))
+                }
+            }
+        }
+    };
+
+    let serialize = Ident::new(&format!("serialize_{}", repr), proc_macro2::Span::call_site());
+    let serde = if config.serde {
+        quote! {
+            impl serde::Serialize for #rust_id {
+                fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    serializer.#serialize(self.value())
+                }
+            }
+            impl<'de> serde::Deserialize<'de> for #rust_id {
+                fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<#rust_id, D::Error> {
+                    let value = <#repr as serde::Deserialize>::deserialize(deserializer)?;
+                    <#rust_id as #core::convert::TryFrom<#repr>>::try_from(value)
+                        .map_err(serde::de::Error::custom)
+                }
+            }
+        }
+    } else {
+        TokenStream::new()
+    };
+    let cdr = if config.cdr {
+        let size_impl = size::size_tokens(ctx, path, type_dcl)?;
+        quote! {
+            impl rtps_cdr::CdrEncode for #rust_id {
+                fn encode(&self, ser: &mut rtps_cdr::Serializer<'_>) -> rtps_cdr::Result<()> {
+                    rtps_cdr::CdrEncode::encode(&self.value(), ser)
+                }
+            }
+            impl rtps_cdr::CdrDecode for #rust_id {
+                fn decode(de: &mut rtps_cdr::Deserializer<'_>) -> rtps_cdr::Result<#rust_id> {
+                    let value = <#repr as rtps_cdr::CdrDecode>::decode(de)?;
+                    Ok(<#rust_id as #core::convert::TryFrom<#repr>>::try_from(value)?)
+                }
+            }
+            #size_impl
+        }
+    } else {
+        TokenStream::new()
+    };
+
+    Ok(quote! {
+        #outer_attrs
+        #[repr(#repr)]
+        pub enum #rust_id {
+            #(#declared = #values,)*
+        }
+        impl #rust_id {
+            /// The value of the enumerator
+            pub const fn value(&self) -> #repr {
+                match self {
+                    #(#rust_id::#variants => #values,)*
+                }
+            }
+
+            /// The IDL name of the enumerator
+            pub const fn as_str(&self) -> &'static str {
+                match self {
+                    #(#rust_id::#variants => #names,)*
+                }
+            }
+        }
+        impl #core::convert::TryFrom<u32> for #rust_id {
+            type Error = rtps_types::EnumError;
+
+            fn try_from(value: u32) -> Result<#rust_id, rtps_types::EnumError> {
+                match value {
+                    #(#values => Ok(#rust_id::#variants),)*
+                    _ => Err(rtps_types::EnumError::Value(value)),
+                }
+            }
+        }
+        #narrow
+        impl #core::fmt::Display for #rust_id {
+            fn fmt(&self, f: &mut #core::fmt::Formatter) -> #core::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+        impl #core::str::FromStr for #rust_id {
+            type Err = rtps_types::EnumError;
+
+            fn from_str(name: &str) -> Result<#rust_id, rtps_types::EnumError> {
+                match name {
+                    #(#names => Ok(#rust_id::#variants),)*
+                    _ => Err(rtps_types::EnumError::Name(name.to_owned())),
+                }
+            }
+        }
+        #serde
+        #cdr
+    })
+}
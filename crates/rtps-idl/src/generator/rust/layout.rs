@@ -0,0 +1,354 @@
+// Copyright (C) 2019  Frank Rehberger
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>
+
+//! Fixed layouts for the exchange through shared memory
+//!
+//! With `Configuration::fixed_layout` structs are `#[repr(C)]`, laid out in memory as their
+//! plain CDR encoding in little endian: the members are placed as XCDR1 aligns them, fields
+//! `_pad0`, `_pad1`, .. of `rtps_types::Padding<N>` filling the gaps, and bounded strings
+//! and sequences are `rtps_types::FixedString<N>` and `rtps_types::FixedVec<T, N>`, stored
+//! at their bound. Compile-time assertions verify the layout, and structs and enums
+//! implement `rtps_types::FixedLayout` to be cast from and to bytes. Each type must be
+//! fully bounded: unbounded strings and sequences, wide strings, `long double`, unions,
+//! mutable structs, `@optional` and `@external` members, and native and mapped types have
+//! no fixed layout, neither have members CDR places off their alignment in memory.
+use proc_macro2::{Ident, Literal, TokenStream};
+use quote::quote;
+use crate::ast::*;
+use crate::IdlError;
+use super::{Emitter, NameKind, const_value, core_crate, ident, member_type, name_ident};
+use super::derives::scoped_name;
+use super::enums;
+use super::xtypes::{self, Extensibility};
+
+/// The placement of a type of fixed layout
+#[derive(Clone, Copy)]
+struct Shape {
+    /// The alignment of the first primitive, padded to by CDR
+    lead: usize,
+    /// The largest alignment of the primitives, which the start of the type must satisfy
+    align: usize,
+    /// The length of the encoding, strings and sequences at their bound
+    len: usize,
+    /// The size in memory
+    size: usize,
+}
+
+impl Shape {
+    fn primitive(size: usize) -> Shape {
+        Shape { lead: size, align: size, len: size, size }
+    }
+}
+
+/// A field of a struct, a member at its offset or padding of a length
+enum Field<'s> {
+    Member(&'s IdlStructMember, usize, Shape),
+    Padding(usize),
+}
+
+/// The reason of an error, within the error of the type without fixed layout or TypeObject
+pub(crate) fn reason(err: IdlError) -> String {
+    match err {
+        IdlError::ErrorMesg(mesg) => mesg,
+        err => format!("{:?}", err),
+    }
+}
+
+fn align_up(pos: usize, align: usize) -> usize {
+    pos.div_ceil(align) * align
+}
+
+/// Computes the layouts, detecting recursion
+struct Layouter<'a, 's> {
+    ctx: &'a Emitter<'s>,
+    /// The structs being laid out
+    stack: Vec<String>,
+}
+
+impl<'a, 's> Layouter<'a, 's> {
+    /// The shape of the type within module `path`, the reason if it has no fixed layout
+    fn shape(&mut self, path: &[String], type_spec: &'s IdlTypeSpec) -> Result<Shape, String> {
+        let ctx = self.ctx;
+        if ctx.type_map.type_spec(path, type_spec).is_some() {
+            return Err("mapped type".to_owned());
+        }
+        let bound = |bound: &IdlValueExpr| const_value(ctx.spec, path, bound)
+            .map(|bound| bound as usize)
+            .ok_or_else(|| format!("bound {:?} is no non-negative integer constant", bound));
+        match type_spec {
+            IdlTypeSpec::BooleanType | IdlTypeSpec::OctetType | IdlTypeSpec::CharType => Ok(Shape::primitive(1)),
+            IdlTypeSpec::I16Type | IdlTypeSpec::U16Type | IdlTypeSpec::WideCharType => Ok(Shape::primitive(2)),
+            IdlTypeSpec::I32Type | IdlTypeSpec::U32Type | IdlTypeSpec::F32Type => Ok(Shape::primitive(4)),
+            IdlTypeSpec::I64Type | IdlTypeSpec::U64Type | IdlTypeSpec::F64Type => Ok(Shape::primitive(8)),
+            // aligned to 16 bytes in memory, to 8 by CDR
+            IdlTypeSpec::F128Type => Err("long double".to_owned()),
+            IdlTypeSpec::StringType(Some(ref n)) => {
+                let n = bound(n)?;
+                Ok(Shape { lead: 4, align: 4, len: n + 5, size: n + 5 })
+            }
+            IdlTypeSpec::StringType(None) => Err("unbounded string".to_owned()),
+            IdlTypeSpec::WideStringType(_) => Err("wide string".to_owned()),
+            IdlTypeSpec::SequenceType(ref elem, Some(ref n)) => {
+                let n = bound(n)?;
+                let elem = self.shape(path, elem)?;
+                sequence(elem, n)
+            }
+            IdlTypeSpec::SequenceType(_, None) => Err("unbounded sequence".to_owned()),
+            IdlTypeSpec::ArrayType(ref elem, ref dims) => {
+                let n = dims.iter().map(|dim| bound(dim)).product::<Result<usize, _>>()?;
+                let elem = self.shape(path, elem)?;
+                array(elem, n)
+            }
+            IdlTypeSpec::ScopedName(ref name) => {
+                let (dcl_path, type_dcl) = ctx.spec.resolve_type(path, name)
+                    .ok_or_else(|| format!("unknown type '{}'", name.0.join("::")))?;
+                let id = type_dcl.kind.id().unwrap_or_default();
+                if ctx.type_map.declaration(&dcl_path, id).is_some() {
+                    return Err(format!("mapped type '{}'", scoped_name(&dcl_path, id)));
+                }
+                match type_dcl.kind {
+                    IdlTypeDclKind::TypeDcl(_, ref aliased) => self.shape(&dcl_path, aliased),
+                    IdlTypeDclKind::StructDcl(..) => self.fields(&dcl_path, type_dcl).map(|(_, shape)| shape),
+                    IdlTypeDclKind::EnumDcl(..) => enums::encoded_len(ctx, &dcl_path, id, &type_dcl.annotations)
+                        .map(Shape::primitive)
+                        .map_err(reason),
+                    IdlTypeDclKind::UnionDcl(..) => Err(format!("union '{}'", scoped_name(&dcl_path, id))),
+                    _ => Err(format!("native type '{}'", scoped_name(&dcl_path, id))),
+                }
+            }
+            IdlTypeSpec::None => Err("no type".to_owned()),
+        }
+    }
+
+    /// The fields of the struct declared in module `path`, the members being placed at their
+    /// CDR offsets, and its shape; the struct is padded to its alignment
+    fn fields(&mut self, path: &[String], type_dcl: &'s IdlTypeDcl) -> Result<(Vec<Field<'s>>, Shape), String> {
+        let (id, members) = match type_dcl.kind {
+            IdlTypeDclKind::StructDcl(ref id, ref members) => (id, members),
+            _ => return Err("no struct".to_owned()),
+        };
+        let name = scoped_name(path, id);
+        if xtypes::extensibility(path, type_dcl).map_err(reason)? == Extensibility::Mutable {
+            return Err(format!("mutable struct '{}'", name));
+        }
+        if self.stack.contains(&name) {
+            return Err(format!("recursive struct '{}'", name));
+        }
+        self.stack.push(name);
+        let mut fields = Vec::new();
+        let mut shape = Shape { lead: 1, align: 1, len: 0, size: 0 };
+        for (index, member) in members.iter().enumerate() {
+            let error = |reason: String| format!("member '{}': {}", member.id, reason);
+            for annotation in &["optional", "external"] {
+                if has_annotation(&member.annotations, annotation) {
+                    return Err(error(format!("@{}", annotation)));
+                }
+            }
+            let member_shape = self.shape(path, &member.type_spec).map_err(error)?;
+            let start = align_up(shape.len, member_shape.lead);
+            if start % member_shape.align != 0 {
+                return Err(error(format!("at CDR offset {}, not aligned to {} in memory", start, member_shape.align)));
+            }
+            if start < shape.size {
+                return Err(error(format!("at CDR offset {}, within the padding of the preceding member", start)));
+            }
+            if start > shape.size {
+                fields.push(Field::Padding(start - shape.size));
+            }
+            fields.push(Field::Member(member, start, member_shape));
+            if index == 0 {
+                shape.lead = member_shape.lead;
+            }
+            shape.align = shape.align.max(member_shape.align);
+            shape.len = start + member_shape.len;
+            shape.size = start + member_shape.size;
+        }
+        let size = align_up(shape.size, shape.align);
+        if size > shape.size {
+            fields.push(Field::Padding(size - shape.size));
+            shape.size = size;
+        }
+        self.stack.pop();
+        Ok((fields, shape))
+    }
+}
+
+/// The shape of `n` elements, placed in memory as CDR places them
+fn array(elem: Shape, n: usize) -> Result<Shape, String> {
+    let distance = align_up(elem.len, elem.lead);
+    if n > 1 && distance != elem.size {
+        return Err(format!("elements {} bytes apart in CDR, {} in memory", distance, elem.size));
+    }
+    Ok(Shape {
+        lead: elem.lead,
+        align: elem.align,
+        len: n.checked_sub(1).map_or(0, |last| last * elem.size + elem.len),
+        size: n * elem.size,
+    })
+}
+
+/// The shape of a sequence of at most `n` elements, the length being followed by the
+/// padding of elements aligned to 8 bytes
+fn sequence(elem: Shape, n: usize) -> Result<Shape, String> {
+    let offset = align_up(4, elem.align);
+    if align_up(4, elem.lead) != offset {
+        return Err(format!("elements at CDR offset {}, not aligned to {} in memory", align_up(4, elem.lead), elem.align));
+    }
+    let elems = array(elem, n)?;
+    Ok(Shape {
+        lead: 4,
+        align: elem.align.max(4),
+        len: if n == 0 { 4 } else { offset + elems.len },
+        size: offset + elems.size,
+    })
+}
+
+/// The error of a type without fixed layout
+fn error(path: &[String], id: &str, reason: String) -> IdlError {
+    IdlError::ErrorMesg(format!("'{}' has no fixed layout, {}", scoped_name(path, id), reason))
+}
+
+/// The identifier of the padding field
+fn padding_ident(index: usize) -> Ident {
+    ident(&format!("_pad{}", index))
+}
+
+/// Verify the declaration in module `path` has a fixed layout, if configured
+pub(crate) fn check(ctx: &Emitter, path: &[String], type_dcl: &IdlTypeDcl) -> Result<(), IdlError> {
+    let mapped = type_dcl.kind.id().and_then(|id| ctx.type_map.declaration(path, id));
+    if !ctx.config.fixed_layout || mapped.is_some() {
+        return Ok(());
+    }
+    let mut layouter = Layouter { ctx, stack: Vec::new() };
+    match type_dcl.kind {
+        IdlTypeDclKind::TypeDcl(ref id, ref type_spec) =>
+            layouter.shape(path, type_spec).map(drop).map_err(|reason| error(path, id, reason)),
+        IdlTypeDclKind::StructDcl(ref id, _) =>
+            layouter.fields(path, type_dcl).map(drop).map_err(|reason| error(path, id, reason)),
+        IdlTypeDclKind::UnionDcl(ref id, ..) => Err(error(path, id, "being a union".to_owned())),
+        IdlTypeDclKind::NativeDcl(ref id) => Err(error(path, id, "being native".to_owned())),
+        IdlTypeDclKind::EnumDcl(..) | IdlTypeDclKind::None => Ok(()),
+    }
+}
+
+/// The bytes of a sequence aligning its elements of the type to 8 bytes
+pub(crate) fn sequence_padding(ctx: &Emitter, path: &[String], elem: &IdlTypeSpec) -> Result<usize, IdlError> {
+    let mut layouter = Layouter { ctx, stack: Vec::new() };
+    let shape = layouter.shape(path, elem)
+        .map_err(|reason| IdlError::ErrorMesg(format!("sequence of no fixed layout, {}", reason)))?;
+    Ok(align_up(4, shape.align) - 4)
+}
+
+/// The fields of the struct, its members with the padding fields in between if configured
+pub(crate) fn struct_fields(ctx: &Emitter, path: &[String], type_dcl: &IdlTypeDcl, members: Vec<TokenStream>)
+                            -> Result<Vec<TokenStream>, IdlError> {
+    let config = ctx.config;
+    let id = match type_dcl.kind {
+        IdlTypeDclKind::StructDcl(ref id, _) if config.fixed_layout => id,
+        _ => return Ok(members),
+    };
+    let (fields, _) = Layouter { ctx, stack: Vec::new() }.fields(path, type_dcl)
+        .map_err(|reason| error(path, id, reason))?;
+    let skip = if config.serde { quote!(#[serde(skip)]) } else { TokenStream::new() };
+    let mut members = members.into_iter();
+    let mut paddings = 0;
+    Ok(fields.iter()
+        .map(|field| match *field {
+            Field::Member(..) => members.next().expect("token of each member"),
+            Field::Padding(len) => {
+                let padding = padding_ident(paddings);
+                let len = Literal::usize_unsuffixed(len);
+                paddings += 1;
+                quote! {
+                    #skip
+                    pub #padding: rtps_types::Padding<#len>
+                }
+            }
+        })
+        .collect())
+}
+
+/// The padding fields of the struct, if configured
+pub(crate) fn padding_fields(ctx: &Emitter, path: &[String], type_dcl: &IdlTypeDcl) -> Result<Vec<Ident>, IdlError> {
+    let id = match type_dcl.kind {
+        IdlTypeDclKind::StructDcl(ref id, _) if ctx.config.fixed_layout => id,
+        _ => return Ok(Vec::new()),
+    };
+    let (fields, _) = Layouter { ctx, stack: Vec::new() }.fields(path, type_dcl)
+        .map_err(|reason| error(path, id, reason))?;
+    let paddings = fields.iter().filter(|field| matches!(field, Field::Padding(..))).count();
+    Ok((0..paddings).map(padding_ident).collect())
+}
+
+/// The layout assertions and the `FixedLayout` impl of a struct or enum, if configured
+pub(crate) fn layout_tokens(ctx: &Emitter, path: &[String], type_dcl: &IdlTypeDcl) -> Result<TokenStream, IdlError> {
+    let config = ctx.config;
+    if !config.fixed_layout {
+        return Ok(TokenStream::new());
+    }
+    let core = core_crate(config);
+    match type_dcl.kind {
+        IdlTypeDclKind::StructDcl(ref id, _) => {
+            let rust_id = name_ident(id, NameKind::Type, config);
+            let (fields, shape) = Layouter { ctx, stack: Vec::new() }.fields(path, type_dcl)
+                .map_err(|reason| error(path, id, reason))?;
+            let mut asserts = Vec::new();
+            let mut checks = Vec::new();
+            for field in &fields {
+                if let Field::Member(member, offset, member_shape) = *field {
+                    let field = name_ident(&member.id, NameKind::Member, config);
+                    let typ = member_type(ctx, path, &member.type_spec, &member.annotations)?;
+                    let end = Literal::usize_unsuffixed(offset + member_shape.size);
+                    let size = Literal::usize_unsuffixed(member_shape.size);
+                    let offset = Literal::usize_unsuffixed(offset);
+                    asserts.push(quote! {
+                        assert!(#core::mem::offset_of!(#rust_id, #field) == #offset);
+                        rtps_types::assert_size::<#typ>(#size);
+                    });
+                    checks.push(quote!(<#typ as rtps_types::FixedLayout>::is_valid(&bytes[#offset..#end])));
+                }
+            }
+            let size = Literal::usize_unsuffixed(shape.size);
+            let is_valid = match checks.into_iter().reduce(|checks, check| quote!(#checks && #check)) {
+                Some(checks) => quote! {
+                    fn is_valid(bytes: &[u8]) -> bool {
+                        #checks
+                    }
+                },
+                None => quote! {
+                    fn is_valid(_bytes: &[u8]) -> bool {
+                        true
+                    }
+                },
+            };
+            Ok(quote! {
+                const _: () = {
+                    assert!(cfg!(target_endian = "little"), "fixed layouts are little endian");
+                    rtps_types::assert_size::<#rust_id>(#size);
+                    #(#asserts)*
+                };
+                unsafe impl rtps_types::FixedLayout for #rust_id {
+                    #is_valid
+                }
+            })
+        }
+        IdlTypeDclKind::EnumDcl(ref id, _) => {
+            let rust_id = name_ident(id, NameKind::Type, config);
+            let len = enums::encoded_len(ctx, path, id, &type_dcl.annotations)?;
+            let repr = ident(&format!("u{}", len * 8));
+            Ok(quote! {
+                unsafe impl rtps_types::FixedLayout for #rust_id {
+                    fn is_valid(bytes: &[u8]) -> bool {
+                        match <#repr as rtps_types::FixedLayout>::read_bytes(bytes) {
+                            Ok(value) => <#rust_id as #core::convert::TryFrom<#repr>>::try_from(value).is_ok(),
+                            Err(_) => false,
+                        }
+                    }
+                }
+            })
+        }
+        _ => Ok(TokenStream::new()),
+    }
+}
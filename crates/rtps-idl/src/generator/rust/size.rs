@@ -0,0 +1,361 @@
+// Copyright (C) 2019  Frank Rehberger
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>
+
+//! Bounds of the encoded size
+//!
+//! With `Configuration::cdr` structs, unions and enums implement `rtps_cdr::SerializedSize`.
+//! The bounds are computed by encoding the declaration as the serializer does, once with
+//! each member at its largest and once at its smallest: the padding of a primitive never
+//! lets a later start end earlier, so both bound the sizes in between. Strings and
+//! sequences are unbounded unless bounded and `Configuration::bounded` holds; mapped and
+//! native types, and recursive ones, are unbounded as well.
+use std::convert::TryFrom;
+use proc_macro2::{Literal, TokenStream};
+use quote::quote;
+use crate::ast::*;
+use crate::IdlError;
+use super::{Emitter, NameKind, const_value, in_place, name_ident, wire_chars};
+use super::derives::scoped_name;
+use super::enums;
+use super::xtypes::{self, Extensibility, is_delimited, struct_member_ids, union_member_ids};
+
+/// Largest member ID of a short parameter header
+const PID_MAX: u32 = 0x3eff;
+/// Longest value of a short parameter header
+const PARAMETER_MAX: usize = 0xffff;
+
+/// Return the end if bounded, `Ok(None)` otherwise
+macro_rules! bounded {
+    ($end:expr) => {
+        match $end? {
+            Some(end) => end,
+            None => return Ok(None),
+        }
+    };
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Encoding {
+    Xcdr1,
+    Xcdr2,
+}
+
+/// Whether members are at their largest or smallest
+#[derive(Clone, Copy, PartialEq)]
+enum Extent {
+    Max,
+    Min,
+}
+
+/// Encodes the declarations at the extent, returning the position following each value,
+/// `None` if unbounded
+struct Sizer<'a, 's> {
+    ctx: &'a Emitter<'s>,
+    /// The scoped name of the type whose size is computed
+    name: String,
+    encoding: Encoding,
+    extent: Extent,
+    /// The structs and unions being encoded, detecting recursion
+    enclosing: Vec<(Vec<String>, String)>,
+}
+
+impl<'a, 's> Sizer<'a, 's> {
+    fn new(ctx: &'a Emitter<'s>, name: String, encoding: Encoding, extent: Extent) -> Sizer<'a, 's> {
+        Sizer { ctx, name, encoding, extent, enclosing: Vec::new() }
+    }
+
+    fn overflow(&self) -> IdlError {
+        IdlError::ErrorMesg(format!("serialized size of '{}' exceeds usize", self.name))
+    }
+
+    /// The end of a value of unknown size
+    fn unknown(&self, pos: usize) -> Result<Option<usize>, IdlError> {
+        match self.extent {
+            Extent::Max => Ok(None),
+            Extent::Min => Ok(Some(pos)),
+        }
+    }
+
+    /// The end of a primitive of `size` bytes, aligned up to the maximum alignment
+    fn primitive(&self, pos: usize, size: usize) -> usize {
+        let max_align = match self.encoding {
+            Encoding::Xcdr1 => 8,
+            Encoding::Xcdr2 => 4,
+        };
+        let align = size.min(max_align);
+        pos.div_ceil(align) * align + size
+    }
+
+    /// The end of the DHEADER of XCDR2
+    fn dheader(&self, pos: usize) -> usize {
+        match self.encoding {
+            Encoding::Xcdr1 => pos,
+            Encoding::Xcdr2 => self.primitive(pos, 4),
+        }
+    }
+
+    /// The bound of a string or sequence, `None` if unbounded
+    fn bound(&self, path: &[String], bound: &Option<Box<IdlValueExpr>>) -> Result<Option<usize>, IdlError> {
+        match bound {
+            Some(ref bound) if self.ctx.config.bounded || in_place(self.ctx.config) => const_value(self.ctx.spec, path, bound)
+                .and_then(|bound| usize::try_from(bound).ok())
+                .map(Some)
+                .ok_or(IdlError::InternalError),
+            _ => Ok(None),
+        }
+    }
+
+    fn type_end(&mut self, path: &[String], type_spec: &IdlTypeSpec, pos: usize) -> Result<Option<usize>, IdlError> {
+        if self.ctx.type_map.type_spec(path, type_spec).is_some() {
+            return self.unknown(pos);
+        }
+        let wire = wire_chars(self.ctx.config);
+        let size = match type_spec {
+            IdlTypeSpec::BooleanType | IdlTypeSpec::OctetType | IdlTypeSpec::CharType => 1,
+            IdlTypeSpec::WideCharType if wire => 2,
+            IdlTypeSpec::WideCharType => 1,
+            IdlTypeSpec::I16Type | IdlTypeSpec::U16Type => 2,
+            IdlTypeSpec::I32Type | IdlTypeSpec::U32Type | IdlTypeSpec::F32Type => 4,
+            IdlTypeSpec::I64Type | IdlTypeSpec::U64Type | IdlTypeSpec::F64Type => 8,
+            IdlTypeSpec::F128Type => 16,
+            // the length, the UTF-16 code units of a wire `wstring`, the octets and NUL otherwise,
+            // a `wstring` of Rust characters taking up to four octets per character
+            IdlTypeSpec::WideStringType(ref bound) if wire => {
+                let pos = self.primitive(pos, 4);
+                return match (self.extent, self.bound(path, bound)?) {
+                    (Extent::Min, _) => Ok(Some(pos)),
+                    (Extent::Max, Some(bound)) => bound.checked_mul(2).and_then(|len| pos.checked_add(len))
+                        .map(Some)
+                        .ok_or_else(|| self.overflow()),
+                    (Extent::Max, None) => Ok(None),
+                };
+            }
+            IdlTypeSpec::StringType(ref bound) | IdlTypeSpec::WideStringType(ref bound) => {
+                let pos = self.primitive(pos, 4);
+                let width = if matches!(type_spec, IdlTypeSpec::WideStringType(_)) { 4 } else { 1 };
+                return match (self.extent, self.bound(path, bound)?) {
+                    (Extent::Min, _) => Ok(Some(pos + 1)),
+                    (Extent::Max, Some(bound)) => bound.checked_mul(width).and_then(|len| len.checked_add(pos + 1))
+                        .map(Some)
+                        .ok_or_else(|| self.overflow()),
+                    (Extent::Max, None) => Ok(None),
+                };
+            }
+            IdlTypeSpec::SequenceType(ref elem, ref bound) => {
+                let pos = self.primitive(pos, 4);
+                return match (self.extent, self.bound(path, bound)?) {
+                    (Extent::Min, _) => Ok(Some(pos)),
+                    (Extent::Max, Some(bound)) => self.repeat(path, elem, bound, pos),
+                    (Extent::Max, None) => Ok(None),
+                };
+            }
+            IdlTypeSpec::ArrayType(ref elem, ref dims) => {
+                let len = dims.iter()
+                    .try_fold(1usize, |len, dim| const_value(self.ctx.spec, path, dim)
+                        .and_then(|dim| usize::try_from(dim).ok())
+                        .and_then(|dim| len.checked_mul(dim)))
+                    .ok_or_else(|| self.overflow())?;
+                return self.repeat(path, elem, len, pos);
+            }
+            IdlTypeSpec::ScopedName(ref name) => {
+                let (dcl_path, type_dcl) = self.ctx.spec.resolve_type(path, name)
+                    .ok_or_else(|| IdlError::ErrorMesg(format!("unknown type '{}'", name.0.join("::"))))?;
+                let mapped = type_dcl.kind.id()
+                    .is_some_and(|id| self.ctx.type_map.declaration(&dcl_path, id).is_some());
+                return match type_dcl.kind {
+                    _ if mapped => self.unknown(pos),
+                    IdlTypeDclKind::TypeDcl(_, ref aliased) => self.type_end(&dcl_path, aliased, pos),
+                    IdlTypeDclKind::StructDcl(..) | IdlTypeDclKind::UnionDcl(..) =>
+                        self.declaration_end(&dcl_path, type_dcl, pos),
+                    IdlTypeDclKind::EnumDcl(ref id, _) => {
+                        let size = enums::encoded_len(self.ctx, &dcl_path, id, &type_dcl.annotations)?;
+                        Ok(Some(self.primitive(pos, size)))
+                    }
+                    IdlTypeDclKind::NativeDcl(_) | IdlTypeDclKind::None => self.unknown(pos),
+                };
+            }
+            IdlTypeSpec::None => return Err(IdlError::InternalError),
+        };
+        Ok(Some(self.primitive(pos, size)))
+    }
+
+    /// The end of `len` elements; their ends repeat once an element starts at an alignment
+    /// seen before, so these are computed up to that and extrapolated
+    fn repeat(&mut self, path: &[String], elem: &IdlTypeSpec, len: usize, pos: usize)
+              -> Result<Option<usize>, IdlError> {
+        let mut starts: Vec<(usize, usize)> = Vec::new();
+        let mut pos = pos;
+        let mut index = 0;
+        while index < len {
+            if let Some(&(first, start)) = starts.iter().find(|&&(_, start)| start % 8 == pos % 8) {
+                let cycles = (len - index) / (index - first);
+                pos = (pos - start).checked_mul(cycles)
+                    .and_then(|grown| pos.checked_add(grown))
+                    .ok_or_else(|| self.overflow())?;
+                index += cycles * (index - first);
+                starts.clear();
+                if index == len {
+                    break;
+                }
+            }
+            starts.push((index, pos));
+            pos = bounded!(self.type_end(path, elem, pos));
+            index += 1;
+        }
+        Ok(Some(pos))
+    }
+
+    /// The end of a struct or union
+    fn declaration_end(&mut self, path: &[String], type_dcl: &IdlTypeDcl, pos: usize)
+                       -> Result<Option<usize>, IdlError> {
+        let id = type_dcl.kind.id().unwrap_or_default().to_owned();
+        if self.enclosing.iter().any(|(dcl_path, dcl_id)| dcl_path.as_slice() == path && dcl_id == &id) {
+            return self.unknown(pos);
+        }
+        self.enclosing.push((path.to_vec(), id));
+        let end = self.members_end(path, type_dcl, pos);
+        self.enclosing.pop();
+        end
+    }
+
+    fn members_end(&mut self, path: &[String], type_dcl: &IdlTypeDcl, pos: usize)
+                   -> Result<Option<usize>, IdlError> {
+        let extensibility = xtypes::extensibility(path, type_dcl)?;
+        let delimited = extensibility != Extensibility::Final;
+        let mut pos = if delimited { self.dheader(pos) } else { pos };
+        match type_dcl.kind {
+            IdlTypeDclKind::StructDcl(ref id, ref members) if extensibility == Extensibility::Mutable => {
+                let ids = struct_member_ids(self.ctx, path, type_dcl, id, members)?;
+                for (member, member_id) in members.iter().zip(ids) {
+                    let optional = has_annotation(&member.annotations, "optional");
+                    if !(optional && self.extent == Extent::Min) {
+                        pos = bounded!(self.parameter_end(path, member_id, &member.type_spec, pos));
+                    }
+                }
+                Ok(Some(self.list_end(pos)))
+            }
+            IdlTypeDclKind::StructDcl(ref id, ref members) => {
+                let ids = struct_member_ids(self.ctx, path, type_dcl, id, members)?;
+                for (member, member_id) in members.iter().zip(ids) {
+                    pos = bounded!(self.member_end(path, member_id, &member.type_spec, &member.annotations, pos));
+                }
+                Ok(Some(pos))
+            }
+            IdlTypeDclKind::UnionDcl(ref id, ref switch_type, ref cases) if extensibility == Extensibility::Mutable => {
+                let ids = union_member_ids(self.ctx, path, type_dcl, id, cases)?;
+                pos = bounded!(self.parameter_end(path, 0, switch_type, pos));
+                let mut ends = Vec::new();
+                for (case, member_id) in cases.iter().zip(ids) {
+                    ends.push(self.parameter_end(path, member_id, &case.elem_spec.type_spec, pos)?);
+                }
+                Ok(self.extreme(ends, pos)?.map(|end| self.list_end(end)))
+            }
+            IdlTypeDclKind::UnionDcl(ref id, ref switch_type, ref cases) => {
+                let ids = union_member_ids(self.ctx, path, type_dcl, id, cases)?;
+                pos = bounded!(self.type_end(path, switch_type, pos));
+                let mut ends = Vec::new();
+                for (case, member_id) in cases.iter().zip(ids) {
+                    let elem = &case.elem_spec;
+                    ends.push(self.member_end(path, member_id, &elem.type_spec, &elem.annotations, pos)?);
+                }
+                self.extreme(ends, pos)
+            }
+            _ => Err(IdlError::InternalError),
+        }
+    }
+
+    /// The end of the members of a mutable type, the parameter list ending with PID_LIST_END
+    fn list_end(&self, pos: usize) -> usize {
+        match self.encoding {
+            Encoding::Xcdr1 => self.primitive(pos, 4),
+            Encoding::Xcdr2 => pos,
+        }
+    }
+
+    /// The largest or smallest end of the union elements
+    fn extreme(&self, ends: Vec<Option<usize>>, pos: usize) -> Result<Option<usize>, IdlError> {
+        let ends = ends.into_iter().collect::<Option<Vec<_>>>();
+        match self.extent {
+            Extent::Max => Ok(ends.map(|ends| ends.into_iter().max().unwrap_or(pos))),
+            Extent::Min => Ok(ends.map(|ends| ends.into_iter().min().unwrap_or(pos))),
+        }
+    }
+
+    /// The end of a member of a final or appendable type, an `@optional` one preceded by
+    /// its parameter header in XCDR1, of length 0 if absent, and by its presence in XCDR2
+    fn member_end(&mut self, path: &[String], member_id: u32, type_spec: &IdlTypeSpec, annotations: &[IdlAnnotation],
+                  pos: usize) -> Result<Option<usize>, IdlError> {
+        if !has_annotation(annotations, "optional") {
+            return self.value_end(path, type_spec, pos);
+        }
+        match (self.encoding, self.extent) {
+            (Encoding::Xcdr1, Extent::Min) => Ok(Some(self.primitive(pos, 4))),
+            (Encoding::Xcdr1, Extent::Max) => self.parameter_end(path, member_id, type_spec, pos),
+            (Encoding::Xcdr2, Extent::Min) => Ok(Some(pos + 1)),
+            (Encoding::Xcdr2, Extent::Max) => self.value_end(path, type_spec, pos + 1),
+        }
+    }
+
+    /// The end of a value, a collection of non-primitive elements being delimited
+    fn value_end(&mut self, path: &[String], type_spec: &IdlTypeSpec, pos: usize) -> Result<Option<usize>, IdlError> {
+        let pos = if is_delimited(self.ctx, path, type_spec) { self.dheader(pos) } else { pos };
+        self.type_end(path, type_spec, pos)
+    }
+
+    /// The end of a member of a mutable type, preceded by its EMHEADER and length in XCDR2,
+    /// and by its parameter header in XCDR1, as optional members of other types are, the
+    /// value aligned relative to its start and padded to a multiple of 4 bytes
+    fn parameter_end(&mut self, path: &[String], member_id: u32, type_spec: &IdlTypeSpec, pos: usize)
+                     -> Result<Option<usize>, IdlError> {
+        let pos = (pos + 3) & !3;
+        match self.encoding {
+            Encoding::Xcdr2 => self.value_end(path, type_spec, pos + 8),
+            Encoding::Xcdr1 => {
+                let len = bounded!(self.value_end(path, type_spec, 0));
+                let len = (len + 3) & !3;
+                let header = if member_id > PID_MAX || len > PARAMETER_MAX { 12 } else { 4 };
+                Ok(Some(pos + header + len))
+            }
+        }
+    }
+}
+
+/// The impl of `rtps_cdr::SerializedSize` of a struct, union or enum
+pub(crate) fn size_tokens(ctx: &Emitter, path: &[String], type_dcl: &IdlTypeDcl) -> Result<TokenStream, IdlError> {
+    let config = ctx.config;
+    let id = match type_dcl.kind {
+        IdlTypeDclKind::StructDcl(ref id, _) | IdlTypeDclKind::UnionDcl(ref id, _, _)
+        | IdlTypeDclKind::EnumDcl(ref id, _) => id,
+        _ => return Ok(TokenStream::new()),
+    };
+    if !config.cdr {
+        return Ok(TokenStream::new());
+    }
+    let type_spec = IdlTypeSpec::ScopedName(IdlScopedName(vec![id.clone()], false));
+    let mut sizes = Vec::new();
+    for &encoding in &[Encoding::Xcdr1, Encoding::Xcdr2] {
+        for &extent in &[Extent::Max, Extent::Min] {
+            let size = Sizer::new(ctx, scoped_name(path, id), encoding, extent).type_end(path, &type_spec, 0)?;
+            sizes.push(size.map(Literal::usize_unsuffixed));
+        }
+    }
+    let (max1, max2) = (&sizes[0], &sizes[2]);
+    let min1 = sizes[1].as_ref().ok_or(IdlError::InternalError)?;
+    let min2 = sizes[3].as_ref().ok_or(IdlError::InternalError)?;
+    let max = |size: &Option<Literal>| match size {
+        Some(size) => quote!(Some(#size)),
+        None => quote!(None),
+    };
+    let (max1, max2) = (max(max1), max(max2));
+    let rust_id = name_ident(id, NameKind::Type, config);
+    Ok(quote! {
+        impl rtps_cdr::SerializedSize for #rust_id {
+            const MAX_SIZE_XCDR1: Option<usize> = #max1;
+            const MIN_SIZE_XCDR1: usize = #min1;
+            const MAX_SIZE_XCDR2: Option<usize> = #max2;
+            const MIN_SIZE_XCDR2: usize = #min2;
+        }
+    })
+}
@@ -0,0 +1,93 @@
+// Copyright (C) 2019  Frank Rehberger
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>
+
+//! Custom Rust types replacing IDL types, see `Configuration::type_map`
+//!
+//! The keys are normalized to their IDL spelling, such as `sequence<octet, 16>`,
+//! scoped names being resolved to absolute names without leading `::`.
+use std::collections::HashMap;
+use proc_macro2::TokenStream;
+use crate::ast::*;
+use crate::{Configuration, IdlError, parse_type_spec};
+use super::derives::scoped_name;
+use super::const_value;
+
+/// The Rust types, keyed by the normalized IDL type
+pub(crate) struct TypeMap<'a> {
+    spec: &'a Specification,
+    types: HashMap<String, TokenStream>,
+}
+
+impl<'a> TypeMap<'a> {
+    /// Normalize the configured mapping, verifying that the IDL types exist and
+    /// the Rust types are valid
+    pub(crate) fn new(config: &Configuration, spec: &'a Specification) -> Result<TypeMap<'a>, IdlError> {
+        let mut types = HashMap::new();
+        for (idl_type, rust_type) in &config.type_map {
+            let type_spec = parse_type_spec(config, idl_type)?;
+            let key = type_key(spec, &[], &type_spec, true)
+                .ok_or_else(|| IdlError::ErrorMesg(
+                    format!("type mapping: '{}' does not name a type", idl_type)))?;
+            let rust_type = syn::parse_str::<syn::Type>(rust_type)
+                .map_err(|e| IdlError::ErrorMesg(
+                    format!("type mapping: '{}' is not a Rust type: {}", rust_type, e)))?;
+            types.insert(key, quote::quote!(#rust_type));
+        }
+        Ok(TypeMap { spec, types })
+    }
+
+    /// The Rust type replacing the type declared in the module `path`
+    pub(crate) fn declaration(&self, path: &[String], id: &str) -> Option<&TokenStream> {
+        self.types.get(&scoped_name(path, id))
+    }
+
+    /// The Rust type replacing the type specification, as seen from within the module `path`;
+    /// a bounded string or sequence falls back onto the mapping of the unbounded type
+    pub(crate) fn type_spec(&self, path: &[String], type_spec: &IdlTypeSpec) -> Option<&TokenStream> {
+        if self.types.is_empty() {
+            return None;
+        }
+        type_key(self.spec, path, type_spec, true)
+            .and_then(|key| self.types.get(&key))
+            .or_else(|| type_key(self.spec, path, type_spec, false)
+                .and_then(|key| self.types.get(&key)))
+    }
+}
+
+/// IDL spelling of the type; the outermost bound is omitted unless `bounded`
+fn type_key(spec: &Specification, path: &[String], type_spec: &IdlTypeSpec, bounded: bool) -> Option<String> {
+    let with_bound = |name: &str, bound: &Option<Box<IdlValueExpr>>| match bound {
+        Some(ref bound) if bounded => const_value(spec, path, bound).map(|bound| format!("{}<{}>", name, bound)),
+        _ => Some(name.to_owned()),
+    };
+    match type_spec {
+        IdlTypeSpec::None | IdlTypeSpec::ArrayType(..) => None,
+        IdlTypeSpec::F32Type => Some("float".to_owned()),
+        IdlTypeSpec::F64Type => Some("double".to_owned()),
+        IdlTypeSpec::F128Type => Some("long double".to_owned()),
+        IdlTypeSpec::I16Type => Some("short".to_owned()),
+        IdlTypeSpec::I32Type => Some("long".to_owned()),
+        IdlTypeSpec::I64Type => Some("long long".to_owned()),
+        IdlTypeSpec::U16Type => Some("unsigned short".to_owned()),
+        IdlTypeSpec::U32Type => Some("unsigned long".to_owned()),
+        IdlTypeSpec::U64Type => Some("unsigned long long".to_owned()),
+        IdlTypeSpec::CharType => Some("char".to_owned()),
+        IdlTypeSpec::WideCharType => Some("wchar".to_owned()),
+        IdlTypeSpec::BooleanType => Some("boolean".to_owned()),
+        IdlTypeSpec::OctetType => Some("octet".to_owned()),
+        IdlTypeSpec::StringType(ref bound) => with_bound("string", bound),
+        IdlTypeSpec::WideStringType(ref bound) => with_bound("wstring", bound),
+        IdlTypeSpec::SequenceType(ref elem, ref bound) => {
+            let elem = type_key(spec, path, elem, true)?;
+            match bound {
+                Some(ref bound) if bounded =>
+                    const_value(spec, path, bound).map(|bound| format!("sequence<{}, {}>", elem, bound)),
+                _ => Some(format!("sequence<{}>", elem)),
+            }
+        }
+        IdlTypeSpec::ScopedName(ref name) => spec.resolve_type(path, name)
+            .map(|(dcl_path, type_dcl)| scoped_name(&dcl_path, type_dcl.kind.id().unwrap_or(""))),
+    }
+}
@@ -0,0 +1,536 @@
+// Copyright (C) 2019  Frank Rehberger
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>
+
+//! TypeObjects of DDS-XTypes, announced by the type discovery
+//!
+//! With `Configuration::type_objects` structs, unions and enums implement
+//! `rtps_types::Discoverable`, holding their minimal and complete TypeObject as computed by
+//! the generator: serialized in XCDR2 little endian and identified by the first 14 bytes of
+//! the MD5 hash of the serialization. Typedefs are described by TypeObjects as well, and
+//! the dependencies of a type list the TypeObjects of all types it refers to, directly or
+//! indirectly. Members refer to other types by the hash of the same equivalence kind, and
+//! minimal TypeObjects identify members by the first 4 bytes of the MD5 hash of their name.
+//! `Configuration::type_map` is disregarded, as it does not alter the IDL type. Native and
+//! recursive types have no TypeObject.
+use std::convert::TryFrom;
+use proc_macro2::{Literal, TokenStream};
+use quote::quote;
+use crate::ast::*;
+use crate::IdlError;
+use super::{Emitter, NameKind, const_value, name_ident, unions};
+use super::derives::scoped_name;
+use super::enums;
+use super::layout::reason;
+use super::xtypes::{self, Extensibility};
+
+/// Equivalence kinds
+const EK_MINIMAL: u8 = 0xf1;
+const EK_COMPLETE: u8 = 0xf2;
+const EK_BOTH: u8 = 0xf3;
+
+/// Type kinds, identifying the primitive types as well
+const TK_NONE: u8 = 0x00;
+const TK_BOOLEAN: u8 = 0x01;
+const TK_BYTE: u8 = 0x02;
+const TK_INT16: u8 = 0x03;
+const TK_INT32: u8 = 0x04;
+const TK_INT64: u8 = 0x05;
+const TK_UINT16: u8 = 0x06;
+const TK_UINT32: u8 = 0x07;
+const TK_UINT64: u8 = 0x08;
+const TK_FLOAT32: u8 = 0x09;
+const TK_FLOAT64: u8 = 0x0a;
+const TK_FLOAT128: u8 = 0x0b;
+const TK_CHAR8: u8 = 0x10;
+const TK_CHAR16: u8 = 0x11;
+const TK_ALIAS: u8 = 0x30;
+const TK_ENUM: u8 = 0x40;
+const TK_STRUCTURE: u8 = 0x51;
+const TK_UNION: u8 = 0x52;
+
+/// TypeIdentifiers of strings and collections, small ones holding bounds below 256
+const TI_STRING8_SMALL: u8 = 0x70;
+const TI_STRING8_LARGE: u8 = 0x71;
+const TI_STRING16_SMALL: u8 = 0x72;
+const TI_STRING16_LARGE: u8 = 0x73;
+const TI_PLAIN_SEQUENCE_SMALL: u8 = 0x80;
+const TI_PLAIN_SEQUENCE_LARGE: u8 = 0x81;
+const TI_PLAIN_ARRAY_SMALL: u8 = 0x90;
+const TI_PLAIN_ARRAY_LARGE: u8 = 0x91;
+
+/// Member flags
+const TRY_CONSTRUCT1: u16 = 0x0001;
+const IS_EXTERNAL: u16 = 0x0004;
+const IS_OPTIONAL: u16 = 0x0008;
+const IS_MUST_UNDERSTAND: u16 = 0x0010;
+const IS_KEY: u16 = 0x0020;
+const IS_DEFAULT: u16 = 0x0040;
+
+/// Type flags of structs and unions
+const IS_FINAL: u16 = 0x0001;
+const IS_APPENDABLE: u16 = 0x0002;
+const IS_MUTABLE: u16 = 0x0004;
+const IS_NESTED: u16 = 0x0008;
+const IS_AUTOID_HASH: u16 = 0x0010;
+
+/// XCDR2 in little endian, aligned relative to the first byte
+#[derive(Default)]
+struct Writer {
+    bytes: Vec<u8>,
+}
+
+impl Writer {
+    fn align(&mut self, size: usize) {
+        let align = size.min(4);
+        let len = self.bytes.len().div_ceil(align) * align;
+        self.bytes.resize(len, 0);
+    }
+
+    fn octet(&mut self, value: u8) {
+        self.bytes.push(value);
+    }
+
+    fn ushort(&mut self, value: u16) {
+        self.align(2);
+        self.bytes.extend_from_slice(&value.to_le_bytes());
+    }
+
+    fn ulong(&mut self, value: u32) {
+        self.align(4);
+        self.bytes.extend_from_slice(&value.to_le_bytes());
+    }
+
+    fn long(&mut self, value: i32) {
+        self.align(4);
+        self.bytes.extend_from_slice(&value.to_le_bytes());
+    }
+
+    /// The length including the terminating NUL, followed by the bytes and NUL
+    fn string(&mut self, value: &str) {
+        self.ulong(value.len() as u32 + 1);
+        self.bytes.extend_from_slice(value.as_bytes());
+        self.bytes.push(0);
+    }
+
+    /// Reserve the DHEADER of an appendable type or a sequence of non-primitive
+    /// elements, returning its offset
+    fn begin(&mut self) -> usize {
+        self.align(4);
+        let at = self.bytes.len();
+        self.bytes.extend_from_slice(&[0; 4]);
+        at
+    }
+
+    /// Set the DHEADER reserved at `at` to the number of bytes following it
+    fn end(&mut self, at: usize) {
+        let len = (self.bytes.len() - at - 4) as u32;
+        self.bytes[at..at + 4].copy_from_slice(&len.to_le_bytes());
+    }
+}
+
+/// A serialized TypeObject and its equivalence hash
+struct Object {
+    name: String,
+    bytes: Vec<u8>,
+    hash: [u8; 14],
+}
+
+/// The first 4 bytes of the MD5 hash of the member name
+fn name_hash(name: &str) -> [u8; 4] {
+    let digest = md5::compute(name.as_bytes());
+    [digest[0], digest[1], digest[2], digest[3]]
+}
+
+/// Whether the TypeIdentifier describes the type in full, not referring to a hash
+fn is_fully_descriptive(type_spec: &IdlTypeSpec) -> bool {
+    match type_spec {
+        IdlTypeSpec::SequenceType(ref elem, _) | IdlTypeSpec::ArrayType(ref elem, _) => is_fully_descriptive(elem),
+        IdlTypeSpec::ScopedName(_) | IdlTypeSpec::None => false,
+        _ => true,
+    }
+}
+
+/// The flags of a struct member; a key must be understood
+fn member_flags(annotations: &[IdlAnnotation]) -> u16 {
+    let mut flags = TRY_CONSTRUCT1;
+    if has_annotation(annotations, "key") {
+        flags |= IS_KEY | IS_MUST_UNDERSTAND;
+    }
+    if has_annotation(annotations, "must_understand") {
+        flags |= IS_MUST_UNDERSTAND;
+    }
+    if has_annotation(annotations, "optional") {
+        flags |= IS_OPTIONAL;
+    }
+    if has_annotation(annotations, "external") {
+        flags |= IS_EXTERNAL;
+    }
+    flags
+}
+
+/// The flags of a struct or union, its extensibility, `@nested` and `@autoid(HASH)`
+fn type_flags(path: &[String], type_dcl: &IdlTypeDcl) -> Result<u16, String> {
+    let mut flags = match xtypes::extensibility(path, type_dcl).map_err(reason)? {
+        Extensibility::Final => IS_FINAL,
+        Extensibility::Appendable => IS_APPENDABLE,
+        Extensibility::Mutable => IS_MUTABLE,
+    };
+    if has_annotation(&type_dcl.annotations, "nested") {
+        flags |= IS_NESTED;
+    }
+    let autoid = find_annotation(&type_dcl.annotations, "autoid").and_then(|autoid| autoid.value());
+    if let Some(IdlValueExpr::ScopedName(ref name)) = autoid {
+        if name.0.last().map(String::as_str) == Some("HASH") {
+            flags |= IS_AUTOID_HASH;
+        }
+    }
+    Ok(flags)
+}
+
+/// Computes the TypeObjects of one equivalence kind, detecting recursion
+struct Describer<'a, 's> {
+    ctx: &'a Emitter<'s>,
+    complete: bool,
+    /// The types being described
+    stack: Vec<String>,
+    /// The TypeObjects described, each following those of its dependencies
+    objects: Vec<Object>,
+}
+
+impl<'a, 's> Describer<'a, 's> {
+    fn equivalence_kind(&self) -> u8 {
+        if self.complete { EK_COMPLETE } else { EK_MINIMAL }
+    }
+
+    /// The hash of the TypeObject of the declaration in module `path`, describing it
+    /// unless done before
+    fn hash(&mut self, path: &[String], type_dcl: &IdlTypeDcl) -> Result<[u8; 14], String> {
+        let name = scoped_name(path, type_dcl.kind.id().unwrap_or_default());
+        if let Some(object) = self.objects.iter().find(|object| object.name == name) {
+            return Ok(object.hash);
+        }
+        if self.stack.contains(&name) {
+            return Err(format!("recursive type '{}'", name));
+        }
+        self.stack.push(name.clone());
+        let bytes = self.type_object(path, type_dcl)?;
+        self.stack.pop();
+        let digest = md5::compute(&bytes);
+        let mut hash = [0; 14];
+        hash.copy_from_slice(&digest[..14]);
+        self.objects.push(Object { name, bytes, hash });
+        Ok(hash)
+    }
+
+    /// The TypeObject, an appendable union of the equivalence kind holding a final union
+    /// of the type kind
+    fn type_object(&mut self, path: &[String], type_dcl: &IdlTypeDcl) -> Result<Vec<u8>, String> {
+        let mut w = Writer::default();
+        let at = w.begin();
+        w.octet(self.equivalence_kind());
+        match type_dcl.kind {
+            IdlTypeDclKind::TypeDcl(ref id, ref type_spec) => {
+                w.octet(TK_ALIAS);
+                self.alias(&mut w, path, id, type_spec)?;
+            }
+            IdlTypeDclKind::StructDcl(ref id, ref members) => {
+                w.octet(TK_STRUCTURE);
+                self.structure(&mut w, path, type_dcl, id, members)?;
+            }
+            IdlTypeDclKind::UnionDcl(ref id, ref switch_type, ref cases) => {
+                w.octet(TK_UNION);
+                self.union(&mut w, path, type_dcl, id, switch_type, cases)?;
+            }
+            IdlTypeDclKind::EnumDcl(ref id, ref enums) => {
+                w.octet(TK_ENUM);
+                self.enumeration(&mut w, path, type_dcl, id, enums)?;
+            }
+            IdlTypeDclKind::NativeDcl(ref id) => return Err(format!("native type '{}'", scoped_name(path, id))),
+            IdlTypeDclKind::None => return Err("no type".to_owned()),
+        }
+        w.end(at);
+        Ok(w.bytes)
+    }
+
+    /// The TypeIdentifier of the type within module `path`, primitive types, strings and
+    /// collections being described in place, others by the hash of their TypeObject
+    fn type_identifier(&mut self, w: &mut Writer, path: &[String], type_spec: &IdlTypeSpec) -> Result<(), String> {
+        let ctx = self.ctx;
+        let bound = |bound: &IdlValueExpr| const_value(ctx.spec, path, bound)
+            .and_then(|bound| u32::try_from(bound).ok())
+            .ok_or_else(|| format!("bound {:?} is no non-negative integer constant", bound));
+        let kind = match type_spec {
+            IdlTypeSpec::BooleanType => TK_BOOLEAN,
+            IdlTypeSpec::OctetType => TK_BYTE,
+            IdlTypeSpec::I16Type => TK_INT16,
+            IdlTypeSpec::I32Type => TK_INT32,
+            IdlTypeSpec::I64Type => TK_INT64,
+            IdlTypeSpec::U16Type => TK_UINT16,
+            IdlTypeSpec::U32Type => TK_UINT32,
+            IdlTypeSpec::U64Type => TK_UINT64,
+            IdlTypeSpec::F32Type => TK_FLOAT32,
+            IdlTypeSpec::F64Type => TK_FLOAT64,
+            IdlTypeSpec::F128Type => TK_FLOAT128,
+            IdlTypeSpec::CharType => TK_CHAR8,
+            IdlTypeSpec::WideCharType => TK_CHAR16,
+            IdlTypeSpec::StringType(ref n) | IdlTypeSpec::WideStringType(ref n) => {
+                let wide = matches!(type_spec, IdlTypeSpec::WideStringType(_));
+                // unbounded strings are of bound 0
+                let n = n.as_ref().map_or(Ok(0), |n| bound(n))?;
+                match (u8::try_from(n), wide) {
+                    (Ok(n), false) => w.bytes.extend_from_slice(&[TI_STRING8_SMALL, n]),
+                    (Ok(n), true) => w.bytes.extend_from_slice(&[TI_STRING16_SMALL, n]),
+                    (Err(_), false) => {
+                        w.octet(TI_STRING8_LARGE);
+                        w.ulong(n);
+                    }
+                    (Err(_), true) => {
+                        w.octet(TI_STRING16_LARGE);
+                        w.ulong(n);
+                    }
+                }
+                return Ok(());
+            }
+            IdlTypeSpec::SequenceType(ref elem, ref n) => {
+                let n = n.as_ref().map_or(Ok(0), |n| bound(n))?;
+                let small = u8::try_from(n);
+                w.octet(if small.is_ok() { TI_PLAIN_SEQUENCE_SMALL } else { TI_PLAIN_SEQUENCE_LARGE });
+                self.collection_header(w, elem);
+                match small {
+                    Ok(n) => w.octet(n),
+                    Err(_) => w.ulong(n),
+                }
+                return self.type_identifier(w, path, elem);
+            }
+            IdlTypeSpec::ArrayType(ref elem, ref dims) => {
+                let dims = dims.iter().map(|dim| bound(dim)).collect::<Result<Vec<_>, _>>()?;
+                let small = dims.iter().all(|&dim| dim < 256);
+                w.octet(if small { TI_PLAIN_ARRAY_SMALL } else { TI_PLAIN_ARRAY_LARGE });
+                self.collection_header(w, elem);
+                w.ulong(dims.len() as u32);
+                for dim in dims {
+                    if small {
+                        w.octet(dim as u8);
+                    } else {
+                        w.ulong(dim);
+                    }
+                }
+                return self.type_identifier(w, path, elem);
+            }
+            IdlTypeSpec::ScopedName(ref name) => {
+                let (dcl_path, type_dcl) = ctx.spec.resolve_type(path, name)
+                    .ok_or_else(|| format!("unknown type '{}'", name.0.join("::")))?;
+                let hash = self.hash(&dcl_path, type_dcl)?;
+                w.octet(self.equivalence_kind());
+                w.bytes.extend_from_slice(&hash);
+                return Ok(());
+            }
+            IdlTypeSpec::None => return Err("no type".to_owned()),
+        };
+        w.octet(kind);
+        Ok(())
+    }
+
+    /// The header of a sequence or array, the equivalence kind of the element
+    fn collection_header(&self, w: &mut Writer, elem: &IdlTypeSpec) {
+        w.octet(if is_fully_descriptive(elem) { EK_BOTH } else { self.equivalence_kind() });
+        w.ushort(TRY_CONSTRUCT1);
+    }
+
+    /// The detail of a type, its name if complete
+    fn type_detail(&self, w: &mut Writer, path: &[String], id: &str) {
+        if self.complete {
+            // no builtin and custom annotations
+            w.octet(0);
+            w.octet(0);
+            w.string(&scoped_name(path, id));
+        }
+    }
+
+    /// The detail of a member or enumerator, its name if complete, its name hash otherwise
+    fn member_detail(&self, w: &mut Writer, name: &str) {
+        if self.complete {
+            w.string(name);
+            w.octet(0);
+            w.octet(0);
+        } else {
+            w.bytes.extend_from_slice(&name_hash(name));
+        }
+    }
+
+    fn alias(&mut self, w: &mut Writer, path: &[String], id: &str, type_spec: &IdlTypeSpec) -> Result<(), String> {
+        w.ushort(0);
+        let header = w.begin();
+        self.type_detail(w, path, id);
+        w.end(header);
+        let body = w.begin();
+        w.ushort(0);
+        self.type_identifier(w, path, type_spec)?;
+        if self.complete {
+            w.octet(0);
+            w.octet(0);
+        }
+        w.end(body);
+        Ok(())
+    }
+
+    fn structure(&mut self, w: &mut Writer, path: &[String], type_dcl: &IdlTypeDcl, id: &str,
+                 members: &[Box<IdlStructMember>]) -> Result<(), String> {
+        let ids = xtypes::struct_member_ids(self.ctx, path, type_dcl, id, members).map_err(reason)?;
+        w.ushort(type_flags(path, type_dcl)?);
+        let header = w.begin();
+        // no base type
+        w.octet(TK_NONE);
+        self.type_detail(w, path, id);
+        w.end(header);
+        let seq = w.begin();
+        w.ulong(members.len() as u32);
+        for (member, member_id) in members.iter().zip(ids) {
+            let at = w.begin();
+            w.ulong(member_id);
+            w.ushort(member_flags(&member.annotations));
+            self.type_identifier(w, path, &member.type_spec)
+                .map_err(|reason| format!("member '{}': {}", member.id, reason))?;
+            self.member_detail(w, &member.id);
+            w.end(at);
+        }
+        w.end(seq);
+        Ok(())
+    }
+
+    fn union(&mut self, w: &mut Writer, path: &[String], type_dcl: &IdlTypeDcl, id: &str,
+             switch_type: &IdlTypeSpec, cases: &[IdlSwitchCase]) -> Result<(), String> {
+        let ids = xtypes::union_member_ids(self.ctx, path, type_dcl, id, cases).map_err(reason)?;
+        let labels = self.labels(path, id, switch_type, cases)?;
+        w.ushort(type_flags(path, type_dcl)?);
+        let header = w.begin();
+        self.type_detail(w, path, id);
+        w.end(header);
+        let discriminator = w.begin();
+        w.ushort(TRY_CONSTRUCT1);
+        self.type_identifier(w, path, switch_type)
+            .map_err(|reason| format!("discriminator: {}", reason))?;
+        if self.complete {
+            w.octet(0);
+            w.octet(0);
+        }
+        w.end(discriminator);
+        let seq = w.begin();
+        w.ulong(cases.len() as u32);
+        for ((case, member_id), labels) in cases.iter().zip(ids).zip(labels) {
+            let elem = &case.elem_spec;
+            let mut flags = TRY_CONSTRUCT1;
+            if case.labels.iter().any(|label| matches!(label, IdlSwitchLabel::Default)) {
+                flags |= IS_DEFAULT;
+            }
+            if has_annotation(&elem.annotations, "external") {
+                flags |= IS_EXTERNAL;
+            }
+            let at = w.begin();
+            w.ulong(member_id);
+            w.ushort(flags);
+            self.type_identifier(w, path, &elem.type_spec)
+                .map_err(|reason| format!("member '{}': {}", elem.id, reason))?;
+            w.ulong(labels.len() as u32);
+            for label in labels {
+                w.long(label);
+            }
+            self.member_detail(w, &elem.id);
+            w.end(at);
+        }
+        w.end(seq);
+        Ok(())
+    }
+
+    /// The values of the labels of each case (see `unions`), as these are 32 bit
+    fn labels(&self, path: &[String], id: &str, switch_type: &IdlTypeSpec, cases: &[IdlSwitchCase])
+              -> Result<Vec<Vec<i32>>, String> {
+        let values = unions::label_values(self.ctx, path, id, switch_type, cases).map_err(reason)?;
+        cases.iter().zip(values)
+            .map(|(case, values)| values.into_iter()
+                .map(|value| i32::try_from(value)
+                    .map_err(|_| format!("label {} of '{}' is no 32 bit constant", value, case.elem_spec.id)))
+                .collect())
+            .collect()
+    }
+
+    fn enumeration(&mut self, w: &mut Writer, path: &[String], type_dcl: &IdlTypeDcl, id: &str,
+                   enums: &[IdlEnumerator]) -> Result<(), String> {
+        let (bit_bound, values) = enums::literals(self.ctx, path, id, &type_dcl.annotations, enums)
+            .map_err(reason)?;
+        let default = enums.iter()
+            .position(|enumerator| has_annotation(&enumerator.annotations, "default_literal"))
+            .unwrap_or(0);
+        w.ushort(0);
+        let header = w.begin();
+        w.ushort(bit_bound as u16);
+        self.type_detail(w, path, id);
+        w.end(header);
+        // the literals are ordered by value
+        let mut literals = enums.iter().zip(values).enumerate().collect::<Vec<_>>();
+        literals.sort_by_key(|&(_, (_, value))| value);
+        let seq = w.begin();
+        w.ulong(literals.len() as u32);
+        for (index, (enumerator, value)) in literals {
+            let at = w.begin();
+            w.long(value as i32);
+            w.ushort(if index == default { IS_DEFAULT } else { 0 });
+            self.member_detail(w, &enumerator.id);
+            w.end(at);
+        }
+        w.end(seq);
+        Ok(())
+    }
+}
+
+/// The error of a type without TypeObject
+fn error(path: &[String], id: &str, reason: String) -> IdlError {
+    IdlError::ErrorMesg(format!("'{}' has no type object, {}", scoped_name(path, id), reason))
+}
+
+/// The `rtps_types::TypeObjects` of the minimal and complete TypeObject
+fn objects_tokens(minimal: &Object, complete: &Object) -> TokenStream {
+    let object = |object: &Object| {
+        let hash = Literal::byte_string(&object.hash);
+        let bytes = Literal::byte_string(&object.bytes);
+        quote!(rtps_types::TypeObject { hash: *#hash, bytes: #bytes })
+    };
+    let (minimal, complete) = (object(minimal), object(complete));
+    quote!(rtps_types::TypeObjects { minimal: #minimal, complete: #complete })
+}
+
+/// The impl of `rtps_types::Discoverable` of a struct, union or enum, if configured
+pub(crate) fn type_object_tokens(ctx: &Emitter, path: &[String], type_dcl: &IdlTypeDcl)
+                                 -> Result<TokenStream, IdlError> {
+    let config = ctx.config;
+    let id = match type_dcl.kind {
+        IdlTypeDclKind::StructDcl(ref id, _) | IdlTypeDclKind::UnionDcl(ref id, ..)
+        | IdlTypeDclKind::EnumDcl(ref id, _) if config.type_objects => id,
+        _ => return Ok(TokenStream::new()),
+    };
+    if ctx.type_map.declaration(path, id).is_some() {
+        return Ok(TokenStream::new());
+    }
+    let describe = |complete| {
+        let mut describer = Describer { ctx, complete, stack: Vec::new(), objects: Vec::new() };
+        describer.hash(path, type_dcl).map_err(|reason| error(path, id, reason))?;
+        Ok::<_, IdlError>(describer.objects)
+    };
+    let (mut minimal, mut complete) = (describe(false)?, describe(true)?);
+    // the type is described last, following its dependencies
+    let type_objects = match (minimal.pop(), complete.pop()) {
+        (Some(minimal), Some(complete)) => objects_tokens(&minimal, &complete),
+        _ => return Err(IdlError::InternalError),
+    };
+    let dependencies = minimal.iter().zip(&complete)
+        .map(|(minimal, complete)| objects_tokens(minimal, complete));
+    let rust_id = name_ident(id, NameKind::Type, config);
+    Ok(quote! {
+        impl rtps_types::Discoverable for #rust_id {
+            const TYPE_OBJECTS: rtps_types::TypeObjects = #type_objects;
+            const DEPENDENCIES: &'static [rtps_types::TypeObjects] = &[#(#dependencies),*];
+        }
+    })
+}
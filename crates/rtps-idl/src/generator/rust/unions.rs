@@ -0,0 +1,318 @@
+// Copyright (C) 2019  Frank Rehberger
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>
+
+//! Unions, encoding their discriminator by the case labels
+//!
+//! The discriminator is encoded as the primitive of the switch type: integers and octets
+//! as such, `boolean` as `bool`, characters by their code and enums by their value, in the
+//! representation of their `@bit_bound`. A case is selected by any of its labels and
+//! encodes the first; a `default` case selects the values no label takes and encodes the
+//! smallest of these. Labels not selecting a case are rejected. Towards binary formats the
+//! serde impls encode the discriminator followed by the element, human readable formats
+//! the derived representation.
+use std::collections::HashSet;
+use proc_macro2::{Literal, TokenStream};
+use quote::quote;
+use crate::ast::*;
+use crate::IdlError;
+use super::{Emitter, NameKind, core_crate, name_ident, wire_chars};
+use super::defaults::{label_value, resolve};
+use super::derives::scoped_name;
+use super::enums;
+use super::xtypes::{is_delimited, member_header, union_member_ids};
+
+/// The primitive encoding the discriminator
+#[derive(Clone, Copy, PartialEq)]
+enum Primitive {
+    Bool,
+    U8,
+    I16,
+    U16,
+    I32,
+    U32,
+    I64,
+    U64,
+}
+
+impl Primitive {
+    fn tokens(self) -> TokenStream {
+        match self {
+            Primitive::Bool => quote!(bool),
+            Primitive::U8 => quote!(u8),
+            Primitive::I16 => quote!(i16),
+            Primitive::U16 => quote!(u16),
+            Primitive::I32 => quote!(i32),
+            Primitive::U32 => quote!(u32),
+            Primitive::I64 => quote!(i64),
+            Primitive::U64 => quote!(u64),
+        }
+    }
+
+    /// The smallest and largest value
+    fn range(self) -> (i128, i128) {
+        match self {
+            Primitive::Bool => (0, 1),
+            Primitive::U8 => (0, i128::from(u8::MAX)),
+            Primitive::I16 => (i128::from(i16::MIN), i128::from(i16::MAX)),
+            Primitive::U16 => (0, i128::from(u16::MAX)),
+            Primitive::I32 => (i128::from(i32::MIN), i128::from(i32::MAX)),
+            Primitive::U32 => (0, i128::from(u32::MAX)),
+            Primitive::I64 => (i128::from(i64::MIN), i128::from(i64::MAX)),
+            Primitive::U64 => (0, i128::from(u64::MAX)),
+        }
+    }
+
+    /// The literal of the value, which is within the range
+    fn literal(self, value: i128) -> TokenStream {
+        let literal = match self {
+            Primitive::Bool => return if value != 0 { quote!(true) } else { quote!(false) },
+            Primitive::U8 => Literal::u8_suffixed(value as u8),
+            Primitive::I16 => Literal::i16_suffixed(value as i16),
+            Primitive::U16 => Literal::u16_suffixed(value as u16),
+            Primitive::I32 => Literal::i32_suffixed(value as i32),
+            Primitive::U32 => Literal::u32_suffixed(value as u32),
+            Primitive::I64 => Literal::i64_suffixed(value as i64),
+            Primitive::U64 => Literal::u64_suffixed(value as u64),
+        };
+        quote!(#literal)
+    }
+}
+
+/// The discriminator of a union and the labels of its cases
+pub(crate) struct Discriminator {
+    /// The primitive type encoding the discriminator
+    pub(crate) typ: TokenStream,
+    /// The label encoded by each case
+    pub(crate) labels: Vec<TokenStream>,
+    /// The labels selecting each case, a `default` case being `None`
+    patterns: Vec<Option<Vec<TokenStream>>>,
+    /// Whether the labels of the cases take all values of the discriminator
+    exhaustive: bool,
+}
+
+impl Discriminator {
+    /// The arms matching the discriminator `label` against the labels, evaluating the
+    /// expression of the selected case; labels selecting no case evaluate `invalid`
+    pub(crate) fn arms(&self, cases: Vec<TokenStream>, invalid: TokenStream) -> TokenStream {
+        let mut arms = Vec::new();
+        let mut default = None;
+        for (patterns, case) in self.patterns.iter().zip(cases) {
+            match patterns {
+                Some(patterns) if !patterns.is_empty() => arms.push(quote!(#(#patterns)|* => #case,)),
+                _ => default = Some(case),
+            }
+        }
+        match default {
+            _ if self.exhaustive => {}
+            Some(case) => arms.push(quote!(_ => #case,)),
+            None => arms.push(quote!(label => #invalid,)),
+        }
+        quote!(#(#arms)*)
+    }
+}
+
+/// The primitive encoding a discriminator of the switch type, and the values of the
+/// enumerators if an enum
+fn primitive(ctx: &Emitter, path: &[String], id: &str, switch_type: &IdlTypeSpec)
+             -> Result<(Primitive, Option<Vec<i128>>), IdlError> {
+    let error = || IdlError::ErrorMesg(
+        format!("union '{}': the discriminator is no integer, char, boolean or enum", scoped_name(path, id)));
+    match resolve(ctx, path, switch_type).map_err(|_| error())? {
+        (dcl_path, _, Some(IdlTypeDcl { kind: IdlTypeDclKind::EnumDcl(ref enum_id, ref enums), annotations, .. })) => {
+            let (_, values) = enums::literals(ctx, &dcl_path, enum_id, annotations, enums)?;
+            let primitive = match enums::encoded_len(ctx, &dcl_path, enum_id, annotations)? {
+                1 => Primitive::U8,
+                2 => Primitive::U16,
+                _ => Primitive::U32,
+            };
+            Ok((primitive, Some(values.into_iter().map(i128::from).collect())))
+        }
+        (_, _, Some(_)) => Err(error()),
+        (_, resolved, None) => Ok((match resolved {
+            IdlTypeSpec::BooleanType => Primitive::Bool,
+            IdlTypeSpec::OctetType | IdlTypeSpec::CharType => Primitive::U8,
+            IdlTypeSpec::WideCharType if wire_chars(ctx.config) => Primitive::U16,
+            IdlTypeSpec::WideCharType => Primitive::U8,
+            IdlTypeSpec::I16Type => Primitive::I16,
+            IdlTypeSpec::U16Type => Primitive::U16,
+            IdlTypeSpec::I32Type => Primitive::I32,
+            IdlTypeSpec::U32Type => Primitive::U32,
+            IdlTypeSpec::I64Type => Primitive::I64,
+            IdlTypeSpec::U64Type => Primitive::U64,
+            _ => return Err(error()),
+        }, None)),
+    }
+}
+
+/// The values of the labels of each case, enumerators being given by their value and
+/// characters by their code; `default` is not listed
+pub(crate) fn label_values(ctx: &Emitter, path: &[String], id: &str, switch_type: &IdlTypeSpec,
+                           cases: &[IdlSwitchCase]) -> Result<Vec<Vec<i128>>, IdlError> {
+    let enumerators = match resolve(ctx, path, switch_type) {
+        Ok((dcl_path, _, Some(type_dcl))) => match type_dcl.kind {
+            IdlTypeDclKind::EnumDcl(ref enum_id, ref enums) => {
+                let (_, values) = enums::literals(ctx, &dcl_path, enum_id, &type_dcl.annotations, enums)?;
+                Some((enums, values))
+            }
+            _ => None,
+        },
+        _ => None,
+    };
+    let value = |expr: &IdlValueExpr| match (expr, enumerators.as_ref()) {
+        (IdlValueExpr::ScopedName(ref name), Some((enums, values))) => enums.iter()
+            .position(|enumerator| name.0.last() == Some(&enumerator.id))
+            .map(|index| i128::from(values[index])),
+        (IdlValueExpr::CharLiteral(ref text), _) | (IdlValueExpr::WideCharLiteral(ref text), _) =>
+            syn::parse_str::<syn::LitChar>(text.trim_start_matches('L')).ok()
+                .map(|literal| i128::from(u32::from(literal.value()))),
+        (expr, _) => label_value(ctx, path, expr),
+    };
+    cases.iter()
+        .map(|case| case.labels.iter()
+            .filter_map(|label| match label {
+                IdlSwitchLabel::Label(ref expr) => Some(expr),
+                IdlSwitchLabel::Default => None,
+            })
+            .map(|expr| value(expr).ok_or_else(|| IdlError::ErrorMesg(
+                format!("union '{}': label {:?} of '{}' is no constant", scoped_name(path, id), expr,
+                        case.elem_spec.id))))
+            .collect())
+        .collect()
+}
+
+/// The discriminator of the union and the labels of its cases, verified to be distinct
+/// and within the discriminator
+pub(crate) fn discriminator(ctx: &Emitter, path: &[String], id: &str, switch_type: &IdlTypeSpec,
+                            cases: &[IdlSwitchCase]) -> Result<Discriminator, IdlError> {
+    let error = |mesg: String| IdlError::ErrorMesg(format!("union '{}': {}", scoped_name(path, id), mesg));
+    let (primitive, enumerators) = primitive(ctx, path, id, switch_type)?;
+    let values = label_values(ctx, path, id, switch_type, cases)?;
+    let (min, max) = primitive.range();
+    let mut seen = HashSet::new();
+    for (case, values) in cases.iter().zip(&values) {
+        for &value in values {
+            if value < min || value > max {
+                return Err(error(format!("label {} of '{}' exceeds the discriminator", value, case.elem_spec.id)));
+            }
+            if !seen.insert(value) {
+                return Err(error(format!("label {} is taken by several cases", value)));
+            }
+        }
+    }
+    // the value a default case encodes, the first enumerator or the smallest value taken by no label
+    let unlabelled = match enumerators {
+        Some(ref enumerators) => enumerators.iter().copied().find(|value| !seen.contains(value)),
+        None => (0..=max).chain((min..0).rev()).find(|value| !seen.contains(value)),
+    };
+    let exhaustive = primitive == Primitive::Bool && unlabelled.is_none();
+    let mut labels = Vec::new();
+    let mut patterns = Vec::new();
+    for (case, values) in cases.iter().zip(values) {
+        let default = case.labels.iter().any(|label| matches!(label, IdlSwitchLabel::Default));
+        let label = match (values.first(), unlabelled) {
+            (Some(&value), _) => value,
+            (None, Some(value)) => value,
+            (None, None) => return Err(error(format!("default case '{}' takes no value", case.elem_spec.id))),
+        };
+        labels.push(primitive.literal(label));
+        let literals = values.into_iter().map(|value| primitive.literal(value)).collect();
+        patterns.push(if default { None } else { Some(literals) });
+    }
+    Ok(Discriminator { typ: primitive.tokens(), labels, patterns, exhaustive })
+}
+
+/// The element of a case towards the serde impls, passed to serialize by the reference
+/// `value`, wrapped into the newtypes of `rtps_types::xtypes` if delimited or optional
+fn element(ctx: &Emitter, path: &[String], member_id: u32, elem: &IdlSwitchElement)
+           -> (TokenStream, TokenStream) {
+    let header = member_header(member_id, &elem.annotations);
+    let delimited = is_delimited(ctx, path, &elem.type_spec);
+    let next = quote!(seq.next_element);
+    let length = quote!(.ok_or_else(|| serde::de::Error::invalid_length(1, &self))?);
+    match (delimited, has_annotation(&elem.annotations, "optional")) {
+        (false, false) => (quote!(value), quote!(#next()?#length)),
+        (true, false) => (quote!(&rtps_types::xtypes::Delimited(value)),
+                          quote!(#next::<rtps_types::xtypes::DelimitedValue<_>>()?#length.0)),
+        (false, true) => (quote!(&rtps_types::xtypes::Optional(#header, value.as_ref())),
+                          quote!(#next::<rtps_types::xtypes::OptionalValue<_>>()?#length.0)),
+        (true, true) => (
+            quote!(&rtps_types::xtypes::Optional(#header, value.as_ref().map(rtps_types::xtypes::Delimited).as_ref())),
+            quote! {
+                #next::<rtps_types::xtypes::OptionalValue<rtps_types::xtypes::DelimitedValue<_>>>()?#length
+                    .0.map(|value| value.0)
+            },
+        ),
+    }
+}
+
+/// The bodies of the serde functions of a union, encoding the discriminator followed by
+/// the element towards binary formats, the derived functions being called otherwise
+pub(crate) fn serde_bodies(ctx: &Emitter, path: &[String], type_dcl: &IdlTypeDcl)
+                           -> Result<(TokenStream, TokenStream), IdlError> {
+    let config = ctx.config;
+    let (id, switch_type, cases) = match type_dcl.kind {
+        IdlTypeDclKind::UnionDcl(ref id, ref switch_type, ref cases) => (id, switch_type, cases),
+        _ => return Err(IdlError::InternalError),
+    };
+    let core = core_crate(config);
+    let rust_id = name_ident(id, NameKind::Type, config);
+    let discriminator = discriminator(ctx, path, id, switch_type, cases)?;
+    let ids = union_member_ids(ctx, path, type_dcl, id, cases)?;
+    let mut encoded = Vec::new();
+    let mut decoded = Vec::new();
+    for ((case, member_id), label) in cases.iter().zip(ids).zip(&discriminator.labels) {
+        let elem = &case.elem_spec;
+        let variant = name_ident(case.variant_id(), NameKind::Variant, config);
+        let field = name_ident(&elem.id, NameKind::Member, config);
+        let binding = if field == "value" { quote!(value) } else { quote!(#field: value) };
+        let (value, read) = element(ctx, path, member_id, elem);
+        encoded.push(quote! {
+            #rust_id::#variant { #binding } => {
+                tuple.serialize_element(&#label)?;
+                tuple.serialize_element(#value)?;
+            }
+        });
+        decoded.push(quote!(#rust_id::#variant { #field: #read }));
+    }
+    let typ = &discriminator.typ;
+    let expecting = format!("union {}", scoped_name(path, id));
+    let arms = discriminator.arms(decoded, quote! {
+        return Err(serde::de::Error::custom(#core::format_args!("invalid discriminator {} of {}", label, #expecting)))
+    });
+    let serialize = quote! {
+        if serializer.is_human_readable() {
+            #rust_id::serialize(self, serializer)
+        } else {
+            use serde::ser::SerializeTuple;
+            let mut tuple = serializer.serialize_tuple(2)?;
+            match self {
+                #(#encoded)*
+            }
+            tuple.end()
+        }
+    };
+    let deserialize = quote! {
+        if deserializer.is_human_readable() {
+            #rust_id::deserialize(deserializer)
+        } else {
+            struct Discriminated;
+            impl<'de> serde::de::Visitor<'de> for Discriminated {
+                type Value = #rust_id;
+                fn expecting(&self, f: &mut #core::fmt::Formatter) -> #core::fmt::Result {
+                    f.write_str(#expecting)
+                }
+                fn visit_seq<A: serde::de::SeqAccess<'de>>(self, mut seq: A) -> Result<#rust_id, A::Error> {
+                    let label = seq.next_element::<#typ>()?
+                        .ok_or_else(|| serde::de::Error::invalid_length(0, &self))?;
+                    Ok(match label {
+                        #arms
+                    })
+                }
+            }
+            deserializer.deserialize_tuple(2, Discriminated)
+        }
+    };
+    Ok((serialize, deserialize))
+}
@@ -0,0 +1,229 @@
+// Copyright (C) 2019  Frank Rehberger
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>
+
+//! Validation of the constraints declared in IDL
+//!
+//! With `Configuration::validate`, structs and unions implement `rtps_types::Validate`,
+//! checking `@range`, `@min` and `@max` of their members, the bounds of strings and
+//! sequences emitted unbounded, and the members of constructed types; the deserializers
+//! validate each value, see `xtypes::serde_tokens()`.
+use proc_macro2::TokenStream;
+use quote::quote;
+use crate::ast::*;
+use crate::IdlError;
+use super::{Emitter, NameKind, in_place, name_ident, wire_chars};
+use super::defaults::{resolve, typed_value};
+use super::derives::scoped_name;
+
+/// The `Validate` impl of a struct or union and the `validate` method, if validating
+pub(crate) fn validate_tokens(ctx: &Emitter, path: &[String], type_dcl: &IdlTypeDcl)
+                              -> Result<TokenStream, IdlError> {
+    let config = ctx.config;
+    if !config.validate {
+        return Ok(TokenStream::new());
+    }
+    let (id, checks) = match type_dcl.kind {
+        IdlTypeDclKind::StructDcl(ref id, ref members) => {
+            let checks = members.iter()
+                .map(|member| {
+                    let rust_id = name_ident(&member.id, NameKind::Member, config);
+                    member_checks(ctx, path, Member {
+                        type_id: id,
+                        id: &member.id,
+                        access: quote!(self.#rust_id),
+                        is_ref: false,
+                        type_spec: &member.type_spec,
+                        annotations: &member.annotations,
+                    })
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            (id, quote!(#(#checks)*))
+        }
+        IdlTypeDclKind::UnionDcl(ref id, _, ref cases) => (id, union_checks(ctx, path, id, cases)?),
+        _ => return Ok(TokenStream::new()),
+    };
+    let rust_id = name_ident(id, NameKind::Type, config);
+    let params = if checks.is_empty() {
+        quote!(_path: &str, _violations: &mut Vec<rtps_types::Violation>)
+    } else {
+        quote!(path: &str, violations: &mut Vec<rtps_types::Violation>)
+    };
+    Ok(quote! {
+        impl rtps_types::Validate for #rust_id {
+            // recursive types pass the violations only on to themselves
+            #[allow(clippy::only_used_in_recursion)]
+            fn validate_into(&self, #params) {
+                #checks
+            }
+        }
+        #[allow(dead_code)]
+        impl #rust_id {
+            /// Verify the constraints declared in IDL, reporting the path of each violating member
+            pub fn validate(&self) -> Result<(), rtps_types::ValidationError> {
+                rtps_types::Validate::validate(self)
+            }
+        }
+    })
+}
+
+/// The checks of the union elements, matching the variants holding constrained elements
+fn union_checks(ctx: &Emitter, path: &[String], id: &str, cases: &[IdlSwitchCase])
+                -> Result<TokenStream, IdlError> {
+    let config = ctx.config;
+    let rust_id = name_ident(id, NameKind::Type, config);
+    let mut patterns = Vec::new();
+    let mut blocks = Vec::new();
+    for case in cases {
+        let elem = &case.elem_spec;
+        let member = name_ident(&elem.id, NameKind::Member, config);
+        let checks = member_checks(ctx, path, Member {
+            type_id: id,
+            id: &elem.id,
+            access: quote!(#member),
+            is_ref: true,
+            type_spec: &elem.type_spec,
+            annotations: &elem.annotations,
+        })?;
+        if !checks.is_empty() {
+            let variant = name_ident(case.variant_id(), NameKind::Variant, config);
+            patterns.push(quote!(#rust_id::#variant { #member }));
+            blocks.push(checks);
+        }
+    }
+    Ok(match patterns.len() {
+        0 => TokenStream::new(),
+        len if len == cases.len() => quote! {
+            match self {
+                #(#patterns => { #blocks })*
+            }
+        },
+        1 => {
+            let (pattern, block) = (&patterns[0], &blocks[0]);
+            quote!(if let #pattern = self { #block })
+        }
+        _ => quote! {
+            match self {
+                #(#patterns => { #blocks })*
+                _ => {}
+            }
+        },
+    })
+}
+
+/// A struct member or union element to check
+struct Member<'a> {
+    /// The struct or union declaring the member
+    type_id: &'a str,
+    id: &'a str,
+    /// The member or, if `is_ref`, a reference to it
+    access: TokenStream,
+    is_ref: bool,
+    type_spec: &'a IdlTypeSpec,
+    annotations: &'a [IdlAnnotation],
+}
+
+/// The checks of a member
+fn member_checks(ctx: &Emitter, path: &[String], member: Member) -> Result<TokenStream, IdlError> {
+    let Member { type_id, id, access, is_ref, type_spec, annotations } = member;
+    let error = |mesg: String| IdlError::ErrorMesg(
+        format!("constraint of '{}::{}': {}", scoped_name(path, type_id), id, mesg));
+    let range = find_annotation(annotations, "range");
+    let min = range.and_then(|range| range.param("min"))
+        .or_else(|| find_annotation(annotations, "min").and_then(|min| min.value()));
+    let max = range.and_then(|range| range.param("max"))
+        .or_else(|| find_annotation(annotations, "max").and_then(|max| max.value()));
+
+    if min.is_some() || max.is_some() {
+        let (_, resolved, _) = resolve(ctx, path, type_spec).map_err(error)?;
+        if !is_ordered(resolved) {
+            return Err(error("only numeric and character types take a range".to_owned()));
+        }
+    }
+
+    let mut checks = Vec::new();
+    if let Some(min) = min {
+        let min = typed_value(ctx, path, type_spec, min).map_err(error)?;
+        checks.push(quote!(rtps_types::validate::min(value, #min, path, #id, violations);));
+    }
+    if let Some(max) = max {
+        let max = typed_value(ctx, path, type_spec, max).map_err(error)?;
+        checks.push(quote!(rtps_types::validate::max(value, #max, path, #id, violations);));
+    }
+    if let Some(bound) = unbounded_bound(ctx, path, type_spec) {
+        let bound = ctx.bound(path, bound)?;
+        // the bound of a `wstring` of Rust characters counts characters
+        let len = match type_spec {
+            IdlTypeSpec::WideStringType(_) if !wire_chars(ctx.config) => quote!(value.chars().count()),
+            _ => quote!(value.len()),
+        };
+        checks.push(quote!(rtps_types::validate::bound(#len, #bound, path, #id, violations);));
+    }
+    if is_validated(ctx, path, type_spec) {
+        checks.push(quote! {
+            rtps_types::Validate::validate_into(value, &rtps_types::validate::member(path, #id), violations);
+        });
+    }
+    if checks.is_empty() {
+        return Ok(TokenStream::new());
+    }
+
+    let external = has_annotation(annotations, "external");
+    let value = match (has_annotation(annotations, "optional"), external, is_ref) {
+        (true, true, _) => return Ok(quote!(if let Some(value) = #access.as_deref() { #(#checks)* })),
+        (true, false, _) => return Ok(quote!(if let Some(value) = #access.as_ref() { #(#checks)* })),
+        (false, true, true) => quote!(&**#access),
+        (false, true, false) => quote!(&*#access),
+        (false, false, true) => access,
+        (false, false, false) => quote!(&#access),
+    };
+    Ok(quote!({ let value = #value; #(#checks)* }))
+}
+
+/// Whether the primitive type is compared by `@range`, `@min` and `@max`
+fn is_ordered(type_spec: &IdlTypeSpec) -> bool {
+    matches!(type_spec,
+        IdlTypeSpec::I16Type | IdlTypeSpec::I32Type | IdlTypeSpec::I64Type
+        | IdlTypeSpec::U16Type | IdlTypeSpec::U32Type | IdlTypeSpec::U64Type
+        | IdlTypeSpec::F32Type | IdlTypeSpec::F64Type | IdlTypeSpec::F128Type
+        | IdlTypeSpec::OctetType | IdlTypeSpec::CharType | IdlTypeSpec::WideCharType)
+}
+
+/// The bound of a string or sequence emitted as `String` or `Vec<T>`
+fn unbounded_bound<'s>(ctx: &Emitter, path: &[String], type_spec: &'s IdlTypeSpec) -> Option<&'s IdlValueExpr> {
+    if ctx.config.bounded || in_place(ctx.config) || ctx.type_map.type_spec(path, type_spec).is_some() {
+        return None;
+    }
+    match type_spec {
+        IdlTypeSpec::StringType(Some(ref bound))
+        | IdlTypeSpec::WideStringType(Some(ref bound))
+        | IdlTypeSpec::SequenceType(_, Some(ref bound)) => Some(bound),
+        _ => None,
+    }
+}
+
+/// Whether the type holds structs or unions, which implement `Validate`
+fn is_validated(ctx: &Emitter, path: &[String], type_spec: &IdlTypeSpec) -> bool {
+    if ctx.type_map.type_spec(path, type_spec).is_some() {
+        return false;
+    }
+    match type_spec {
+        IdlTypeSpec::SequenceType(ref type_spec, _)
+        | IdlTypeSpec::ArrayType(ref type_spec, _) => is_validated(ctx, path, type_spec),
+        IdlTypeSpec::ScopedName(ref name) => match ctx.spec.resolve_type(path, name) {
+            Some((dcl_path, type_dcl)) => {
+                let mapped = type_dcl.kind.id()
+                    .is_some_and(|id| ctx.type_map.declaration(&dcl_path, id).is_some());
+                match type_dcl.kind {
+                    _ if mapped => false,
+                    IdlTypeDclKind::TypeDcl(_, ref type_spec) => is_validated(ctx, &dcl_path, type_spec),
+                    IdlTypeDclKind::StructDcl(..) | IdlTypeDclKind::UnionDcl(..) => true,
+                    _ => false,
+                }
+            }
+            None => false,
+        },
+        _ => false,
+    }
+}
@@ -0,0 +1,155 @@
+// Copyright (C) 2019  Frank Rehberger
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>
+
+//! Zero-copy views of structs
+//!
+//! With `Configuration::views` each final or appendable struct `Foo` is accompanied by
+//! `FooView<'a>`, reading its XCDR1 encoding in place by `rtps_cdr::CdrView`. The accessor
+//! of a member returns a string as `&'a str`, a sequence of octets as `&'a [u8]` and a
+//! struct named directly as its view, other members as their owned type; `to_owned()`
+//! decodes the struct. Mutable structs are parameter lists, these have no view.
+use proc_macro2::{Ident, Literal, TokenStream};
+use quote::quote;
+use crate::ast::*;
+use crate::IdlError;
+use super::{Emitter, NameKind, allow_naming, held_type, ident, member_type, name_ident, rust_name};
+use super::defaults::resolve;
+use super::derives::scoped_name;
+use super::xtypes::{self, Extensibility};
+
+/// Methods of the views besides the accessors
+const VIEW_METHODS: &[&str] = &["new", "to_owned"];
+
+/// How the view reads a member
+enum Access {
+    /// Borrowed by the `CdrView` impl of the type
+    View(TokenStream),
+    /// Decoded by the `CdrDecode` impl of the type
+    Decode(TokenStream),
+    /// Decoded by the `CdrDecode` impl of the type held if present, after the parameter
+    /// header of an `@optional` member
+    Optional(TokenStream),
+}
+
+/// The identifier of the view of the struct `id`
+fn view_ident(id: &str, config: &crate::Configuration) -> Ident {
+    let rust_id = rust_name(id, NameKind::Type, config);
+    ident(&format!("{}View", rust_id.trim_start_matches("r#")))
+}
+
+/// Whether the struct declared in module `path` has a view
+fn has_view(ctx: &Emitter, path: &[String], type_dcl: &IdlTypeDcl) -> Result<bool, IdlError> {
+    match type_dcl.kind {
+        IdlTypeDclKind::StructDcl(ref id, _) if ctx.type_map.declaration(path, id).is_none() =>
+            Ok(xtypes::extensibility(path, type_dcl)? != Extensibility::Mutable),
+        _ => Ok(false),
+    }
+}
+
+/// The path of the view of the struct `name`, as seen from within the module `path`
+fn view_path(name: &IdlScopedName, config: &crate::Configuration) -> TokenStream {
+    let (last, modules) = name.0.split_last().expect("scoped name of a struct");
+    let modules = modules.iter().map(|module| name_ident(module, NameKind::Module, config));
+    let view_id = view_ident(last, config);
+    if name.1 {
+        quote!(crate #(:: #modules)* :: #view_id)
+    } else {
+        quote!(#(#modules ::)* #view_id)
+    }
+}
+
+/// How the view reads the member, owned if `@optional` or `@external`
+fn access(ctx: &Emitter, path: &[String], member: &IdlStructMember) -> Result<Access, IdlError> {
+    let decode = || member_type(ctx, path, &member.type_spec, &member.annotations).map(Access::Decode);
+    if has_annotation(&member.annotations, "optional") {
+        return held_type(ctx, path, &member.type_spec, &member.annotations).map(Access::Optional);
+    }
+    if has_annotation(&member.annotations, "external") {
+        return decode();
+    }
+    if let IdlTypeSpec::ScopedName(ref name) = *member.type_spec {
+        if let Some((dcl_path, type_dcl)) = ctx.spec.resolve_type(path, name) {
+            if has_view(ctx, &dcl_path, type_dcl)? {
+                let view = view_path(name, ctx.config);
+                return Ok(Access::View(quote!(#view<'a>)));
+            }
+        }
+    }
+    match resolve(ctx, path, &member.type_spec) {
+        Ok((_, IdlTypeSpec::StringType(_), None)) => Ok(Access::View(quote!(&'a str))),
+        Ok((dcl_path, IdlTypeSpec::SequenceType(ref elem, _), None))
+            if matches!(resolve(ctx, &dcl_path, elem), Ok((_, IdlTypeSpec::OctetType, None))) =>
+            Ok(Access::View(quote!(&'a [u8]))),
+        _ => decode(),
+    }
+}
+
+/// The view of a final or appendable struct, its accessors and its `CdrView` impl
+pub(crate) fn view_tokens(ctx: &Emitter, path: &[String], type_dcl: &IdlTypeDcl) -> Result<TokenStream, IdlError> {
+    let config = ctx.config;
+    let (id, members) = match type_dcl.kind {
+        IdlTypeDclKind::StructDcl(ref id, ref members) if config.cdr && config.views => (id, members),
+        _ => return Ok(TokenStream::new()),
+    };
+    if !has_view(ctx, path, type_dcl)? {
+        return Ok(TokenStream::new());
+    }
+    let rust_id = name_ident(id, NameKind::Type, config);
+    let view_id = view_ident(id, config);
+    let allow_naming = allow_naming("non_camel_case_types", config);
+    let mut accessors = Vec::new();
+    let mut skips = Vec::new();
+    for (index, member) in members.iter().enumerate() {
+        let field = name_ident(&member.id, NameKind::Member, config);
+        if VIEW_METHODS.contains(&field.to_string().as_str()) {
+            return Err(IdlError::ErrorMesg(format!("member '{}' of '{}' collides with a method of its view",
+                                                   member.id, scoped_name(path, id))));
+        }
+        let index = Literal::usize_unsuffixed(index);
+        let (typ, read, skip) = match access(ctx, path, member)? {
+            Access::View(typ) => (typ.clone(), quote!(<#typ as rtps_cdr::CdrView<'a>>::view),
+                                  quote!(members.skip_view::<#typ>(de)?;)),
+            Access::Decode(typ) => (typ.clone(), quote!(<#typ as rtps_cdr::CdrDecode>::decode),
+                                    quote!(members.skip_decoded::<#typ>(de)?;)),
+            Access::Optional(typ) => (quote!(Option<#typ>), quote!(rtps_cdr::Deserializer::optional::<#typ>),
+                                      quote!(members.skip_optional::<#typ>(de)?;)),
+        };
+        accessors.push(quote! {
+            pub fn #field(&self) -> rtps_cdr::Result<#typ> {
+                #read(&mut self.members.member(#index))
+            }
+        });
+        skips.push(skip);
+    }
+    let len = Literal::usize_unsuffixed(members.len());
+    let mutable = if members.is_empty() { TokenStream::new() } else { quote!(mut) };
+    Ok(quote! {
+        #[allow(dead_code)]
+        #allow_naming
+        #[derive(Clone, Debug)]
+        pub struct #view_id<'a> {
+            members: rtps_cdr::ViewMembers<'a, #len>,
+        }
+        #[allow(dead_code)]
+        impl<'a> #view_id<'a> {
+            /// View the XCDR1 encoding in the byte order, the members being decoded when accessed
+            pub fn new(bytes: &'a [u8], endianness: rtps_cdr::Endianness) -> rtps_cdr::Result<#view_id<'a>> {
+                rtps_cdr::CdrView::view(&mut rtps_cdr::Deserializer::new(bytes, endianness))
+            }
+            #(#accessors)*
+            /// Decode the struct viewed
+            pub fn to_owned(&self) -> rtps_cdr::Result<#rust_id> {
+                rtps_cdr::CdrDecode::decode(&mut self.members.start())
+            }
+        }
+        impl<'a> rtps_cdr::CdrView<'a> for #view_id<'a> {
+            fn view(de: &mut rtps_cdr::Deserializer<'a>) -> rtps_cdr::Result<#view_id<'a>> {
+                let #mutable members = rtps_cdr::ViewMembers::begin(de)?;
+                #(#skips)*
+                Ok(#view_id { members })
+            }
+        }
+    })
+}
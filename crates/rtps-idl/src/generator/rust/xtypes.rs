@@ -0,0 +1,489 @@
+// Copyright (C) 2019  Frank Rehberger
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>
+
+//! Extensibility of DDS-XTypes
+//!
+//! Structs and unions are final unless annotated `@appendable` or `@mutable` (or
+//! `@extensibility(..)`). The serde impls of appendable types wrap the derived ones into
+//! the newtype `rtps_types::xtypes::DELIMITED`, those of mutable types encode the
+//! members as map from member header to value, which CDR encodes by DHEADER and EMHEADER
+//! or as parameter list. Optional members of the other types are wrapped with their member
+//! header into the newtype `rtps_types::xtypes::OPTIONAL`, which XCDR1 encodes by a
+//! parameter header. Member IDs are assigned sequentially unless given by `@id` or
+//! hashed by `@autoid(HASH)` and `@hashid`. With `Configuration::cdr`, structs and unions
+//! implement `rtps_types::xtypes::Extensible`, selecting the encapsulation of their payload.
+use std::collections::HashMap;
+use proc_macro2::{Literal, TokenStream};
+use quote::quote;
+use crate::ast::*;
+use crate::IdlError;
+use super::{Emitter, NameKind, name_ident, const_value, unions};
+use super::defaults::resolve;
+use super::derives::scoped_name;
+
+/// Extensibility kind of a struct or union
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum Extensibility {
+    Final,
+    Appendable,
+    Mutable,
+}
+
+/// The name of an enumerator given as annotation parameter, such as `HASH`
+fn enumerator(expr: &IdlValueExpr) -> Option<&str> {
+    match expr {
+        IdlValueExpr::ScopedName(ref name) => name.0.last().map(|id| id.as_str()),
+        _ => None,
+    }
+}
+
+/// The extensibility of the struct or union
+pub(crate) fn extensibility(path: &[String], type_dcl: &IdlTypeDcl) -> Result<Extensibility, IdlError> {
+    let id = match type_dcl.kind {
+        IdlTypeDclKind::StructDcl(ref id, _) | IdlTypeDclKind::UnionDcl(ref id, _, _) => id,
+        _ => return Ok(Extensibility::Final),
+    };
+    let error = |mesg: &str| IdlError::ErrorMesg(format!("extensibility of '{}': {}", scoped_name(path, id), mesg));
+    let mut kinds = Vec::new();
+    for annotation in &type_dcl.annotations {
+        let kind = match annotation.id() {
+            "final" => Extensibility::Final,
+            "appendable" => Extensibility::Appendable,
+            "mutable" => Extensibility::Mutable,
+            "extensibility" => match annotation.value().and_then(enumerator) {
+                Some("FINAL") => Extensibility::Final,
+                Some("APPENDABLE") => Extensibility::Appendable,
+                Some("MUTABLE") => Extensibility::Mutable,
+                _ => return Err(error("expected FINAL, APPENDABLE or MUTABLE")),
+            },
+            _ => continue,
+        };
+        if !kinds.contains(&kind) {
+            kinds.push(kind);
+        }
+    }
+    match kinds.as_slice() {
+        [] => Ok(Extensibility::Final),
+        [kind] => Ok(*kind),
+        _ => Err(error("conflicting annotations")),
+    }
+}
+
+/// The member ID derived from the name, the first 4 bytes of its MD5 hash in little endian
+fn hash_id(name: &str) -> u32 {
+    let digest = md5::compute(name.as_bytes());
+    u32::from_le_bytes([digest[0], digest[1], digest[2], digest[3]]) & 0x0fff_ffff
+}
+
+/// The member IDs of the struct members or union elements, given by their name and
+/// annotations, the sequential ones starting at `first`
+fn member_ids(ctx: &Emitter, path: &[String], id: &str, annotations: &[IdlAnnotation],
+                         members: &[(&str, &[IdlAnnotation])], first: u64) -> Result<Vec<u32>, IdlError> {
+    let error = |member: &str, mesg: String| IdlError::ErrorMesg(
+        format!("member ID of '{}::{}': {}", scoped_name(path, id), member, mesg));
+    let hashed = match find_annotation(annotations, "autoid").map(|autoid| autoid.value().and_then(enumerator)) {
+        None | Some(Some("SEQUENTIAL")) => false,
+        Some(Some("HASH")) => true,
+        Some(_) => return Err(IdlError::ErrorMesg(
+            format!("autoid of '{}': expected SEQUENTIAL or HASH", scoped_name(path, id)))),
+    };
+    let mut ids = Vec::with_capacity(members.len());
+    let mut seen: HashMap<u32, &str> = HashMap::new();
+    let mut next = first;
+    for &(member, annotations) in members {
+        let member_id = match (find_annotation(annotations, "id"), find_annotation(annotations, "hashid")) {
+            (Some(annotation), _) => annotation.value()
+                .and_then(|value| const_value(ctx.spec, path, value))
+                .ok_or_else(|| error(member, "expected a non-negative integer constant".to_owned()))?,
+            (None, Some(annotation)) => match annotation.value() {
+                Some(IdlValueExpr::StringLiteral(ref name)) => u64::from(hash_id(name.trim_matches('"'))),
+                Some(_) => return Err(error(member, "expected a string literal".to_owned())),
+                None => u64::from(hash_id(member)),
+            },
+            (None, None) if hashed => u64::from(hash_id(member)),
+            (None, None) => next,
+        };
+        if member_id > 0x0fff_ffff {
+            return Err(error(member, format!("{} exceeds 0x0fffffff", member_id)));
+        }
+        let member_id = member_id as u32;
+        if let Some(other) = seen.insert(member_id, member) {
+            return Err(error(member, format!("{} is assigned to '{}' as well", member_id, other)));
+        }
+        ids.push(member_id);
+        next = u64::from(member_id) + 1;
+    }
+    Ok(ids)
+}
+
+/// The member IDs of the members of a struct, starting at 0
+pub(crate) fn struct_member_ids(ctx: &Emitter, path: &[String], type_dcl: &IdlTypeDcl, id: &str,
+                                members: &[Box<IdlStructMember>]) -> Result<Vec<u32>, IdlError> {
+    let names = members.iter()
+        .map(|member| (member.id.as_str(), member.annotations.as_slice()))
+        .collect::<Vec<_>>();
+    member_ids(ctx, path, id, &type_dcl.annotations, &names, 0)
+}
+
+/// The member IDs of the elements of a union, starting at 1 as 0 is the discriminator
+pub(crate) fn union_member_ids(ctx: &Emitter, path: &[String], type_dcl: &IdlTypeDcl, id: &str,
+                               cases: &[IdlSwitchCase]) -> Result<Vec<u32>, IdlError> {
+    let names = cases.iter()
+        .map(|case| (case.elem_spec.id.as_str(), case.elem_spec.annotations.as_slice()))
+        .collect::<Vec<_>>();
+    let ids = member_ids(ctx, path, id, &type_dcl.annotations, &names, 1)?;
+    if ids.contains(&0) {
+        return Err(IdlError::ErrorMesg(
+            format!("member ID of '{}': 0 is the discriminator", scoped_name(path, id))));
+    }
+    Ok(ids)
+}
+
+/// The attributes deriving the serde functions, which the trait impls call; the derived
+/// deserializer of an appendable struct defaults the members not encoded
+pub(crate) fn serde_attributes(ctx: &Emitter, path: &[String], type_dcl: &IdlTypeDcl)
+                               -> Result<TokenStream, IdlError> {
+    let config = ctx.config;
+    if !config.serde {
+        return Ok(TokenStream::new());
+    }
+    let extensibility = extensibility(path, type_dcl)?;
+    let union = matches!(type_dcl.kind, IdlTypeDclKind::UnionDcl(..));
+    let remote = if config.validate || extensibility != Extensibility::Final || union {
+        quote!(#[serde(remote = "Self")])
+    } else {
+        TokenStream::new()
+    };
+    let default = match type_dcl.kind {
+        IdlTypeDclKind::StructDcl(..) if extensibility == Extensibility::Appendable => quote!(#[serde(default)]),
+        _ => TokenStream::new(),
+    };
+    Ok(quote!(#remote #default))
+}
+
+/// `#[serde(with)]` of a member holding a collection of non-primitive elements, which
+/// XCDR2 precedes by a DHEADER, and of an optional member, which XCDR1 precedes by a
+/// parameter header of its member ID
+pub(crate) fn serde_with(ctx: &Emitter, path: &[String], member_id: u32, type_spec: &IdlTypeSpec,
+                         annotations: &[IdlAnnotation]) -> TokenStream {
+    if !ctx.config.serde {
+        return TokenStream::new();
+    }
+    let module = match (is_delimited(ctx, path, type_spec), has_annotation(annotations, "optional")) {
+        (false, false) => return TokenStream::new(),
+        (true, false) => return quote!(#[serde(with = "rtps_types::xtypes::delimited")]),
+        (false, true) => "optional",
+        (true, true) => "delimited_option",
+    };
+    let flag = if is_must_understand(annotations) { 0x8000_0000 } else { 0 };
+    let serialize = format!("rtps_types::xtypes::{}::serialize::<{}, _, _>", module, flag | member_id);
+    let deserialize = format!("rtps_types::xtypes::{}::deserialize", module);
+    quote!(#[serde(serialize_with = #serialize, deserialize_with = #deserialize)])
+}
+
+/// Whether the type is a sequence or array of non-primitive elements
+pub(crate) fn is_delimited(ctx: &Emitter, path: &[String], type_spec: &IdlTypeSpec) -> bool {
+    match resolve(ctx, path, type_spec) {
+        Ok((dcl_path, IdlTypeSpec::SequenceType(ref elem, _), _))
+        | Ok((dcl_path, IdlTypeSpec::ArrayType(ref elem, _), _)) => !is_primitive(ctx, &dcl_path, elem),
+        _ => false,
+    }
+}
+
+/// Whether the type is a primitive or enum, encoded without a DHEADER
+fn is_primitive(ctx: &Emitter, path: &[String], type_spec: &IdlTypeSpec) -> bool {
+    match resolve(ctx, path, type_spec) {
+        Ok((_, _, Some(type_dcl))) => matches!(type_dcl.kind, IdlTypeDclKind::EnumDcl(..)),
+        Ok((_, resolved, None)) => !matches!(resolved,
+            IdlTypeSpec::StringType(_) | IdlTypeSpec::WideStringType(_)
+            | IdlTypeSpec::SequenceType(..) | IdlTypeSpec::ArrayType(..)),
+        // mapped types are assumed to be primitive
+        Err(_) => true,
+    }
+}
+
+/// The impl of `rtps_types::xtypes::Extensible` of a struct or union, selecting the
+/// encapsulation of its payload, if encoding CDR
+pub(crate) fn extensible_tokens(ctx: &Emitter, path: &[String], type_dcl: &IdlTypeDcl) -> Result<TokenStream, IdlError> {
+    let config = ctx.config;
+    let id = match type_dcl.kind {
+        IdlTypeDclKind::StructDcl(ref id, _) | IdlTypeDclKind::UnionDcl(ref id, _, _) => id,
+        _ => return Ok(TokenStream::new()),
+    };
+    if !config.cdr {
+        return Ok(TokenStream::new());
+    }
+    let rust_id = name_ident(id, NameKind::Type, config);
+    let kind = match extensibility(path, type_dcl)? {
+        Extensibility::Final => quote!(Final),
+        Extensibility::Appendable => quote!(Appendable),
+        Extensibility::Mutable => quote!(Mutable),
+    };
+    Ok(quote! {
+        impl rtps_types::xtypes::Extensible for #rust_id {
+            const EXTENSIBILITY: rtps_types::xtypes::Extensibility = rtps_types::xtypes::Extensibility::#kind;
+        }
+    })
+}
+
+/// The serde impls of a struct or union calling the derived functions, if validating
+/// deserializers or if the type is extensible; those of a union encode its discriminator
+/// (see `unions`)
+pub(crate) fn serde_tokens(ctx: &Emitter, path: &[String], type_dcl: &IdlTypeDcl) -> Result<TokenStream, IdlError> {
+    let config = ctx.config;
+    let (id, union) = match type_dcl.kind {
+        IdlTypeDclKind::StructDcl(ref id, _) => (id, false),
+        IdlTypeDclKind::UnionDcl(ref id, _, _) => (id, true),
+        _ => return Ok(TokenStream::new()),
+    };
+    let extensibility = extensibility(path, type_dcl)?;
+    if !config.serde || (!config.validate && extensibility == Extensibility::Final && !union) {
+        return Ok(TokenStream::new());
+    }
+    let rust_id = name_ident(id, NameKind::Type, config);
+    let (derived_serialize, derived_deserialize) = if union {
+        unions::serde_bodies(ctx, path, type_dcl)?
+    } else {
+        (quote!(#rust_id::serialize(self, serializer)), quote!(#rust_id::deserialize(deserializer)))
+    };
+    let validate = if config.validate {
+        quote!(rtps_types::Validate::validate(&value).map_err(serde::de::Error::custom)?;)
+    } else {
+        TokenStream::new()
+    };
+    let (serialize, deserialize, impls) = match extensibility {
+        Extensibility::Final => (derived_serialize, quote!(#derived_deserialize?), TokenStream::new()),
+        Extensibility::Appendable => (
+            quote! {
+                serializer.serialize_newtype_struct(rtps_types::xtypes::DELIMITED,
+                                                    &rtps_types::xtypes::Derived(self))
+            },
+            quote! {
+                deserializer.deserialize_newtype_struct(rtps_types::xtypes::DELIMITED,
+                                                        rtps_types::xtypes::DerivedVisitor::<#rust_id>::default())?
+            },
+            quote! {
+                impl rtps_types::xtypes::SerializeDerived for #rust_id {
+                    fn serialize_derived<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                        #derived_serialize
+                    }
+                }
+                impl<'de> rtps_types::xtypes::DeserializeDerived<'de> for #rust_id {
+                    fn deserialize_derived<D: serde::Deserializer<'de>>(deserializer: D) -> Result<#rust_id, D::Error> {
+                        #derived_deserialize
+                    }
+                }
+            },
+        ),
+        Extensibility::Mutable => (
+            quote! {
+                if serializer.is_human_readable() {
+                    #rust_id::serialize(self, serializer)
+                } else {
+                    serializer.serialize_newtype_struct(rtps_types::xtypes::MUTABLE,
+                                                        &rtps_types::xtypes::Members(self))
+                }
+            },
+            quote! {
+                if deserializer.is_human_readable() {
+                    #rust_id::deserialize(deserializer)?
+                } else {
+                    deserializer.deserialize_newtype_struct(rtps_types::xtypes::MUTABLE,
+                                                            rtps_types::xtypes::MembersVisitor::<#rust_id>::default())?
+                }
+            },
+            members_tokens(ctx, path, type_dcl)?,
+        ),
+    };
+    Ok(quote! {
+        impl serde::Serialize for #rust_id {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                #serialize
+            }
+        }
+        impl<'de> serde::Deserialize<'de> for #rust_id {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<#rust_id, D::Error> {
+                let value = #deserialize;
+                #validate
+                Ok(value)
+            }
+        }
+        #impls
+    })
+}
+
+/// Whether the member of a mutable type is flagged must-understand, being a key or
+/// annotated `@must_understand`
+fn is_must_understand(annotations: &[IdlAnnotation]) -> bool {
+    has_annotation(annotations, "key") || has_annotation(annotations, "must_understand")
+}
+
+/// The member header of a member of a mutable type, the member ID and must-understand flag
+pub(crate) fn member_header(member_id: u32, annotations: &[IdlAnnotation]) -> TokenStream {
+    let id = Literal::u32_suffixed(member_id);
+    match (is_must_understand(annotations), member_id) {
+        (true, 0) => quote!(rtps_types::xtypes::MUST_UNDERSTAND),
+        (true, _) => quote!(rtps_types::xtypes::MUST_UNDERSTAND | #id),
+        (false, _) => quote!(#id),
+    }
+}
+
+/// A member of a mutable type, accessed by the expression `place` and the reference
+/// `access` to it
+struct Member {
+    /// The number of entries of an optional member, depending on its presence
+    count: Option<TokenStream>,
+    /// Serialize the entry
+    entry: TokenStream,
+    /// The match arm of the member ID
+    key: Literal,
+    /// Decode the value, which may be wrapped into `Some`
+    read: TokenStream,
+}
+
+impl Member {
+    fn new(ctx: &Emitter, path: &[String], member_id: u32, type_spec: &IdlTypeSpec,
+           annotations: &[IdlAnnotation], place: TokenStream, access: TokenStream) -> Member {
+        let header = member_header(member_id, annotations);
+        let header = if is_must_understand(annotations) && member_id != 0 {
+            quote!(&(#header))
+        } else {
+            quote!(&#header)
+        };
+        let delimited = is_delimited(ctx, path, type_spec);
+        let (value, read) = if delimited {
+            (quote!(&rtps_types::xtypes::Delimited(value)),
+             quote!(map.next_value::<rtps_types::xtypes::DelimitedValue<_>>()?.0))
+        } else {
+            (quote!(value), quote!(map.next_value()?))
+        };
+        let key = Literal::u32_unsuffixed(member_id);
+        if has_annotation(annotations, "optional") {
+            Member {
+                count: Some(quote!(usize::from(#place.is_some()))),
+                entry: quote! {
+                    if let Some(value) = #access {
+                        map.serialize_entry(#header, #value)?;
+                    }
+                },
+                key,
+                read: quote!(Some(#read)),
+            }
+        } else {
+            let value = if delimited { quote!(&rtps_types::xtypes::Delimited(#access)) } else { access };
+            Member { count: None, entry: quote!(map.serialize_entry(#header, #value)?;), key, read }
+        }
+    }
+}
+
+/// The impls of `SerializeMembers` and `DeserializeMembers` of a mutable struct or union;
+/// keys and members annotated `@must_understand` are flagged must-understand. The
+/// discriminator of a union is encoded as member 0 holding the label of the case (see
+/// `unions`), followed by the selected element, whose IDs start at 1.
+fn members_tokens(ctx: &Emitter, path: &[String], type_dcl: &IdlTypeDcl) -> Result<TokenStream, IdlError> {
+    let config = ctx.config;
+    let (count, entries, arms, id) = match type_dcl.kind {
+        IdlTypeDclKind::StructDcl(ref id, ref members) => {
+            let ids = struct_member_ids(ctx, path, type_dcl, id, members)?;
+            let mut required = 0;
+            let mut counts = Vec::new();
+            let mut entries = Vec::new();
+            let mut arms = Vec::new();
+            for (member, member_id) in members.iter().zip(ids) {
+                let field = name_ident(&member.id, NameKind::Member, config);
+                let Member { count, entry, key, read } =
+                    Member::new(ctx, path, member_id, &member.type_spec, &member.annotations,
+                                quote!(self.#field), quote!(&self.#field));
+                match count {
+                    Some(count) => counts.push(count),
+                    None => required += 1,
+                }
+                entries.push(entry);
+                arms.push(quote!(#key => value.#field = #read,));
+            }
+            let required = Literal::usize_unsuffixed(required);
+            let count = quote!(#required #(+ #counts)*);
+            (count, quote!(#(#entries)*), quote!(#(#arms)*), id)
+        }
+        IdlTypeDclKind::UnionDcl(ref id, ref switch_type, ref cases) => {
+            let rust_id = name_ident(id, NameKind::Type, config);
+            let ids = union_member_ids(ctx, path, type_dcl, id, cases)?;
+            let discriminator = unions::discriminator(ctx, path, id, switch_type, cases)?;
+            let mut counts = Vec::new();
+            let mut entries = Vec::new();
+            let mut arms = Vec::new();
+            for ((case, member_id), label) in cases.iter().zip(ids).zip(&discriminator.labels) {
+                let elem = &case.elem_spec;
+                let variant = name_ident(case.variant_id(), NameKind::Variant, config);
+                let field = name_ident(&elem.id, NameKind::Member, config);
+                let Member { count, entry, key, read } =
+                    Member::new(ctx, path, member_id, &elem.type_spec, &elem.annotations,
+                                quote!(value), quote!(value));
+                let binding = if field == "value" { quote!(value) } else { quote!(#field: value) };
+                counts.push(match count {
+                    Some(count) => quote!(#rust_id::#variant { #binding } => 1 + #count,),
+                    None => quote!(#rust_id::#variant { .. } => 2,),
+                });
+                entries.push(quote! {
+                    #rust_id::#variant { #binding } => {
+                        map.serialize_entry(&rtps_types::xtypes::MUST_UNDERSTAND, &#label)?;
+                        #entry
+                    }
+                });
+                arms.push(quote!(#key => value = #rust_id::#variant { #field: #read },));
+            }
+            let typ = &discriminator.typ;
+            (quote!(match self { #(#counts)* }),
+             quote!(match self { #(#entries)* }),
+             quote! {
+                 0 => {
+                     map.next_value::<#typ>()?;
+                 }
+                 #(#arms)*
+             },
+             id)
+        }
+        _ => return Err(IdlError::InternalError),
+    };
+    let rust_id = name_ident(id, NameKind::Type, config);
+    // a struct without members skips all
+    let (entries, members) = if arms.is_empty() {
+        (quote!(let _ = map;),
+         quote! {
+             while let Some(header) = map.next_key::<u32>()? {
+                 rtps_types::xtypes::skip_member(&mut map, header)?;
+             }
+             Ok(#rust_id::default())
+         })
+    } else {
+        (entries,
+         quote! {
+             let mut value = #rust_id::default();
+             while let Some(header) = map.next_key::<u32>()? {
+                 match header & rtps_types::xtypes::MEMBER_ID_MASK {
+                     #arms
+                     _ => rtps_types::xtypes::skip_member(&mut map, header)?,
+                 }
+             }
+             Ok(value)
+         })
+    };
+    Ok(quote! {
+        impl rtps_types::xtypes::SerializeMembers for #rust_id {
+            fn member_count(&self) -> usize {
+                #count
+            }
+            fn serialize_members<M: serde::ser::SerializeMap>(&self, map: &mut M) -> Result<(), M::Error> {
+                #entries
+                Ok(())
+            }
+        }
+        impl<'de> rtps_types::xtypes::DeserializeMembers<'de> for #rust_id {
+            fn deserialize_members<A: serde::de::MapAccess<'de>>(mut map: A) -> Result<#rust_id, A::Error> {
+                #members
+            }
+        }
+    })
+}
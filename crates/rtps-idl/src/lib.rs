@@ -4,7 +4,10 @@
 // http://www.apache.org/licenses/LICENSE-2.0>
 extern crate linked_hash_map;
 
-mod ast;
+pub mod ast;
+mod config;
+pub mod generator;
+pub mod visit;
 
 use pest::Parser;
 use pest::iterators::{Pair, Pairs};
@@ -15,10 +18,7 @@ use std::io::{Write, Read};
 use std::fs::File;
 
 use crate::ast::*;
-
-const MODULE_PRELUDE: &[u8] = b"#[allow(unused_imports)]
-use std::vec::Vec;
-";
+use crate::generator::{Generator, RustGenerator};
 
 ///
 #[derive(Debug)]
@@ -35,27 +35,173 @@ pub trait IdlLoader {
     fn load(&self, filename: &str) -> Result<String, Error>;
 }
 
+/// Naming convention applied to the generated Rust items
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum NamingConvention {
+    /// Copy IDL identifiers verbatim
+    Idl,
+    /// snake_case modules and fields, UpperCamelCase types and variants,
+    /// SCREAMING_SNAKE_CASE constants; IDL names are kept for serialization
+    Rust,
+}
+
+/// Mapping of the IDL character and wide string types
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CharMapping {
+    /// `char` and `wchar` as Rust `char`, `wstring` as `String`
+    Rust,
+    /// `char` as octet `rtps_types::Char`, `wchar` and `wstring` as UTF-16 code units
+    /// `rtps_types::WChar` and `rtps_types::WString`, as encoded in CDR
+    Wire,
+}
+
+/// Pointer type of `@external` members
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ExternalMapping {
+    /// `Box<T>`
+    Box,
+    /// `std::sync::Arc<T>`, serde requires its feature `rc`
+    Arc,
+}
+
+/// Derives and outer attributes applied to the types matching a pattern
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TypeRule {
+    /// Scoped IDL name, such as `DDS::DCPS::GUID_t`; `*` matches any sequence of characters
+    pub pattern: String,
+    pub derives: Vec<String>,
+    pub attributes: Vec<String>,
+}
+
+impl TypeRule {
+    /// Whether the scoped IDL name (without leading `::`) matches the pattern
+    pub fn matches(&self, scoped_name: &str) -> bool {
+        fn glob(pattern: &[u8], name: &[u8]) -> bool {
+            match pattern.split_first() {
+                None => name.is_empty(),
+                Some((b'*', rest)) => (0..=name.len()).any(|idx| glob(rest, &name[idx..])),
+                Some((ch, rest)) => name.first() == Some(ch) && glob(rest, &name[1..]),
+            }
+        }
+        glob(self.pattern.trim_start_matches("::").as_bytes(), scoped_name.as_bytes())
+    }
+}
+
 ///
 #[derive(Debug)]
 pub struct Configuration {
     pub definition: HashMap<String, String>,
     pub verbose: bool,
+    /// Suffix appended to IDL identifiers colliding with Rust keywords;
+    /// if `None`, raw identifiers `r#..` are emitted where possible.
+    pub keyword_suffix: Option<String>,
+    pub naming: NamingConvention,
+    pub chars: CharMapping,
+    /// Derives of the generated structs, unions and enums, besides serde's;
+    /// derives which cannot compile for a type (e.g. `Eq` with float members) are omitted
+    pub derives: Vec<String>,
+    /// Derive serde's `Serialize` and `Deserialize`, and emit serde attributes
+    pub serde: bool,
+    /// Outer attributes of the generated structs, unions and enums, such as `#[non_exhaustive]`
+    pub attributes: Vec<String>,
+    /// Additional derives and attributes for the types matching a pattern
+    pub type_rules: Vec<TypeRule>,
+    /// Rust types replacing IDL types, keyed by the IDL type such as `unsigned long long`,
+    /// `sequence<octet>` or `DDS::Time_t`; a mapped type declaration becomes a type alias
+    pub type_map: HashMap<String, String>,
+    /// Module path providing the Rust types of `native` declarations lacking a type mapping,
+    /// such as `crate::native`; without it, each native type must be mapped
+    pub native_path: Option<String>,
+    /// Emit bounded strings and sequences as `rtps_types::BoundedString<N>`,
+    /// `rtps_types::BoundedWideString<N>` and `rtps_types::BoundedVec<T, N>`, rejecting oversize
+    /// data; otherwise as `String` and `Vec<T>`
+    pub bounded: bool,
+    /// Pointer type of `@external` members
+    pub external: ExternalMapping,
+    /// Implement `rtps_types::Validate` for structs and unions, checking the constraints such
+    /// as `@range`, and validate deserialized values, failing the deserialization on violation
+    pub validate: bool,
+    /// Implement `rtps_cdr::CdrEncode` and `rtps_cdr::CdrDecode`, encoding CDR without serde
+    pub cdr: bool,
+    /// Emit a zero-copy view `FooView<'a>` of each final or appendable struct, reading its
+    /// XCDR1 encoding in place; requires `cdr`
+    pub views: bool,
+    /// Emit `#[repr(C)]` structs laid out in memory as their plain CDR encoding in little
+    /// endian, for the exchange through shared memory; bounded strings and sequences are
+    /// stored at their bound, and unbounded types are rejected
+    pub fixed_layout: bool,
+    /// Emit `core` and `alloc` paths only, for crates without `std` which declare
+    /// `extern crate alloc`; serde's derives are imported from `serde` by its feature `derive`
+    pub no_std: bool,
+    /// Emit bounded strings and sequences as `rtps_types::FixedString<N>` and
+    /// `rtps_types::FixedVec<T, N>`, stored in place without allocating
+    pub heapless: bool,
+    /// Implement `rtps_types::Discoverable` for structs, unions and enums, holding their
+    /// minimal and complete XTypes TypeObject and equivalence hash for the type discovery
+    pub type_objects: bool,
 }
 
 ///
 impl Configuration {
     pub fn new(defs: HashMap<String, String>, verbose: bool) -> Configuration {
-        Configuration { definition: defs, verbose: verbose }
+        Configuration {
+            definition: defs,
+            verbose: verbose,
+            keyword_suffix: None,
+            naming: NamingConvention::Idl,
+            chars: CharMapping::Rust,
+            derives: default_derives(),
+            serde: true,
+            attributes: Vec::new(),
+            type_rules: Vec::new(),
+            type_map: HashMap::new(),
+            native_path: None,
+            bounded: false,
+            external: ExternalMapping::Box,
+            validate: false,
+            cdr: false,
+            views: false,
+            fixed_layout: false,
+            no_std: false,
+            heapless: false,
+            type_objects: false,
+        }
     }
 }
 
 ///
 impl Default for Configuration {
     fn default() -> Configuration {
-        Configuration { definition: HashMap::default(), verbose: false }
+        Configuration {
+            definition: HashMap::default(),
+            verbose: false,
+            keyword_suffix: None,
+            naming: NamingConvention::Idl,
+            chars: CharMapping::Rust,
+            derives: default_derives(),
+            serde: true,
+            attributes: Vec::new(),
+            type_rules: Vec::new(),
+            type_map: HashMap::new(),
+            native_path: None,
+            bounded: false,
+            external: ExternalMapping::Box,
+            validate: false,
+            cdr: false,
+            views: false,
+            fixed_layout: false,
+            no_std: false,
+            heapless: false,
+            type_objects: false,
+        }
     }
 }
 
+/// The derives of the generated types, as before derives were configurable
+fn default_derives() -> Vec<String> {
+    vec!["Clone".to_owned(), "Debug".to_owned()]
+}
+
 ///
 pub fn load_idl(_config: &Configuration, _path: &str) -> Result<String, Error> {
     return Ok("".to_owned());
@@ -71,6 +217,10 @@ struct Context<'i> {
     config: &'i Configuration,
     definitions: HashMap<String, String>,
     root_module: Box<IdlModule>,
+    /// Included file being processed, `None` for the top level input
+    file: Option<String>,
+    /// Annotations applied to the definition being processed
+    annotations: Vec<IdlAnnotation>,
 }
 
 
@@ -80,19 +230,37 @@ impl<'i> Context<'i> {
             config: &config,
             definitions: HashMap::default(),
             root_module: Box::new(IdlModule::new(None, 0)),
+            file: None,
+            annotations: Vec::new(),
         }
     }
 
     ///
-    fn lookup_module(&mut self, scope: &Scope) -> &mut Box<IdlModule> {
+    fn span(&self, pair: &Pair<Rule>) -> Span {
+        let span = pair.clone().into_span();
+        let (line, column) = span.start_pos().line_col();
+        Span {
+            file: self.file.clone(),
+            start: span.start(),
+            end: span.end(),
+            line,
+            column,
+        }
+    }
+
+    /// Find the module of the scope, creating the missing modules on the way
+    fn lookup_module(&mut self, scope: &Scope, span: Option<Span>) -> &mut Box<IdlModule> {
         // Starting from Root traverse the scope-path
         let mut current_module = &mut self.root_module;
         let level = scope.len();
 
         for name in scope {
             let submodule =
-                current_module.modules.entry(name.to_owned()).or_insert(
-                    Box::new(IdlModule::new(Some(name.to_owned()), level)));
+                current_module.modules.entry(name.to_owned()).or_insert_with(|| {
+                    let mut module = IdlModule::new(Some(name.to_owned()), level);
+                    module.span = span.clone().unwrap_or_default();
+                    Box::new(module)
+                });
             current_module = submodule;
         }
 
@@ -102,7 +270,7 @@ impl<'i> Context<'i> {
     ///
     fn add_type_dcl(&mut self, scope: &mut Scope, key: String, type_dcl: Box<IdlTypeDcl>)
                     -> Result<(), IdlError> {
-        let current_module = self.lookup_module(scope);
+        let current_module = self.lookup_module(scope, None);
         current_module.types
             .entry(key)
             .or_insert(type_dcl);
@@ -113,7 +281,7 @@ impl<'i> Context<'i> {
     ///
     fn add_const_dcl(&mut self, scope: &mut Scope, key: String, const_dcl: Box<IdlConstDcl>)
                      -> Result<(), IdlError> {
-        let current_module = self.lookup_module(scope);
+        let current_module = self.lookup_module(scope, None);
         current_module.constants
             .entry(key)
             .or_insert(const_dcl);
@@ -192,7 +360,8 @@ impl<'i> Context<'i> {
      /// array_declarator = { identifier ~ fixed_array_size+ }
      /// simple_declarator = { identifier }
     pub fn read_struct_member_declarator(&mut self, scope: &mut Scope,
-                                         pair: &Pair<Rule>, type_spec: &Box<IdlTypeSpec>)
+                                         pair: &Pair<Rule>, type_spec: &Box<IdlTypeSpec>,
+                                         annotations: &[IdlAnnotation])
                                          -> Result<Box<IdlStructMember>, IdlError>
     {
         let decl = pair.clone().into_inner().next().unwrap();
@@ -209,6 +378,8 @@ impl<'i> Context<'i> {
                 let member_dcl = Box::new(IdlStructMember {
                     id: id,
                     type_spec: type_spec.clone(),
+                    annotations: annotations.to_vec(),
+                    span: self.span(pair),
                 });
 
                 Ok(member_dcl)
@@ -231,6 +402,8 @@ impl<'i> Context<'i> {
                 let member_dcl = Box::new(IdlStructMember {
                     id: id,
                     type_spec: array_type_spec,
+                    annotations: annotations.to_vec(),
+                    span: self.span(pair),
                 });
 
                 Ok(member_dcl)
@@ -241,17 +414,20 @@ impl<'i> Context<'i> {
     }
 
 
-    // member = { type_spec ~ declarators ~ ";" }
+    // member = { annotation_appl* ~ type_spec ~ declarators ~ ";" }
     // declarators = { declarator ~ ("," ~ declarator )* }
     // declarator = { array_declarator | simple_declarator }
     fn read_struct_member(&mut self, scope: &mut Scope,
                           pair: &Pair<Rule>) -> Result<Vec<Box<IdlStructMember>>, IdlError>
     {
-        let mut iter = pair.clone().into_inner();
         if self.config.verbose {
             print!("{:indent$}", "", indent = 3 * scope.len());
             println!("{:?}", pair.as_rule());
         }
+        let annotations = self.read_annotations(scope, pair)?;
+        let mut iter = pair.clone().into_inner()
+            .filter(|p| p.as_rule() != Rule::annotation_appl);
+
         let type_spec: Box<IdlTypeSpec> = self.read_type_spec(scope, &iter.next().unwrap())?;
 
         // skip rule 'declarators' and parse sibblings `declarator'
@@ -259,7 +435,8 @@ impl<'i> Context<'i> {
 
         let members: Result<Vec<Box<IdlStructMember>>, IdlError> =
             declarators.map(|declarator|
-                self.read_struct_member_declarator(scope, &declarator, &type_spec)).collect();
+                self.read_struct_member_declarator(scope, &declarator, &type_spec,
+                                                   &annotations)).collect();
 
         members
     }
@@ -274,11 +451,68 @@ impl<'i> Context<'i> {
             println!("{:?}", pair.as_rule());
         }
         match pair.as_rule() {
-            Rule::identifier | Rule::enumerator=> Ok(pair.as_str().to_owned()),
+            Rule::identifier => Ok(pair.as_str().to_owned()),
             _ => Err(IdlError::ExpectedItem(Rule::identifier)),
         }
     }
 
+    /// annotation_appl = { "@" ~ scoped_name ~ ("(" ~ annotation_appl_params ~ ")")? }
+    /// annotation_appl_params = { annotation_appl_param ~ ("," ~ annotation_appl_param)* | const_expr }
+    /// annotation_appl_param = { identifier ~ "=" ~ const_expr }
+    fn read_annotation_appl(&mut self, scope: &mut Scope,
+                            pair: &Pair<Rule>) -> Result<IdlAnnotation, IdlError>
+    {
+        let mut iter = pair.clone().into_inner();
+        if self.config.verbose {
+            print!("{:indent$}", "", indent = 3 * scope.len());
+            println!("{:?} '{}'", pair.as_rule(), pair.as_str());
+        }
+        let name = self.read_scoped_name(scope, &iter.next().unwrap())?;
+        let mut params = Vec::new();
+
+        if let Some(params_pair) = iter.next() {
+            for p in params_pair.into_inner() {
+                match p.as_rule() {
+                    Rule::annotation_appl_param => {
+                        let mut param_iter = p.clone().into_inner();
+                        let id = self.read_identifier(scope, &param_iter.next().unwrap())?;
+                        let value = self.read_const_expr(scope, &param_iter.next().unwrap())?;
+                        params.push(IdlAnnotationParam { id: Some(id), value });
+                    }
+                    _ => {
+                        let value = self.read_const_expr(scope, &p)?;
+                        params.push(IdlAnnotationParam { id: None, value });
+                    }
+                }
+            }
+        }
+
+        Ok(IdlAnnotation { name, params })
+    }
+
+    /// Read the annotations among the children of `pair`
+    fn read_annotations(&mut self, scope: &mut Scope,
+                        pair: &Pair<Rule>) -> Result<Vec<IdlAnnotation>, IdlError>
+    {
+        pair.clone().into_inner()
+            .filter(|p| p.as_rule() == Rule::annotation_appl)
+            .map(|p| self.read_annotation_appl(scope, &p))
+            .collect()
+    }
+
+    /// enumerator = { annotation_appl* ~ identifier }
+    fn read_enumerator(&mut self, scope: &mut Scope,
+                       pair: &Pair<Rule>) -> Result<IdlEnumerator, IdlError>
+    {
+        let annotations = self.read_annotations(scope, pair)?;
+        let ident = pair.clone().into_inner()
+            .find(|p| p.as_rule() == Rule::identifier)
+            .ok_or(IdlError::ExpectedItem(Rule::identifier))?;
+        let id = self.read_identifier(scope, &ident)?;
+
+        Ok(IdlEnumerator { id, annotations, span: self.span(pair) })
+    }
+
     /// scoped_name = { "::"? ~ identifier ~ ("::" ~ identifier)* }
     fn read_scoped_name(&mut self, scope: &mut Scope,
                         pair: &Pair<Rule>) -> Result<IdlScopedName, IdlError>
@@ -439,8 +673,11 @@ impl<'i> Context<'i> {
             Rule::simple_declarator => {
                 let id = self.read_identifier(scope, &iter.next().unwrap())?;
 
-                let type_dcl = Box::new(
-                    IdlTypeDcl(IdlTypeDclKind::TypeDcl(id.clone(), type_spec.clone())));
+                let type_dcl = Box::new(IdlTypeDcl {
+                    kind: IdlTypeDclKind::TypeDcl(id.clone(), type_spec.clone()),
+                    annotations: self.annotations.clone(),
+                    span: self.span(pair),
+                });
                 self.add_type_dcl(scope, id, type_dcl)
             }
 
@@ -459,8 +696,11 @@ impl<'i> Context<'i> {
                         .collect();
                 let array_type_spec =
                     Box::new(IdlTypeSpec::ArrayType(type_spec.clone(), array_sizes?));
-                let type_dcl = Box::new(
-                    IdlTypeDcl(IdlTypeDclKind::TypeDcl(id, array_type_spec)));
+                let type_dcl = Box::new(IdlTypeDcl {
+                    kind: IdlTypeDclKind::TypeDcl(id, array_type_spec),
+                    annotations: self.annotations.clone(),
+                    span: self.span(pair),
+                });
                 self.add_type_dcl(scope, key, type_dcl)
             }
 
@@ -479,13 +719,29 @@ impl<'i> Context<'i> {
             println!("{:?}", pair.as_rule());
         }
         match pair.as_rule() {
+            // definition = { annotation_appl* ~ module_dcl ~ ";" | annotation_appl* ~ const_dcl ~ ";" | ... }
+            Rule::definition => {
+                self.annotations = self.read_annotations(scope, pair)?;
+
+                for p in iter.filter(|p| p.as_rule() != Rule::annotation_appl) {
+                    let _ = self.process::<L>(scope, loader, &p);
+                }
+
+                self.annotations.clear();
+                Ok(())
+            }
+
             // module_dcl = { "module" ~ identifier ~ "{" ~ definition* ~ "}" }
             Rule::module_dcl => {
+                // annotations of modules are not retained
+                self.annotations.clear();
+
                 let id = iter.next().unwrap().as_str();
 
                 scope.push(id.to_owned());
 
-                let _ = self.lookup_module(scope);
+                let span = self.span(pair);
+                let _ = self.lookup_module(scope, Some(span));
 
                 for p in iter {
                     let _ = self.process::<L>(scope, loader, &p);
@@ -508,8 +764,11 @@ impl<'i> Context<'i> {
                 let m2 = m1?;
                 let members = m2.into_iter().flatten().collect::<Vec<_>>();
 
-                let typedcl = Box::new(IdlTypeDcl(IdlTypeDclKind::StructDcl(id,
-                                                                            members)));
+                let typedcl = Box::new(IdlTypeDcl {
+                    kind: IdlTypeDclKind::StructDcl(id, members),
+                    annotations: std::mem::take(&mut self.annotations),
+                    span: self.span(pair),
+                });
                 self.add_type_dcl(scope, key, typedcl)
             }
 
@@ -519,9 +778,11 @@ impl<'i> Context<'i> {
                 let key = id.to_owned();
                 let switch_type_spec = self.read_switch_type_spec(scope, &iter.next().unwrap())?;
                 let switch_body = self.read_switch_body(scope, &iter.next().unwrap())?;
-                let union_def = Box::new(
-                    IdlTypeDcl(IdlTypeDclKind::UnionDcl(
-                        id, switch_type_spec, switch_body)));
+                let union_def = Box::new(IdlTypeDcl {
+                    kind: IdlTypeDclKind::UnionDcl(id, switch_type_spec, switch_body),
+                    annotations: std::mem::take(&mut self.annotations),
+                    span: self.span(pair),
+                });
 
                 self.add_type_dcl(scope, key, union_def)
             }
@@ -540,18 +801,34 @@ impl<'i> Context<'i> {
             }
 
             // enum_dcl = { "enum" ~ identifier ~ "{" ~ enumerator ~ ("," ~ enumerator)* ~ ","? ~ "}" }
-            // enumerator = { identifier }
+            // enumerator = { annotation_appl* ~ identifier }
             Rule::enum_dcl => {
                 let id = iter.next().unwrap().as_str().to_owned();
                 let key = id.clone();
                 let enums: Result<Vec<_>, IdlError> =
-                    iter.map(|p| self.read_identifier(scope, &p))
+                    iter.map(|p| self.read_enumerator(scope, &p))
                         .collect();
 
-                let typedcl = Box::new(
-                    IdlTypeDcl(IdlTypeDclKind::EnumDcl(id, enums?)));
+                let typedcl = Box::new(IdlTypeDcl {
+                    kind: IdlTypeDclKind::EnumDcl(id, enums?),
+                    annotations: std::mem::take(&mut self.annotations),
+                    span: self.span(pair),
+                });
+                self.add_type_dcl(scope, key, typedcl)
+            }
+            // native_dcl = { "native" ~ simple_declarator }
+            Rule::native_dcl => {
+                let decl = iter.next().unwrap();
+                let id = self.read_identifier(scope, &decl.into_inner().next().unwrap())?;
+                let key = id.clone();
+                let typedcl = Box::new(IdlTypeDcl {
+                    kind: IdlTypeDclKind::NativeDcl(id),
+                    annotations: std::mem::take(&mut self.annotations),
+                    span: self.span(pair),
+                });
                 self.add_type_dcl(scope, key, typedcl)
             }
+
             // const_dcl = { "const" ~ const_type ~ identifier ~ "=" ~ const_expr }
             Rule::const_dcl => {
                 let type_spec =
@@ -559,7 +836,13 @@ impl<'i> Context<'i> {
                 let id = self.read_identifier(scope, &iter.next().unwrap())?;
                 let key = id.clone();
                 let const_expr = self.read_const_expr(scope, &iter.next().unwrap())?;
-                let const_dcl = Box::new(IdlConstDcl { id: id, typedcl: type_spec, value: const_expr });
+                let const_dcl = Box::new(IdlConstDcl {
+                    id,
+                    typedcl: type_spec,
+                    value: const_expr,
+                    annotations: std::mem::take(&mut self.annotations),
+                    span: self.span(pair),
+                });
                 self.add_const_dcl(scope, key, const_dcl)
             }
 
@@ -575,9 +858,11 @@ impl<'i> Context<'i> {
                             IdlParser::parse(Rule::specification, &data)
                                 .map_err(|e| IdlError::ErrorMesg(e.to_string()))?;
 
+                        let including_file = self.file.replace(fname.to_owned());
                         for p in idl {
                             self.process::<L>(scope, loader, &p)?;
                         }
+                        self.file = including_file;
                     }
                     _ => {}
                 }
@@ -599,7 +884,8 @@ impl<'i> Context<'i> {
     /// array_declarator = { identifier ~ fixed_array_size+ }
     /// simple_declarator = { identifier }
     pub fn read_switch_element_declarator(&mut self, scope: &mut Scope,
-                                          pair: &Pair<Rule>, type_spec: &Box<IdlTypeSpec>)
+                                          pair: &Pair<Rule>, type_spec: &Box<IdlTypeSpec>,
+                                          annotations: Vec<IdlAnnotation>)
                                           -> Result<Box<IdlSwitchElement>, IdlError>
     {
         let decl = pair.clone().into_inner().next().unwrap();
@@ -616,6 +902,8 @@ impl<'i> Context<'i> {
                 let member_dcl = Box::new(IdlSwitchElement {
                     id: id,
                     type_spec: type_spec.clone(),
+                    annotations,
+                    span: self.span(pair),
                 });
 
                 Ok(member_dcl)
@@ -638,6 +926,8 @@ impl<'i> Context<'i> {
                 let member_dcl = Box::new(IdlSwitchElement {
                     id: id,
                     type_spec: array_type_spec,
+                    annotations,
+                    span: self.span(pair),
                 });
 
                 Ok(member_dcl)
@@ -647,19 +937,23 @@ impl<'i> Context<'i> {
         }
     }
 
-    /// element_spec = { type_spec ~ declarator }
+    /// element_spec = { annotation_appl* ~ type_spec ~ declarator }
     fn read_switch_element_spec(&mut self, scope: &mut Scope,
                                 pair: &Pair<Rule>) -> Result<Box<IdlSwitchElement>, IdlError>
     {
-        let mut iter = pair.clone().into_inner();
         if self.config.verbose {
             print!("{:indent$}", "", indent = 3 * scope.len());
             println!("{:?}", pair.as_rule());
         }
+        let annotations = self.read_annotations(scope, pair)?;
+        let mut iter = pair.clone().into_inner()
+            .filter(|p| p.as_rule() != Rule::annotation_appl);
+
         let type_spec: Box<IdlTypeSpec> = self.read_type_spec(scope, &iter.next().unwrap())?;
 
         let element_spec =
-            self.read_switch_element_declarator(scope, &iter.next().unwrap(), &type_spec);
+            self.read_switch_element_declarator(scope, &iter.next().unwrap(), &type_spec,
+                                                annotations);
 
         element_spec
     }
@@ -736,6 +1030,7 @@ impl<'i> Context<'i> {
         Ok(IdlSwitchCase {
             labels: case_labels?,
             elem_spec: elem_spec?,
+            span: self.span(pair),
         })
     }
 
@@ -744,13 +1039,25 @@ impl<'i> Context<'i> {
 }
 
 
-///
-///
-pub fn generate_with_loader<W: Write, L: IdlLoader>(
-    out: &mut W,
+/// Parse a standalone IDL type specification, such as `sequence<octet, 16>`
+pub(crate) fn parse_type_spec(config: &Configuration, text: &str) -> Result<Box<IdlTypeSpec>, IdlError> {
+    let text = text.trim();
+    let invalid = || IdlError::ErrorMesg(format!("'{}' is not an IDL type", text));
+    let pair = IdlParser::parse(Rule::type_spec, text)
+        .map_err(|_| invalid())?
+        .next()
+        .ok_or_else(invalid)?;
+    if pair.clone().into_span().end() != text.len() {
+        return Err(invalid());
+    }
+    Context::new(config).read_type_spec(&mut Scope::new(), &pair)
+}
+
+/// Parse the IDL specification into its AST, includes are resolved by the loader
+pub fn parse_with_loader<L: IdlLoader>(
     loader: &mut L,
     config: &Configuration,
-    idldecl: &str) -> Result<(), IdlError> {
+    idldecl: &str) -> Result<Specification, IdlError> {
     let mut ctx = Context::new(config);
 
     let idl: Pairs<Rule> =
@@ -763,8 +1070,29 @@ pub fn generate_with_loader<W: Write, L: IdlLoader>(
         let _ = ctx.process::<L>(&mut scope, loader, &p);
     }
 
-    let _ = out.write(MODULE_PRELUDE);
-    ctx.root_module.as_mut().write(out, 0).map_err(|_| IdlError::InternalError)
+    Ok(Specification { root: *ctx.root_module })
+}
+
+///
+///
+pub fn generate_with_loader<W: Write, L: IdlLoader>(
+    out: &mut W,
+    loader: &mut L,
+    config: &Configuration,
+    idldecl: &str) -> Result<(), IdlError> {
+    generate_with_generator(out, loader, &RustGenerator, config, idldecl)
+}
+
+/// Parse the IDL specification and emit the output of the given backend
+pub fn generate_with_generator<W: Write, L: IdlLoader>(
+    out: &mut W,
+    loader: &mut L,
+    generator: &dyn Generator,
+    config: &Configuration,
+    idldecl: &str) -> Result<(), IdlError> {
+    let spec = parse_with_loader(loader, config, idldecl)?;
+
+    generator.generate(out, &spec, config)
 }
 
 
@@ -812,4 +1140,12 @@ pub fn generate_with_search_path<W: Write>(out: &mut W, search_path: Vec<String>
     let mut loader = Loader::new(search_path);
 
     generate_with_loader(out, &mut loader, config, data)
-}
\ No newline at end of file
+}
+
+/// Parse the IDL specification into its AST, includes are searched along the search path
+pub fn parse_with_search_path(search_path: Vec<String>,
+                              config: &Configuration, data: &str) -> Result<Specification, IdlError> {
+    let mut loader = Loader::new(search_path);
+
+    parse_with_loader(&mut loader, config, data)
+}
@@ -0,0 +1,374 @@
+// Copyright (C) 2019  Frank Rehberger
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>
+
+//! Traversal of the AST
+//!
+//! Each `visit_*` method of the traits `Visitor` and `VisitorMut` defaults to
+//! the corresponding `walk_*` function, which visits the children of the node.
+//! Implementors override the methods of interest and call the `walk_*`
+//! function to continue the traversal below the node.
+use crate::ast::*;
+
+/// Traversal of a shared AST
+pub trait Visitor<'ast> {
+    fn visit_specification(&mut self, spec: &'ast Specification) {
+        walk_specification(self, spec)
+    }
+
+    fn visit_module(&mut self, module: &'ast IdlModule) {
+        walk_module(self, module)
+    }
+
+    fn visit_type_dcl(&mut self, type_dcl: &'ast IdlTypeDcl) {
+        walk_type_dcl(self, type_dcl)
+    }
+
+    fn visit_struct_member(&mut self, member: &'ast IdlStructMember) {
+        walk_struct_member(self, member)
+    }
+
+    fn visit_switch_case(&mut self, case: &'ast IdlSwitchCase) {
+        walk_switch_case(self, case)
+    }
+
+    fn visit_switch_element(&mut self, element: &'ast IdlSwitchElement) {
+        walk_switch_element(self, element)
+    }
+
+    fn visit_enumerator(&mut self, enumerator: &'ast IdlEnumerator) {
+        walk_enumerator(self, enumerator)
+    }
+
+    fn visit_const_dcl(&mut self, const_dcl: &'ast IdlConstDcl) {
+        walk_const_dcl(self, const_dcl)
+    }
+
+    fn visit_annotation(&mut self, annotation: &'ast IdlAnnotation) {
+        walk_annotation(self, annotation)
+    }
+
+    fn visit_type_spec(&mut self, type_spec: &'ast IdlTypeSpec) {
+        walk_type_spec(self, type_spec)
+    }
+
+    fn visit_value_expr(&mut self, expr: &'ast IdlValueExpr) {
+        walk_value_expr(self, expr)
+    }
+
+    fn visit_scoped_name(&mut self, _name: &'ast IdlScopedName) {}
+}
+
+/// Visits the root module of the specification
+pub fn walk_specification<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, spec: &'ast Specification) {
+    v.visit_module(&spec.root);
+}
+
+/// Visits the types, submodules and constants, in the order these are emitted
+pub fn walk_module<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, module: &'ast IdlModule) {
+    for type_dcl in module.types.values() {
+        v.visit_type_dcl(type_dcl);
+    }
+    for submodule in module.modules.values() {
+        v.visit_module(submodule);
+    }
+    for const_dcl in module.constants.values() {
+        v.visit_const_dcl(const_dcl);
+    }
+}
+
+/// Visits the annotations, members, cases or enumerators of a type declaration
+pub fn walk_type_dcl<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, type_dcl: &'ast IdlTypeDcl) {
+    for annotation in &type_dcl.annotations {
+        v.visit_annotation(annotation);
+    }
+    match type_dcl.kind {
+        IdlTypeDclKind::None | IdlTypeDclKind::NativeDcl(_) => {}
+        IdlTypeDclKind::TypeDcl(_, ref type_spec) => v.visit_type_spec(type_spec),
+        IdlTypeDclKind::StructDcl(_, ref members) => {
+            for member in members {
+                v.visit_struct_member(member);
+            }
+        }
+        IdlTypeDclKind::UnionDcl(_, ref switch_type_spec, ref cases) => {
+            v.visit_type_spec(switch_type_spec);
+            for case in cases {
+                v.visit_switch_case(case);
+            }
+        }
+        IdlTypeDclKind::EnumDcl(_, ref enumerators) => {
+            for enumerator in enumerators {
+                v.visit_enumerator(enumerator);
+            }
+        }
+    }
+}
+
+/// Visits the annotations and the type of a struct member
+pub fn walk_struct_member<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, member: &'ast IdlStructMember) {
+    for annotation in &member.annotations {
+        v.visit_annotation(annotation);
+    }
+    v.visit_type_spec(&member.type_spec);
+}
+
+/// Visits the labels and the element of a union case
+pub fn walk_switch_case<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, case: &'ast IdlSwitchCase) {
+    for label in &case.labels {
+        if let IdlSwitchLabel::Label(ref expr) = label {
+            v.visit_value_expr(expr);
+        }
+    }
+    v.visit_switch_element(&case.elem_spec);
+}
+
+/// Visits the annotations and the type of a union element
+pub fn walk_switch_element<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, element: &'ast IdlSwitchElement) {
+    for annotation in &element.annotations {
+        v.visit_annotation(annotation);
+    }
+    v.visit_type_spec(&element.type_spec);
+}
+
+/// Visits the annotations of an enumerator
+pub fn walk_enumerator<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, enumerator: &'ast IdlEnumerator) {
+    for annotation in &enumerator.annotations {
+        v.visit_annotation(annotation);
+    }
+}
+
+/// Visits the type and the value of a constant declaration
+pub fn walk_const_dcl<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, const_dcl: &'ast IdlConstDcl) {
+    for annotation in &const_dcl.annotations {
+        v.visit_annotation(annotation);
+    }
+    v.visit_type_spec(&const_dcl.typedcl);
+    v.visit_value_expr(&const_dcl.value);
+}
+
+/// Visits the name and the parameter values of an annotation
+pub fn walk_annotation<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, annotation: &'ast IdlAnnotation) {
+    v.visit_scoped_name(&annotation.name);
+    for param in &annotation.params {
+        v.visit_value_expr(&param.value);
+    }
+}
+
+/// Visits the element types, bounds and scoped names of a type specification
+pub fn walk_type_spec<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, type_spec: &'ast IdlTypeSpec) {
+    match type_spec {
+        IdlTypeSpec::ArrayType(ref type_spec, ref dims) => {
+            v.visit_type_spec(type_spec);
+            for dim in dims {
+                v.visit_value_expr(dim);
+            }
+        }
+        IdlTypeSpec::SequenceType(ref type_spec, ref bound) => {
+            v.visit_type_spec(type_spec);
+            if let Some(bound) = bound {
+                v.visit_value_expr(bound);
+            }
+        }
+        IdlTypeSpec::StringType(Some(ref bound))
+        | IdlTypeSpec::WideStringType(Some(ref bound)) => v.visit_value_expr(bound),
+        IdlTypeSpec::ScopedName(ref name) => v.visit_scoped_name(name),
+        _ => {}
+    }
+}
+
+/// Visits the operands of a constant expression
+pub fn walk_value_expr<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, expr: &'ast IdlValueExpr) {
+    match expr {
+        IdlValueExpr::UnaryOp(_, ref expr)
+        | IdlValueExpr::BinaryOp(_, ref expr)
+        | IdlValueExpr::Brace(ref expr) => v.visit_value_expr(expr),
+        IdlValueExpr::Expr(ref expr1, ref expr2) => {
+            v.visit_value_expr(expr1);
+            v.visit_value_expr(expr2);
+        }
+        IdlValueExpr::ScopedName(ref name) => v.visit_scoped_name(name),
+        _ => {}
+    }
+}
+
+/// Traversal of a mutable AST, permitting to rewrite nodes in place
+pub trait VisitorMut {
+    fn visit_specification_mut(&mut self, spec: &mut Specification) {
+        walk_specification_mut(self, spec)
+    }
+
+    fn visit_module_mut(&mut self, module: &mut IdlModule) {
+        walk_module_mut(self, module)
+    }
+
+    fn visit_type_dcl_mut(&mut self, type_dcl: &mut IdlTypeDcl) {
+        walk_type_dcl_mut(self, type_dcl)
+    }
+
+    fn visit_struct_member_mut(&mut self, member: &mut IdlStructMember) {
+        walk_struct_member_mut(self, member)
+    }
+
+    fn visit_switch_case_mut(&mut self, case: &mut IdlSwitchCase) {
+        walk_switch_case_mut(self, case)
+    }
+
+    fn visit_switch_element_mut(&mut self, element: &mut IdlSwitchElement) {
+        walk_switch_element_mut(self, element)
+    }
+
+    fn visit_enumerator_mut(&mut self, enumerator: &mut IdlEnumerator) {
+        walk_enumerator_mut(self, enumerator)
+    }
+
+    fn visit_const_dcl_mut(&mut self, const_dcl: &mut IdlConstDcl) {
+        walk_const_dcl_mut(self, const_dcl)
+    }
+
+    fn visit_annotation_mut(&mut self, annotation: &mut IdlAnnotation) {
+        walk_annotation_mut(self, annotation)
+    }
+
+    fn visit_type_spec_mut(&mut self, type_spec: &mut IdlTypeSpec) {
+        walk_type_spec_mut(self, type_spec)
+    }
+
+    fn visit_value_expr_mut(&mut self, expr: &mut IdlValueExpr) {
+        walk_value_expr_mut(self, expr)
+    }
+
+    fn visit_scoped_name_mut(&mut self, _name: &mut IdlScopedName) {}
+}
+
+/// Visits the root module of the specification, mutably
+pub fn walk_specification_mut<V: VisitorMut + ?Sized>(v: &mut V, spec: &mut Specification) {
+    v.visit_module_mut(&mut spec.root);
+}
+
+/// Visits the types, submodules and constants, in the order these are emitted
+pub fn walk_module_mut<V: VisitorMut + ?Sized>(v: &mut V, module: &mut IdlModule) {
+    for entry in module.types.entries() {
+        v.visit_type_dcl_mut(entry.into_mut());
+    }
+    for entry in module.modules.entries() {
+        v.visit_module_mut(entry.into_mut());
+    }
+    for entry in module.constants.entries() {
+        v.visit_const_dcl_mut(entry.into_mut());
+    }
+}
+
+/// Visits the annotations, members, cases or enumerators of a type declaration, mutably
+pub fn walk_type_dcl_mut<V: VisitorMut + ?Sized>(v: &mut V, type_dcl: &mut IdlTypeDcl) {
+    for annotation in &mut type_dcl.annotations {
+        v.visit_annotation_mut(annotation);
+    }
+    match type_dcl.kind {
+        IdlTypeDclKind::None | IdlTypeDclKind::NativeDcl(_) => {}
+        IdlTypeDclKind::TypeDcl(_, ref mut type_spec) => v.visit_type_spec_mut(type_spec),
+        IdlTypeDclKind::StructDcl(_, ref mut members) => {
+            for member in members {
+                v.visit_struct_member_mut(member);
+            }
+        }
+        IdlTypeDclKind::UnionDcl(_, ref mut switch_type_spec, ref mut cases) => {
+            v.visit_type_spec_mut(switch_type_spec);
+            for case in cases {
+                v.visit_switch_case_mut(case);
+            }
+        }
+        IdlTypeDclKind::EnumDcl(_, ref mut enumerators) => {
+            for enumerator in enumerators {
+                v.visit_enumerator_mut(enumerator);
+            }
+        }
+    }
+}
+
+/// Visits the annotations and the type of a struct member, mutably
+pub fn walk_struct_member_mut<V: VisitorMut + ?Sized>(v: &mut V, member: &mut IdlStructMember) {
+    for annotation in &mut member.annotations {
+        v.visit_annotation_mut(annotation);
+    }
+    v.visit_type_spec_mut(&mut member.type_spec);
+}
+
+/// Visits the labels and the element of a union case, mutably
+pub fn walk_switch_case_mut<V: VisitorMut + ?Sized>(v: &mut V, case: &mut IdlSwitchCase) {
+    for label in &mut case.labels {
+        if let IdlSwitchLabel::Label(ref mut expr) = label {
+            v.visit_value_expr_mut(expr);
+        }
+    }
+    v.visit_switch_element_mut(&mut case.elem_spec);
+}
+
+/// Visits the annotations and the type of a union element, mutably
+pub fn walk_switch_element_mut<V: VisitorMut + ?Sized>(v: &mut V, element: &mut IdlSwitchElement) {
+    for annotation in &mut element.annotations {
+        v.visit_annotation_mut(annotation);
+    }
+    v.visit_type_spec_mut(&mut element.type_spec);
+}
+
+/// Visits the annotations of an enumerator, mutably
+pub fn walk_enumerator_mut<V: VisitorMut + ?Sized>(v: &mut V, enumerator: &mut IdlEnumerator) {
+    for annotation in &mut enumerator.annotations {
+        v.visit_annotation_mut(annotation);
+    }
+}
+
+/// Visits the type and the value of a constant declaration, mutably
+pub fn walk_const_dcl_mut<V: VisitorMut + ?Sized>(v: &mut V, const_dcl: &mut IdlConstDcl) {
+    for annotation in &mut const_dcl.annotations {
+        v.visit_annotation_mut(annotation);
+    }
+    v.visit_type_spec_mut(&mut const_dcl.typedcl);
+    v.visit_value_expr_mut(&mut const_dcl.value);
+}
+
+/// Visits the name and the parameter values of an annotation, mutably
+pub fn walk_annotation_mut<V: VisitorMut + ?Sized>(v: &mut V, annotation: &mut IdlAnnotation) {
+    v.visit_scoped_name_mut(&mut annotation.name);
+    for param in &mut annotation.params {
+        v.visit_value_expr_mut(&mut param.value);
+    }
+}
+
+/// Visits the element types, bounds and scoped names of a type specification, mutably
+pub fn walk_type_spec_mut<V: VisitorMut + ?Sized>(v: &mut V, type_spec: &mut IdlTypeSpec) {
+    match type_spec {
+        IdlTypeSpec::ArrayType(ref mut type_spec, ref mut dims) => {
+            v.visit_type_spec_mut(type_spec);
+            for dim in dims {
+                v.visit_value_expr_mut(dim);
+            }
+        }
+        IdlTypeSpec::SequenceType(ref mut type_spec, ref mut bound) => {
+            v.visit_type_spec_mut(type_spec);
+            if let Some(bound) = bound {
+                v.visit_value_expr_mut(bound);
+            }
+        }
+        IdlTypeSpec::StringType(Some(ref mut bound))
+        | IdlTypeSpec::WideStringType(Some(ref mut bound)) => v.visit_value_expr_mut(bound),
+        IdlTypeSpec::ScopedName(ref mut name) => v.visit_scoped_name_mut(name),
+        _ => {}
+    }
+}
+
+/// Visits the operands of a constant expression, mutably
+pub fn walk_value_expr_mut<V: VisitorMut + ?Sized>(v: &mut V, expr: &mut IdlValueExpr) {
+    match expr {
+        IdlValueExpr::UnaryOp(_, ref mut expr)
+        | IdlValueExpr::BinaryOp(_, ref mut expr)
+        | IdlValueExpr::Brace(ref mut expr) => v.visit_value_expr_mut(expr),
+        IdlValueExpr::Expr(ref mut expr1, ref mut expr2) => {
+            v.visit_value_expr_mut(expr1);
+            v.visit_value_expr_mut(expr2);
+        }
+        IdlValueExpr::ScopedName(ref mut name) => v.visit_scoped_name_mut(name),
+        _ => {}
+    }
+}
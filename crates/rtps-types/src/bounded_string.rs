@@ -0,0 +1,215 @@
+// Copyright (C) 2019  Frank Rehberger
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>
+
+//! Strings of IDL types `string<N>` and `wstring<N>`
+//!
+//! The bound of a `string` counts the bytes of its CDR encoding, the bound of a
+//! `wstring` of Rust characters counts characters.
+use alloc::{borrow::ToOwned, string::String};
+use core::borrow::Borrow;
+use core::convert::TryFrom;
+use core::fmt;
+use core::ops::{Deref, DerefMut};
+use crate::BoundError;
+
+/// Declares a string holding at most `N` units, as counted by `$len`
+macro_rules! bounded_string {
+    ($(#[$attr:meta])* $name:ident, $unit:literal, $len:expr) => {
+        $(#[$attr])*
+        #[derive(Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub struct $name<const N: usize> {
+            inner: String,
+        }
+
+        impl<const N: usize> $name<N> {
+            #[doc = concat!("The maximum length in ", $unit)]
+            pub const BOUND: usize = N;
+
+            /// An empty string
+            pub fn new() -> Self {
+                $name { inner: String::new() }
+            }
+
+            fn check(len: usize) -> Result<(), BoundError> {
+                if len > N {
+                    Err(BoundError { bound: N, len })
+                } else {
+                    Ok(())
+                }
+            }
+
+            /// Append the character, failing if the bound would be exceeded
+            pub fn push(&mut self, ch: char) -> Result<(), BoundError> {
+                Self::check($len(&self.inner) + $len(ch.encode_utf8(&mut [0; 4])))?;
+                self.inner.push(ch);
+                Ok(())
+            }
+
+            /// Append the string, failing if the bound would be exceeded
+            pub fn push_str(&mut self, s: &str) -> Result<(), BoundError> {
+                Self::check($len(&self.inner) + $len(s))?;
+                self.inner.push_str(s);
+                Ok(())
+            }
+
+            /// Insert the character at the byte position, failing if the bound would be exceeded
+            pub fn insert(&mut self, idx: usize, ch: char) -> Result<(), BoundError> {
+                Self::check($len(&self.inner) + $len(ch.encode_utf8(&mut [0; 4])))?;
+                self.inner.insert(idx, ch);
+                Ok(())
+            }
+
+            /// Insert the string at the byte position, failing if the bound would be exceeded
+            pub fn insert_str(&mut self, idx: usize, s: &str) -> Result<(), BoundError> {
+                Self::check($len(&self.inner) + $len(s))?;
+                self.inner.insert_str(idx, s);
+                Ok(())
+            }
+
+            /// Remove the last character
+            pub fn pop(&mut self) -> Option<char> {
+                self.inner.pop()
+            }
+
+            /// Shorten the string to `len` bytes
+            pub fn truncate(&mut self, len: usize) {
+                self.inner.truncate(len)
+            }
+
+            pub fn clear(&mut self) {
+                self.inner.clear()
+            }
+
+            pub fn as_str(&self) -> &str {
+                &self.inner
+            }
+
+            pub fn into_string(self) -> String {
+                self.inner
+            }
+        }
+
+        impl<const N: usize> TryFrom<String> for $name<N> {
+            type Error = BoundError;
+
+            fn try_from(s: String) -> Result<Self, BoundError> {
+                Self::check($len(&s))?;
+                Ok($name { inner: s })
+            }
+        }
+
+        impl<'a, const N: usize> TryFrom<&'a str> for $name<N> {
+            type Error = BoundError;
+
+            fn try_from(s: &'a str) -> Result<Self, BoundError> {
+                Self::check($len(s))?;
+                Ok($name { inner: s.to_owned() })
+            }
+        }
+
+        impl<const N: usize> From<$name<N>> for String {
+            fn from(s: $name<N>) -> String {
+                s.inner
+            }
+        }
+
+        impl<const N: usize> Deref for $name<N> {
+            type Target = str;
+
+            fn deref(&self) -> &str {
+                &self.inner
+            }
+        }
+
+        // the length of `str` cannot be changed
+        impl<const N: usize> DerefMut for $name<N> {
+            fn deref_mut(&mut self) -> &mut str {
+                &mut self.inner
+            }
+        }
+
+        impl<const N: usize> AsRef<str> for $name<N> {
+            fn as_ref(&self) -> &str {
+                &self.inner
+            }
+        }
+
+        impl<const N: usize> Borrow<str> for $name<N> {
+            fn borrow(&self) -> &str {
+                &self.inner
+            }
+        }
+
+        impl<const N: usize> PartialEq<str> for $name<N> {
+            fn eq(&self, other: &str) -> bool {
+                self.inner == other
+            }
+        }
+
+        impl<'a, const N: usize> PartialEq<&'a str> for $name<N> {
+            fn eq(&self, other: &&'a str) -> bool {
+                self.inner == *other
+            }
+        }
+
+        impl<const N: usize> fmt::Debug for $name<N> {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                fmt::Debug::fmt(&self.inner, f)
+            }
+        }
+
+        impl<const N: usize> fmt::Display for $name<N> {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                fmt::Display::fmt(&self.inner, f)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<const N: usize> serde::Serialize for $name<N> {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(&self.inner)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de, const N: usize> serde::Deserialize<'de> for $name<N> {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                struct Visitor<const N: usize>;
+
+                impl<'de, const N: usize> serde::de::Visitor<'de> for Visitor<N> {
+                    type Value = $name<N>;
+
+                    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                        write!(f, concat!("a string of at most {} ", $unit), N)
+                    }
+
+                    fn visit_str<E: serde::de::Error>(self, s: &str) -> Result<Self::Value, E> {
+                        $name::try_from(s)
+                            .map_err(|_| E::invalid_length($len(s), &self))
+                    }
+
+                    fn visit_string<E: serde::de::Error>(self, s: String) -> Result<Self::Value, E> {
+                        let len = $len(&s);
+                        $name::try_from(s)
+                            .map_err(|_| E::invalid_length(len, &self))
+                    }
+                }
+
+                deserializer.deserialize_string(Visitor::<N>)
+            }
+        }
+    };
+}
+
+bounded_string! {
+    /// String holding at most `N` bytes, the length of its CDR encoding
+    BoundedString, "bytes", str::len
+}
+
+bounded_string! {
+    /// Wide string of Rust characters holding at most `N` characters, as `wstring<N>`
+    /// bounds its wide characters
+    BoundedWideString, "characters", |s: &str| s.chars().count()
+}
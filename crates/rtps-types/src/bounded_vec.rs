@@ -0,0 +1,193 @@
+// Copyright (C) 2019  Frank Rehberger
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>
+
+//! Sequence of IDL type `sequence<T, N>`
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+use core::fmt;
+use core::ops::{Deref, DerefMut};
+use crate::BoundError;
+
+/// Vector holding at most `N` elements
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BoundedVec<T, const N: usize> {
+    inner: Vec<T>,
+}
+
+impl<T, const N: usize> BoundedVec<T, N> {
+    /// The maximum number of elements
+    pub const BOUND: usize = N;
+
+    /// An empty vector
+    pub fn new() -> Self {
+        BoundedVec { inner: Vec::new() }
+    }
+
+    fn check(len: usize) -> Result<(), BoundError> {
+        if len > N {
+            Err(BoundError { bound: N, len })
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Append the element, failing if the bound would be exceeded
+    pub fn push(&mut self, value: T) -> Result<(), BoundError> {
+        Self::check(self.inner.len() + 1)?;
+        self.inner.push(value);
+        Ok(())
+    }
+
+    /// Insert the element at the position, failing if the bound would be exceeded
+    pub fn insert(&mut self, idx: usize, value: T) -> Result<(), BoundError> {
+        Self::check(self.inner.len() + 1)?;
+        self.inner.insert(idx, value);
+        Ok(())
+    }
+
+    /// Append the elements, failing without change if the bound would be exceeded
+    pub fn extend_from_slice(&mut self, values: &[T]) -> Result<(), BoundError>
+        where T: Clone {
+        Self::check(self.inner.len() + values.len())?;
+        self.inner.extend_from_slice(values);
+        Ok(())
+    }
+
+    pub fn pop(&mut self) -> Option<T> {
+        self.inner.pop()
+    }
+
+    pub fn remove(&mut self, idx: usize) -> T {
+        self.inner.remove(idx)
+    }
+
+    pub fn truncate(&mut self, len: usize) {
+        self.inner.truncate(len)
+    }
+
+    pub fn clear(&mut self) {
+        self.inner.clear()
+    }
+
+    pub fn as_slice(&self) -> &[T] {
+        &self.inner
+    }
+
+    pub fn into_vec(self) -> Vec<T> {
+        self.inner
+    }
+}
+
+impl<T, const N: usize> Default for BoundedVec<T, N> {
+    fn default() -> Self {
+        BoundedVec::new()
+    }
+}
+
+impl<T, const N: usize> TryFrom<Vec<T>> for BoundedVec<T, N> {
+    type Error = BoundError;
+
+    fn try_from(values: Vec<T>) -> Result<Self, BoundError> {
+        Self::check(values.len())?;
+        Ok(BoundedVec { inner: values })
+    }
+}
+
+impl<'a, T: Clone, const N: usize> TryFrom<&'a [T]> for BoundedVec<T, N> {
+    type Error = BoundError;
+
+    fn try_from(values: &'a [T]) -> Result<Self, BoundError> {
+        Self::check(values.len())?;
+        Ok(BoundedVec { inner: values.to_vec() })
+    }
+}
+
+impl<T, const N: usize> From<BoundedVec<T, N>> for Vec<T> {
+    fn from(values: BoundedVec<T, N>) -> Vec<T> {
+        values.inner
+    }
+}
+
+impl<T, const N: usize> Deref for BoundedVec<T, N> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        &self.inner
+    }
+}
+
+// the length of a slice cannot be changed
+impl<T, const N: usize> DerefMut for BoundedVec<T, N> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        &mut self.inner
+    }
+}
+
+impl<T, const N: usize> AsRef<[T]> for BoundedVec<T, N> {
+    fn as_ref(&self) -> &[T] {
+        &self.inner
+    }
+}
+
+impl<T, const N: usize> IntoIterator for BoundedVec<T, N> {
+    type Item = T;
+    type IntoIter = alloc::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner.into_iter()
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a BoundedVec<T, N> {
+    type Item = &'a T;
+    type IntoIter = core::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner.iter()
+    }
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for BoundedVec<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&self.inner, f)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize, const N: usize> serde::Serialize for BoundedVec<T, N> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.inner.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de>, const N: usize> serde::Deserialize<'de> for BoundedVec<T, N> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct Visitor<T, const N: usize>(core::marker::PhantomData<T>);
+
+        impl<'de, T: serde::Deserialize<'de>, const N: usize> serde::de::Visitor<'de> for Visitor<T, N> {
+            type Value = BoundedVec<T, N>;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "a sequence of at most {} elements", N)
+            }
+
+            // fails as soon as the bound is exceeded, without reading the remainder
+            fn visit_seq<A: serde::de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                let mut values = Vec::with_capacity(seq.size_hint().unwrap_or(0).min(N));
+                while let Some(value) = seq.next_element()? {
+                    if values.len() == N {
+                        let len = N + 1 + seq.size_hint().unwrap_or(0);
+                        return Err(serde::de::Error::invalid_length(len, &self));
+                    }
+                    values.push(value);
+                }
+                Ok(BoundedVec { inner: values })
+            }
+        }
+
+        deserializer.deserialize_seq(Visitor::<T, N>(core::marker::PhantomData))
+    }
+}
@@ -0,0 +1,366 @@
+// Copyright (C) 2019  Frank Rehberger
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>
+
+//! Characters and wide strings of IDL types `char`, `wchar` and `wstring`
+//!
+//! `char` is an 8-bit character, converting from and to Rust `char` as Latin-1;
+//! `wchar` and `wstring` hold UTF-16 code units, as encoded in CDR.
+use alloc::{string::String, vec::Vec};
+use core::convert::TryFrom;
+use core::fmt;
+use crate::{BoundError, CharError};
+
+/// Character of IDL type `char`, an octet
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct Char(pub u8);
+
+impl Char {
+    /// The Latin-1 character, panics for characters beyond `U+00FF`
+    pub const fn from_char(ch: char) -> Char {
+        assert!(ch as u32 <= 0xFF, "character is not representable as Latin-1");
+        Char(ch as u8)
+    }
+
+    /// The character, interpreting the octet as Latin-1
+    pub const fn to_char(self) -> char {
+        self.0 as char
+    }
+}
+
+impl From<u8> for Char {
+    fn from(val: u8) -> Char {
+        Char(val)
+    }
+}
+
+impl From<Char> for u8 {
+    fn from(ch: Char) -> u8 {
+        ch.0
+    }
+}
+
+impl From<Char> for char {
+    fn from(ch: Char) -> char {
+        ch.to_char()
+    }
+}
+
+impl TryFrom<char> for Char {
+    type Error = CharError;
+
+    fn try_from(ch: char) -> Result<Char, CharError> {
+        u8::try_from(u32::from(ch))
+            .map(Char)
+            .map_err(|_| CharError { code: u32::from(ch) })
+    }
+}
+
+impl fmt::Debug for Char {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&self.to_char(), f)
+    }
+}
+
+impl fmt::Display for Char {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.to_char(), f)
+    }
+}
+
+/// Character of IDL type `wchar`, a UTF-16 code unit
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct WChar(pub u16);
+
+impl WChar {
+    /// The character of the Basic Multilingual Plane, panics for characters beyond `U+FFFF`
+    pub const fn from_char(ch: char) -> WChar {
+        assert!(ch as u32 <= 0xFFFF, "character is not representable as single UTF-16 code unit");
+        WChar(ch as u16)
+    }
+}
+
+impl From<u16> for WChar {
+    fn from(val: u16) -> WChar {
+        WChar(val)
+    }
+}
+
+impl From<WChar> for u16 {
+    fn from(ch: WChar) -> u16 {
+        ch.0
+    }
+}
+
+impl TryFrom<char> for WChar {
+    type Error = CharError;
+
+    fn try_from(ch: char) -> Result<WChar, CharError> {
+        u16::try_from(u32::from(ch))
+            .map(WChar)
+            .map_err(|_| CharError { code: u32::from(ch) })
+    }
+}
+
+/// Fails for surrogate code units
+impl TryFrom<WChar> for char {
+    type Error = CharError;
+
+    fn try_from(ch: WChar) -> Result<char, CharError> {
+        char::from_u32(u32::from(ch.0)).ok_or(CharError { code: u32::from(ch.0) })
+    }
+}
+
+impl fmt::Debug for WChar {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match char::try_from(*self) {
+            Ok(ch) => fmt::Debug::fmt(&ch, f),
+            Err(_) => write!(f, "'\\u{{{:x}}}'", self.0),
+        }
+    }
+}
+
+/// String of IDL type `wstring`, UTF-16 code units
+#[derive(Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct WString {
+    units: Vec<u16>,
+}
+
+impl WString {
+    /// An empty string
+    pub fn new() -> WString {
+        WString { units: Vec::new() }
+    }
+
+    /// The string of UTF-16 code units, these are not validated
+    pub fn from_units(units: Vec<u16>) -> WString {
+        WString { units }
+    }
+
+    pub fn as_units(&self) -> &[u16] {
+        &self.units
+    }
+
+    pub fn into_units(self) -> Vec<u16> {
+        self.units
+    }
+
+    /// The number of UTF-16 code units
+    pub fn len(&self) -> usize {
+        self.units.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.units.is_empty()
+    }
+
+    pub fn push(&mut self, ch: WChar) {
+        self.units.push(ch.0)
+    }
+
+    pub fn push_str(&mut self, s: &str) {
+        self.units.extend(s.encode_utf16())
+    }
+
+    pub fn clear(&mut self) {
+        self.units.clear()
+    }
+
+    /// The UTF-8 string, failing for unpaired surrogates
+    pub fn to_utf8(&self) -> Result<String, CharError> {
+        char::decode_utf16(self.units.iter().cloned())
+            .map(|ch| ch.map_err(|e| CharError { code: u32::from(e.unpaired_surrogate()) }))
+            .collect()
+    }
+
+    /// The UTF-8 string, replacing unpaired surrogates by `U+FFFD`
+    pub fn to_utf8_lossy(&self) -> String {
+        String::from_utf16_lossy(&self.units)
+    }
+}
+
+impl<'a> From<&'a str> for WString {
+    fn from(s: &'a str) -> WString {
+        WString { units: s.encode_utf16().collect() }
+    }
+}
+
+impl From<String> for WString {
+    fn from(s: String) -> WString {
+        WString::from(s.as_str())
+    }
+}
+
+impl<'a> TryFrom<&'a WString> for String {
+    type Error = CharError;
+
+    fn try_from(s: &'a WString) -> Result<String, CharError> {
+        s.to_utf8()
+    }
+}
+
+impl fmt::Debug for WString {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&self.to_utf8_lossy(), f)
+    }
+}
+
+impl fmt::Display for WString {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.to_utf8_lossy(), f)
+    }
+}
+
+/// String of IDL type `wstring<N>`, holding at most `N` UTF-16 code units
+#[derive(Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BoundedWString<const N: usize> {
+    inner: WString,
+}
+
+impl<const N: usize> BoundedWString<N> {
+    /// The maximum number of UTF-16 code units
+    pub const BOUND: usize = N;
+
+    /// An empty string
+    pub fn new() -> Self {
+        BoundedWString { inner: WString::new() }
+    }
+
+    fn check(len: usize) -> Result<(), BoundError> {
+        if len > N {
+            Err(BoundError { bound: N, len })
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Append the character, failing if the bound would be exceeded
+    pub fn push(&mut self, ch: WChar) -> Result<(), BoundError> {
+        Self::check(self.inner.len() + 1)?;
+        self.inner.push(ch);
+        Ok(())
+    }
+
+    /// Append the string, failing if the bound would be exceeded
+    pub fn push_str(&mut self, s: &str) -> Result<(), BoundError> {
+        Self::check(self.inner.len() + s.encode_utf16().count())?;
+        self.inner.push_str(s);
+        Ok(())
+    }
+
+    pub fn clear(&mut self) {
+        self.inner.clear()
+    }
+
+    pub fn as_wstring(&self) -> &WString {
+        &self.inner
+    }
+
+    pub fn into_wstring(self) -> WString {
+        self.inner
+    }
+}
+
+impl<const N: usize> core::ops::Deref for BoundedWString<N> {
+    type Target = WString;
+
+    fn deref(&self) -> &WString {
+        &self.inner
+    }
+}
+
+impl<const N: usize> TryFrom<WString> for BoundedWString<N> {
+    type Error = BoundError;
+
+    fn try_from(s: WString) -> Result<Self, BoundError> {
+        Self::check(s.len())?;
+        Ok(BoundedWString { inner: s })
+    }
+}
+
+impl<'a, const N: usize> TryFrom<&'a str> for BoundedWString<N> {
+    type Error = BoundError;
+
+    fn try_from(s: &'a str) -> Result<Self, BoundError> {
+        BoundedWString::try_from(WString::from(s))
+    }
+}
+
+impl<const N: usize> From<BoundedWString<N>> for WString {
+    fn from(s: BoundedWString<N>) -> WString {
+        s.inner
+    }
+}
+
+impl<const N: usize> fmt::Debug for BoundedWString<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&self.inner, f)
+    }
+}
+
+impl<const N: usize> fmt::Display for BoundedWString<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.inner, f)
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde_impls {
+    use alloc::vec::Vec;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use crate::BoundedVec;
+    use super::{BoundedWString, Char, WChar, WString};
+
+    impl Serialize for Char {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.serialize_u8(self.0)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Char {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            u8::deserialize(deserializer).map(Char)
+        }
+    }
+
+    impl Serialize for WChar {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.serialize_u16(self.0)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for WChar {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            u16::deserialize(deserializer).map(WChar)
+        }
+    }
+
+    // a sequence of UTF-16 code units
+    impl Serialize for WString {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            self.units.serialize(serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for WString {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            Vec::<u16>::deserialize(deserializer).map(WString::from_units)
+        }
+    }
+
+    impl<const N: usize> Serialize for BoundedWString<N> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            self.inner.serialize(serializer)
+        }
+    }
+
+    impl<'de, const N: usize> Deserialize<'de> for BoundedWString<N> {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let units = BoundedVec::<u16, N>::deserialize(deserializer)?;
+            Ok(BoundedWString { inner: WString::from_units(units.into_vec()) })
+        }
+    }
+}
@@ -0,0 +1,493 @@
+// Copyright (C) 2019  Frank Rehberger
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>
+
+//! Types of fixed layout, exchanged through shared memory as bytes
+//!
+//! A type of fixed layout is `#[repr(C)]` and free of implicit padding, its bytes being
+//! its plain CDR encoding in little endian. Bounded strings and sequences are stored at
+//! their bound, the length followed by the elements, the unused ones zero or default;
+//! the bytes equal the encoding if these are full, and keep their positions otherwise.
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::convert::TryFrom;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::mem;
+use crate::{BoundError, Char, WChar};
+
+/// The bytes are no value of the type
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LayoutError {
+    /// The number of bytes differs from the size of the type
+    Size { expected: usize, actual: usize },
+    /// The bytes are not aligned as the type requires
+    Align(usize),
+    /// The bytes hold no valid value, such as a boolean other than 0 and 1
+    Invalid,
+}
+
+impl fmt::Display for LayoutError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LayoutError::Size { expected, actual } => write!(f, "{} bytes instead of {}", actual, expected),
+            LayoutError::Align(align) => write!(f, "bytes not aligned to {}", align),
+            LayoutError::Invalid => f.write_str("bytes of no valid value"),
+        }
+    }
+}
+
+impl core::error::Error for LayoutError {}
+
+/// A type of fixed layout, cast from and to bytes
+///
+/// # Safety
+///
+/// The type has no implicit padding, pointers nor interior mutability, and any bytes
+/// accepted by `is_valid` form a value.
+pub unsafe trait FixedLayout: Sized {
+    /// Fails to compile, if evaluated by the casts, unless the type is free of implicit padding
+    const NO_PADDING: () = ();
+
+    /// Whether the bytes, as many as the size of the type, form a value
+    fn is_valid(bytes: &[u8]) -> bool;
+
+    /// The bytes of the value
+    fn as_bytes(&self) -> &[u8] {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::NO_PADDING;
+        // SAFETY: without implicit padding all bytes are initialized
+        unsafe { core::slice::from_raw_parts(self as *const Self as *const u8, mem::size_of::<Self>()) }
+    }
+
+    /// The value the bytes hold, in place; these must be aligned as the type
+    fn from_bytes(bytes: &[u8]) -> Result<&Self, LayoutError> {
+        check::<Self>(bytes)?;
+        // SAFETY: the bytes are of the size and alignment of a valid value
+        Ok(unsafe { &*(bytes.as_ptr() as *const Self) })
+    }
+
+    /// The value the bytes hold, in place and mutable; these must be aligned as the type
+    fn from_bytes_mut(bytes: &mut [u8]) -> Result<&mut Self, LayoutError> {
+        check::<Self>(bytes)?;
+        // SAFETY: as `from_bytes`, any value written being valid
+        Ok(unsafe { &mut *(bytes.as_mut_ptr() as *mut Self) })
+    }
+
+    /// A copy of the value the bytes hold, these need not be aligned
+    fn read_bytes(bytes: &[u8]) -> Result<Self, LayoutError> {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::NO_PADDING;
+        if bytes.len() != mem::size_of::<Self>() {
+            return Err(LayoutError::Size { expected: mem::size_of::<Self>(), actual: bytes.len() });
+        }
+        if !Self::is_valid(bytes) {
+            return Err(LayoutError::Invalid);
+        }
+        // SAFETY: the bytes are of the size of a valid value
+        Ok(unsafe { core::ptr::read_unaligned(bytes.as_ptr() as *const Self) })
+    }
+}
+
+/// Fails to compile, if evaluated in a constant, unless `T` is of the size
+pub const fn assert_size<T>(size: usize) {
+    assert!(mem::size_of::<T>() == size, "the size differs from the layout");
+}
+
+/// Verify the bytes hold a value of `T` in place
+fn check<T: FixedLayout>(bytes: &[u8]) -> Result<(), LayoutError> {
+    #[allow(clippy::let_unit_value)]
+    let () = T::NO_PADDING;
+    if bytes.len() != mem::size_of::<T>() {
+        return Err(LayoutError::Size { expected: mem::size_of::<T>(), actual: bytes.len() });
+    }
+    if bytes.as_ptr() as usize % mem::align_of::<T>() != 0 {
+        return Err(LayoutError::Align(mem::align_of::<T>()));
+    }
+    if !T::is_valid(bytes) {
+        return Err(LayoutError::Invalid);
+    }
+    Ok(())
+}
+
+macro_rules! fixed_layout {
+    ($($typ:ty),*) => {
+        $(
+            unsafe impl FixedLayout for $typ {
+                fn is_valid(_bytes: &[u8]) -> bool {
+                    true
+                }
+            }
+        )*
+    };
+}
+
+fixed_layout!(u8, i8, u16, i16, u32, i32, u64, i64, f32, f64, Char, WChar);
+
+unsafe impl FixedLayout for bool {
+    fn is_valid(bytes: &[u8]) -> bool {
+        bytes[0] <= 1
+    }
+}
+
+unsafe impl<T: FixedLayout, const N: usize> FixedLayout for [T; N] {
+    const NO_PADDING: () = T::NO_PADDING;
+
+    fn is_valid(bytes: &[u8]) -> bool {
+        mem::size_of::<T>() == 0 || bytes.chunks(mem::size_of::<T>()).all(T::is_valid)
+    }
+}
+
+/// Bytes padding the members of a struct to their CDR alignment; these are ignored
+/// by comparison and hashing
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct Padding<const N: usize>(pub [u8; N]);
+
+impl<const N: usize> Default for Padding<N> {
+    fn default() -> Self {
+        Padding([0; N])
+    }
+}
+
+impl<const N: usize> PartialEq for Padding<N> {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl<const N: usize> Eq for Padding<N> {}
+
+impl<const N: usize> PartialOrd for Padding<N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const N: usize> Ord for Padding<N> {
+    fn cmp(&self, _other: &Self) -> Ordering {
+        Ordering::Equal
+    }
+}
+
+impl<const N: usize> Hash for Padding<N> {
+    fn hash<H: Hasher>(&self, _state: &mut H) {}
+}
+
+impl<const N: usize> fmt::Debug for Padding<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Padding<{}>", N)
+    }
+}
+
+unsafe impl<const N: usize> FixedLayout for Padding<N> {
+    fn is_valid(_bytes: &[u8]) -> bool {
+        true
+    }
+}
+
+/// String of IDL type `string<N>` stored in place, as encoded in CDR: the length
+/// including the terminating NUL, the UTF-8 bytes, the unused ones being zero, and the NUL
+#[derive(Clone, Copy)]
+#[repr(C)]
+pub struct FixedString<const N: usize> {
+    len: [u8; 4],
+    bytes: [u8; N],
+    nul: u8,
+}
+
+impl<const N: usize> FixedString<N> {
+    /// The maximum length in bytes
+    pub const BOUND: usize = N;
+
+    /// An empty string
+    pub fn new() -> Self {
+        FixedString { len: 1u32.to_ne_bytes(), bytes: [0; N], nul: 0 }
+    }
+
+    /// The length in bytes
+    pub fn len(&self) -> usize {
+        u32::from_ne_bytes(self.len) as usize - 1
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn as_str(&self) -> &str {
+        core::str::from_utf8(&self.bytes[..self.len()]).expect("UTF-8 verified when stored")
+    }
+
+    /// Replace the string, failing without change if the bound would be exceeded
+    pub fn set(&mut self, s: &str) -> Result<(), BoundError> {
+        if s.len() > N {
+            return Err(BoundError { bound: N, len: s.len() });
+        }
+        self.bytes = [0; N];
+        self.bytes[..s.len()].copy_from_slice(s.as_bytes());
+        self.len = (s.len() as u32 + 1).to_ne_bytes();
+        Ok(())
+    }
+}
+
+impl<const N: usize> Default for FixedString<N> {
+    fn default() -> Self {
+        FixedString::new()
+    }
+}
+
+impl<'a, const N: usize> TryFrom<&'a str> for FixedString<N> {
+    type Error = BoundError;
+
+    fn try_from(s: &'a str) -> Result<Self, BoundError> {
+        let mut string = FixedString::new();
+        string.set(s)?;
+        Ok(string)
+    }
+}
+
+impl<const N: usize> core::ops::Deref for FixedString<N> {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> PartialEq for FixedString<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl<const N: usize> Eq for FixedString<N> {}
+
+impl<const N: usize> PartialEq<str> for FixedString<N> {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl<'a, const N: usize> PartialEq<&'a str> for FixedString<N> {
+    fn eq(&self, other: &&'a str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl<const N: usize> PartialOrd for FixedString<N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const N: usize> Ord for FixedString<N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+impl<const N: usize> Hash for FixedString<N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state)
+    }
+}
+
+impl<const N: usize> fmt::Debug for FixedString<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl<const N: usize> fmt::Display for FixedString<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// The length is within the bound, the bytes are UTF-8 and the unused ones zero
+unsafe impl<const N: usize> FixedLayout for FixedString<N> {
+    fn is_valid(bytes: &[u8]) -> bool {
+        let len = u32::from_ne_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as usize;
+        if len == 0 || len > N + 1 {
+            return false;
+        }
+        let (used, unused) = bytes[4..].split_at(len - 1);
+        core::str::from_utf8(used).is_ok() && unused.iter().all(|&byte| byte == 0)
+    }
+}
+
+/// Sequence of IDL type `sequence<T, N>` stored in place, as encoded in CDR: the length,
+/// `P` bytes aligning the elements to 8 bytes if these require it, and the elements,
+/// the unused ones being default. Without fixed layout, it serves as sequence not
+/// allocating, any padding being implicit.
+#[derive(Clone, Copy)]
+#[repr(C)]
+pub struct FixedVec<T, const N: usize, const P: usize = 0> {
+    len: [u8; 4],
+    padding: [u8; P],
+    elems: [T; N],
+}
+
+impl<T, const N: usize, const P: usize> FixedVec<T, N, P> {
+    /// The maximum number of elements
+    pub const BOUND: usize = N;
+
+    /// An empty sequence
+    pub fn new() -> Self
+        where T: Default {
+        FixedVec { len: [0; 4], padding: [0; P], elems: core::array::from_fn(|_| T::default()) }
+    }
+
+    pub fn len(&self) -> usize {
+        u32::from_ne_bytes(self.len) as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn as_slice(&self) -> &[T] {
+        &self.elems[..self.len()]
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        let len = self.len();
+        &mut self.elems[..len]
+    }
+
+    /// Append the element, failing if the bound would be exceeded
+    pub fn push(&mut self, value: T) -> Result<(), BoundError> {
+        let len = self.len();
+        if len == N {
+            return Err(BoundError { bound: N, len: len + 1 });
+        }
+        self.elems[len] = value;
+        self.len = (len as u32 + 1).to_ne_bytes();
+        Ok(())
+    }
+
+    pub fn pop(&mut self) -> Option<T>
+        where T: Default {
+        let len = self.len().checked_sub(1)?;
+        self.len = (len as u32).to_ne_bytes();
+        Some(mem::take(&mut self.elems[len]))
+    }
+
+    pub fn clear(&mut self)
+        where T: Default {
+        while self.pop().is_some() {}
+    }
+}
+
+impl<T: Default, const N: usize, const P: usize> Default for FixedVec<T, N, P> {
+    fn default() -> Self {
+        FixedVec::new()
+    }
+}
+
+impl<T: Default, const N: usize, const P: usize> TryFrom<Vec<T>> for FixedVec<T, N, P> {
+    type Error = BoundError;
+
+    fn try_from(values: Vec<T>) -> Result<Self, BoundError> {
+        if values.len() > N {
+            return Err(BoundError { bound: N, len: values.len() });
+        }
+        let mut vec = FixedVec::new();
+        for value in values {
+            vec.push(value)?;
+        }
+        Ok(vec)
+    }
+}
+
+impl<T, const N: usize, const P: usize> core::ops::Deref for FixedVec<T, N, P> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T, const N: usize, const P: usize> core::ops::DerefMut for FixedVec<T, N, P> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+
+impl<T: PartialEq, const N: usize, const P: usize> PartialEq for FixedVec<T, N, P> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: Eq, const N: usize, const P: usize> Eq for FixedVec<T, N, P> {}
+
+impl<T: PartialOrd, const N: usize, const P: usize> PartialOrd for FixedVec<T, N, P> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.as_slice().partial_cmp(other.as_slice())
+    }
+}
+
+impl<T: Ord, const N: usize, const P: usize> Ord for FixedVec<T, N, P> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_slice().cmp(other.as_slice())
+    }
+}
+
+impl<T: Hash, const N: usize, const P: usize> Hash for FixedVec<T, N, P> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_slice().hash(state)
+    }
+}
+
+impl<T: fmt::Debug, const N: usize, const P: usize> fmt::Debug for FixedVec<T, N, P> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(self.as_slice(), f)
+    }
+}
+
+/// The length is within the bound and the elements, used or not, are valid
+unsafe impl<T: FixedLayout, const N: usize, const P: usize> FixedLayout for FixedVec<T, N, P> {
+    /// The padding must align the elements
+    const NO_PADDING: () = assert!(mem::size_of::<Self>() == 4 + P + N * mem::size_of::<T>(),
+                                   "the padding does not align the elements");
+
+    fn is_valid(bytes: &[u8]) -> bool {
+        let len = u32::from_ne_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as usize;
+        len <= N && <[T; N]>::is_valid(&bytes[4 + P..])
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<const N: usize> serde::Serialize for FixedString<N> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, const N: usize> serde::Deserialize<'de> for FixedString<N> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = <alloc::borrow::Cow<'de, str> as serde::Deserialize>::deserialize(deserializer)?;
+        FixedString::try_from(&*s)
+            .map_err(|err| serde::de::Error::invalid_length(err.len, &"a string within the bound"))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize, const N: usize, const P: usize> serde::Serialize for FixedVec<T, N, P> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.as_slice().serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T, const N: usize, const P: usize> serde::Deserialize<'de> for FixedVec<T, N, P>
+    where T: serde::Deserialize<'de> + Default {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let values = <Vec<T> as serde::Deserialize>::deserialize(deserializer)?;
+        FixedVec::try_from(values)
+            .map_err(|err| serde::de::Error::invalid_length(err.len, &"a sequence within the bound"))
+    }
+}
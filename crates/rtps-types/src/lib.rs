@@ -0,0 +1,86 @@
+// Copyright (C) 2019  Frank Rehberger
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>
+
+//! Runtime types of the Rust code generated from RTPS IDL
+//!
+//! The crate is `no_std`, requiring `alloc` only.
+#![no_std]
+extern crate alloc;
+
+use alloc::string::String;
+use core::fmt;
+
+mod bounded_string;
+mod bounded_vec;
+mod chars;
+mod fixed;
+mod long_double;
+mod type_object;
+pub mod validate;
+#[cfg(feature = "serde")]
+pub mod xtypes;
+
+pub use crate::bounded_string::{BoundedString, BoundedWideString};
+pub use crate::bounded_vec::BoundedVec;
+pub use crate::chars::{BoundedWString, Char, WChar, WString};
+pub use crate::fixed::{assert_size, FixedLayout, FixedString, FixedVec, LayoutError, Padding};
+pub use crate::long_double::LongDouble;
+pub use crate::type_object::{Discoverable, EquivalenceHash, TypeIdentifier, TypeIdentifierWithDependencies,
+                             TypeIdentifierWithSize, TypeInformation, TypeObject, TypeObjects,
+                             EK_COMPLETE, EK_MINIMAL};
+pub use crate::validate::{Validate, ValidationError, Violation};
+
+/// A bounded string or sequence would exceed its bound
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BoundError {
+    /// The maximum length
+    pub bound: usize,
+    /// The length the operation would result in
+    pub len: usize,
+}
+
+impl fmt::Display for BoundError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "length {} exceeds the bound {}", self.len, self.bound)
+    }
+}
+
+impl core::error::Error for BoundError {}
+
+/// A character is not representable by the IDL character type, or a wide string
+/// holds an unpaired surrogate
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CharError {
+    /// The Unicode scalar value or UTF-16 code unit
+    pub code: u32,
+}
+
+impl fmt::Display for CharError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "character U+{:04X} is not representable", self.code)
+    }
+}
+
+impl core::error::Error for CharError {}
+
+/// A value or name does not denote an enumerator
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum EnumError {
+    /// The value of no enumerator
+    Value(u32),
+    /// The name of no enumerator
+    Name(String),
+}
+
+impl fmt::Display for EnumError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            EnumError::Value(value) => write!(f, "{} is the value of no enumerator", value),
+            EnumError::Name(name) => write!(f, "'{}' is the name of no enumerator", name),
+        }
+    }
+}
+
+impl core::error::Error for EnumError {}
@@ -0,0 +1,209 @@
+// Copyright (C) 2019  Frank Rehberger
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>
+
+//! Floating point number of IDL type `long double`
+use core::cmp::Ordering;
+use core::fmt;
+
+const SIGN: u128 = 1 << 127;
+const EXP_BITS: u32 = 15;
+const EXP_MAX: u128 = (1 << EXP_BITS) - 1;
+const EXP_BIAS: i32 = 16383;
+const FRAC_BITS: u32 = 112;
+const FRAC_MASK: u128 = (1 << FRAC_BITS) - 1;
+
+const F64_EXP_BIAS: i32 = 1023;
+const F64_FRAC_BITS: u32 = 52;
+const F64_FRAC_MASK: u64 = (1 << F64_FRAC_BITS) - 1;
+
+/// IEEE 754 binary128 number of IDL type `long double`, holding the 16 bytes as encoded.
+///
+/// Arithmetic is not provided; the value converts exactly from `f64` and with rounding
+/// to `f64`. Comparison follows the floating point semantics.
+#[derive(Clone, Copy, Default)]
+pub struct LongDouble(u128);
+
+impl LongDouble {
+    pub const ZERO: LongDouble = LongDouble(0);
+
+    pub const fn from_bits(bits: u128) -> LongDouble {
+        LongDouble(bits)
+    }
+
+    pub const fn to_bits(self) -> u128 {
+        self.0
+    }
+
+    pub const fn from_le_bytes(bytes: [u8; 16]) -> LongDouble {
+        LongDouble(u128::from_le_bytes(bytes))
+    }
+
+    pub const fn from_be_bytes(bytes: [u8; 16]) -> LongDouble {
+        LongDouble(u128::from_be_bytes(bytes))
+    }
+
+    pub const fn to_le_bytes(self) -> [u8; 16] {
+        self.0.to_le_bytes()
+    }
+
+    pub const fn to_be_bytes(self) -> [u8; 16] {
+        self.0.to_be_bytes()
+    }
+
+    pub const fn is_nan(self) -> bool {
+        (self.0 >> FRAC_BITS) & EXP_MAX == EXP_MAX && self.0 & FRAC_MASK != 0
+    }
+
+    /// The exact value of the `f64`
+    pub const fn from_f64(val: f64) -> LongDouble {
+        let bits = val.to_bits();
+        let sign = ((bits >> 63) as u128) << 127;
+        let exp = ((bits >> F64_FRAC_BITS) & 0x7FF) as i32;
+        let frac = bits & F64_FRAC_MASK;
+
+        let (exp, frac) = if exp == 0x7FF {
+            // infinity or NaN, retaining the payload
+            (EXP_MAX, (frac as u128) << (FRAC_BITS - F64_FRAC_BITS))
+        } else if exp == 0 && frac == 0 {
+            (0, 0)
+        } else if exp == 0 {
+            // subnormal, normalized as binary128 covers a greater range
+            let msb = 63 - frac.leading_zeros();
+            let frac = (frac as u128) & !(1 << msb);
+            ((msb as i32 - 1074 + EXP_BIAS) as u128, frac << (FRAC_BITS - msb))
+        } else {
+            ((exp - F64_EXP_BIAS + EXP_BIAS) as u128, (frac as u128) << (FRAC_BITS - F64_FRAC_BITS))
+        };
+        LongDouble(sign | exp << FRAC_BITS | frac)
+    }
+
+    /// The nearest `f64`, rounding to even; values beyond the range of `f64` become infinite
+    pub fn to_f64(self) -> f64 {
+        let sign = ((self.0 >> 127) as u64) << 63;
+        let exp = ((self.0 >> FRAC_BITS) & EXP_MAX) as i32;
+        let frac = self.0 & FRAC_MASK;
+
+        if exp == EXP_MAX as i32 {
+            let payload = (frac >> (FRAC_BITS - F64_FRAC_BITS)) as u64;
+            // a NaN keeps its quiet bit, so remains a NaN
+            let payload = if frac != 0 { payload | 1 << (F64_FRAC_BITS - 1) } else { 0 };
+            return f64::from_bits(sign | 0x7FF << F64_FRAC_BITS | payload);
+        }
+        if exp == 0 && frac == 0 {
+            return f64::from_bits(sign);
+        }
+
+        let exp = exp - EXP_BIAS;
+        let (significand, exp) = if exp == -EXP_BIAS {
+            // subnormal binary128, below the range of f64
+            (frac, 1 - EXP_BIAS)
+        } else {
+            (frac | 1 << FRAC_BITS, exp)
+        };
+        let bits = if exp > F64_EXP_BIAS {
+            0x7FF << F64_FRAC_BITS
+        } else if exp >= 1 - F64_EXP_BIAS {
+            // normal, the carry of rounding increments the exponent
+            let mantissa = round_shr(significand, FRAC_BITS - F64_FRAC_BITS) as u64;
+            let biased = ((exp + F64_EXP_BIAS) as u64) << F64_FRAC_BITS;
+            (biased + (mantissa - (1 << F64_FRAC_BITS))).min(0x7FF << F64_FRAC_BITS)
+        } else {
+            // subnormal f64, in units of 2^-1074
+            let shift = (FRAC_BITS as i32 - F64_FRAC_BITS as i32) + (1 - F64_EXP_BIAS - exp);
+            if shift > 120 {
+                0
+            } else {
+                round_shr(significand, shift as u32) as u64
+            }
+        };
+        f64::from_bits(sign | bits)
+    }
+}
+
+/// Shift right, rounding to nearest even
+fn round_shr(val: u128, shift: u32) -> u128 {
+    let quotient = val >> shift;
+    let remainder = val & ((1 << shift) - 1);
+    let half = 1 << (shift - 1);
+    if remainder > half || (remainder == half && quotient & 1 == 1) {
+        quotient + 1
+    } else {
+        quotient
+    }
+}
+
+impl From<f64> for LongDouble {
+    fn from(val: f64) -> LongDouble {
+        LongDouble::from_f64(val)
+    }
+}
+
+impl From<f32> for LongDouble {
+    fn from(val: f32) -> LongDouble {
+        LongDouble::from_f64(f64::from(val))
+    }
+}
+
+impl From<LongDouble> for f64 {
+    fn from(val: LongDouble) -> f64 {
+        val.to_f64()
+    }
+}
+
+impl PartialEq for LongDouble {
+    fn eq(&self, other: &LongDouble) -> bool {
+        self.partial_cmp(other) == Some(Ordering::Equal)
+    }
+}
+
+impl PartialOrd for LongDouble {
+    fn partial_cmp(&self, other: &LongDouble) -> Option<Ordering> {
+        if self.is_nan() || other.is_nan() {
+            return None;
+        }
+        // positive and negative zero are equal
+        if (self.0 | other.0) & !SIGN == 0 {
+            return Some(Ordering::Equal);
+        }
+        // sign-magnitude onto an ordered unsigned key
+        let key = |bits: u128| if bits & SIGN != 0 { !bits } else { bits | SIGN };
+        Some(key(self.0).cmp(&key(other.0)))
+    }
+}
+
+impl fmt::Debug for LongDouble {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&self.to_f64(), f)
+    }
+}
+
+impl fmt::Display for LongDouble {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.to_f64(), f)
+    }
+}
+
+/// Human readable formats hold the nearest `f64`, binary formats the 16 bytes
+#[cfg(feature = "serde")]
+impl serde::Serialize for LongDouble {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.serialize_f64(self.to_f64())
+        } else {
+            serializer.serialize_u128(self.0)
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for LongDouble {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            f64::deserialize(deserializer).map(LongDouble::from_f64)
+        } else {
+            u128::deserialize(deserializer).map(LongDouble)
+        }
+    }
+}
@@ -0,0 +1,284 @@
+// Copyright (C) 2019  Frank Rehberger
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>
+
+//! TypeObjects of DDS-XTypes, describing the types for the type discovery
+//!
+//! The generated structs, unions and enums implement [`Discoverable`], holding their
+//! minimal and complete TypeObject serialized in XCDR2 little endian, along with those of
+//! the types they depend on. The equivalence hash of a TypeObject is the first 14 bytes of
+//! the MD5 hash of its serialization; its TypeIdentifier is the equivalence kind followed
+//! by the hash. [`TypeInformation`] carries the identifiers of a topic type and of its
+//! dependencies within the SEDP data, its serde impls encoding the mutable struct of
+//! DDS-XTypes holding appendable ones.
+use alloc::vec::Vec;
+
+/// The equivalence hash of a TypeObject
+pub type EquivalenceHash = [u8; 14];
+
+/// The TypeIdentifier of a type given by the hash of its TypeObject, the equivalence
+/// kind followed by the hash
+pub type TypeIdentifier = [u8; 15];
+
+/// Equivalence kind of minimal TypeObjects
+pub const EK_MINIMAL: u8 = 0xf1;
+
+/// Equivalence kind of complete TypeObjects
+pub const EK_COMPLETE: u8 = 0xf2;
+
+/// A serialized TypeObject and its equivalence hash
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TypeObject {
+    /// The equivalence hash
+    pub hash: EquivalenceHash,
+    /// The TypeObject in XCDR2 little endian, without encapsulation
+    pub bytes: &'static [u8],
+}
+
+impl TypeObject {
+    /// The equivalence kind, [`EK_MINIMAL`] or [`EK_COMPLETE`], following the DHEADER
+    pub fn equivalence_kind(&self) -> u8 {
+        self.bytes[4]
+    }
+
+    /// The TypeIdentifier denoting the type by its hash
+    pub fn type_identifier(&self) -> TypeIdentifier {
+        let mut identifier = [0; 15];
+        identifier[0] = self.equivalence_kind();
+        identifier[1..].copy_from_slice(&self.hash);
+        identifier
+    }
+
+    /// The TypeIdentifier and the size of the serialized TypeObject
+    pub fn with_size(&self) -> TypeIdentifierWithSize {
+        TypeIdentifierWithSize {
+            type_id: self.type_identifier(),
+            typeobject_serialized_size: self.bytes.len() as u32,
+        }
+    }
+}
+
+/// The minimal and complete TypeObject of a type
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TypeObjects {
+    /// The TypeObject describing the assignability of the type
+    pub minimal: TypeObject,
+    /// The TypeObject describing the type in full, including the names
+    pub complete: TypeObject,
+}
+
+/// A struct, union or enum of the IDL, described by TypeObjects
+pub trait Discoverable {
+    /// The TypeObjects of the type
+    const TYPE_OBJECTS: TypeObjects;
+    /// The TypeObjects of the types the type depends upon, directly or indirectly
+    const DEPENDENCIES: &'static [TypeObjects];
+}
+
+/// A TypeIdentifier and the size of the TypeObject it denotes
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct TypeIdentifierWithSize {
+    pub type_id: TypeIdentifier,
+    pub typeobject_serialized_size: u32,
+}
+
+/// The TypeIdentifier of a type and those of its dependencies
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct TypeIdentifierWithDependencies {
+    pub typeid_with_size: TypeIdentifierWithSize,
+    /// The number of dependencies, -1 if unknown
+    pub dependent_typeid_count: i32,
+    pub dependent_typeids: Vec<TypeIdentifierWithSize>,
+}
+
+impl TypeIdentifierWithDependencies {
+    fn new(type_object: TypeObject, dependencies: impl ExactSizeIterator<Item = TypeObject>) -> Self {
+        TypeIdentifierWithDependencies {
+            typeid_with_size: type_object.with_size(),
+            dependent_typeid_count: dependencies.len() as i32,
+            dependent_typeids: dependencies.map(|dependency| dependency.with_size()).collect(),
+        }
+    }
+}
+
+/// The type information advertised by the SEDP data of a topic type
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct TypeInformation {
+    pub minimal: TypeIdentifierWithDependencies,
+    pub complete: TypeIdentifierWithDependencies,
+}
+
+impl TypeInformation {
+    /// Member ID of the minimal identifiers
+    pub const MINIMAL_ID: u32 = 0x1001;
+    /// Member ID of the complete identifiers
+    pub const COMPLETE_ID: u32 = 0x1002;
+
+    /// The type information of the type
+    pub fn of<T: Discoverable + ?Sized>() -> TypeInformation {
+        TypeInformation {
+            minimal: TypeIdentifierWithDependencies::new(
+                T::TYPE_OBJECTS.minimal, T::DEPENDENCIES.iter().map(|objects| objects.minimal)),
+            complete: TypeIdentifierWithDependencies::new(
+                T::TYPE_OBJECTS.complete, T::DEPENDENCIES.iter().map(|objects| objects.complete)),
+        }
+    }
+
+    /// Whether both describe the same type, the minimal TypeIdentifiers being equal
+    pub fn matches(&self, other: &TypeInformation) -> bool {
+        self.minimal.typeid_with_size.type_id == other.minimal.typeid_with_size.type_id
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde_impls {
+    use core::fmt;
+    use serde::de::{self, Deserialize, Deserializer, MapAccess, SeqAccess, Visitor};
+    use serde::ser::{Serialize, SerializeMap, SerializeStruct, Serializer};
+    use crate::xtypes::{self, Delimited, DelimitedValue, Members, MembersVisitor};
+    use super::{TypeIdentifierWithDependencies, TypeIdentifierWithSize, TypeInformation};
+
+    // both are appendable, their fields preceded by a DHEADER
+
+    struct WithSizeFields<'a>(&'a TypeIdentifierWithSize);
+
+    impl<'a> Serialize for WithSizeFields<'a> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let mut state = serializer.serialize_struct("TypeIdentifierWithSize", 2)?;
+            state.serialize_field("type_id", &self.0.type_id)?;
+            state.serialize_field("typeobject_serialized_size", &self.0.typeobject_serialized_size)?;
+            state.end()
+        }
+    }
+
+    impl Serialize for TypeIdentifierWithSize {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            Delimited(&WithSizeFields(self)).serialize(serializer)
+        }
+    }
+
+    struct WithSizeValue(TypeIdentifierWithSize);
+
+    impl<'de> Deserialize<'de> for WithSizeValue {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            struct WithSizeVisitor;
+
+            impl<'de> Visitor<'de> for WithSizeVisitor {
+                type Value = WithSizeValue;
+
+                fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    f.write_str("a TypeIdentifier with size")
+                }
+
+                fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                    let type_id = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(0, &self))?;
+                    let typeobject_serialized_size = seq.next_element()?
+                        .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+                    Ok(WithSizeValue(TypeIdentifierWithSize { type_id, typeobject_serialized_size }))
+                }
+            }
+
+            deserializer.deserialize_struct("TypeIdentifierWithSize",
+                                            &["type_id", "typeobject_serialized_size"], WithSizeVisitor)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for TypeIdentifierWithSize {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            DelimitedValue::<WithSizeValue>::deserialize(deserializer).map(|value| value.0 .0)
+        }
+    }
+
+    struct DependenciesFields<'a>(&'a TypeIdentifierWithDependencies);
+
+    impl<'a> Serialize for DependenciesFields<'a> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let mut state = serializer.serialize_struct("TypeIdentifierWithDependencies", 3)?;
+            state.serialize_field("typeid_with_size", &self.0.typeid_with_size)?;
+            state.serialize_field("dependent_typeid_count", &self.0.dependent_typeid_count)?;
+            state.serialize_field("dependent_typeids", &Delimited(&self.0.dependent_typeids))?;
+            state.end()
+        }
+    }
+
+    impl Serialize for TypeIdentifierWithDependencies {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            Delimited(&DependenciesFields(self)).serialize(serializer)
+        }
+    }
+
+    struct DependenciesValue(TypeIdentifierWithDependencies);
+
+    impl<'de> Deserialize<'de> for DependenciesValue {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            struct DependenciesVisitor;
+
+            impl<'de> Visitor<'de> for DependenciesVisitor {
+                type Value = DependenciesValue;
+
+                fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    f.write_str("a TypeIdentifier with dependencies")
+                }
+
+                fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                    let typeid_with_size = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(0, &self))?;
+                    let dependent_typeid_count = seq.next_element()?
+                        .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+                    let dependent_typeids = seq.next_element::<DelimitedValue<_>>()?
+                        .ok_or_else(|| de::Error::invalid_length(2, &self))?.0;
+                    Ok(DependenciesValue(TypeIdentifierWithDependencies {
+                        typeid_with_size, dependent_typeid_count, dependent_typeids,
+                    }))
+                }
+            }
+
+            deserializer.deserialize_struct("TypeIdentifierWithDependencies",
+                                            &["typeid_with_size", "dependent_typeid_count", "dependent_typeids"],
+                                            DependenciesVisitor)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for TypeIdentifierWithDependencies {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            DelimitedValue::<DependenciesValue>::deserialize(deserializer).map(|value| value.0 .0)
+        }
+    }
+
+    impl xtypes::SerializeMembers for TypeInformation {
+        fn member_count(&self) -> usize {
+            2
+        }
+
+        fn serialize_members<M: SerializeMap>(&self, map: &mut M) -> Result<(), M::Error> {
+            map.serialize_entry(&TypeInformation::MINIMAL_ID, &self.minimal)?;
+            map.serialize_entry(&TypeInformation::COMPLETE_ID, &self.complete)
+        }
+    }
+
+    impl<'de> xtypes::DeserializeMembers<'de> for TypeInformation {
+        fn deserialize_members<A: MapAccess<'de>>(mut map: A) -> Result<TypeInformation, A::Error> {
+            let mut value = TypeInformation::default();
+            while let Some(header) = map.next_key::<u32>()? {
+                match header & xtypes::MEMBER_ID_MASK {
+                    TypeInformation::MINIMAL_ID => value.minimal = map.next_value()?,
+                    TypeInformation::COMPLETE_ID => value.complete = map.next_value()?,
+                    _ => xtypes::skip_member(&mut map, header)?,
+                }
+            }
+            Ok(value)
+        }
+    }
+
+    impl Serialize for TypeInformation {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.serialize_newtype_struct(xtypes::MUTABLE, &Members(self))
+        }
+    }
+
+    impl<'de> Deserialize<'de> for TypeInformation {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<TypeInformation, D::Error> {
+            deserializer.deserialize_newtype_struct(xtypes::MUTABLE, MembersVisitor::default())
+        }
+    }
+}
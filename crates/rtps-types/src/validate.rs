@@ -0,0 +1,161 @@
+// Copyright (C) 2019  Frank Rehberger
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>
+
+//! Validation of the constraints declared in IDL, such as `@range`, `@min` and `@max`
+//!
+//! The generated types implement `Validate`, reporting each violating member by its
+//! path, such as `header.values[2]`. The functions of this module are used by the
+//! generated code.
+use alloc::{borrow::ToOwned, boxed::Box, format, string::String, vec::Vec};
+#[cfg(target_has_atomic = "ptr")]
+use alloc::sync::Arc;
+use core::cmp::Ordering;
+use core::fmt;
+use crate::{BoundedVec, FixedVec};
+
+/// A member violating a constraint
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Violation {
+    /// Path of the member, its IDL name and index of the element, separated by `.`
+    pub path: String,
+    pub message: String,
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.path, self.message)
+    }
+}
+
+/// The violations of a value, at least one
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ValidationError {
+    pub violations: Vec<Violation>,
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (idx, violation) in self.violations.iter().enumerate() {
+            if idx > 0 {
+                f.write_str("; ")?;
+            }
+            fmt::Display::fmt(violation, f)?;
+        }
+        Ok(())
+    }
+}
+
+impl core::error::Error for ValidationError {}
+
+/// A value whose members are constrained
+pub trait Validate {
+    /// Append the violations of the value found at `path`, empty for the value itself
+    fn validate_into(&self, path: &str, violations: &mut Vec<Violation>);
+
+    /// Verify the constraints, reporting all violations
+    fn validate(&self) -> Result<(), ValidationError> {
+        let mut violations = Vec::new();
+        self.validate_into("", &mut violations);
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(ValidationError { violations })
+        }
+    }
+}
+
+impl<T: Validate + ?Sized> Validate for Box<T> {
+    fn validate_into(&self, path: &str, violations: &mut Vec<Violation>) {
+        (**self).validate_into(path, violations)
+    }
+}
+
+#[cfg(target_has_atomic = "ptr")]
+impl<T: Validate + ?Sized> Validate for Arc<T> {
+    fn validate_into(&self, path: &str, violations: &mut Vec<Violation>) {
+        (**self).validate_into(path, violations)
+    }
+}
+
+impl<T: Validate> Validate for Option<T> {
+    fn validate_into(&self, path: &str, violations: &mut Vec<Violation>) {
+        if let Some(value) = self {
+            value.validate_into(path, violations)
+        }
+    }
+}
+
+impl<T: Validate> Validate for [T] {
+    fn validate_into(&self, path: &str, violations: &mut Vec<Violation>) {
+        for (idx, value) in self.iter().enumerate() {
+            value.validate_into(&format!("{}[{}]", path, idx), violations)
+        }
+    }
+}
+
+impl<T: Validate, const N: usize> Validate for [T; N] {
+    fn validate_into(&self, path: &str, violations: &mut Vec<Violation>) {
+        self[..].validate_into(path, violations)
+    }
+}
+
+impl<T: Validate> Validate for Vec<T> {
+    fn validate_into(&self, path: &str, violations: &mut Vec<Violation>) {
+        self[..].validate_into(path, violations)
+    }
+}
+
+impl<T: Validate, const N: usize> Validate for BoundedVec<T, N> {
+    fn validate_into(&self, path: &str, violations: &mut Vec<Violation>) {
+        self[..].validate_into(path, violations)
+    }
+}
+
+impl<T: Validate, const N: usize, const P: usize> Validate for FixedVec<T, N, P> {
+    fn validate_into(&self, path: &str, violations: &mut Vec<Violation>) {
+        self[..].validate_into(path, violations)
+    }
+}
+
+/// The path of the member `id` of the value at `path`
+pub fn member(path: &str, id: &str) -> String {
+    if path.is_empty() {
+        id.to_owned()
+    } else {
+        format!("{}.{}", path, id)
+    }
+}
+
+/// Report the member `id` if below `min`, or not comparable such as NaN
+pub fn min<T: PartialOrd + fmt::Debug>(value: &T, min: T, path: &str, id: &str,
+                                       violations: &mut Vec<Violation>) {
+    if let Some(Ordering::Less) | None = value.partial_cmp(&min) {
+        violations.push(Violation {
+            path: member(path, id),
+            message: format!("{:?} is below the minimum {:?}", value, min),
+        });
+    }
+}
+
+/// Report the member `id` if above `max`, or not comparable such as NaN
+pub fn max<T: PartialOrd + fmt::Debug>(value: &T, max: T, path: &str, id: &str,
+                                       violations: &mut Vec<Violation>) {
+    if let Some(Ordering::Greater) | None = value.partial_cmp(&max) {
+        violations.push(Violation {
+            path: member(path, id),
+            message: format!("{:?} is above the maximum {:?}", value, max),
+        });
+    }
+}
+
+/// Report the member `id` if its length exceeds `bound`
+pub fn bound(len: usize, bound: usize, path: &str, id: &str, violations: &mut Vec<Violation>) {
+    if len > bound {
+        violations.push(Violation {
+            path: member(path, id),
+            message: format!("length {} exceeds the bound {}", len, bound),
+        });
+    }
+}
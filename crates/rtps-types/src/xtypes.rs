@@ -0,0 +1,279 @@
+// Copyright (C) 2019  Frank Rehberger
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0>
+
+//! Extensible types of DDS-XTypes, as passed to a serde data format
+//!
+//! The generated code wraps appendable types and collections of non-primitive elements in
+//! a newtype named [`DELIMITED`], which formats other than CDR regard as transparent.
+//! Towards binary formats, the members of a mutable type are wrapped in a newtype named
+//! [`MUTABLE`] holding a map from the member header, the member ID combined with
+//! [`MUST_UNDERSTAND`], to the member value; absent optional members are left out. CDR
+//! encodes these by the DHEADER and EMHEADER of XCDR2 or the parameter list of XCDR1,
+//! other binary formats encode them as ordinary newtypes and maps. The optional members
+//! of other types are wrapped in a newtype named [`OPTIONAL`] holding the member header
+//! and the option, which XCDR1 encodes by a parameter header and XCDR2 by a presence
+//! flag. Structs and unions
+//! generated for CDR implement [`Extensible`], which selects the encapsulation of a
+//! serialized payload.
+use alloc::format;
+use core::fmt;
+use core::marker::PhantomData;
+use serde::de::{self, Deserialize, Deserializer, IgnoredAny, MapAccess, SeqAccess, Visitor};
+use serde::ser::{Serialize, SerializeMap, Serializer};
+
+/// Name of the newtype whose encoding a delimiter precedes
+pub const DELIMITED: &str = "$rtps_types::xtypes::Delimited";
+
+/// Name of the newtype holding the members of a mutable type
+pub const MUTABLE: &str = "$rtps_types::xtypes::Mutable";
+
+/// Name of the newtype holding the member header and value of an optional member
+pub const OPTIONAL: &str = "$rtps_types::xtypes::Optional";
+
+/// Flag of the member header, the reader failing if not knowing the member
+pub const MUST_UNDERSTAND: u32 = 0x8000_0000;
+
+/// Mask of the member ID within the member header
+pub const MEMBER_ID_MASK: u32 = 0x0fff_ffff;
+
+/// Extensibility kind of a struct or union, selecting its encapsulation
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Extensibility {
+    Final,
+    Appendable,
+    Mutable,
+}
+
+/// A struct or union of the IDL, as encapsulated into a serialized payload
+pub trait Extensible {
+    const EXTENSIBILITY: Extensibility;
+}
+
+/// The serde impls derived for `#[serde(remote = "Self")]`, which the impls of an
+/// appendable type wrap
+pub trait SerializeDerived {
+    fn serialize_derived<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>;
+}
+
+/// See [`SerializeDerived`]
+pub trait DeserializeDerived<'de>: Sized {
+    fn deserialize_derived<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>;
+}
+
+/// The members of a mutable type
+pub trait SerializeMembers {
+    /// The number of members present
+    fn member_count(&self) -> usize;
+
+    /// Serialize the present members as map entries from member header to value
+    fn serialize_members<M: SerializeMap>(&self, map: &mut M) -> Result<(), M::Error>;
+}
+
+/// See [`SerializeMembers`]; members not present take their default value
+pub trait DeserializeMembers<'de>: Sized {
+    fn deserialize_members<A: MapAccess<'de>>(map: A) -> Result<Self, A::Error>;
+}
+
+/// A value wrapped in the newtype [`DELIMITED`]
+pub struct Delimited<'a, T: ?Sized>(pub &'a T);
+
+impl<'a, T: Serialize + ?Sized> Serialize for Delimited<'a, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_newtype_struct(DELIMITED, self.0)
+    }
+}
+
+/// A value read from the newtype [`DELIMITED`]
+pub struct DelimitedValue<T>(pub T);
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for DelimitedValue<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct DelimitedVisitor<T>(PhantomData<T>);
+
+        impl<'de, T: Deserialize<'de>> Visitor<'de> for DelimitedVisitor<T> {
+            type Value = DelimitedValue<T>;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a delimited value")
+            }
+
+            fn visit_newtype_struct<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+                T::deserialize(deserializer).map(DelimitedValue)
+            }
+        }
+
+        deserializer.deserialize_newtype_struct(DELIMITED, DelimitedVisitor(PhantomData))
+    }
+}
+
+/// The derived serialization of an appendable type
+pub struct Derived<'a, T: ?Sized>(pub &'a T);
+
+impl<'a, T: SerializeDerived + ?Sized> Serialize for Derived<'a, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize_derived(serializer)
+    }
+}
+
+/// Visitor of the newtype [`DELIMITED`] holding an appendable type
+pub struct DerivedVisitor<T>(PhantomData<T>);
+
+impl<T> Default for DerivedVisitor<T> {
+    fn default() -> Self {
+        DerivedVisitor(PhantomData)
+    }
+}
+
+impl<'de, T: DeserializeDerived<'de>> Visitor<'de> for DerivedVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("an appendable type")
+    }
+
+    fn visit_newtype_struct<D: Deserializer<'de>>(self, deserializer: D) -> Result<T, D::Error> {
+        T::deserialize_derived(deserializer)
+    }
+}
+
+/// The members of a mutable type, a map from member header to value
+pub struct Members<'a, T: ?Sized>(pub &'a T);
+
+impl<'a, T: SerializeMembers + ?Sized> Serialize for Members<'a, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.0.member_count()))?;
+        self.0.serialize_members(&mut map)?;
+        map.end()
+    }
+}
+
+/// Visitor of the newtype [`MUTABLE`] holding a mutable type
+pub struct MembersVisitor<T>(PhantomData<T>);
+
+impl<T> Default for MembersVisitor<T> {
+    fn default() -> Self {
+        MembersVisitor(PhantomData)
+    }
+}
+
+impl<'de, T: DeserializeMembers<'de>> Visitor<'de> for MembersVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("the members of a mutable type")
+    }
+
+    fn visit_newtype_struct<D: Deserializer<'de>>(self, deserializer: D) -> Result<T, D::Error> {
+        deserializer.deserialize_map(self)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<T, A::Error> {
+        T::deserialize_members(map)
+    }
+}
+
+/// Skip the value of an unknown member, failing if it must be understood
+pub fn skip_member<'de, A: MapAccess<'de>>(map: &mut A, header: u32) -> Result<(), A::Error> {
+    if header & MUST_UNDERSTAND != 0 {
+        return Err(de::Error::custom(
+            format!("unknown member {} must be understood", header & MEMBER_ID_MASK)));
+    }
+    map.next_value::<IgnoredAny>().map(|_| ())
+}
+
+/// An optional member given its member header, wrapped in the newtype [`OPTIONAL`] with the
+/// header towards binary formats
+pub struct Optional<'a, T>(pub u32, pub Option<&'a T>);
+
+impl<'a, T: Serialize> Serialize for Optional<'a, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            self.1.serialize(serializer)
+        } else {
+            serializer.serialize_newtype_struct(OPTIONAL, &(self.0, self.1))
+        }
+    }
+}
+
+/// An optional member read from the newtype [`OPTIONAL`]
+pub struct OptionalValue<T>(pub Option<T>);
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for OptionalValue<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct OptionalVisitor<T>(PhantomData<T>);
+
+        impl<'de, T: Deserialize<'de>> Visitor<'de> for OptionalVisitor<T> {
+            type Value = OptionalValue<T>;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("an optional member")
+            }
+
+            fn visit_newtype_struct<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+                <(u32, Option<T>)>::deserialize(deserializer).map(|(_, value)| OptionalValue(value))
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                seq.next_element::<u32>()?.ok_or_else(|| de::Error::invalid_length(0, &self))?;
+                seq.next_element()?.map(OptionalValue).ok_or_else(|| de::Error::invalid_length(1, &self))
+            }
+        }
+
+        if deserializer.is_human_readable() {
+            Option::deserialize(deserializer).map(OptionalValue)
+        } else {
+            deserializer.deserialize_newtype_struct(OPTIONAL, OptionalVisitor(PhantomData))
+        }
+    }
+}
+
+/// `#[serde(with)]` of collections whose elements are not primitive, such as sequences
+/// of structs or strings
+pub mod delimited {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use super::{Delimited, DelimitedValue};
+
+    pub fn serialize<T: Serialize, S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        Delimited(value).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, T: Deserialize<'de>, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        DelimitedValue::deserialize(deserializer).map(|value| value.0)
+    }
+}
+
+/// `#[serde(serialize_with, deserialize_with)]` of the optional members of final and
+/// appendable types, given the member header
+pub mod optional {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use super::{Optional, OptionalValue};
+
+    pub fn serialize<const HEADER: u32, T: Serialize, S: Serializer>(value: &Option<T>, serializer: S)
+                                                                     -> Result<S::Ok, S::Error> {
+        Optional(HEADER, value.as_ref()).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, T: Deserialize<'de>, D: Deserializer<'de>>(deserializer: D)
+                                                                       -> Result<Option<T>, D::Error> {
+        OptionalValue::deserialize(deserializer).map(|value| value.0)
+    }
+}
+
+/// `#[serde(serialize_with, deserialize_with)]` of optional collections whose elements are
+/// not primitive, given the member header
+pub mod delimited_option {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use super::{Delimited, DelimitedValue, Optional, OptionalValue};
+
+    pub fn serialize<const HEADER: u32, T: Serialize, S: Serializer>(value: &Option<T>, serializer: S)
+                                                                     -> Result<S::Ok, S::Error> {
+        Optional(HEADER, value.as_ref().map(Delimited).as_ref()).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, T: Deserialize<'de>, D: Deserializer<'de>>(deserializer: D)
+                                                                       -> Result<Option<T>, D::Error> {
+        OptionalValue::<DelimitedValue<T>>::deserialize(deserializer).map(|value| value.0.map(|value| value.0))
+    }
+}
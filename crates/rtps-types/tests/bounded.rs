@@ -0,0 +1,59 @@
+extern crate rtps_types;
+
+use std::convert::TryFrom;
+use rtps_types::{BoundError, BoundedString, BoundedVec, BoundedWideString};
+
+#[test]
+fn bounded_string() {
+    let mut s = BoundedString::<4>::try_from("abc").unwrap();
+    assert_eq!(s.push('d'), Ok(()));
+    assert_eq!(s.push('e'), Err(BoundError { bound: 4, len: 5 }));
+    assert_eq!(s, "abcd");
+
+    // the bound counts bytes
+    s.clear();
+    assert_eq!(s.push_str("ää"), Ok(()));
+    assert!(s.insert(0, 'a').is_err());
+    assert!(BoundedString::<4>::try_from("abcde".to_owned()).is_err());
+    assert_eq!(BoundedString::<4>::BOUND, 4);
+}
+
+#[test]
+fn bounded_wide_string() {
+    // the bound counts characters
+    let mut s = BoundedWideString::<2>::try_from("\u{20ac}").unwrap();
+    assert_eq!(s.push('\u{1d11e}'), Ok(()));
+    assert_eq!(s.push('a'), Err(BoundError { bound: 2, len: 3 }));
+    assert_eq!(s.len(), 7);
+    assert!(BoundedWideString::<2>::try_from("abc").is_err());
+    let s: BoundedWideString<2> = serde_json::from_str("\"äö\"").unwrap();
+    assert_eq!(s, "äö");
+    assert!(serde_json::from_str::<BoundedWideString<2>>("\"abc\"").is_err());
+}
+
+#[test]
+fn bounded_vec() {
+    let mut v = BoundedVec::<u8, 2>::new();
+    assert_eq!(v.push(1), Ok(()));
+    assert_eq!(v.insert(0, 0), Ok(()));
+    assert_eq!(v.push(2), Err(BoundError { bound: 2, len: 3 }));
+    assert_eq!(v.as_slice(), &[0, 1]);
+    v[1] = 3;
+    assert_eq!(v.into_vec(), vec![0, 3]);
+
+    assert!(BoundedVec::<u8, 2>::try_from(vec![1, 2, 3]).is_err());
+    let mut v = BoundedVec::<u8, 2>::try_from(&[1][..]).unwrap();
+    assert!(v.extend_from_slice(&[2, 3]).is_err());
+    assert_eq!(v.len(), 1);
+}
+
+#[test]
+fn serde_rejects_oversize_data() {
+    let s: BoundedString<3> = serde_json::from_str("\"abc\"").unwrap();
+    assert_eq!(serde_json::to_string(&s).unwrap(), "\"abc\"");
+    assert!(serde_json::from_str::<BoundedString<3>>("\"abcd\"").is_err());
+
+    let v: BoundedVec<u16, 3> = serde_json::from_str("[1, 2, 3]").unwrap();
+    assert_eq!(serde_json::to_string(&v).unwrap(), "[1,2,3]");
+    assert!(serde_json::from_str::<BoundedVec<u16, 3>>("[1, 2, 3, 4]").is_err());
+}
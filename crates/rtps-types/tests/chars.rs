@@ -0,0 +1,44 @@
+extern crate rtps_types;
+
+use std::convert::TryFrom;
+use rtps_types::{BoundedWString, Char, CharError, WChar, WString};
+
+#[test]
+fn char_latin1() {
+    const A: Char = Char::from_char('a');
+    assert_eq!(A, Char(b'a'));
+    assert_eq!(char::from(Char(0xE4)), 'ä');
+    assert_eq!(Char::try_from('ä'), Ok(Char(0xE4)));
+    assert_eq!(Char::try_from('€'), Err(CharError { code: 0x20AC }));
+}
+
+#[test]
+fn wchar_utf16() {
+    assert_eq!(WChar::try_from('€'), Ok(WChar(0x20AC)));
+    assert!(WChar::try_from('😀').is_err());
+    assert!(char::try_from(WChar(0xD800)).is_err());
+    assert_eq!(char::try_from(WChar(0x20AC)), Ok('€'));
+}
+
+#[test]
+fn wstring_utf16() {
+    let s = WString::from("a€😀");
+    assert_eq!(s.as_units(), &[0x61, 0x20AC, 0xD83D, 0xDE00]);
+    assert_eq!(s.to_utf8().unwrap(), "a€😀");
+    assert_eq!(WString::from_units(vec![0xD800]).to_utf8(), Err(CharError { code: 0xD800 }));
+
+    let mut bounded = BoundedWString::<3>::try_from("a€").unwrap();
+    // the bound counts code units
+    assert!(bounded.push_str("😀").is_err());
+    assert!(bounded.push(WChar(0x62)).is_ok());
+    assert_eq!(bounded.len(), 3);
+}
+
+#[test]
+fn serde_encoding() {
+    assert_eq!(serde_json::to_string(&Char(b'a')).unwrap(), "97");
+    assert_eq!(serde_json::to_string(&WString::from("a€")).unwrap(), "[97,8364]");
+    let s: WString = serde_json::from_str("[97,8364]").unwrap();
+    assert_eq!(s, WString::from("a€"));
+    assert!(serde_json::from_str::<BoundedWString<1>>("[97,8364]").is_err());
+}
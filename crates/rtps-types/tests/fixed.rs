@@ -0,0 +1,69 @@
+extern crate rtps_types;
+
+use std::convert::TryFrom;
+use std::mem;
+use rtps_types::{BoundError, FixedLayout, FixedString, FixedVec, LayoutError, Padding};
+
+#[test]
+fn fixed_string() {
+    // the length counts the terminating NUL, unused bytes are zero
+    let mut s = FixedString::<5>::try_from("abc").unwrap();
+    assert_eq!(s.as_bytes(), [4, 0, 0, 0, b'a', b'b', b'c', 0, 0, 0]);
+    assert_eq!(s.set("abcdef"), Err(BoundError { bound: 5, len: 6 }));
+    assert_eq!(s, "abc");
+    s.set("ä").unwrap();
+    assert_eq!((s.len(), s.as_str()), (2, "ä"));
+    assert_eq!(FixedString::<5>::default().as_bytes(), [1, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+
+    assert!(FixedString::<5>::read_bytes(&[6, 0, 0, 0, b'a', b'b', b'c', b'd', b'e', 0]).is_ok());
+    assert_eq!(FixedString::<5>::read_bytes(&[7, 0, 0, 0, 0, 0, 0, 0, 0, 0]).unwrap_err(), LayoutError::Invalid);
+    assert_eq!(FixedString::<5>::read_bytes(&[0; 10]).unwrap_err(), LayoutError::Invalid);
+    assert_eq!(FixedString::<5>::read_bytes(&[2, 0, 0, 0, 0xff, 0, 0, 0, 0, 0]).unwrap_err(), LayoutError::Invalid);
+    assert_eq!(FixedString::<5>::read_bytes(&[2, 0, 0, 0, b'a', b'b', 0, 0, 0, 0]).unwrap_err(), LayoutError::Invalid);
+}
+
+#[test]
+fn fixed_vec() {
+    let mut v = FixedVec::<u16, 3>::new();
+    assert_eq!(v.push(1), Ok(()));
+    assert_eq!(v.push(2), Ok(()));
+    assert_eq!(v.as_bytes(), [2, 0, 0, 0, 1, 0, 2, 0, 0, 0]);
+    assert_eq!(v.pop(), Some(2));
+    assert_eq!(v.as_slice(), [1]);
+    assert!(FixedVec::<u16, 3>::try_from(vec![1, 2, 3, 4]).is_err());
+
+    // elements aligned to 8 bytes follow 4 bytes of padding
+    let wide = FixedVec::<u64, 1, 4>::try_from(vec![5]).unwrap();
+    assert_eq!(mem::size_of_val(&wide), 16);
+    assert_eq!(wide.as_bytes(), [1, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0]);
+
+    // without the padding a sequence of any elements, not cast to bytes
+    let names = FixedVec::<String, 2>::try_from(vec!["a".to_owned()]).unwrap();
+    assert_eq!(names.as_slice(), ["a"]);
+    assert_eq!(FixedVec::<u64, 1>::try_from(vec![5]).unwrap().as_slice(), [5]);
+
+    // equal by the elements in use
+    let mut other = FixedVec::<u16, 3>::try_from(vec![1, 9]).unwrap();
+    other.pop();
+    assert_eq!(v, other);
+    assert_eq!(FixedVec::<bool, 2>::read_bytes(&[1, 0, 0, 0, 1, 2]).unwrap_err(), LayoutError::Invalid);
+    assert_eq!(FixedVec::<u8, 2>::read_bytes(&[3, 0, 0, 0, 1, 2]).unwrap_err(), LayoutError::Invalid);
+}
+
+#[test]
+fn padding_is_ignored() {
+    assert_eq!(Padding([1, 2]), Padding::default());
+    assert_eq!(format!("{:?}", Padding::<3>::default()), "Padding<3>");
+    assert!(<bool>::read_bytes(&[1]).unwrap());
+    assert_eq!(u32::from_bytes(&[0; 3]).unwrap_err(), LayoutError::Size { expected: 4, actual: 3 });
+}
+
+#[test]
+fn serde_rejects_oversize_data() {
+    let s: FixedString<3> = serde_json::from_str("\"abc\"").unwrap();
+    assert_eq!(serde_json::to_string(&s).unwrap(), "\"abc\"");
+    assert!(serde_json::from_str::<FixedString<3>>("\"abcd\"").is_err());
+    let v: FixedVec<u8, 2> = serde_json::from_str("[1, 2]").unwrap();
+    assert_eq!(serde_json::to_string(&v).unwrap(), "[1,2]");
+    assert!(serde_json::from_str::<FixedVec<u8, 2>>("[1, 2, 3]").is_err());
+}
@@ -0,0 +1,48 @@
+extern crate rtps_types;
+
+use rtps_types::LongDouble;
+
+#[test]
+fn f64_conversion() {
+    assert_eq!(LongDouble::from_f64(1.0).to_bits(), 0x3FFF << 112);
+    assert_eq!(LongDouble::from_f64(-2.0).to_bits(), 0xC000 << 112);
+    assert_eq!(LongDouble::from_f64(0.0).to_bits(), 0);
+
+    let values = [1.0, -1.5, 0.1, std::f64::consts::PI, f64::MAX, f64::MIN_POSITIVE,
+        5e-324, 1.5e-310, -0.0, f64::INFINITY, f64::NEG_INFINITY];
+    for &val in values.iter() {
+        let long_double = LongDouble::from(val);
+        assert_eq!(long_double.to_f64().to_bits(), val.to_bits(), "{}", val);
+    }
+    assert!(LongDouble::from(f64::NAN).is_nan());
+    assert!(LongDouble::from(f64::NAN).to_f64().is_nan());
+}
+
+#[test]
+fn lossy_conversion() {
+    // 1 + 2^-60 rounds to 1, 1 + 2^-52 + 2^-53 rounds to even
+    let one = 0x3FFFu128 << 112;
+    assert_eq!(LongDouble::from_bits(one | 1 << 52).to_f64(), 1.0);
+    assert_eq!(LongDouble::from_bits(one | 3 << 59).to_f64(), 1.0 + 2.0 * f64::EPSILON);
+    // beyond the range of f64
+    assert_eq!(LongDouble::from_bits(0x7FFEu128 << 112).to_f64(), f64::INFINITY);
+    assert_eq!(LongDouble::from_bits(1).to_f64(), 0.0);
+}
+
+#[test]
+fn comparison() {
+    assert_eq!(LongDouble::from(0.0), LongDouble::from(-0.0));
+    assert_ne!(LongDouble::from(f64::NAN), LongDouble::from(f64::NAN));
+    assert!(LongDouble::from(-3.0) < LongDouble::from(-2.0));
+    assert!(LongDouble::from(-1.0) < LongDouble::from(0.5));
+    assert!(LongDouble::from(1.0) < LongDouble::from_bits((0x3FFF << 112) | 1));
+}
+
+#[test]
+fn bytes_and_serde() {
+    let val = LongDouble::from(1.0);
+    assert_eq!(val.to_be_bytes()[..2], [0x3F, 0xFF]);
+    assert_eq!(LongDouble::from_le_bytes(val.to_le_bytes()).to_bits(), val.to_bits());
+    assert_eq!(serde_json::to_string(&val).unwrap(), "1.0");
+    assert_eq!(serde_json::from_str::<LongDouble>("1.0").unwrap(), val);
+}
@@ -0,0 +1,42 @@
+extern crate rtps_types;
+
+use std::convert::TryFrom;
+use rtps_types::validate::{self, Violation};
+use rtps_types::{BoundedVec, Validate};
+
+struct Point {
+    x: i32,
+}
+
+impl Validate for Point {
+    fn validate_into(&self, path: &str, violations: &mut Vec<Violation>) {
+        validate::min(&self.x, 0, path, "x", violations);
+        validate::max(&self.x, 10, path, "x", violations);
+    }
+}
+
+#[test]
+fn violation_paths() {
+    assert!(Point { x: 5 }.validate().is_ok());
+
+    let points = vec![Point { x: 1 }, Point { x: -1 }, Point { x: 11 }];
+    let mut violations = Vec::new();
+    points.validate_into("points", &mut violations);
+    let paths = violations.iter().map(|v| v.path.as_str()).collect::<Vec<_>>();
+    assert_eq!(paths, ["points[1].x", "points[2].x"]);
+    assert_eq!(violations[0].message, "-1 is below the minimum 0");
+
+    let nested = BoundedVec::<Option<Box<Point>>, 2>::try_from(vec![None, Some(Box::new(Point { x: 20 }))])
+        .unwrap();
+    let err = nested.validate().unwrap_err();
+    assert_eq!(err.to_string(), "[1].x: 20 is above the maximum 10");
+}
+
+#[test]
+fn nan_violates() {
+    let mut violations = Vec::new();
+    validate::min(&f64::NAN, 0.0, "", "ratio", &mut violations);
+    validate::bound(9, 8, "", "name", &mut violations);
+    assert_eq!(violations.len(), 2);
+    assert_eq!(violations[1].to_string(), "name: length 9 exceeds the bound 8");
+}
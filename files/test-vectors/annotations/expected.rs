@@ -0,0 +1,210 @@
+#[allow(unused_imports)]
+use std::vec::Vec;
+#[allow(unused_imports)]
+use serde_derive::{Serialize, Deserialize};
+#[allow(non_snake_case)]
+pub mod Annotated {
+    #[allow(unused_imports)]
+    use serde_derive::{Serialize, Deserialize};
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[derive(Serialize, Deserialize)]
+    #[derive(Clone, Debug)]
+    pub struct Sample {
+        pub id: i32,
+        #[serde(default)]
+        #[serde(
+            serialize_with = "rtps_types::xtypes::optional::serialize::<1, _, _>",
+            deserialize_with = "rtps_types::xtypes::optional::deserialize"
+        )]
+        pub level: Option<i16>,
+        pub count: u8,
+    }
+    impl Default for Sample {
+        fn default() -> Sample {
+            Sample {
+                id: Default::default(),
+                level: Default::default(),
+                count: 7,
+            }
+        }
+    }
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[derive(Clone, Debug, Default)]
+    #[repr(u32)]
+    pub enum Color {
+        RED = 1,
+        #[default]
+        GREEN = 2,
+        BLUE = 3,
+    }
+    impl Color {
+        /// The value of the enumerator
+        pub const fn value(&self) -> u32 {
+            match self {
+                Color::RED => 1,
+                Color::GREEN => 2,
+                Color::BLUE => 3,
+            }
+        }
+        /// The IDL name of the enumerator
+        pub const fn as_str(&self) -> &'static str {
+            match self {
+                Color::RED => "RED",
+                Color::GREEN => "GREEN",
+                Color::BLUE => "BLUE",
+            }
+        }
+    }
+    impl ::std::convert::TryFrom<u32> for Color {
+        type Error = rtps_types::EnumError;
+        fn try_from(value: u32) -> Result<Color, rtps_types::EnumError> {
+            match value {
+                1 => Ok(Color::RED),
+                2 => Ok(Color::GREEN),
+                3 => Ok(Color::BLUE),
+                _ => Err(rtps_types::EnumError::Value(value)),
+            }
+        }
+    }
+    impl From<Color> for u32 {
+        fn from(value: Color) -> u32 {
+            value.value()
+        }
+    }
+    impl ::std::fmt::Display for Color {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            f.write_str(self.as_str())
+        }
+    }
+    impl ::std::str::FromStr for Color {
+        type Err = rtps_types::EnumError;
+        fn from_str(name: &str) -> Result<Color, rtps_types::EnumError> {
+            match name {
+                "RED" => Ok(Color::RED),
+                "GREEN" => Ok(Color::GREEN),
+                "BLUE" => Ok(Color::BLUE),
+                _ => Err(rtps_types::EnumError::Name(name.to_owned())),
+            }
+        }
+    }
+    impl serde::Serialize for Color {
+        fn serialize<S: serde::Serializer>(
+            &self,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            serializer.serialize_u32(self.value())
+        }
+    }
+    impl<'de> serde::Deserialize<'de> for Color {
+        fn deserialize<D: serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Color, D::Error> {
+            let value = <u32 as serde::Deserialize>::deserialize(deserializer)?;
+            <Color as ::std::convert::TryFrom<u32>>::try_from(value)
+                .map_err(serde::de::Error::custom)
+        }
+    }
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[derive(Serialize, Deserialize)]
+    #[derive(Clone, Debug)]
+    #[serde(remote = "Self")]
+    pub enum Choice {
+        RED { value: Box<i32> },
+        default { raw: u8 },
+    }
+    impl Default for Choice {
+        fn default() -> Choice {
+            Choice::default {
+                raw: Default::default(),
+            }
+        }
+    }
+    impl serde::Serialize for Choice {
+        fn serialize<S: serde::Serializer>(
+            &self,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            if serializer.is_human_readable() {
+                Choice::serialize(self, serializer)
+            } else {
+                serializer
+                    .serialize_newtype_struct(
+                        rtps_types::xtypes::MUTABLE,
+                        &rtps_types::xtypes::Members(self),
+                    )
+            }
+        }
+    }
+    impl<'de> serde::Deserialize<'de> for Choice {
+        fn deserialize<D: serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Choice, D::Error> {
+            let value = if deserializer.is_human_readable() {
+                Choice::deserialize(deserializer)?
+            } else {
+                deserializer
+                    .deserialize_newtype_struct(
+                        rtps_types::xtypes::MUTABLE,
+                        rtps_types::xtypes::MembersVisitor::<Choice>::default(),
+                    )?
+            };
+            Ok(value)
+        }
+    }
+    impl rtps_types::xtypes::SerializeMembers for Choice {
+        fn member_count(&self) -> usize {
+            match self {
+                Choice::RED { .. } => 2,
+                Choice::default { .. } => 2,
+            }
+        }
+        fn serialize_members<M: serde::ser::SerializeMap>(
+            &self,
+            map: &mut M,
+        ) -> Result<(), M::Error> {
+            match self {
+                Choice::RED { value } => {
+                    map.serialize_entry(&rtps_types::xtypes::MUST_UNDERSTAND, &1u32)?;
+                    map.serialize_entry(&1u32, value)?;
+                }
+                Choice::default { raw: value } => {
+                    map.serialize_entry(&rtps_types::xtypes::MUST_UNDERSTAND, &2u32)?;
+                    map.serialize_entry(&2u32, value)?;
+                }
+            }
+            Ok(())
+        }
+    }
+    impl<'de> rtps_types::xtypes::DeserializeMembers<'de> for Choice {
+        fn deserialize_members<A: serde::de::MapAccess<'de>>(
+            mut map: A,
+        ) -> Result<Choice, A::Error> {
+            let mut value = Choice::default();
+            while let Some(header) = map.next_key::<u32>()? {
+                match header & rtps_types::xtypes::MEMBER_ID_MASK {
+                    0 => {
+                        map.next_value::<u32>()?;
+                    }
+                    1 => {
+                        value = Choice::RED {
+                            value: map.next_value()?,
+                        };
+                    }
+                    2 => {
+                        value = Choice::default {
+                            raw: map.next_value()?,
+                        };
+                    }
+                    _ => rtps_types::xtypes::skip_member(&mut map, header)?,
+                }
+            }
+            Ok(value)
+        }
+    }
+    #[allow(dead_code)]
+    #[allow(non_upper_case_globals)]
+    pub const MAX: i32 = 10;
+}
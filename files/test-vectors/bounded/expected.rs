@@ -0,0 +1,31 @@
+#[allow(unused_imports)]
+use std::vec::Vec;
+#[allow(unused_imports)]
+use serde_derive::{Serialize, Deserialize};
+#[allow(non_snake_case)]
+pub mod Bounded {
+    #[allow(unused_imports)]
+    use serde_derive::{Serialize, Deserialize};
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    pub type Name = rtps_types::BoundedString<16>;
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    pub type Payload = rtps_types::BoundedVec<u8, 64>;
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[derive(Serialize, Deserialize)]
+    #[derive(Clone, Debug, Default)]
+    pub struct Sample {
+        pub name: Name,
+        pub description: rtps_types::BoundedString<34>,
+        #[serde(with = "rtps_types::xtypes::delimited")]
+        pub aliases: rtps_types::BoundedVec<Name, 8>,
+        #[serde(with = "rtps_types::xtypes::delimited")]
+        pub matrix: Vec<rtps_types::BoundedVec<i32, 3>>,
+        pub payload: Payload,
+    }
+    #[allow(dead_code)]
+    #[allow(non_upper_case_globals)]
+    pub const MAX_NAME: u32 = 16;
+}
@@ -0,0 +1,2246 @@
+#[allow(unused_imports)]
+use std::vec::Vec;
+#[allow(unused_imports)]
+use serde_derive::{Serialize, Deserialize};
+#[allow(non_snake_case)]
+pub mod Telemetry {
+    #[allow(unused_imports)]
+    use serde_derive::{Serialize, Deserialize};
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[derive(Clone, Debug, PartialEq, Default)]
+    #[repr(u32)]
+    pub enum Status {
+        #[default]
+        IDLE = 0,
+        ACTIVE = 1,
+        FAILED = 8,
+    }
+    impl Status {
+        /// The value of the enumerator
+        pub const fn value(&self) -> u32 {
+            match self {
+                Status::IDLE => 0,
+                Status::ACTIVE => 1,
+                Status::FAILED => 8,
+            }
+        }
+        /// The IDL name of the enumerator
+        pub const fn as_str(&self) -> &'static str {
+            match self {
+                Status::IDLE => "IDLE",
+                Status::ACTIVE => "ACTIVE",
+                Status::FAILED => "FAILED",
+            }
+        }
+    }
+    impl ::std::convert::TryFrom<u32> for Status {
+        type Error = rtps_types::EnumError;
+        fn try_from(value: u32) -> Result<Status, rtps_types::EnumError> {
+            match value {
+                0 => Ok(Status::IDLE),
+                1 => Ok(Status::ACTIVE),
+                8 => Ok(Status::FAILED),
+                _ => Err(rtps_types::EnumError::Value(value)),
+            }
+        }
+    }
+    impl From<Status> for u32 {
+        fn from(value: Status) -> u32 {
+            value.value()
+        }
+    }
+    impl ::std::fmt::Display for Status {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            f.write_str(self.as_str())
+        }
+    }
+    impl ::std::str::FromStr for Status {
+        type Err = rtps_types::EnumError;
+        fn from_str(name: &str) -> Result<Status, rtps_types::EnumError> {
+            match name {
+                "IDLE" => Ok(Status::IDLE),
+                "ACTIVE" => Ok(Status::ACTIVE),
+                "FAILED" => Ok(Status::FAILED),
+                _ => Err(rtps_types::EnumError::Name(name.to_owned())),
+            }
+        }
+    }
+    impl serde::Serialize for Status {
+        fn serialize<S: serde::Serializer>(
+            &self,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            serializer.serialize_u32(self.value())
+        }
+    }
+    impl<'de> serde::Deserialize<'de> for Status {
+        fn deserialize<D: serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Status, D::Error> {
+            let value = <u32 as serde::Deserialize>::deserialize(deserializer)?;
+            <Status as ::std::convert::TryFrom<u32>>::try_from(value)
+                .map_err(serde::de::Error::custom)
+        }
+    }
+    impl rtps_cdr::CdrEncode for Status {
+        fn encode(&self, ser: &mut rtps_cdr::Serializer<'_>) -> rtps_cdr::Result<()> {
+            rtps_cdr::CdrEncode::encode(&self.value(), ser)
+        }
+    }
+    impl rtps_cdr::CdrDecode for Status {
+        fn decode(de: &mut rtps_cdr::Deserializer<'_>) -> rtps_cdr::Result<Status> {
+            let value = <u32 as rtps_cdr::CdrDecode>::decode(de)?;
+            Ok(<Status as ::std::convert::TryFrom<u32>>::try_from(value)?)
+        }
+    }
+    impl rtps_cdr::SerializedSize for Status {
+        const MAX_SIZE_XCDR1: Option<usize> = Some(4);
+        const MIN_SIZE_XCDR1: usize = 4;
+        const MAX_SIZE_XCDR2: Option<usize> = Some(4);
+        const MIN_SIZE_XCDR2: usize = 4;
+    }
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    pub type Samples = Vec<f64>;
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[derive(Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Default)]
+    #[serde(remote = "Self")]
+    pub struct Empty {}
+    impl rtps_types::Validate for Empty {
+        #[allow(clippy::only_used_in_recursion)]
+        fn validate_into(
+            &self,
+            _path: &str,
+            _violations: &mut Vec<rtps_types::Violation>,
+        ) {}
+    }
+    #[allow(dead_code)]
+    impl Empty {
+        /// Verify the constraints declared in IDL, reporting the path of each violating member
+        pub fn validate(&self) -> Result<(), rtps_types::ValidationError> {
+            rtps_types::Validate::validate(self)
+        }
+    }
+    impl rtps_types::xtypes::Extensible for Empty {
+        const EXTENSIBILITY: rtps_types::xtypes::Extensibility = rtps_types::xtypes::Extensibility::Final;
+    }
+    impl serde::Serialize for Empty {
+        fn serialize<S: serde::Serializer>(
+            &self,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            Empty::serialize(self, serializer)
+        }
+    }
+    impl<'de> serde::Deserialize<'de> for Empty {
+        fn deserialize<D: serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Empty, D::Error> {
+            let value = Empty::deserialize(deserializer)?;
+            rtps_types::Validate::validate(&value).map_err(serde::de::Error::custom)?;
+            Ok(value)
+        }
+    }
+    impl rtps_cdr::CdrEncode for Empty {
+        fn encode(&self, ser: &mut rtps_cdr::Serializer<'_>) -> rtps_cdr::Result<()> {
+            let _ = ser;
+            Ok(())
+        }
+    }
+    impl rtps_cdr::CdrDecode for Empty {
+        fn decode(de: &mut rtps_cdr::Deserializer<'_>) -> rtps_cdr::Result<Empty> {
+            let value = {
+                let _ = de;
+                Empty {}
+            };
+            rtps_types::Validate::validate(&value)?;
+            Ok(value)
+        }
+    }
+    impl rtps_cdr::SerializedSize for Empty {
+        const MAX_SIZE_XCDR1: Option<usize> = Some(0);
+        const MIN_SIZE_XCDR1: usize = 0;
+        const MAX_SIZE_XCDR2: Option<usize> = Some(0);
+        const MIN_SIZE_XCDR2: usize = 0;
+    }
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[derive(Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Default)]
+    #[serde(remote = "Self")]
+    pub struct Header {
+        pub stamp: u64,
+        pub quality: u8,
+        pub status: Status,
+        pub source: rtps_types::BoundedString<16>,
+    }
+    impl rtps_types::Validate for Header {
+        #[allow(clippy::only_used_in_recursion)]
+        fn validate_into(
+            &self,
+            path: &str,
+            violations: &mut Vec<rtps_types::Violation>,
+        ) {
+            {
+                let value = &self.quality;
+                rtps_types::validate::min(value, 0, path, "quality", violations);
+                rtps_types::validate::max(value, 100, path, "quality", violations);
+            }
+        }
+    }
+    #[allow(dead_code)]
+    impl Header {
+        /// Verify the constraints declared in IDL, reporting the path of each violating member
+        pub fn validate(&self) -> Result<(), rtps_types::ValidationError> {
+            rtps_types::Validate::validate(self)
+        }
+    }
+    impl rtps_types::xtypes::Extensible for Header {
+        const EXTENSIBILITY: rtps_types::xtypes::Extensibility = rtps_types::xtypes::Extensibility::Final;
+    }
+    impl serde::Serialize for Header {
+        fn serialize<S: serde::Serializer>(
+            &self,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            Header::serialize(self, serializer)
+        }
+    }
+    impl<'de> serde::Deserialize<'de> for Header {
+        fn deserialize<D: serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Header, D::Error> {
+            let value = Header::deserialize(deserializer)?;
+            rtps_types::Validate::validate(&value).map_err(serde::de::Error::custom)?;
+            Ok(value)
+        }
+    }
+    impl rtps_cdr::CdrEncode for Header {
+        fn encode(&self, ser: &mut rtps_cdr::Serializer<'_>) -> rtps_cdr::Result<()> {
+            rtps_cdr::CdrEncode::encode(&self.stamp, ser)?;
+            rtps_cdr::CdrEncode::encode(&self.quality, ser)?;
+            rtps_cdr::CdrEncode::encode(&self.status, ser)?;
+            rtps_cdr::CdrEncode::encode(&self.source, ser)?;
+            Ok(())
+        }
+    }
+    impl rtps_cdr::CdrDecode for Header {
+        fn decode(de: &mut rtps_cdr::Deserializer<'_>) -> rtps_cdr::Result<Header> {
+            let value = Header {
+                stamp: rtps_cdr::CdrDecode::decode(de)?,
+                quality: rtps_cdr::CdrDecode::decode(de)?,
+                status: rtps_cdr::CdrDecode::decode(de)?,
+                source: rtps_cdr::CdrDecode::decode(de)?,
+            };
+            rtps_types::Validate::validate(&value)?;
+            Ok(value)
+        }
+    }
+    impl rtps_cdr::SerializedSize for Header {
+        const MAX_SIZE_XCDR1: Option<usize> = Some(37);
+        const MIN_SIZE_XCDR1: usize = 21;
+        const MAX_SIZE_XCDR2: Option<usize> = Some(37);
+        const MIN_SIZE_XCDR2: usize = 21;
+    }
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[derive(Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Default)]
+    #[serde(remote = "Self")]
+    pub struct Frame {
+        pub header: Header,
+        pub samples: Samples,
+        pub counters: rtps_types::BoundedVec<i16, 8>,
+        pub matrix: [[f32; 3]; 2],
+        #[serde(with = "rtps_types::xtypes::delimited")]
+        pub tags: Vec<String>,
+        #[serde(default)]
+        #[serde(
+            serialize_with = "rtps_types::xtypes::delimited_option::serialize::<5, _, _>",
+            deserialize_with = "rtps_types::xtypes::delimited_option::deserialize"
+        )]
+        pub history: Option<Vec<Header>>,
+        #[serde(default)]
+        #[serde(
+            serialize_with = "rtps_types::xtypes::optional::serialize::<6, _, _>",
+            deserialize_with = "rtps_types::xtypes::optional::deserialize"
+        )]
+        pub offset: Option<i32>,
+        pub marker: Empty,
+    }
+    impl rtps_types::Validate for Frame {
+        #[allow(clippy::only_used_in_recursion)]
+        fn validate_into(
+            &self,
+            path: &str,
+            violations: &mut Vec<rtps_types::Violation>,
+        ) {
+            {
+                let value = &self.header;
+                rtps_types::Validate::validate_into(
+                    value,
+                    &rtps_types::validate::member(path, "header"),
+                    violations,
+                );
+            }
+            if let Some(value) = self.history.as_ref() {
+                rtps_types::Validate::validate_into(
+                    value,
+                    &rtps_types::validate::member(path, "history"),
+                    violations,
+                );
+            }
+            {
+                let value = &self.marker;
+                rtps_types::Validate::validate_into(
+                    value,
+                    &rtps_types::validate::member(path, "marker"),
+                    violations,
+                );
+            }
+        }
+    }
+    #[allow(dead_code)]
+    impl Frame {
+        /// Verify the constraints declared in IDL, reporting the path of each violating member
+        pub fn validate(&self) -> Result<(), rtps_types::ValidationError> {
+            rtps_types::Validate::validate(self)
+        }
+    }
+    impl rtps_types::xtypes::Extensible for Frame {
+        const EXTENSIBILITY: rtps_types::xtypes::Extensibility = rtps_types::xtypes::Extensibility::Final;
+    }
+    impl serde::Serialize for Frame {
+        fn serialize<S: serde::Serializer>(
+            &self,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            Frame::serialize(self, serializer)
+        }
+    }
+    impl<'de> serde::Deserialize<'de> for Frame {
+        fn deserialize<D: serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Frame, D::Error> {
+            let value = Frame::deserialize(deserializer)?;
+            rtps_types::Validate::validate(&value).map_err(serde::de::Error::custom)?;
+            Ok(value)
+        }
+    }
+    impl rtps_cdr::CdrEncode for Frame {
+        fn encode(&self, ser: &mut rtps_cdr::Serializer<'_>) -> rtps_cdr::Result<()> {
+            rtps_cdr::CdrEncode::encode(&self.header, ser)?;
+            rtps_cdr::CdrEncode::encode(&self.samples, ser)?;
+            rtps_cdr::CdrEncode::encode(&self.counters, ser)?;
+            rtps_cdr::CdrEncode::encode(&self.matrix, ser)?;
+            rtps_cdr::CdrEncode::encode(&rtps_cdr::Delimited(&self.tags), ser)?;
+            ser.optional(5u32, self.history.as_ref().map(rtps_cdr::Delimited))?;
+            ser.optional(6u32, self.offset.as_ref())?;
+            rtps_cdr::CdrEncode::encode(&self.marker, ser)?;
+            Ok(())
+        }
+    }
+    impl rtps_cdr::CdrDecode for Frame {
+        fn decode(de: &mut rtps_cdr::Deserializer<'_>) -> rtps_cdr::Result<Frame> {
+            let value = Frame {
+                header: rtps_cdr::CdrDecode::decode(de)?,
+                samples: rtps_cdr::CdrDecode::decode(de)?,
+                counters: rtps_cdr::CdrDecode::decode(de)?,
+                matrix: rtps_cdr::CdrDecode::decode(de)?,
+                tags: <rtps_cdr::Delimited<_> as rtps_cdr::CdrDecode>::decode(de)?.0,
+                history: de.optional::<rtps_cdr::Delimited<_>>()?.map(|value| value.0),
+                offset: de.optional()?,
+                marker: rtps_cdr::CdrDecode::decode(de)?,
+            };
+            rtps_types::Validate::validate(&value)?;
+            Ok(value)
+        }
+    }
+    impl rtps_cdr::SerializedSize for Frame {
+        const MAX_SIZE_XCDR1: Option<usize> = None;
+        const MIN_SIZE_XCDR1: usize = 68;
+        const MAX_SIZE_XCDR2: Option<usize> = None;
+        const MIN_SIZE_XCDR2: usize = 66;
+    }
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[derive(Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Default)]
+    #[serde(remote = "Self")]
+    pub struct App {
+        pub x: i32,
+        #[serde(default)]
+        #[serde(
+            serialize_with = "rtps_types::xtypes::optional::serialize::<1, _, _>",
+            deserialize_with = "rtps_types::xtypes::optional::deserialize"
+        )]
+        pub s: Option<String>,
+    }
+    impl rtps_types::Validate for App {
+        #[allow(clippy::only_used_in_recursion)]
+        fn validate_into(
+            &self,
+            _path: &str,
+            _violations: &mut Vec<rtps_types::Violation>,
+        ) {}
+    }
+    #[allow(dead_code)]
+    impl App {
+        /// Verify the constraints declared in IDL, reporting the path of each violating member
+        pub fn validate(&self) -> Result<(), rtps_types::ValidationError> {
+            rtps_types::Validate::validate(self)
+        }
+    }
+    impl rtps_types::xtypes::Extensible for App {
+        const EXTENSIBILITY: rtps_types::xtypes::Extensibility = rtps_types::xtypes::Extensibility::Final;
+    }
+    impl serde::Serialize for App {
+        fn serialize<S: serde::Serializer>(
+            &self,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            App::serialize(self, serializer)
+        }
+    }
+    impl<'de> serde::Deserialize<'de> for App {
+        fn deserialize<D: serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<App, D::Error> {
+            let value = App::deserialize(deserializer)?;
+            rtps_types::Validate::validate(&value).map_err(serde::de::Error::custom)?;
+            Ok(value)
+        }
+    }
+    impl rtps_cdr::CdrEncode for App {
+        fn encode(&self, ser: &mut rtps_cdr::Serializer<'_>) -> rtps_cdr::Result<()> {
+            rtps_cdr::CdrEncode::encode(&self.x, ser)?;
+            ser.optional(1u32, self.s.as_ref())?;
+            Ok(())
+        }
+    }
+    impl rtps_cdr::CdrDecode for App {
+        fn decode(de: &mut rtps_cdr::Deserializer<'_>) -> rtps_cdr::Result<App> {
+            let value = App {
+                x: rtps_cdr::CdrDecode::decode(de)?,
+                s: de.optional()?,
+            };
+            rtps_types::Validate::validate(&value)?;
+            Ok(value)
+        }
+    }
+    impl rtps_cdr::SerializedSize for App {
+        const MAX_SIZE_XCDR1: Option<usize> = None;
+        const MIN_SIZE_XCDR1: usize = 8;
+        const MAX_SIZE_XCDR2: Option<usize> = None;
+        const MIN_SIZE_XCDR2: usize = 5;
+    }
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[derive(Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Default)]
+    #[serde(remote = "Self")]
+    #[serde(default)]
+    pub struct Range {
+        pub low: i32,
+        pub high: i32,
+    }
+    impl rtps_types::Validate for Range {
+        #[allow(clippy::only_used_in_recursion)]
+        fn validate_into(
+            &self,
+            _path: &str,
+            _violations: &mut Vec<rtps_types::Violation>,
+        ) {}
+    }
+    #[allow(dead_code)]
+    impl Range {
+        /// Verify the constraints declared in IDL, reporting the path of each violating member
+        pub fn validate(&self) -> Result<(), rtps_types::ValidationError> {
+            rtps_types::Validate::validate(self)
+        }
+    }
+    impl rtps_types::xtypes::Extensible for Range {
+        const EXTENSIBILITY: rtps_types::xtypes::Extensibility = rtps_types::xtypes::Extensibility::Appendable;
+    }
+    impl serde::Serialize for Range {
+        fn serialize<S: serde::Serializer>(
+            &self,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            serializer
+                .serialize_newtype_struct(
+                    rtps_types::xtypes::DELIMITED,
+                    &rtps_types::xtypes::Derived(self),
+                )
+        }
+    }
+    impl<'de> serde::Deserialize<'de> for Range {
+        fn deserialize<D: serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Range, D::Error> {
+            let value = deserializer
+                .deserialize_newtype_struct(
+                    rtps_types::xtypes::DELIMITED,
+                    rtps_types::xtypes::DerivedVisitor::<Range>::default(),
+                )?;
+            rtps_types::Validate::validate(&value).map_err(serde::de::Error::custom)?;
+            Ok(value)
+        }
+    }
+    impl rtps_types::xtypes::SerializeDerived for Range {
+        fn serialize_derived<S: serde::Serializer>(
+            &self,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            Range::serialize(self, serializer)
+        }
+    }
+    impl<'de> rtps_types::xtypes::DeserializeDerived<'de> for Range {
+        fn deserialize_derived<D: serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Range, D::Error> {
+            Range::deserialize(deserializer)
+        }
+    }
+    impl rtps_cdr::CdrEncode for Range {
+        fn encode(&self, ser: &mut rtps_cdr::Serializer<'_>) -> rtps_cdr::Result<()> {
+            ser.delimited(|ser| {
+                rtps_cdr::CdrEncode::encode(&self.low, ser)?;
+                rtps_cdr::CdrEncode::encode(&self.high, ser)?;
+                Ok(())
+            })
+        }
+    }
+    impl rtps_cdr::CdrDecode for Range {
+        fn decode(de: &mut rtps_cdr::Deserializer<'_>) -> rtps_cdr::Result<Range> {
+            let value = de
+                .delimited(|de| Ok(Range {
+                    low: de.appended()?,
+                    high: de.appended()?,
+                }))?;
+            rtps_types::Validate::validate(&value)?;
+            Ok(value)
+        }
+    }
+    impl rtps_cdr::SerializedSize for Range {
+        const MAX_SIZE_XCDR1: Option<usize> = Some(8);
+        const MIN_SIZE_XCDR1: usize = 8;
+        const MAX_SIZE_XCDR2: Option<usize> = Some(12);
+        const MIN_SIZE_XCDR2: usize = 12;
+    }
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[derive(Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Default)]
+    #[serde(remote = "Self")]
+    #[serde(default)]
+    pub struct Window {
+        pub range: Range,
+        #[serde(with = "rtps_types::xtypes::delimited")]
+        pub gaps: Vec<Range>,
+        #[serde(default)]
+        #[serde(
+            serialize_with = "rtps_types::xtypes::optional::serialize::<2, _, _>",
+            deserialize_with = "rtps_types::xtypes::optional::deserialize"
+        )]
+        pub limit: Option<Range>,
+    }
+    impl rtps_types::Validate for Window {
+        #[allow(clippy::only_used_in_recursion)]
+        fn validate_into(
+            &self,
+            path: &str,
+            violations: &mut Vec<rtps_types::Violation>,
+        ) {
+            {
+                let value = &self.range;
+                rtps_types::Validate::validate_into(
+                    value,
+                    &rtps_types::validate::member(path, "range"),
+                    violations,
+                );
+            }
+            {
+                let value = &self.gaps;
+                rtps_types::Validate::validate_into(
+                    value,
+                    &rtps_types::validate::member(path, "gaps"),
+                    violations,
+                );
+            }
+            if let Some(value) = self.limit.as_ref() {
+                rtps_types::Validate::validate_into(
+                    value,
+                    &rtps_types::validate::member(path, "limit"),
+                    violations,
+                );
+            }
+        }
+    }
+    #[allow(dead_code)]
+    impl Window {
+        /// Verify the constraints declared in IDL, reporting the path of each violating member
+        pub fn validate(&self) -> Result<(), rtps_types::ValidationError> {
+            rtps_types::Validate::validate(self)
+        }
+    }
+    impl rtps_types::xtypes::Extensible for Window {
+        const EXTENSIBILITY: rtps_types::xtypes::Extensibility = rtps_types::xtypes::Extensibility::Appendable;
+    }
+    impl serde::Serialize for Window {
+        fn serialize<S: serde::Serializer>(
+            &self,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            serializer
+                .serialize_newtype_struct(
+                    rtps_types::xtypes::DELIMITED,
+                    &rtps_types::xtypes::Derived(self),
+                )
+        }
+    }
+    impl<'de> serde::Deserialize<'de> for Window {
+        fn deserialize<D: serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Window, D::Error> {
+            let value = deserializer
+                .deserialize_newtype_struct(
+                    rtps_types::xtypes::DELIMITED,
+                    rtps_types::xtypes::DerivedVisitor::<Window>::default(),
+                )?;
+            rtps_types::Validate::validate(&value).map_err(serde::de::Error::custom)?;
+            Ok(value)
+        }
+    }
+    impl rtps_types::xtypes::SerializeDerived for Window {
+        fn serialize_derived<S: serde::Serializer>(
+            &self,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            Window::serialize(self, serializer)
+        }
+    }
+    impl<'de> rtps_types::xtypes::DeserializeDerived<'de> for Window {
+        fn deserialize_derived<D: serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Window, D::Error> {
+            Window::deserialize(deserializer)
+        }
+    }
+    impl rtps_cdr::CdrEncode for Window {
+        fn encode(&self, ser: &mut rtps_cdr::Serializer<'_>) -> rtps_cdr::Result<()> {
+            ser.delimited(|ser| {
+                rtps_cdr::CdrEncode::encode(&self.range, ser)?;
+                rtps_cdr::CdrEncode::encode(&rtps_cdr::Delimited(&self.gaps), ser)?;
+                ser.optional(2u32, self.limit.as_ref())?;
+                Ok(())
+            })
+        }
+    }
+    impl rtps_cdr::CdrDecode for Window {
+        fn decode(de: &mut rtps_cdr::Deserializer<'_>) -> rtps_cdr::Result<Window> {
+            let value = de
+                .delimited(|de| Ok(Window {
+                    range: de.appended()?,
+                    gaps: de.appended::<rtps_cdr::Delimited<_>>()?.0,
+                    limit: de.appended_with(|de| de.optional())?,
+                }))?;
+            rtps_types::Validate::validate(&value)?;
+            Ok(value)
+        }
+    }
+    impl rtps_cdr::SerializedSize for Window {
+        const MAX_SIZE_XCDR1: Option<usize> = None;
+        const MIN_SIZE_XCDR1: usize = 16;
+        const MAX_SIZE_XCDR2: Option<usize> = None;
+        const MIN_SIZE_XCDR2: usize = 25;
+    }
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[derive(Clone, Debug, PartialEq, Default)]
+    #[repr(u32)]
+    pub enum Kind {
+        #[default]
+        K_A = 0,
+        K_B = 5,
+        K_C = 6,
+    }
+    impl Kind {
+        /// The value of the enumerator
+        pub const fn value(&self) -> u32 {
+            match self {
+                Kind::K_A => 0,
+                Kind::K_B => 5,
+                Kind::K_C => 6,
+            }
+        }
+        /// The IDL name of the enumerator
+        pub const fn as_str(&self) -> &'static str {
+            match self {
+                Kind::K_A => "K_A",
+                Kind::K_B => "K_B",
+                Kind::K_C => "K_C",
+            }
+        }
+    }
+    impl ::std::convert::TryFrom<u32> for Kind {
+        type Error = rtps_types::EnumError;
+        fn try_from(value: u32) -> Result<Kind, rtps_types::EnumError> {
+            match value {
+                0 => Ok(Kind::K_A),
+                5 => Ok(Kind::K_B),
+                6 => Ok(Kind::K_C),
+                _ => Err(rtps_types::EnumError::Value(value)),
+            }
+        }
+    }
+    impl From<Kind> for u32 {
+        fn from(value: Kind) -> u32 {
+            value.value()
+        }
+    }
+    impl ::std::fmt::Display for Kind {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            f.write_str(self.as_str())
+        }
+    }
+    impl ::std::str::FromStr for Kind {
+        type Err = rtps_types::EnumError;
+        fn from_str(name: &str) -> Result<Kind, rtps_types::EnumError> {
+            match name {
+                "K_A" => Ok(Kind::K_A),
+                "K_B" => Ok(Kind::K_B),
+                "K_C" => Ok(Kind::K_C),
+                _ => Err(rtps_types::EnumError::Name(name.to_owned())),
+            }
+        }
+    }
+    impl serde::Serialize for Kind {
+        fn serialize<S: serde::Serializer>(
+            &self,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            serializer.serialize_u32(self.value())
+        }
+    }
+    impl<'de> serde::Deserialize<'de> for Kind {
+        fn deserialize<D: serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Kind, D::Error> {
+            let value = <u32 as serde::Deserialize>::deserialize(deserializer)?;
+            <Kind as ::std::convert::TryFrom<u32>>::try_from(value)
+                .map_err(serde::de::Error::custom)
+        }
+    }
+    impl rtps_cdr::CdrEncode for Kind {
+        fn encode(&self, ser: &mut rtps_cdr::Serializer<'_>) -> rtps_cdr::Result<()> {
+            rtps_cdr::CdrEncode::encode(&self.value(), ser)
+        }
+    }
+    impl rtps_cdr::CdrDecode for Kind {
+        fn decode(de: &mut rtps_cdr::Deserializer<'_>) -> rtps_cdr::Result<Kind> {
+            let value = <u32 as rtps_cdr::CdrDecode>::decode(de)?;
+            Ok(<Kind as ::std::convert::TryFrom<u32>>::try_from(value)?)
+        }
+    }
+    impl rtps_cdr::SerializedSize for Kind {
+        const MAX_SIZE_XCDR1: Option<usize> = Some(4);
+        const MIN_SIZE_XCDR1: usize = 4;
+        const MAX_SIZE_XCDR2: Option<usize> = Some(4);
+        const MIN_SIZE_XCDR2: usize = 4;
+    }
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[derive(Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq)]
+    #[serde(remote = "Self")]
+    pub enum Code {
+        a { a: i32 },
+        b { b: u8 },
+        default { other: i16 },
+    }
+    impl Default for Code {
+        fn default() -> Code {
+            Code::default {
+                other: Default::default(),
+            }
+        }
+    }
+    impl rtps_types::Validate for Code {
+        #[allow(clippy::only_used_in_recursion)]
+        fn validate_into(
+            &self,
+            _path: &str,
+            _violations: &mut Vec<rtps_types::Violation>,
+        ) {}
+    }
+    #[allow(dead_code)]
+    impl Code {
+        /// Verify the constraints declared in IDL, reporting the path of each violating member
+        pub fn validate(&self) -> Result<(), rtps_types::ValidationError> {
+            rtps_types::Validate::validate(self)
+        }
+    }
+    impl rtps_types::xtypes::Extensible for Code {
+        const EXTENSIBILITY: rtps_types::xtypes::Extensibility = rtps_types::xtypes::Extensibility::Final;
+    }
+    impl serde::Serialize for Code {
+        fn serialize<S: serde::Serializer>(
+            &self,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            if serializer.is_human_readable() {
+                Code::serialize(self, serializer)
+            } else {
+                use serde::ser::SerializeTuple;
+                let mut tuple = serializer.serialize_tuple(2)?;
+                match self {
+                    Code::a { a: value } => {
+                        tuple.serialize_element(&7i32)?;
+                        tuple.serialize_element(value)?;
+                    }
+                    Code::b { b: value } => {
+                        tuple.serialize_element(&42i32)?;
+                        tuple.serialize_element(value)?;
+                    }
+                    Code::default { other: value } => {
+                        tuple.serialize_element(&0i32)?;
+                        tuple.serialize_element(value)?;
+                    }
+                }
+                tuple.end()
+            }
+        }
+    }
+    impl<'de> serde::Deserialize<'de> for Code {
+        fn deserialize<D: serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Code, D::Error> {
+            let value = if deserializer.is_human_readable() {
+                Code::deserialize(deserializer)
+            } else {
+                struct Discriminated;
+                impl<'de> serde::de::Visitor<'de> for Discriminated {
+                    type Value = Code;
+                    fn expecting(
+                        &self,
+                        f: &mut ::std::fmt::Formatter,
+                    ) -> ::std::fmt::Result {
+                        f.write_str("union Telemetry::Code")
+                    }
+                    fn visit_seq<A: serde::de::SeqAccess<'de>>(
+                        self,
+                        mut seq: A,
+                    ) -> Result<Code, A::Error> {
+                        let label = seq
+                            .next_element::<i32>()?
+                            .ok_or_else(|| serde::de::Error::invalid_length(0, &self))?;
+                        Ok(
+                            match label {
+                                7i32 => {
+                                    Code::a {
+                                        a: seq
+                                            .next_element()?
+                                            .ok_or_else(|| serde::de::Error::invalid_length(1, &self))?,
+                                    }
+                                }
+                                42i32 | 43i32 => {
+                                    Code::b {
+                                        b: seq
+                                            .next_element()?
+                                            .ok_or_else(|| serde::de::Error::invalid_length(1, &self))?,
+                                    }
+                                }
+                                _ => {
+                                    Code::default {
+                                        other: seq
+                                            .next_element()?
+                                            .ok_or_else(|| serde::de::Error::invalid_length(1, &self))?,
+                                    }
+                                }
+                            },
+                        )
+                    }
+                }
+                deserializer.deserialize_tuple(2, Discriminated)
+            }?;
+            rtps_types::Validate::validate(&value).map_err(serde::de::Error::custom)?;
+            Ok(value)
+        }
+    }
+    impl rtps_cdr::CdrEncode for Code {
+        fn encode(&self, ser: &mut rtps_cdr::Serializer<'_>) -> rtps_cdr::Result<()> {
+            rtps_cdr::CdrEncode::encode(&rtps_cdr::CdrUnion::discriminator(self), ser)?;
+            rtps_cdr::CdrUnion::encode_element(self, ser)
+        }
+    }
+    impl rtps_cdr::CdrDecode for Code {
+        fn decode(de: &mut rtps_cdr::Deserializer<'_>) -> rtps_cdr::Result<Code> {
+            let value = {
+                let discriminator = rtps_cdr::CdrDecode::decode(de)?;
+                rtps_cdr::CdrUnion::decode_element(discriminator, de)
+            }?;
+            rtps_types::Validate::validate(&value)?;
+            Ok(value)
+        }
+    }
+    impl rtps_cdr::CdrUnion for Code {
+        type Discriminator = i32;
+        fn discriminator(&self) -> i32 {
+            match self {
+                Code::a { .. } => 7i32,
+                Code::b { .. } => 42i32,
+                Code::default { .. } => 0i32,
+            }
+        }
+        fn encode_element(
+            &self,
+            ser: &mut rtps_cdr::Serializer<'_>,
+        ) -> rtps_cdr::Result<()> {
+            match self {
+                Code::a { a: value } => {
+                    rtps_cdr::CdrEncode::encode(value, ser)?;
+                }
+                Code::b { b: value } => {
+                    rtps_cdr::CdrEncode::encode(value, ser)?;
+                }
+                Code::default { other: value } => {
+                    rtps_cdr::CdrEncode::encode(value, ser)?;
+                }
+            }
+            Ok(())
+        }
+        fn decode_element(
+            discriminator: i32,
+            de: &mut rtps_cdr::Deserializer<'_>,
+        ) -> rtps_cdr::Result<Code> {
+            Ok(
+                match discriminator {
+                    7i32 => {
+                        Code::a {
+                            a: rtps_cdr::CdrDecode::decode(de)?,
+                        }
+                    }
+                    42i32 | 43i32 => {
+                        Code::b {
+                            b: rtps_cdr::CdrDecode::decode(de)?,
+                        }
+                    }
+                    _ => {
+                        Code::default {
+                            other: rtps_cdr::CdrDecode::decode(de)?,
+                        }
+                    }
+                },
+            )
+        }
+    }
+    impl rtps_cdr::SerializedSize for Code {
+        const MAX_SIZE_XCDR1: Option<usize> = Some(8);
+        const MIN_SIZE_XCDR1: usize = 5;
+        const MAX_SIZE_XCDR2: Option<usize> = Some(8);
+        const MIN_SIZE_XCDR2: usize = 5;
+    }
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[derive(Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq)]
+    #[serde(remote = "Self")]
+    pub enum Selection {
+        K_B { b: i32 },
+        K_C { c: String },
+    }
+    impl Default for Selection {
+        fn default() -> Selection {
+            Selection::K_B {
+                b: Default::default(),
+            }
+        }
+    }
+    impl rtps_types::Validate for Selection {
+        #[allow(clippy::only_used_in_recursion)]
+        fn validate_into(
+            &self,
+            _path: &str,
+            _violations: &mut Vec<rtps_types::Violation>,
+        ) {}
+    }
+    #[allow(dead_code)]
+    impl Selection {
+        /// Verify the constraints declared in IDL, reporting the path of each violating member
+        pub fn validate(&self) -> Result<(), rtps_types::ValidationError> {
+            rtps_types::Validate::validate(self)
+        }
+    }
+    impl rtps_types::xtypes::Extensible for Selection {
+        const EXTENSIBILITY: rtps_types::xtypes::Extensibility = rtps_types::xtypes::Extensibility::Final;
+    }
+    impl serde::Serialize for Selection {
+        fn serialize<S: serde::Serializer>(
+            &self,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            if serializer.is_human_readable() {
+                Selection::serialize(self, serializer)
+            } else {
+                use serde::ser::SerializeTuple;
+                let mut tuple = serializer.serialize_tuple(2)?;
+                match self {
+                    Selection::K_B { b: value } => {
+                        tuple.serialize_element(&5u32)?;
+                        tuple.serialize_element(value)?;
+                    }
+                    Selection::K_C { c: value } => {
+                        tuple.serialize_element(&6u32)?;
+                        tuple.serialize_element(value)?;
+                    }
+                }
+                tuple.end()
+            }
+        }
+    }
+    impl<'de> serde::Deserialize<'de> for Selection {
+        fn deserialize<D: serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Selection, D::Error> {
+            let value = if deserializer.is_human_readable() {
+                Selection::deserialize(deserializer)
+            } else {
+                struct Discriminated;
+                impl<'de> serde::de::Visitor<'de> for Discriminated {
+                    type Value = Selection;
+                    fn expecting(
+                        &self,
+                        f: &mut ::std::fmt::Formatter,
+                    ) -> ::std::fmt::Result {
+                        f.write_str("union Telemetry::Selection")
+                    }
+                    fn visit_seq<A: serde::de::SeqAccess<'de>>(
+                        self,
+                        mut seq: A,
+                    ) -> Result<Selection, A::Error> {
+                        let label = seq
+                            .next_element::<u32>()?
+                            .ok_or_else(|| serde::de::Error::invalid_length(0, &self))?;
+                        Ok(
+                            match label {
+                                5u32 => {
+                                    Selection::K_B {
+                                        b: seq
+                                            .next_element()?
+                                            .ok_or_else(|| serde::de::Error::invalid_length(1, &self))?,
+                                    }
+                                }
+                                6u32 => {
+                                    Selection::K_C {
+                                        c: seq
+                                            .next_element()?
+                                            .ok_or_else(|| serde::de::Error::invalid_length(1, &self))?,
+                                    }
+                                }
+                                label => {
+                                    return Err(
+                                        serde::de::Error::custom(
+                                            ::std::format_args!(
+                                                "invalid discriminator {} of {}", label,
+                                                "union Telemetry::Selection"
+                                            ),
+                                        ),
+                                    );
+                                }
+                            },
+                        )
+                    }
+                }
+                deserializer.deserialize_tuple(2, Discriminated)
+            }?;
+            rtps_types::Validate::validate(&value).map_err(serde::de::Error::custom)?;
+            Ok(value)
+        }
+    }
+    impl rtps_cdr::CdrEncode for Selection {
+        fn encode(&self, ser: &mut rtps_cdr::Serializer<'_>) -> rtps_cdr::Result<()> {
+            rtps_cdr::CdrEncode::encode(&rtps_cdr::CdrUnion::discriminator(self), ser)?;
+            rtps_cdr::CdrUnion::encode_element(self, ser)
+        }
+    }
+    impl rtps_cdr::CdrDecode for Selection {
+        fn decode(de: &mut rtps_cdr::Deserializer<'_>) -> rtps_cdr::Result<Selection> {
+            let value = {
+                let discriminator = rtps_cdr::CdrDecode::decode(de)?;
+                rtps_cdr::CdrUnion::decode_element(discriminator, de)
+            }?;
+            rtps_types::Validate::validate(&value)?;
+            Ok(value)
+        }
+    }
+    impl rtps_cdr::CdrUnion for Selection {
+        type Discriminator = u32;
+        fn discriminator(&self) -> u32 {
+            match self {
+                Selection::K_B { .. } => 5u32,
+                Selection::K_C { .. } => 6u32,
+            }
+        }
+        fn encode_element(
+            &self,
+            ser: &mut rtps_cdr::Serializer<'_>,
+        ) -> rtps_cdr::Result<()> {
+            match self {
+                Selection::K_B { b: value } => {
+                    rtps_cdr::CdrEncode::encode(value, ser)?;
+                }
+                Selection::K_C { c: value } => {
+                    rtps_cdr::CdrEncode::encode(value, ser)?;
+                }
+            }
+            Ok(())
+        }
+        fn decode_element(
+            discriminator: u32,
+            de: &mut rtps_cdr::Deserializer<'_>,
+        ) -> rtps_cdr::Result<Selection> {
+            Ok(
+                match discriminator {
+                    5u32 => {
+                        Selection::K_B {
+                            b: rtps_cdr::CdrDecode::decode(de)?,
+                        }
+                    }
+                    6u32 => {
+                        Selection::K_C {
+                            c: rtps_cdr::CdrDecode::decode(de)?,
+                        }
+                    }
+                    label => {
+                        return Err(
+                            rtps_cdr::Error::InvalidDiscriminator(
+                                i128::from(label),
+                                "union Telemetry::Selection",
+                            ),
+                        );
+                    }
+                },
+            )
+        }
+    }
+    impl rtps_cdr::SerializedSize for Selection {
+        const MAX_SIZE_XCDR1: Option<usize> = None;
+        const MIN_SIZE_XCDR1: usize = 8;
+        const MAX_SIZE_XCDR2: Option<usize> = None;
+        const MIN_SIZE_XCDR2: usize = 8;
+    }
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[derive(Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq)]
+    #[serde(remote = "Self")]
+    pub enum Reading {
+        value { value: f64 },
+        text { text: String },
+        ranges { #[serde(with = "rtps_types::xtypes::delimited")] ranges: Vec<Range> },
+    }
+    impl Default for Reading {
+        fn default() -> Reading {
+            Reading::value {
+                value: Default::default(),
+            }
+        }
+    }
+    impl rtps_types::Validate for Reading {
+        #[allow(clippy::only_used_in_recursion)]
+        fn validate_into(
+            &self,
+            path: &str,
+            violations: &mut Vec<rtps_types::Violation>,
+        ) {
+            if let Reading::ranges { ranges } = self {
+                {
+                    let value = ranges;
+                    rtps_types::Validate::validate_into(
+                        value,
+                        &rtps_types::validate::member(path, "ranges"),
+                        violations,
+                    );
+                }
+            }
+        }
+    }
+    #[allow(dead_code)]
+    impl Reading {
+        /// Verify the constraints declared in IDL, reporting the path of each violating member
+        pub fn validate(&self) -> Result<(), rtps_types::ValidationError> {
+            rtps_types::Validate::validate(self)
+        }
+    }
+    impl rtps_types::xtypes::Extensible for Reading {
+        const EXTENSIBILITY: rtps_types::xtypes::Extensibility = rtps_types::xtypes::Extensibility::Final;
+    }
+    impl serde::Serialize for Reading {
+        fn serialize<S: serde::Serializer>(
+            &self,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            if serializer.is_human_readable() {
+                Reading::serialize(self, serializer)
+            } else {
+                use serde::ser::SerializeTuple;
+                let mut tuple = serializer.serialize_tuple(2)?;
+                match self {
+                    Reading::value { value } => {
+                        tuple.serialize_element(&0i32)?;
+                        tuple.serialize_element(value)?;
+                    }
+                    Reading::text { text: value } => {
+                        tuple.serialize_element(&1i32)?;
+                        tuple.serialize_element(value)?;
+                    }
+                    Reading::ranges { ranges: value } => {
+                        tuple.serialize_element(&2i32)?;
+                        tuple.serialize_element(&rtps_types::xtypes::Delimited(value))?;
+                    }
+                }
+                tuple.end()
+            }
+        }
+    }
+    impl<'de> serde::Deserialize<'de> for Reading {
+        fn deserialize<D: serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Reading, D::Error> {
+            let value = if deserializer.is_human_readable() {
+                Reading::deserialize(deserializer)
+            } else {
+                struct Discriminated;
+                impl<'de> serde::de::Visitor<'de> for Discriminated {
+                    type Value = Reading;
+                    fn expecting(
+                        &self,
+                        f: &mut ::std::fmt::Formatter,
+                    ) -> ::std::fmt::Result {
+                        f.write_str("union Telemetry::Reading")
+                    }
+                    fn visit_seq<A: serde::de::SeqAccess<'de>>(
+                        self,
+                        mut seq: A,
+                    ) -> Result<Reading, A::Error> {
+                        let label = seq
+                            .next_element::<i32>()?
+                            .ok_or_else(|| serde::de::Error::invalid_length(0, &self))?;
+                        Ok(
+                            match label {
+                                0i32 => {
+                                    Reading::value {
+                                        value: seq
+                                            .next_element()?
+                                            .ok_or_else(|| serde::de::Error::invalid_length(1, &self))?,
+                                    }
+                                }
+                                1i32 => {
+                                    Reading::text {
+                                        text: seq
+                                            .next_element()?
+                                            .ok_or_else(|| serde::de::Error::invalid_length(1, &self))?,
+                                    }
+                                }
+                                2i32 => {
+                                    Reading::ranges {
+                                        ranges: seq
+                                            .next_element::<rtps_types::xtypes::DelimitedValue<_>>()?
+                                            .ok_or_else(|| serde::de::Error::invalid_length(1, &self))?
+                                            .0,
+                                    }
+                                }
+                                label => {
+                                    return Err(
+                                        serde::de::Error::custom(
+                                            ::std::format_args!(
+                                                "invalid discriminator {} of {}", label,
+                                                "union Telemetry::Reading"
+                                            ),
+                                        ),
+                                    );
+                                }
+                            },
+                        )
+                    }
+                }
+                deserializer.deserialize_tuple(2, Discriminated)
+            }?;
+            rtps_types::Validate::validate(&value).map_err(serde::de::Error::custom)?;
+            Ok(value)
+        }
+    }
+    impl rtps_cdr::CdrEncode for Reading {
+        fn encode(&self, ser: &mut rtps_cdr::Serializer<'_>) -> rtps_cdr::Result<()> {
+            rtps_cdr::CdrEncode::encode(&rtps_cdr::CdrUnion::discriminator(self), ser)?;
+            rtps_cdr::CdrUnion::encode_element(self, ser)
+        }
+    }
+    impl rtps_cdr::CdrDecode for Reading {
+        fn decode(de: &mut rtps_cdr::Deserializer<'_>) -> rtps_cdr::Result<Reading> {
+            let value = {
+                let discriminator = rtps_cdr::CdrDecode::decode(de)?;
+                rtps_cdr::CdrUnion::decode_element(discriminator, de)
+            }?;
+            rtps_types::Validate::validate(&value)?;
+            Ok(value)
+        }
+    }
+    impl rtps_cdr::CdrUnion for Reading {
+        type Discriminator = i32;
+        fn discriminator(&self) -> i32 {
+            match self {
+                Reading::value { .. } => 0i32,
+                Reading::text { .. } => 1i32,
+                Reading::ranges { .. } => 2i32,
+            }
+        }
+        fn encode_element(
+            &self,
+            ser: &mut rtps_cdr::Serializer<'_>,
+        ) -> rtps_cdr::Result<()> {
+            match self {
+                Reading::value { value } => {
+                    rtps_cdr::CdrEncode::encode(value, ser)?;
+                }
+                Reading::text { text: value } => {
+                    rtps_cdr::CdrEncode::encode(value, ser)?;
+                }
+                Reading::ranges { ranges: value } => {
+                    rtps_cdr::CdrEncode::encode(&rtps_cdr::Delimited(value), ser)?;
+                }
+            }
+            Ok(())
+        }
+        fn decode_element(
+            discriminator: i32,
+            de: &mut rtps_cdr::Deserializer<'_>,
+        ) -> rtps_cdr::Result<Reading> {
+            Ok(
+                match discriminator {
+                    0i32 => {
+                        Reading::value {
+                            value: rtps_cdr::CdrDecode::decode(de)?,
+                        }
+                    }
+                    1i32 => {
+                        Reading::text {
+                            text: rtps_cdr::CdrDecode::decode(de)?,
+                        }
+                    }
+                    2i32 => {
+                        Reading::ranges {
+                            ranges: <rtps_cdr::Delimited<
+                                _,
+                            > as rtps_cdr::CdrDecode>::decode(de)?
+                                .0,
+                        }
+                    }
+                    label => {
+                        return Err(
+                            rtps_cdr::Error::InvalidDiscriminator(
+                                i128::from(label),
+                                "union Telemetry::Reading",
+                            ),
+                        );
+                    }
+                },
+            )
+        }
+    }
+    impl rtps_cdr::SerializedSize for Reading {
+        const MAX_SIZE_XCDR1: Option<usize> = None;
+        const MIN_SIZE_XCDR1: usize = 8;
+        const MAX_SIZE_XCDR2: Option<usize> = None;
+        const MIN_SIZE_XCDR2: usize = 9;
+    }
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[derive(Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq)]
+    #[serde(remote = "Self")]
+    pub enum Setting {
+        enabled { enabled: bool },
+        range { range: Range },
+    }
+    impl Default for Setting {
+        fn default() -> Setting {
+            Setting::enabled {
+                enabled: Default::default(),
+            }
+        }
+    }
+    impl rtps_types::Validate for Setting {
+        #[allow(clippy::only_used_in_recursion)]
+        fn validate_into(
+            &self,
+            path: &str,
+            violations: &mut Vec<rtps_types::Violation>,
+        ) {
+            if let Setting::range { range } = self {
+                {
+                    let value = range;
+                    rtps_types::Validate::validate_into(
+                        value,
+                        &rtps_types::validate::member(path, "range"),
+                        violations,
+                    );
+                }
+            }
+        }
+    }
+    #[allow(dead_code)]
+    impl Setting {
+        /// Verify the constraints declared in IDL, reporting the path of each violating member
+        pub fn validate(&self) -> Result<(), rtps_types::ValidationError> {
+            rtps_types::Validate::validate(self)
+        }
+    }
+    impl rtps_types::xtypes::Extensible for Setting {
+        const EXTENSIBILITY: rtps_types::xtypes::Extensibility = rtps_types::xtypes::Extensibility::Appendable;
+    }
+    impl serde::Serialize for Setting {
+        fn serialize<S: serde::Serializer>(
+            &self,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            serializer
+                .serialize_newtype_struct(
+                    rtps_types::xtypes::DELIMITED,
+                    &rtps_types::xtypes::Derived(self),
+                )
+        }
+    }
+    impl<'de> serde::Deserialize<'de> for Setting {
+        fn deserialize<D: serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Setting, D::Error> {
+            let value = deserializer
+                .deserialize_newtype_struct(
+                    rtps_types::xtypes::DELIMITED,
+                    rtps_types::xtypes::DerivedVisitor::<Setting>::default(),
+                )?;
+            rtps_types::Validate::validate(&value).map_err(serde::de::Error::custom)?;
+            Ok(value)
+        }
+    }
+    impl rtps_types::xtypes::SerializeDerived for Setting {
+        fn serialize_derived<S: serde::Serializer>(
+            &self,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            if serializer.is_human_readable() {
+                Setting::serialize(self, serializer)
+            } else {
+                use serde::ser::SerializeTuple;
+                let mut tuple = serializer.serialize_tuple(2)?;
+                match self {
+                    Setting::enabled { enabled: value } => {
+                        tuple.serialize_element(&0i16)?;
+                        tuple.serialize_element(value)?;
+                    }
+                    Setting::range { range: value } => {
+                        tuple.serialize_element(&1i16)?;
+                        tuple.serialize_element(value)?;
+                    }
+                }
+                tuple.end()
+            }
+        }
+    }
+    impl<'de> rtps_types::xtypes::DeserializeDerived<'de> for Setting {
+        fn deserialize_derived<D: serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Setting, D::Error> {
+            if deserializer.is_human_readable() {
+                Setting::deserialize(deserializer)
+            } else {
+                struct Discriminated;
+                impl<'de> serde::de::Visitor<'de> for Discriminated {
+                    type Value = Setting;
+                    fn expecting(
+                        &self,
+                        f: &mut ::std::fmt::Formatter,
+                    ) -> ::std::fmt::Result {
+                        f.write_str("union Telemetry::Setting")
+                    }
+                    fn visit_seq<A: serde::de::SeqAccess<'de>>(
+                        self,
+                        mut seq: A,
+                    ) -> Result<Setting, A::Error> {
+                        let label = seq
+                            .next_element::<i16>()?
+                            .ok_or_else(|| serde::de::Error::invalid_length(0, &self))?;
+                        Ok(
+                            match label {
+                                0i16 => {
+                                    Setting::enabled {
+                                        enabled: seq
+                                            .next_element()?
+                                            .ok_or_else(|| serde::de::Error::invalid_length(1, &self))?,
+                                    }
+                                }
+                                1i16 => {
+                                    Setting::range {
+                                        range: seq
+                                            .next_element()?
+                                            .ok_or_else(|| serde::de::Error::invalid_length(1, &self))?,
+                                    }
+                                }
+                                label => {
+                                    return Err(
+                                        serde::de::Error::custom(
+                                            ::std::format_args!(
+                                                "invalid discriminator {} of {}", label,
+                                                "union Telemetry::Setting"
+                                            ),
+                                        ),
+                                    );
+                                }
+                            },
+                        )
+                    }
+                }
+                deserializer.deserialize_tuple(2, Discriminated)
+            }
+        }
+    }
+    impl rtps_cdr::CdrEncode for Setting {
+        fn encode(&self, ser: &mut rtps_cdr::Serializer<'_>) -> rtps_cdr::Result<()> {
+            ser.delimited(|ser| {
+                rtps_cdr::CdrEncode::encode(
+                    &rtps_cdr::CdrUnion::discriminator(self),
+                    ser,
+                )?;
+                rtps_cdr::CdrUnion::encode_element(self, ser)
+            })
+        }
+    }
+    impl rtps_cdr::CdrDecode for Setting {
+        fn decode(de: &mut rtps_cdr::Deserializer<'_>) -> rtps_cdr::Result<Setting> {
+            let value = de
+                .delimited(|de| {
+                    let discriminator = rtps_cdr::CdrDecode::decode(de)?;
+                    rtps_cdr::CdrUnion::decode_element(discriminator, de)
+                })?;
+            rtps_types::Validate::validate(&value)?;
+            Ok(value)
+        }
+    }
+    impl rtps_cdr::CdrUnion for Setting {
+        type Discriminator = i16;
+        fn discriminator(&self) -> i16 {
+            match self {
+                Setting::enabled { .. } => 0i16,
+                Setting::range { .. } => 1i16,
+            }
+        }
+        fn encode_element(
+            &self,
+            ser: &mut rtps_cdr::Serializer<'_>,
+        ) -> rtps_cdr::Result<()> {
+            match self {
+                Setting::enabled { enabled: value } => {
+                    rtps_cdr::CdrEncode::encode(value, ser)?;
+                }
+                Setting::range { range: value } => {
+                    rtps_cdr::CdrEncode::encode(value, ser)?;
+                }
+            }
+            Ok(())
+        }
+        fn decode_element(
+            discriminator: i16,
+            de: &mut rtps_cdr::Deserializer<'_>,
+        ) -> rtps_cdr::Result<Setting> {
+            Ok(
+                match discriminator {
+                    0i16 => {
+                        Setting::enabled {
+                            enabled: rtps_cdr::CdrDecode::decode(de)?,
+                        }
+                    }
+                    1i16 => {
+                        Setting::range {
+                            range: rtps_cdr::CdrDecode::decode(de)?,
+                        }
+                    }
+                    label => {
+                        return Err(
+                            rtps_cdr::Error::InvalidDiscriminator(
+                                i128::from(label),
+                                "union Telemetry::Setting",
+                            ),
+                        );
+                    }
+                },
+            )
+        }
+    }
+    impl rtps_cdr::SerializedSize for Setting {
+        const MAX_SIZE_XCDR1: Option<usize> = Some(12);
+        const MIN_SIZE_XCDR1: usize = 3;
+        const MAX_SIZE_XCDR2: Option<usize> = Some(20);
+        const MIN_SIZE_XCDR2: usize = 7;
+    }
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[derive(Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Default)]
+    #[serde(remote = "Self")]
+    pub struct Config {
+        pub name: String,
+        pub status: Status,
+        #[serde(with = "rtps_types::xtypes::delimited")]
+        pub ranges: Vec<Range>,
+        #[serde(default)]
+        #[serde(
+            serialize_with = "rtps_types::xtypes::optional::serialize::<12, _, _>",
+            deserialize_with = "rtps_types::xtypes::optional::deserialize"
+        )]
+        pub window: Option<Window>,
+        #[serde(default)]
+        #[serde(
+            serialize_with = "rtps_types::xtypes::optional::serialize::<2147483661, _, _>",
+            deserialize_with = "rtps_types::xtypes::optional::deserialize"
+        )]
+        pub level: Option<u16>,
+        pub reading: Reading,
+    }
+    impl rtps_types::Validate for Config {
+        #[allow(clippy::only_used_in_recursion)]
+        fn validate_into(
+            &self,
+            path: &str,
+            violations: &mut Vec<rtps_types::Violation>,
+        ) {
+            {
+                let value = &self.ranges;
+                rtps_types::Validate::validate_into(
+                    value,
+                    &rtps_types::validate::member(path, "ranges"),
+                    violations,
+                );
+            }
+            if let Some(value) = self.window.as_ref() {
+                rtps_types::Validate::validate_into(
+                    value,
+                    &rtps_types::validate::member(path, "window"),
+                    violations,
+                );
+            }
+            {
+                let value = &self.reading;
+                rtps_types::Validate::validate_into(
+                    value,
+                    &rtps_types::validate::member(path, "reading"),
+                    violations,
+                );
+            }
+        }
+    }
+    #[allow(dead_code)]
+    impl Config {
+        /// Verify the constraints declared in IDL, reporting the path of each violating member
+        pub fn validate(&self) -> Result<(), rtps_types::ValidationError> {
+            rtps_types::Validate::validate(self)
+        }
+    }
+    impl rtps_types::xtypes::Extensible for Config {
+        const EXTENSIBILITY: rtps_types::xtypes::Extensibility = rtps_types::xtypes::Extensibility::Mutable;
+    }
+    impl serde::Serialize for Config {
+        fn serialize<S: serde::Serializer>(
+            &self,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            if serializer.is_human_readable() {
+                Config::serialize(self, serializer)
+            } else {
+                serializer
+                    .serialize_newtype_struct(
+                        rtps_types::xtypes::MUTABLE,
+                        &rtps_types::xtypes::Members(self),
+                    )
+            }
+        }
+    }
+    impl<'de> serde::Deserialize<'de> for Config {
+        fn deserialize<D: serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Config, D::Error> {
+            let value = if deserializer.is_human_readable() {
+                Config::deserialize(deserializer)?
+            } else {
+                deserializer
+                    .deserialize_newtype_struct(
+                        rtps_types::xtypes::MUTABLE,
+                        rtps_types::xtypes::MembersVisitor::<Config>::default(),
+                    )?
+            };
+            rtps_types::Validate::validate(&value).map_err(serde::de::Error::custom)?;
+            Ok(value)
+        }
+    }
+    impl rtps_types::xtypes::SerializeMembers for Config {
+        fn member_count(&self) -> usize {
+            4 + usize::from(self.window.is_some()) + usize::from(self.level.is_some())
+        }
+        fn serialize_members<M: serde::ser::SerializeMap>(
+            &self,
+            map: &mut M,
+        ) -> Result<(), M::Error> {
+            map.serialize_entry(&rtps_types::xtypes::MUST_UNDERSTAND, &self.name)?;
+            map.serialize_entry(&10u32, &self.status)?;
+            map.serialize_entry(&11u32, &rtps_types::xtypes::Delimited(&self.ranges))?;
+            if let Some(value) = &self.window {
+                map.serialize_entry(&12u32, value)?;
+            }
+            if let Some(value) = &self.level {
+                map.serialize_entry(
+                    &(rtps_types::xtypes::MUST_UNDERSTAND | 13u32),
+                    value,
+                )?;
+            }
+            map.serialize_entry(&14u32, &self.reading)?;
+            Ok(())
+        }
+    }
+    impl<'de> rtps_types::xtypes::DeserializeMembers<'de> for Config {
+        fn deserialize_members<A: serde::de::MapAccess<'de>>(
+            mut map: A,
+        ) -> Result<Config, A::Error> {
+            let mut value = Config::default();
+            while let Some(header) = map.next_key::<u32>()? {
+                match header & rtps_types::xtypes::MEMBER_ID_MASK {
+                    0 => value.name = map.next_value()?,
+                    10 => value.status = map.next_value()?,
+                    11 => {
+                        value.ranges = map
+                            .next_value::<rtps_types::xtypes::DelimitedValue<_>>()?
+                            .0;
+                    }
+                    12 => value.window = Some(map.next_value()?),
+                    13 => value.level = Some(map.next_value()?),
+                    14 => value.reading = map.next_value()?,
+                    _ => rtps_types::xtypes::skip_member(&mut map, header)?,
+                }
+            }
+            Ok(value)
+        }
+    }
+    impl rtps_cdr::CdrEncode for Config {
+        fn encode(&self, ser: &mut rtps_cdr::Serializer<'_>) -> rtps_cdr::Result<()> {
+            ser.members(|ser| {
+                ser.member(
+                    rtps_types::xtypes::MUST_UNDERSTAND,
+                    |ser| rtps_cdr::CdrEncode::encode(&self.name, ser),
+                )?;
+                ser.member(10u32, |ser| rtps_cdr::CdrEncode::encode(&self.status, ser))?;
+                ser.member(
+                    11u32,
+                    |ser| rtps_cdr::CdrEncode::encode(
+                        &rtps_cdr::Delimited(&self.ranges),
+                        ser,
+                    ),
+                )?;
+                if let Some(value) = &self.window {
+                    ser.member(12u32, |ser| rtps_cdr::CdrEncode::encode(value, ser))?;
+                }
+                if let Some(value) = &self.level {
+                    ser.member(
+                        rtps_types::xtypes::MUST_UNDERSTAND | 13u32,
+                        |ser| rtps_cdr::CdrEncode::encode(value, ser),
+                    )?;
+                }
+                ser.member(
+                    14u32,
+                    |ser| rtps_cdr::CdrEncode::encode(&self.reading, ser),
+                )?;
+                Ok(())
+            })
+        }
+    }
+    impl rtps_cdr::CdrDecode for Config {
+        fn decode(de: &mut rtps_cdr::Deserializer<'_>) -> rtps_cdr::Result<Config> {
+            let mut value = Config::default();
+            de.members(|de, header| {
+                match header & rtps_types::xtypes::MEMBER_ID_MASK {
+                    0 => value.name = rtps_cdr::CdrDecode::decode(de)?,
+                    10 => value.status = rtps_cdr::CdrDecode::decode(de)?,
+                    11 => {
+                        value.ranges = <rtps_cdr::Delimited<
+                            _,
+                        > as rtps_cdr::CdrDecode>::decode(de)?
+                            .0;
+                    }
+                    12 => value.window = Some(rtps_cdr::CdrDecode::decode(de)?),
+                    13 => value.level = Some(rtps_cdr::CdrDecode::decode(de)?),
+                    14 => value.reading = rtps_cdr::CdrDecode::decode(de)?,
+                    _ => return Ok(false),
+                }
+                Ok(true)
+            })?;
+            rtps_types::Validate::validate(&value)?;
+            Ok(value)
+        }
+    }
+    impl rtps_cdr::SerializedSize for Config {
+        const MAX_SIZE_XCDR1: Option<usize> = None;
+        const MIN_SIZE_XCDR1: usize = 44;
+        const MAX_SIZE_XCDR2: Option<usize> = None;
+        const MIN_SIZE_XCDR2: usize = 65;
+    }
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[derive(Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Default)]
+    #[serde(remote = "Self")]
+    pub struct ConfigName {
+        pub name: String,
+        pub status: Status,
+    }
+    impl rtps_types::Validate for ConfigName {
+        #[allow(clippy::only_used_in_recursion)]
+        fn validate_into(
+            &self,
+            _path: &str,
+            _violations: &mut Vec<rtps_types::Violation>,
+        ) {}
+    }
+    #[allow(dead_code)]
+    impl ConfigName {
+        /// Verify the constraints declared in IDL, reporting the path of each violating member
+        pub fn validate(&self) -> Result<(), rtps_types::ValidationError> {
+            rtps_types::Validate::validate(self)
+        }
+    }
+    impl rtps_types::xtypes::Extensible for ConfigName {
+        const EXTENSIBILITY: rtps_types::xtypes::Extensibility = rtps_types::xtypes::Extensibility::Mutable;
+    }
+    impl serde::Serialize for ConfigName {
+        fn serialize<S: serde::Serializer>(
+            &self,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            if serializer.is_human_readable() {
+                ConfigName::serialize(self, serializer)
+            } else {
+                serializer
+                    .serialize_newtype_struct(
+                        rtps_types::xtypes::MUTABLE,
+                        &rtps_types::xtypes::Members(self),
+                    )
+            }
+        }
+    }
+    impl<'de> serde::Deserialize<'de> for ConfigName {
+        fn deserialize<D: serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<ConfigName, D::Error> {
+            let value = if deserializer.is_human_readable() {
+                ConfigName::deserialize(deserializer)?
+            } else {
+                deserializer
+                    .deserialize_newtype_struct(
+                        rtps_types::xtypes::MUTABLE,
+                        rtps_types::xtypes::MembersVisitor::<ConfigName>::default(),
+                    )?
+            };
+            rtps_types::Validate::validate(&value).map_err(serde::de::Error::custom)?;
+            Ok(value)
+        }
+    }
+    impl rtps_types::xtypes::SerializeMembers for ConfigName {
+        fn member_count(&self) -> usize {
+            2
+        }
+        fn serialize_members<M: serde::ser::SerializeMap>(
+            &self,
+            map: &mut M,
+        ) -> Result<(), M::Error> {
+            map.serialize_entry(&rtps_types::xtypes::MUST_UNDERSTAND, &self.name)?;
+            map.serialize_entry(&10u32, &self.status)?;
+            Ok(())
+        }
+    }
+    impl<'de> rtps_types::xtypes::DeserializeMembers<'de> for ConfigName {
+        fn deserialize_members<A: serde::de::MapAccess<'de>>(
+            mut map: A,
+        ) -> Result<ConfigName, A::Error> {
+            let mut value = ConfigName::default();
+            while let Some(header) = map.next_key::<u32>()? {
+                match header & rtps_types::xtypes::MEMBER_ID_MASK {
+                    0 => value.name = map.next_value()?,
+                    10 => value.status = map.next_value()?,
+                    _ => rtps_types::xtypes::skip_member(&mut map, header)?,
+                }
+            }
+            Ok(value)
+        }
+    }
+    impl rtps_cdr::CdrEncode for ConfigName {
+        fn encode(&self, ser: &mut rtps_cdr::Serializer<'_>) -> rtps_cdr::Result<()> {
+            ser.members(|ser| {
+                ser.member(
+                    rtps_types::xtypes::MUST_UNDERSTAND,
+                    |ser| rtps_cdr::CdrEncode::encode(&self.name, ser),
+                )?;
+                ser.member(10u32, |ser| rtps_cdr::CdrEncode::encode(&self.status, ser))?;
+                Ok(())
+            })
+        }
+    }
+    impl rtps_cdr::CdrDecode for ConfigName {
+        fn decode(de: &mut rtps_cdr::Deserializer<'_>) -> rtps_cdr::Result<ConfigName> {
+            let mut value = ConfigName::default();
+            de.members(|de, header| {
+                match header & rtps_types::xtypes::MEMBER_ID_MASK {
+                    0 => value.name = rtps_cdr::CdrDecode::decode(de)?,
+                    10 => value.status = rtps_cdr::CdrDecode::decode(de)?,
+                    _ => return Ok(false),
+                }
+                Ok(true)
+            })?;
+            rtps_types::Validate::validate(&value)?;
+            Ok(value)
+        }
+    }
+    impl rtps_cdr::SerializedSize for ConfigName {
+        const MAX_SIZE_XCDR1: Option<usize> = None;
+        const MIN_SIZE_XCDR1: usize = 24;
+        const MAX_SIZE_XCDR2: Option<usize> = None;
+        const MIN_SIZE_XCDR2: usize = 32;
+    }
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[derive(Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq)]
+    #[serde(remote = "Self")]
+    pub enum Command {
+        start { start: String },
+        stop { #[serde(with = "rtps_types::xtypes::delimited")] stop: Vec<Range> },
+        configure { configure: Config },
+    }
+    impl Default for Command {
+        fn default() -> Command {
+            Command::start {
+                start: Default::default(),
+            }
+        }
+    }
+    impl rtps_types::Validate for Command {
+        #[allow(clippy::only_used_in_recursion)]
+        fn validate_into(
+            &self,
+            path: &str,
+            violations: &mut Vec<rtps_types::Violation>,
+        ) {
+            match self {
+                Command::stop { stop } => {
+                    let value = stop;
+                    rtps_types::Validate::validate_into(
+                        value,
+                        &rtps_types::validate::member(path, "stop"),
+                        violations,
+                    );
+                }
+                Command::configure { configure } => {
+                    let value = configure;
+                    rtps_types::Validate::validate_into(
+                        value,
+                        &rtps_types::validate::member(path, "configure"),
+                        violations,
+                    );
+                }
+                _ => {}
+            }
+        }
+    }
+    #[allow(dead_code)]
+    impl Command {
+        /// Verify the constraints declared in IDL, reporting the path of each violating member
+        pub fn validate(&self) -> Result<(), rtps_types::ValidationError> {
+            rtps_types::Validate::validate(self)
+        }
+    }
+    impl rtps_types::xtypes::Extensible for Command {
+        const EXTENSIBILITY: rtps_types::xtypes::Extensibility = rtps_types::xtypes::Extensibility::Mutable;
+    }
+    impl serde::Serialize for Command {
+        fn serialize<S: serde::Serializer>(
+            &self,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            if serializer.is_human_readable() {
+                Command::serialize(self, serializer)
+            } else {
+                serializer
+                    .serialize_newtype_struct(
+                        rtps_types::xtypes::MUTABLE,
+                        &rtps_types::xtypes::Members(self),
+                    )
+            }
+        }
+    }
+    impl<'de> serde::Deserialize<'de> for Command {
+        fn deserialize<D: serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Command, D::Error> {
+            let value = if deserializer.is_human_readable() {
+                Command::deserialize(deserializer)?
+            } else {
+                deserializer
+                    .deserialize_newtype_struct(
+                        rtps_types::xtypes::MUTABLE,
+                        rtps_types::xtypes::MembersVisitor::<Command>::default(),
+                    )?
+            };
+            rtps_types::Validate::validate(&value).map_err(serde::de::Error::custom)?;
+            Ok(value)
+        }
+    }
+    impl rtps_types::xtypes::SerializeMembers for Command {
+        fn member_count(&self) -> usize {
+            match self {
+                Command::start { .. } => 2,
+                Command::stop { .. } => 2,
+                Command::configure { .. } => 2,
+            }
+        }
+        fn serialize_members<M: serde::ser::SerializeMap>(
+            &self,
+            map: &mut M,
+        ) -> Result<(), M::Error> {
+            match self {
+                Command::start { start: value } => {
+                    map.serialize_entry(&rtps_types::xtypes::MUST_UNDERSTAND, &0i32)?;
+                    map.serialize_entry(&1u32, value)?;
+                }
+                Command::stop { stop: value } => {
+                    map.serialize_entry(&rtps_types::xtypes::MUST_UNDERSTAND, &1i32)?;
+                    map.serialize_entry(
+                        &(rtps_types::xtypes::MUST_UNDERSTAND | 2u32),
+                        &rtps_types::xtypes::Delimited(value),
+                    )?;
+                }
+                Command::configure { configure: value } => {
+                    map.serialize_entry(&rtps_types::xtypes::MUST_UNDERSTAND, &2i32)?;
+                    map.serialize_entry(&3u32, value)?;
+                }
+            }
+            Ok(())
+        }
+    }
+    impl<'de> rtps_types::xtypes::DeserializeMembers<'de> for Command {
+        fn deserialize_members<A: serde::de::MapAccess<'de>>(
+            mut map: A,
+        ) -> Result<Command, A::Error> {
+            let mut value = Command::default();
+            while let Some(header) = map.next_key::<u32>()? {
+                match header & rtps_types::xtypes::MEMBER_ID_MASK {
+                    0 => {
+                        map.next_value::<i32>()?;
+                    }
+                    1 => {
+                        value = Command::start {
+                            start: map.next_value()?,
+                        };
+                    }
+                    2 => {
+                        value = Command::stop {
+                            stop: map
+                                .next_value::<rtps_types::xtypes::DelimitedValue<_>>()?
+                                .0,
+                        };
+                    }
+                    3 => {
+                        value = Command::configure {
+                            configure: map.next_value()?,
+                        };
+                    }
+                    _ => rtps_types::xtypes::skip_member(&mut map, header)?,
+                }
+            }
+            Ok(value)
+        }
+    }
+    impl rtps_cdr::CdrEncode for Command {
+        fn encode(&self, ser: &mut rtps_cdr::Serializer<'_>) -> rtps_cdr::Result<()> {
+            ser.members(|ser| {
+                match self {
+                    Command::start { start: value } => {
+                        ser.member(
+                            rtps_types::xtypes::MUST_UNDERSTAND,
+                            |ser| rtps_cdr::CdrEncode::encode(&0i32, ser),
+                        )?;
+                        ser.member(1u32, |ser| rtps_cdr::CdrEncode::encode(value, ser))?;
+                    }
+                    Command::stop { stop: value } => {
+                        ser.member(
+                            rtps_types::xtypes::MUST_UNDERSTAND,
+                            |ser| rtps_cdr::CdrEncode::encode(&1i32, ser),
+                        )?;
+                        ser.member(
+                            rtps_types::xtypes::MUST_UNDERSTAND | 2u32,
+                            |ser| rtps_cdr::CdrEncode::encode(
+                                &rtps_cdr::Delimited(value),
+                                ser,
+                            ),
+                        )?;
+                    }
+                    Command::configure { configure: value } => {
+                        ser.member(
+                            rtps_types::xtypes::MUST_UNDERSTAND,
+                            |ser| rtps_cdr::CdrEncode::encode(&2i32, ser),
+                        )?;
+                        ser.member(3u32, |ser| rtps_cdr::CdrEncode::encode(value, ser))?;
+                    }
+                }
+                Ok(())
+            })
+        }
+    }
+    impl rtps_cdr::CdrDecode for Command {
+        fn decode(de: &mut rtps_cdr::Deserializer<'_>) -> rtps_cdr::Result<Command> {
+            let mut value = Command::default();
+            de.members(|de, header| {
+                match header & rtps_types::xtypes::MEMBER_ID_MASK {
+                    0 => {
+                        <i32 as rtps_cdr::CdrDecode>::decode(de)?;
+                    }
+                    1 => {
+                        value = Command::start {
+                            start: rtps_cdr::CdrDecode::decode(de)?,
+                        };
+                    }
+                    2 => {
+                        value = Command::stop {
+                            stop: <rtps_cdr::Delimited<
+                                _,
+                            > as rtps_cdr::CdrDecode>::decode(de)?
+                                .0,
+                        };
+                    }
+                    3 => {
+                        value = Command::configure {
+                            configure: rtps_cdr::CdrDecode::decode(de)?,
+                        };
+                    }
+                    _ => return Ok(false),
+                }
+                Ok(true)
+            })?;
+            rtps_types::Validate::validate(&value)?;
+            Ok(value)
+        }
+    }
+    impl rtps_cdr::SerializedSize for Command {
+        const MAX_SIZE_XCDR1: Option<usize> = None;
+        const MIN_SIZE_XCDR1: usize = 20;
+        const MAX_SIZE_XCDR2: Option<usize> = None;
+        const MIN_SIZE_XCDR2: usize = 29;
+    }
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[derive(Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Default)]
+    #[serde(remote = "Self")]
+    pub struct Nothing {}
+    impl rtps_types::Validate for Nothing {
+        #[allow(clippy::only_used_in_recursion)]
+        fn validate_into(
+            &self,
+            _path: &str,
+            _violations: &mut Vec<rtps_types::Violation>,
+        ) {}
+    }
+    #[allow(dead_code)]
+    impl Nothing {
+        /// Verify the constraints declared in IDL, reporting the path of each violating member
+        pub fn validate(&self) -> Result<(), rtps_types::ValidationError> {
+            rtps_types::Validate::validate(self)
+        }
+    }
+    impl rtps_types::xtypes::Extensible for Nothing {
+        const EXTENSIBILITY: rtps_types::xtypes::Extensibility = rtps_types::xtypes::Extensibility::Mutable;
+    }
+    impl serde::Serialize for Nothing {
+        fn serialize<S: serde::Serializer>(
+            &self,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            if serializer.is_human_readable() {
+                Nothing::serialize(self, serializer)
+            } else {
+                serializer
+                    .serialize_newtype_struct(
+                        rtps_types::xtypes::MUTABLE,
+                        &rtps_types::xtypes::Members(self),
+                    )
+            }
+        }
+    }
+    impl<'de> serde::Deserialize<'de> for Nothing {
+        fn deserialize<D: serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Nothing, D::Error> {
+            let value = if deserializer.is_human_readable() {
+                Nothing::deserialize(deserializer)?
+            } else {
+                deserializer
+                    .deserialize_newtype_struct(
+                        rtps_types::xtypes::MUTABLE,
+                        rtps_types::xtypes::MembersVisitor::<Nothing>::default(),
+                    )?
+            };
+            rtps_types::Validate::validate(&value).map_err(serde::de::Error::custom)?;
+            Ok(value)
+        }
+    }
+    impl rtps_types::xtypes::SerializeMembers for Nothing {
+        fn member_count(&self) -> usize {
+            0
+        }
+        fn serialize_members<M: serde::ser::SerializeMap>(
+            &self,
+            map: &mut M,
+        ) -> Result<(), M::Error> {
+            let _ = map;
+            Ok(())
+        }
+    }
+    impl<'de> rtps_types::xtypes::DeserializeMembers<'de> for Nothing {
+        fn deserialize_members<A: serde::de::MapAccess<'de>>(
+            mut map: A,
+        ) -> Result<Nothing, A::Error> {
+            while let Some(header) = map.next_key::<u32>()? {
+                rtps_types::xtypes::skip_member(&mut map, header)?;
+            }
+            Ok(Nothing::default())
+        }
+    }
+    impl rtps_cdr::CdrEncode for Nothing {
+        fn encode(&self, ser: &mut rtps_cdr::Serializer<'_>) -> rtps_cdr::Result<()> {
+            ser.members(|_| Ok(()))
+        }
+    }
+    impl rtps_cdr::CdrDecode for Nothing {
+        fn decode(de: &mut rtps_cdr::Deserializer<'_>) -> rtps_cdr::Result<Nothing> {
+            let value = Nothing {};
+            de.members(|_, _| Ok(false))?;
+            rtps_types::Validate::validate(&value)?;
+            Ok(value)
+        }
+    }
+    impl rtps_cdr::SerializedSize for Nothing {
+        const MAX_SIZE_XCDR1: Option<usize> = Some(4);
+        const MIN_SIZE_XCDR1: usize = 4;
+        const MAX_SIZE_XCDR2: Option<usize> = Some(4);
+        const MIN_SIZE_XCDR2: usize = 4;
+    }
+}
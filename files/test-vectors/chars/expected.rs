@@ -0,0 +1,34 @@
+#[allow(unused_imports)]
+use std::vec::Vec;
+#[allow(unused_imports)]
+use serde_derive::{Serialize, Deserialize};
+#[allow(non_snake_case)]
+pub mod Chars {
+    #[allow(unused_imports)]
+    use serde_derive::{Serialize, Deserialize};
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[derive(Serialize, Deserialize)]
+    #[derive(Clone, Debug, Default)]
+    pub struct Text {
+        pub initial: char,
+        pub symbol: char,
+        pub name: String,
+        pub label: String,
+        pub code: rtps_types::BoundedWideString<8>,
+        pub letters: [char; 4],
+        pub symbols: Vec<char>,
+    }
+    #[allow(dead_code)]
+    #[allow(non_upper_case_globals)]
+    pub const SEPARATOR: char = ':';
+    #[allow(dead_code)]
+    #[allow(non_upper_case_globals)]
+    pub const EURO: char = '€';
+    #[allow(dead_code)]
+    #[allow(non_upper_case_globals)]
+    pub const GREETING: &str = "hello";
+    #[allow(dead_code)]
+    #[allow(non_upper_case_globals)]
+    pub const WIDE_GREETING: &str = "grüß dich";
+}
@@ -0,0 +1,34 @@
+#[allow(unused_imports)]
+use std::vec::Vec;
+#[allow(unused_imports)]
+use serde_derive::{Serialize, Deserialize};
+#[allow(non_snake_case)]
+pub mod Chars {
+    #[allow(unused_imports)]
+    use serde_derive::{Serialize, Deserialize};
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[derive(Serialize, Deserialize)]
+    #[derive(Clone, Debug, Default)]
+    pub struct Text {
+        pub initial: rtps_types::Char,
+        pub symbol: rtps_types::WChar,
+        pub name: String,
+        pub label: rtps_types::WString,
+        pub code: rtps_types::BoundedWString<8>,
+        pub letters: [rtps_types::Char; 4],
+        pub symbols: Vec<rtps_types::WChar>,
+    }
+    #[allow(dead_code)]
+    #[allow(non_upper_case_globals)]
+    pub const SEPARATOR: rtps_types::Char = rtps_types::Char::from_char(':');
+    #[allow(dead_code)]
+    #[allow(non_upper_case_globals)]
+    pub const EURO: rtps_types::WChar = rtps_types::WChar::from_char('€');
+    #[allow(dead_code)]
+    #[allow(non_upper_case_globals)]
+    pub const GREETING: &str = "hello";
+    #[allow(dead_code)]
+    #[allow(non_upper_case_globals)]
+    pub const WIDE_GREETING: &str = "grüß dich";
+}
@@ -3,4 +3,5 @@ use std::vec::Vec;
 #[allow(unused_imports)]
 use serde_derive::{Serialize, Deserialize};
 #[allow(dead_code)]
-pub const Foo: i32 = 2*1;
+#[allow(non_upper_case_globals)]
+pub const Foo: i32 = 2 * 1;
@@ -0,0 +1,102 @@
+#[allow(unused_imports)]
+use std::vec::Vec;
+#[allow(unused_imports)]
+use serde_derive::{Serialize, Deserialize};
+#[allow(non_snake_case)]
+pub mod Precedence {
+    #[allow(unused_imports)]
+    use serde_derive::{Serialize, Deserialize};
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[derive(Clone, Debug, Default)]
+    #[repr(u32)]
+    pub enum Mode {
+        #[default]
+        SLOW = 5,
+        FAST = 7,
+        BURST = 8,
+    }
+    impl Mode {
+        /// The value of the enumerator
+        pub const fn value(&self) -> u32 {
+            match self {
+                Mode::SLOW => 5,
+                Mode::FAST => 7,
+                Mode::BURST => 8,
+            }
+        }
+        /// The IDL name of the enumerator
+        pub const fn as_str(&self) -> &'static str {
+            match self {
+                Mode::SLOW => "SLOW",
+                Mode::FAST => "FAST",
+                Mode::BURST => "BURST",
+            }
+        }
+    }
+    impl ::std::convert::TryFrom<u32> for Mode {
+        type Error = rtps_types::EnumError;
+        fn try_from(value: u32) -> Result<Mode, rtps_types::EnumError> {
+            match value {
+                5 => Ok(Mode::SLOW),
+                7 => Ok(Mode::FAST),
+                8 => Ok(Mode::BURST),
+                _ => Err(rtps_types::EnumError::Value(value)),
+            }
+        }
+    }
+    impl From<Mode> for u32 {
+        fn from(value: Mode) -> u32 {
+            value.value()
+        }
+    }
+    impl ::std::fmt::Display for Mode {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            f.write_str(self.as_str())
+        }
+    }
+    impl ::std::str::FromStr for Mode {
+        type Err = rtps_types::EnumError;
+        fn from_str(name: &str) -> Result<Mode, rtps_types::EnumError> {
+            match name {
+                "SLOW" => Ok(Mode::SLOW),
+                "FAST" => Ok(Mode::FAST),
+                "BURST" => Ok(Mode::BURST),
+                _ => Err(rtps_types::EnumError::Name(name.to_owned())),
+            }
+        }
+    }
+    impl serde::Serialize for Mode {
+        fn serialize<S: serde::Serializer>(
+            &self,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            serializer.serialize_u32(self.value())
+        }
+    }
+    impl<'de> serde::Deserialize<'de> for Mode {
+        fn deserialize<D: serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Mode, D::Error> {
+            let value = <u32 as serde::Deserialize>::deserialize(deserializer)?;
+            <Mode as ::std::convert::TryFrom<u32>>::try_from(value)
+                .map_err(serde::de::Error::custom)
+        }
+    }
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[derive(Serialize, Deserialize)]
+    #[derive(Clone, Debug, Default)]
+    pub struct Frame {
+        pub name: rtps_types::BoundedString<5>,
+        pub data: rtps_types::BoundedVec<u8, 7>,
+        pub label: rtps_types::BoundedString<10>,
+        pub mode: Mode,
+    }
+    #[allow(dead_code)]
+    #[allow(non_upper_case_globals)]
+    pub const LEN: u32 = 10 - 2 - 3;
+    #[allow(dead_code)]
+    #[allow(non_upper_case_globals)]
+    pub const CAP: u32 = 2 * 3 + 4;
+}
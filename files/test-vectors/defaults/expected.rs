@@ -0,0 +1,134 @@
+#[allow(unused_imports)]
+use std::vec::Vec;
+#[allow(non_snake_case)]
+pub mod Defaults {
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[derive(Clone, Debug, PartialEq, Default)]
+    #[repr(u32)]
+    pub enum Mode {
+        IDLE = 0,
+        #[default]
+        RUNNING = 1,
+        STOPPED = 2,
+    }
+    impl Mode {
+        /// The value of the enumerator
+        pub const fn value(&self) -> u32 {
+            match self {
+                Mode::IDLE => 0,
+                Mode::RUNNING => 1,
+                Mode::STOPPED => 2,
+            }
+        }
+        /// The IDL name of the enumerator
+        pub const fn as_str(&self) -> &'static str {
+            match self {
+                Mode::IDLE => "IDLE",
+                Mode::RUNNING => "RUNNING",
+                Mode::STOPPED => "STOPPED",
+            }
+        }
+    }
+    impl ::std::convert::TryFrom<u32> for Mode {
+        type Error = rtps_types::EnumError;
+        fn try_from(value: u32) -> Result<Mode, rtps_types::EnumError> {
+            match value {
+                0 => Ok(Mode::IDLE),
+                1 => Ok(Mode::RUNNING),
+                2 => Ok(Mode::STOPPED),
+                _ => Err(rtps_types::EnumError::Value(value)),
+            }
+        }
+    }
+    impl From<Mode> for u32 {
+        fn from(value: Mode) -> u32 {
+            value.value()
+        }
+    }
+    impl ::std::fmt::Display for Mode {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            f.write_str(self.as_str())
+        }
+    }
+    impl ::std::str::FromStr for Mode {
+        type Err = rtps_types::EnumError;
+        fn from_str(name: &str) -> Result<Mode, rtps_types::EnumError> {
+            match name {
+                "IDLE" => Ok(Mode::IDLE),
+                "RUNNING" => Ok(Mode::RUNNING),
+                "STOPPED" => Ok(Mode::STOPPED),
+                _ => Err(rtps_types::EnumError::Name(name.to_owned())),
+            }
+        }
+    }
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    pub type Block = [u8; 64];
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct Config {
+        pub count: i32,
+        pub ratio: f64,
+        pub enabled: bool,
+        pub tag: char,
+        pub name: String,
+        pub label: rtps_types::BoundedString<8>,
+        pub mode: Mode,
+        pub stamp: i64,
+        pub block: Block,
+        pub table: [[i16; 40]; 4],
+    }
+    impl Default for Config {
+        fn default() -> Config {
+            Config {
+                count: 42,
+                ratio: 1.0,
+                enabled: true,
+                tag: 'x',
+                name: ::std::convert::From::from("main"),
+                label: ::std::convert::TryFrom::try_from("abc")
+                    .expect("default within the bound"),
+                mode: Mode::STOPPED,
+                stamp: Default::default(),
+                block: ::std::array::from_fn(|_| Default::default()),
+                table: ::std::array::from_fn(|_| ::std::array::from_fn(|_| Default::default())),
+            }
+        }
+    }
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[derive(Clone, Debug, PartialEq, Default)]
+    pub struct Plain {
+        pub mode: Mode,
+        pub values: [i32; 8],
+    }
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[derive(Clone, Debug, PartialEq)]
+    pub enum ByMode {
+        IDLE { idle: i32 },
+        RUNNING { running: i16 },
+        default { other: u8 },
+    }
+    impl Default for ByMode {
+        fn default() -> ByMode {
+            ByMode::RUNNING { running: 3 }
+        }
+    }
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[derive(Clone, Debug, PartialEq)]
+    pub enum ByNumber {
+        one { one: i32 },
+        zero { zero: Block },
+    }
+    impl Default for ByNumber {
+        fn default() -> ByNumber {
+            ByNumber::zero {
+                zero: ::std::array::from_fn(|_| Default::default()),
+            }
+        }
+    }
+}
@@ -0,0 +1,115 @@
+#[allow(unused_imports)]
+use std::vec::Vec;
+#[allow(non_snake_case)]
+pub mod Derives {
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Default, Copy, PartialOrd)]
+    /// Generated from IDL
+    #[non_exhaustive]
+    pub struct Point {
+        pub x: i32,
+        pub y: i32,
+    }
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[derive(Clone, Debug, PartialEq, Copy, PartialOrd)]
+    /// Generated from IDL
+    pub struct Sample {
+        pub origin: Point,
+        pub value: f64,
+        pub data: [u8; 64],
+    }
+    impl Default for Sample {
+        fn default() -> Sample {
+            Sample {
+                origin: Default::default(),
+                value: Default::default(),
+                data: ::std::array::from_fn(|_| Default::default()),
+            }
+        }
+    }
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    pub type PointAlias = Point;
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Default, PartialOrd)]
+    /// Generated from IDL
+    pub struct Line {
+        pub start: PointAlias,
+        pub end: PointAlias,
+        pub name: String,
+    }
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Default, Copy, PartialOrd)]
+    /// Generated from IDL
+    #[repr(u32)]
+    pub enum Kind {
+        #[default]
+        FIRST = 0,
+        SECOND = 1,
+    }
+    impl Kind {
+        /// The value of the enumerator
+        pub const fn value(&self) -> u32 {
+            match self {
+                Kind::FIRST => 0,
+                Kind::SECOND => 1,
+            }
+        }
+        /// The IDL name of the enumerator
+        pub const fn as_str(&self) -> &'static str {
+            match self {
+                Kind::FIRST => "FIRST",
+                Kind::SECOND => "SECOND",
+            }
+        }
+    }
+    impl ::std::convert::TryFrom<u32> for Kind {
+        type Error = rtps_types::EnumError;
+        fn try_from(value: u32) -> Result<Kind, rtps_types::EnumError> {
+            match value {
+                0 => Ok(Kind::FIRST),
+                1 => Ok(Kind::SECOND),
+                _ => Err(rtps_types::EnumError::Value(value)),
+            }
+        }
+    }
+    impl From<Kind> for u32 {
+        fn from(value: Kind) -> u32 {
+            value.value()
+        }
+    }
+    impl ::std::fmt::Display for Kind {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            f.write_str(self.as_str())
+        }
+    }
+    impl ::std::str::FromStr for Kind {
+        type Err = rtps_types::EnumError;
+        fn from_str(name: &str) -> Result<Kind, rtps_types::EnumError> {
+            match name {
+                "FIRST" => Ok(Kind::FIRST),
+                "SECOND" => Ok(Kind::SECOND),
+                _ => Err(rtps_types::EnumError::Name(name.to_owned())),
+            }
+        }
+    }
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd)]
+    /// Generated from IDL
+    pub enum Shape {
+        FIRST { point: Point },
+        SECOND { line: Line },
+    }
+    impl Default for Shape {
+        fn default() -> Shape {
+            Shape::FIRST {
+                point: Default::default(),
+            }
+        }
+    }
+}
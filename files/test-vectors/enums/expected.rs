@@ -0,0 +1,273 @@
+#[allow(unused_imports)]
+use std::vec::Vec;
+#[allow(unused_imports)]
+use serde_derive::{Serialize, Deserialize};
+#[allow(non_snake_case)]
+pub mod Enums {
+    #[allow(unused_imports)]
+    use serde_derive::{Serialize, Deserialize};
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[derive(Clone, Debug, PartialEq, Eq, Default, Copy)]
+    #[repr(u32)]
+    pub enum Color {
+        #[default]
+        RED = 0,
+        GREEN = 1,
+        BLUE = 2,
+    }
+    impl Color {
+        /// The value of the enumerator
+        pub const fn value(&self) -> u32 {
+            match self {
+                Color::RED => 0,
+                Color::GREEN => 1,
+                Color::BLUE => 2,
+            }
+        }
+        /// The IDL name of the enumerator
+        pub const fn as_str(&self) -> &'static str {
+            match self {
+                Color::RED => "RED",
+                Color::GREEN => "GREEN",
+                Color::BLUE => "BLUE",
+            }
+        }
+    }
+    impl ::std::convert::TryFrom<u32> for Color {
+        type Error = rtps_types::EnumError;
+        fn try_from(value: u32) -> Result<Color, rtps_types::EnumError> {
+            match value {
+                0 => Ok(Color::RED),
+                1 => Ok(Color::GREEN),
+                2 => Ok(Color::BLUE),
+                _ => Err(rtps_types::EnumError::Value(value)),
+            }
+        }
+    }
+    impl From<Color> for u32 {
+        fn from(value: Color) -> u32 {
+            value.value()
+        }
+    }
+    impl ::std::fmt::Display for Color {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            f.write_str(self.as_str())
+        }
+    }
+    impl ::std::str::FromStr for Color {
+        type Err = rtps_types::EnumError;
+        fn from_str(name: &str) -> Result<Color, rtps_types::EnumError> {
+            match name {
+                "RED" => Ok(Color::RED),
+                "GREEN" => Ok(Color::GREEN),
+                "BLUE" => Ok(Color::BLUE),
+                _ => Err(rtps_types::EnumError::Name(name.to_owned())),
+            }
+        }
+    }
+    impl serde::Serialize for Color {
+        fn serialize<S: serde::Serializer>(
+            &self,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            serializer.serialize_u32(self.value())
+        }
+    }
+    impl<'de> serde::Deserialize<'de> for Color {
+        fn deserialize<D: serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Color, D::Error> {
+            let value = <u32 as serde::Deserialize>::deserialize(deserializer)?;
+            <Color as ::std::convert::TryFrom<u32>>::try_from(value)
+                .map_err(serde::de::Error::custom)
+        }
+    }
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[derive(Clone, Debug, PartialEq, Eq, Default, Copy)]
+    #[repr(u8)]
+    pub enum Level {
+        LOW = 10,
+        MEDIUM = 11,
+        #[default]
+        HIGH = 12,
+        EXTREME = 100,
+    }
+    impl Level {
+        /// The value of the enumerator
+        pub const fn value(&self) -> u8 {
+            match self {
+                Level::LOW => 10,
+                Level::MEDIUM => 11,
+                Level::HIGH => 12,
+                Level::EXTREME => 100,
+            }
+        }
+        /// The IDL name of the enumerator
+        pub const fn as_str(&self) -> &'static str {
+            match self {
+                Level::LOW => "LOW",
+                Level::MEDIUM => "MEDIUM",
+                Level::HIGH => "HIGH",
+                Level::EXTREME => "EXTREME",
+            }
+        }
+    }
+    impl ::std::convert::TryFrom<u32> for Level {
+        type Error = rtps_types::EnumError;
+        fn try_from(value: u32) -> Result<Level, rtps_types::EnumError> {
+            match value {
+                10 => Ok(Level::LOW),
+                11 => Ok(Level::MEDIUM),
+                12 => Ok(Level::HIGH),
+                100 => Ok(Level::EXTREME),
+                _ => Err(rtps_types::EnumError::Value(value)),
+            }
+        }
+    }
+    impl From<Level> for u32 {
+        fn from(value: Level) -> u32 {
+            u32::from(value.value())
+        }
+    }
+    impl From<Level> for u8 {
+        fn from(value: Level) -> u8 {
+            value.value()
+        }
+    }
+    impl ::std::convert::TryFrom<u8> for Level {
+        type Error = rtps_types::EnumError;
+        fn try_from(value: u8) -> Result<Level, rtps_types::EnumError> {
+            <Level as ::std::convert::TryFrom<u32>>::try_from(u32::from(value))
+        }
+    }
+    impl ::std::fmt::Display for Level {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            f.write_str(self.as_str())
+        }
+    }
+    impl ::std::str::FromStr for Level {
+        type Err = rtps_types::EnumError;
+        fn from_str(name: &str) -> Result<Level, rtps_types::EnumError> {
+            match name {
+                "LOW" => Ok(Level::LOW),
+                "MEDIUM" => Ok(Level::MEDIUM),
+                "HIGH" => Ok(Level::HIGH),
+                "EXTREME" => Ok(Level::EXTREME),
+                _ => Err(rtps_types::EnumError::Name(name.to_owned())),
+            }
+        }
+    }
+    impl serde::Serialize for Level {
+        fn serialize<S: serde::Serializer>(
+            &self,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            serializer.serialize_u8(self.value())
+        }
+    }
+    impl<'de> serde::Deserialize<'de> for Level {
+        fn deserialize<D: serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Level, D::Error> {
+            let value = <u8 as serde::Deserialize>::deserialize(deserializer)?;
+            <Level as ::std::convert::TryFrom<u8>>::try_from(value)
+                .map_err(serde::de::Error::custom)
+        }
+    }
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[derive(Clone, Debug, PartialEq, Eq, Default, Copy)]
+    #[repr(u16)]
+    pub enum Flag {
+        #[default]
+        TOP = 2048,
+        BOTTOM = 1,
+    }
+    impl Flag {
+        /// The value of the enumerator
+        pub const fn value(&self) -> u16 {
+            match self {
+                Flag::TOP => 2048,
+                Flag::BOTTOM => 1,
+            }
+        }
+        /// The IDL name of the enumerator
+        pub const fn as_str(&self) -> &'static str {
+            match self {
+                Flag::TOP => "TOP",
+                Flag::BOTTOM => "BOTTOM",
+            }
+        }
+    }
+    impl ::std::convert::TryFrom<u32> for Flag {
+        type Error = rtps_types::EnumError;
+        fn try_from(value: u32) -> Result<Flag, rtps_types::EnumError> {
+            match value {
+                2048 => Ok(Flag::TOP),
+                1 => Ok(Flag::BOTTOM),
+                _ => Err(rtps_types::EnumError::Value(value)),
+            }
+        }
+    }
+    impl From<Flag> for u32 {
+        fn from(value: Flag) -> u32 {
+            u32::from(value.value())
+        }
+    }
+    impl From<Flag> for u16 {
+        fn from(value: Flag) -> u16 {
+            value.value()
+        }
+    }
+    impl ::std::convert::TryFrom<u16> for Flag {
+        type Error = rtps_types::EnumError;
+        fn try_from(value: u16) -> Result<Flag, rtps_types::EnumError> {
+            <Flag as ::std::convert::TryFrom<u32>>::try_from(u32::from(value))
+        }
+    }
+    impl ::std::fmt::Display for Flag {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            f.write_str(self.as_str())
+        }
+    }
+    impl ::std::str::FromStr for Flag {
+        type Err = rtps_types::EnumError;
+        fn from_str(name: &str) -> Result<Flag, rtps_types::EnumError> {
+            match name {
+                "TOP" => Ok(Flag::TOP),
+                "BOTTOM" => Ok(Flag::BOTTOM),
+                _ => Err(rtps_types::EnumError::Name(name.to_owned())),
+            }
+        }
+    }
+    impl serde::Serialize for Flag {
+        fn serialize<S: serde::Serializer>(
+            &self,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            serializer.serialize_u16(self.value())
+        }
+    }
+    impl<'de> serde::Deserialize<'de> for Flag {
+        fn deserialize<D: serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Flag, D::Error> {
+            let value = <u16 as serde::Deserialize>::deserialize(deserializer)?;
+            <Flag as ::std::convert::TryFrom<u16>>::try_from(value)
+                .map_err(serde::de::Error::custom)
+        }
+    }
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[derive(Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Eq, Default, Copy)]
+    pub struct Setting {
+        pub color: Color,
+        pub level: Level,
+    }
+    #[allow(dead_code)]
+    #[allow(non_upper_case_globals)]
+    pub const BASE: i32 = 10;
+}
@@ -0,0 +1,47 @@
+#[allow(unused_imports)]
+use std::vec::Vec;
+#[allow(non_snake_case)]
+pub mod Optional {
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[derive(Clone, Debug, Default)]
+    pub struct Node {
+        pub value: i32,
+        pub next: Option<::std::sync::Arc<Node>>,
+    }
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[derive(Clone, Debug)]
+    pub struct Header {
+        pub id: i32,
+        pub name: Option<String>,
+        pub flags: i16,
+        pub count: ::std::sync::Arc<i32>,
+        pub blob: ::std::sync::Arc<[u8; 16]>,
+    }
+    impl Default for Header {
+        fn default() -> Header {
+            Header {
+                id: Default::default(),
+                name: Default::default(),
+                flags: Default::default(),
+                count: ::std::sync::Arc::new(5),
+                blob: Default::default(),
+            }
+        }
+    }
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[derive(Clone, Debug)]
+    pub enum Content {
+        header { header: Option<Header> },
+        list { list: ::std::sync::Arc<Node> },
+    }
+    impl Default for Content {
+        fn default() -> Content {
+            Content::header {
+                header: Default::default(),
+            }
+        }
+    }
+}
@@ -0,0 +1,193 @@
+#[allow(unused_imports)]
+use std::vec::Vec;
+#[allow(unused_imports)]
+use serde_derive::{Serialize, Deserialize};
+#[allow(non_snake_case)]
+pub mod r#match {
+    #[allow(unused_imports)]
+    use serde_derive::{Serialize, Deserialize};
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    pub type r#ref = i32;
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[derive(Serialize, Deserialize)]
+    #[derive(Clone, Debug, Default)]
+    #[serde(rename = "type")]
+    pub struct r#type {
+        #[serde(rename = "loop")]
+        pub r#loop: r#ref,
+        #[serde(rename = "self")]
+        pub self_: i16,
+        #[serde(rename = "async")]
+        pub r#async: f64,
+    }
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[derive(Clone, Debug, Default)]
+    #[repr(u32)]
+    pub enum Self_ {
+        #[default]
+        crate_ = 0,
+        super_ = 1,
+        r#move = 2,
+    }
+    impl Self_ {
+        /// The value of the enumerator
+        pub const fn value(&self) -> u32 {
+            match self {
+                Self_::crate_ => 0,
+                Self_::super_ => 1,
+                Self_::r#move => 2,
+            }
+        }
+        /// The IDL name of the enumerator
+        pub const fn as_str(&self) -> &'static str {
+            match self {
+                Self_::crate_ => "crate",
+                Self_::super_ => "super",
+                Self_::r#move => "move",
+            }
+        }
+    }
+    impl ::std::convert::TryFrom<u32> for Self_ {
+        type Error = rtps_types::EnumError;
+        fn try_from(value: u32) -> Result<Self_, rtps_types::EnumError> {
+            match value {
+                0 => Ok(Self_::crate_),
+                1 => Ok(Self_::super_),
+                2 => Ok(Self_::r#move),
+                _ => Err(rtps_types::EnumError::Value(value)),
+            }
+        }
+    }
+    impl From<Self_> for u32 {
+        fn from(value: Self_) -> u32 {
+            value.value()
+        }
+    }
+    impl ::std::fmt::Display for Self_ {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            f.write_str(self.as_str())
+        }
+    }
+    impl ::std::str::FromStr for Self_ {
+        type Err = rtps_types::EnumError;
+        fn from_str(name: &str) -> Result<Self_, rtps_types::EnumError> {
+            match name {
+                "crate" => Ok(Self_::crate_),
+                "super" => Ok(Self_::super_),
+                "move" => Ok(Self_::r#move),
+                _ => Err(rtps_types::EnumError::Name(name.to_owned())),
+            }
+        }
+    }
+    impl serde::Serialize for Self_ {
+        fn serialize<S: serde::Serializer>(
+            &self,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            serializer.serialize_u32(self.value())
+        }
+    }
+    impl<'de> serde::Deserialize<'de> for Self_ {
+        fn deserialize<D: serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Self_, D::Error> {
+            let value = <u32 as serde::Deserialize>::deserialize(deserializer)?;
+            <Self_ as ::std::convert::TryFrom<u32>>::try_from(value)
+                .map_err(serde::de::Error::custom)
+        }
+    }
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[derive(Serialize, Deserialize)]
+    #[derive(Clone, Debug)]
+    #[serde(rename = "yield")]
+    #[serde(remote = "Self")]
+    pub enum r#yield {
+        #[serde(rename = "where")]
+        r#where { #[serde(rename = "fn")] r#fn: i32 },
+    }
+    impl Default for r#yield {
+        fn default() -> r#yield {
+            r#yield::r#where {
+                r#fn: Default::default(),
+            }
+        }
+    }
+    impl serde::Serialize for r#yield {
+        fn serialize<S: serde::Serializer>(
+            &self,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            if serializer.is_human_readable() {
+                r#yield::serialize(self, serializer)
+            } else {
+                use serde::ser::SerializeTuple;
+                let mut tuple = serializer.serialize_tuple(2)?;
+                match self {
+                    r#yield::r#where { r#fn: value } => {
+                        tuple.serialize_element(&1i32)?;
+                        tuple.serialize_element(value)?;
+                    }
+                }
+                tuple.end()
+            }
+        }
+    }
+    impl<'de> serde::Deserialize<'de> for r#yield {
+        fn deserialize<D: serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<r#yield, D::Error> {
+            let value = if deserializer.is_human_readable() {
+                r#yield::deserialize(deserializer)
+            } else {
+                struct Discriminated;
+                impl<'de> serde::de::Visitor<'de> for Discriminated {
+                    type Value = r#yield;
+                    fn expecting(
+                        &self,
+                        f: &mut ::std::fmt::Formatter,
+                    ) -> ::std::fmt::Result {
+                        f.write_str("union match::yield")
+                    }
+                    fn visit_seq<A: serde::de::SeqAccess<'de>>(
+                        self,
+                        mut seq: A,
+                    ) -> Result<r#yield, A::Error> {
+                        let label = seq
+                            .next_element::<i32>()?
+                            .ok_or_else(|| serde::de::Error::invalid_length(0, &self))?;
+                        Ok(
+                            match label {
+                                1i32 => {
+                                    r#yield::r#where {
+                                        r#fn: seq
+                                            .next_element()?
+                                            .ok_or_else(|| serde::de::Error::invalid_length(1, &self))?,
+                                    }
+                                }
+                                label => {
+                                    return Err(
+                                        serde::de::Error::custom(
+                                            ::std::format_args!(
+                                                "invalid discriminator {} of {}", label,
+                                                "union match::yield"
+                                            ),
+                                        ),
+                                    );
+                                }
+                            },
+                        )
+                    }
+                }
+                deserializer.deserialize_tuple(2, Discriminated)
+            }?;
+            Ok(value)
+        }
+    }
+    #[allow(dead_code)]
+    #[allow(non_upper_case_globals)]
+    pub const r#where: i32 = 1;
+}
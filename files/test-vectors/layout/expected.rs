@@ -0,0 +1,491 @@
+#[allow(unused_imports)]
+use std::vec::Vec;
+#[allow(unused_imports)]
+use serde_derive::{Serialize, Deserialize};
+#[allow(non_snake_case)]
+pub mod Layout {
+    #[allow(unused_imports)]
+    use serde_derive::{Serialize, Deserialize};
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[derive(Clone, Debug, PartialEq, Default)]
+    #[repr(u32)]
+    pub enum Mode {
+        #[default]
+        OFF = 0,
+        ON = 1,
+    }
+    impl Mode {
+        /// The value of the enumerator
+        pub const fn value(&self) -> u32 {
+            match self {
+                Mode::OFF => 0,
+                Mode::ON => 1,
+            }
+        }
+        /// The IDL name of the enumerator
+        pub const fn as_str(&self) -> &'static str {
+            match self {
+                Mode::OFF => "OFF",
+                Mode::ON => "ON",
+            }
+        }
+    }
+    impl ::std::convert::TryFrom<u32> for Mode {
+        type Error = rtps_types::EnumError;
+        fn try_from(value: u32) -> Result<Mode, rtps_types::EnumError> {
+            match value {
+                0 => Ok(Mode::OFF),
+                1 => Ok(Mode::ON),
+                _ => Err(rtps_types::EnumError::Value(value)),
+            }
+        }
+    }
+    impl From<Mode> for u32 {
+        fn from(value: Mode) -> u32 {
+            value.value()
+        }
+    }
+    impl ::std::fmt::Display for Mode {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            f.write_str(self.as_str())
+        }
+    }
+    impl ::std::str::FromStr for Mode {
+        type Err = rtps_types::EnumError;
+        fn from_str(name: &str) -> Result<Mode, rtps_types::EnumError> {
+            match name {
+                "OFF" => Ok(Mode::OFF),
+                "ON" => Ok(Mode::ON),
+                _ => Err(rtps_types::EnumError::Name(name.to_owned())),
+            }
+        }
+    }
+    impl serde::Serialize for Mode {
+        fn serialize<S: serde::Serializer>(
+            &self,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            serializer.serialize_u32(self.value())
+        }
+    }
+    impl<'de> serde::Deserialize<'de> for Mode {
+        fn deserialize<D: serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Mode, D::Error> {
+            let value = <u32 as serde::Deserialize>::deserialize(deserializer)?;
+            <Mode as ::std::convert::TryFrom<u32>>::try_from(value)
+                .map_err(serde::de::Error::custom)
+        }
+    }
+    impl rtps_cdr::CdrEncode for Mode {
+        fn encode(&self, ser: &mut rtps_cdr::Serializer<'_>) -> rtps_cdr::Result<()> {
+            rtps_cdr::CdrEncode::encode(&self.value(), ser)
+        }
+    }
+    impl rtps_cdr::CdrDecode for Mode {
+        fn decode(de: &mut rtps_cdr::Deserializer<'_>) -> rtps_cdr::Result<Mode> {
+            let value = <u32 as rtps_cdr::CdrDecode>::decode(de)?;
+            Ok(<Mode as ::std::convert::TryFrom<u32>>::try_from(value)?)
+        }
+    }
+    impl rtps_cdr::SerializedSize for Mode {
+        const MAX_SIZE_XCDR1: Option<usize> = Some(4);
+        const MIN_SIZE_XCDR1: usize = 4;
+        const MAX_SIZE_XCDR2: Option<usize> = Some(4);
+        const MIN_SIZE_XCDR2: usize = 4;
+    }
+    unsafe impl rtps_types::FixedLayout for Mode {
+        fn is_valid(bytes: &[u8]) -> bool {
+            match <u32 as rtps_types::FixedLayout>::read_bytes(bytes) {
+                Ok(value) => {
+                    <Mode as ::std::convert::TryFrom<u32>>::try_from(value).is_ok()
+                }
+                Err(_) => false,
+            }
+        }
+    }
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[derive(Clone, Debug, PartialEq, Default)]
+    #[repr(u8)]
+    pub enum Level {
+        #[default]
+        LOW = 0,
+        HIGH = 1,
+    }
+    impl Level {
+        /// The value of the enumerator
+        pub const fn value(&self) -> u8 {
+            match self {
+                Level::LOW => 0,
+                Level::HIGH => 1,
+            }
+        }
+        /// The IDL name of the enumerator
+        pub const fn as_str(&self) -> &'static str {
+            match self {
+                Level::LOW => "LOW",
+                Level::HIGH => "HIGH",
+            }
+        }
+    }
+    impl ::std::convert::TryFrom<u32> for Level {
+        type Error = rtps_types::EnumError;
+        fn try_from(value: u32) -> Result<Level, rtps_types::EnumError> {
+            match value {
+                0 => Ok(Level::LOW),
+                1 => Ok(Level::HIGH),
+                _ => Err(rtps_types::EnumError::Value(value)),
+            }
+        }
+    }
+    impl From<Level> for u32 {
+        fn from(value: Level) -> u32 {
+            u32::from(value.value())
+        }
+    }
+    impl From<Level> for u8 {
+        fn from(value: Level) -> u8 {
+            value.value()
+        }
+    }
+    impl ::std::convert::TryFrom<u8> for Level {
+        type Error = rtps_types::EnumError;
+        fn try_from(value: u8) -> Result<Level, rtps_types::EnumError> {
+            <Level as ::std::convert::TryFrom<u32>>::try_from(u32::from(value))
+        }
+    }
+    impl ::std::fmt::Display for Level {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            f.write_str(self.as_str())
+        }
+    }
+    impl ::std::str::FromStr for Level {
+        type Err = rtps_types::EnumError;
+        fn from_str(name: &str) -> Result<Level, rtps_types::EnumError> {
+            match name {
+                "LOW" => Ok(Level::LOW),
+                "HIGH" => Ok(Level::HIGH),
+                _ => Err(rtps_types::EnumError::Name(name.to_owned())),
+            }
+        }
+    }
+    impl serde::Serialize for Level {
+        fn serialize<S: serde::Serializer>(
+            &self,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            serializer.serialize_u8(self.value())
+        }
+    }
+    impl<'de> serde::Deserialize<'de> for Level {
+        fn deserialize<D: serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Level, D::Error> {
+            let value = <u8 as serde::Deserialize>::deserialize(deserializer)?;
+            <Level as ::std::convert::TryFrom<u8>>::try_from(value)
+                .map_err(serde::de::Error::custom)
+        }
+    }
+    impl rtps_cdr::CdrEncode for Level {
+        fn encode(&self, ser: &mut rtps_cdr::Serializer<'_>) -> rtps_cdr::Result<()> {
+            rtps_cdr::CdrEncode::encode(&self.value(), ser)
+        }
+    }
+    impl rtps_cdr::CdrDecode for Level {
+        fn decode(de: &mut rtps_cdr::Deserializer<'_>) -> rtps_cdr::Result<Level> {
+            let value = <u8 as rtps_cdr::CdrDecode>::decode(de)?;
+            Ok(<Level as ::std::convert::TryFrom<u8>>::try_from(value)?)
+        }
+    }
+    impl rtps_cdr::SerializedSize for Level {
+        const MAX_SIZE_XCDR1: Option<usize> = Some(1);
+        const MIN_SIZE_XCDR1: usize = 1;
+        const MAX_SIZE_XCDR2: Option<usize> = Some(1);
+        const MIN_SIZE_XCDR2: usize = 1;
+    }
+    unsafe impl rtps_types::FixedLayout for Level {
+        fn is_valid(bytes: &[u8]) -> bool {
+            match <u8 as rtps_types::FixedLayout>::read_bytes(bytes) {
+                Ok(value) => {
+                    <Level as ::std::convert::TryFrom<u8>>::try_from(value).is_ok()
+                }
+                Err(_) => false,
+            }
+        }
+    }
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    pub type Name = rtps_types::FixedString<7>;
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[derive(Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Default)]
+    #[repr(C)]
+    pub struct Point {
+        pub x: i32,
+        pub y: i32,
+    }
+    impl rtps_types::xtypes::Extensible for Point {
+        const EXTENSIBILITY: rtps_types::xtypes::Extensibility = rtps_types::xtypes::Extensibility::Final;
+    }
+    impl rtps_cdr::CdrEncode for Point {
+        fn encode(&self, ser: &mut rtps_cdr::Serializer<'_>) -> rtps_cdr::Result<()> {
+            rtps_cdr::CdrEncode::encode(&self.x, ser)?;
+            rtps_cdr::CdrEncode::encode(&self.y, ser)?;
+            Ok(())
+        }
+    }
+    impl rtps_cdr::CdrDecode for Point {
+        fn decode(de: &mut rtps_cdr::Deserializer<'_>) -> rtps_cdr::Result<Point> {
+            Ok(Point {
+                x: rtps_cdr::CdrDecode::decode(de)?,
+                y: rtps_cdr::CdrDecode::decode(de)?,
+            })
+        }
+    }
+    impl rtps_cdr::SerializedSize for Point {
+        const MAX_SIZE_XCDR1: Option<usize> = Some(8);
+        const MIN_SIZE_XCDR1: usize = 8;
+        const MAX_SIZE_XCDR2: Option<usize> = Some(8);
+        const MIN_SIZE_XCDR2: usize = 8;
+    }
+    const _: () = {
+        assert!(cfg!(target_endian = "little"), "fixed layouts are little endian");
+        rtps_types::assert_size::<Point>(8);
+        assert!(::std::mem::offset_of!(Point, x) == 0);
+        rtps_types::assert_size::<i32>(4);
+        assert!(::std::mem::offset_of!(Point, y) == 4);
+        rtps_types::assert_size::<i32>(4);
+    };
+    unsafe impl rtps_types::FixedLayout for Point {
+        fn is_valid(bytes: &[u8]) -> bool {
+            <i32 as rtps_types::FixedLayout>::is_valid(&bytes[0..4])
+                && <i32 as rtps_types::FixedLayout>::is_valid(&bytes[4..8])
+        }
+    }
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[derive(Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Default)]
+    #[repr(C)]
+    pub struct Sample {
+        pub flag: u8,
+        #[serde(skip)]
+        pub _pad0: rtps_types::Padding<7>,
+        pub value: f64,
+        pub mode: Mode,
+        pub level: Level,
+        #[serde(skip)]
+        pub _pad1: rtps_types::Padding<3>,
+        pub name: Name,
+        #[serde(with = "rtps_types::xtypes::delimited")]
+        pub points: rtps_types::FixedVec<Point, 2>,
+        pub counts: [i16; 3],
+        pub tag: rtps_types::Char,
+        pub ok: bool,
+        pub stamps: rtps_types::FixedVec<u64, 2, 4>,
+        pub wide: rtps_types::WChar,
+        #[serde(skip)]
+        pub _pad2: rtps_types::Padding<6>,
+    }
+    impl rtps_types::xtypes::Extensible for Sample {
+        const EXTENSIBILITY: rtps_types::xtypes::Extensibility = rtps_types::xtypes::Extensibility::Final;
+    }
+    impl rtps_cdr::CdrEncode for Sample {
+        fn encode(&self, ser: &mut rtps_cdr::Serializer<'_>) -> rtps_cdr::Result<()> {
+            rtps_cdr::CdrEncode::encode(&self.flag, ser)?;
+            rtps_cdr::CdrEncode::encode(&self.value, ser)?;
+            rtps_cdr::CdrEncode::encode(&self.mode, ser)?;
+            rtps_cdr::CdrEncode::encode(&self.level, ser)?;
+            rtps_cdr::CdrEncode::encode(&self.name, ser)?;
+            rtps_cdr::CdrEncode::encode(&rtps_cdr::Delimited(&self.points), ser)?;
+            rtps_cdr::CdrEncode::encode(&self.counts, ser)?;
+            rtps_cdr::CdrEncode::encode(&self.tag, ser)?;
+            rtps_cdr::CdrEncode::encode(&self.ok, ser)?;
+            rtps_cdr::CdrEncode::encode(&self.stamps, ser)?;
+            rtps_cdr::CdrEncode::encode(&self.wide, ser)?;
+            Ok(())
+        }
+    }
+    impl rtps_cdr::CdrDecode for Sample {
+        fn decode(de: &mut rtps_cdr::Deserializer<'_>) -> rtps_cdr::Result<Sample> {
+            Ok(Sample {
+                flag: rtps_cdr::CdrDecode::decode(de)?,
+                value: rtps_cdr::CdrDecode::decode(de)?,
+                mode: rtps_cdr::CdrDecode::decode(de)?,
+                level: rtps_cdr::CdrDecode::decode(de)?,
+                name: rtps_cdr::CdrDecode::decode(de)?,
+                points: <rtps_cdr::Delimited<_> as rtps_cdr::CdrDecode>::decode(de)?.0,
+                counts: rtps_cdr::CdrDecode::decode(de)?,
+                tag: rtps_cdr::CdrDecode::decode(de)?,
+                ok: rtps_cdr::CdrDecode::decode(de)?,
+                stamps: rtps_cdr::CdrDecode::decode(de)?,
+                wide: rtps_cdr::CdrDecode::decode(de)?,
+                _pad0: Default::default(),
+                _pad1: Default::default(),
+                _pad2: Default::default(),
+            })
+        }
+    }
+    impl rtps_cdr::SerializedSize for Sample {
+        const MAX_SIZE_XCDR1: Option<usize> = Some(90);
+        const MIN_SIZE_XCDR1: usize = 50;
+        const MAX_SIZE_XCDR2: Option<usize> = Some(86);
+        const MIN_SIZE_XCDR2: usize = 50;
+    }
+    const _: () = {
+        assert!(cfg!(target_endian = "little"), "fixed layouts are little endian");
+        rtps_types::assert_size::<Sample>(96);
+        assert!(::std::mem::offset_of!(Sample, flag) == 0);
+        rtps_types::assert_size::<u8>(1);
+        assert!(::std::mem::offset_of!(Sample, value) == 8);
+        rtps_types::assert_size::<f64>(8);
+        assert!(::std::mem::offset_of!(Sample, mode) == 16);
+        rtps_types::assert_size::<Mode>(4);
+        assert!(::std::mem::offset_of!(Sample, level) == 20);
+        rtps_types::assert_size::<Level>(1);
+        assert!(::std::mem::offset_of!(Sample, name) == 24);
+        rtps_types::assert_size::<Name>(12);
+        assert!(::std::mem::offset_of!(Sample, points) == 36);
+        rtps_types::assert_size::<rtps_types::FixedVec<Point, 2>>(20);
+        assert!(::std::mem::offset_of!(Sample, counts) == 56);
+        rtps_types::assert_size::<[i16; 3]>(6);
+        assert!(::std::mem::offset_of!(Sample, tag) == 62);
+        rtps_types::assert_size::<rtps_types::Char>(1);
+        assert!(::std::mem::offset_of!(Sample, ok) == 63);
+        rtps_types::assert_size::<bool>(1);
+        assert!(::std::mem::offset_of!(Sample, stamps) == 64);
+        rtps_types::assert_size::<rtps_types::FixedVec<u64, 2, 4>>(24);
+        assert!(::std::mem::offset_of!(Sample, wide) == 88);
+        rtps_types::assert_size::<rtps_types::WChar>(2);
+    };
+    unsafe impl rtps_types::FixedLayout for Sample {
+        fn is_valid(bytes: &[u8]) -> bool {
+            <u8 as rtps_types::FixedLayout>::is_valid(&bytes[0..1])
+                && <f64 as rtps_types::FixedLayout>::is_valid(&bytes[8..16])
+                && <Mode as rtps_types::FixedLayout>::is_valid(&bytes[16..20])
+                && <Level as rtps_types::FixedLayout>::is_valid(&bytes[20..21])
+                && <Name as rtps_types::FixedLayout>::is_valid(&bytes[24..36])
+                && <rtps_types::FixedVec<
+                    Point,
+                    2,
+                > as rtps_types::FixedLayout>::is_valid(&bytes[36..56])
+                && <[i16; 3] as rtps_types::FixedLayout>::is_valid(&bytes[56..62])
+                && <rtps_types::Char as rtps_types::FixedLayout>::is_valid(
+                    &bytes[62..63],
+                ) && <bool as rtps_types::FixedLayout>::is_valid(&bytes[63..64])
+                && <rtps_types::FixedVec<
+                    u64,
+                    2,
+                    4,
+                > as rtps_types::FixedLayout>::is_valid(&bytes[64..88])
+                && <rtps_types::WChar as rtps_types::FixedLayout>::is_valid(
+                    &bytes[88..90],
+                )
+        }
+    }
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[derive(Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq)]
+    #[serde(remote = "Self")]
+    #[serde(default)]
+    #[repr(C)]
+    pub struct Wrapper {
+        pub origin: Point,
+        pub size: u8,
+        #[serde(skip)]
+        pub _pad0: rtps_types::Padding<3>,
+    }
+    impl Default for Wrapper {
+        fn default() -> Wrapper {
+            Wrapper {
+                origin: Default::default(),
+                size: 4,
+                _pad0: Default::default(),
+            }
+        }
+    }
+    impl rtps_types::xtypes::Extensible for Wrapper {
+        const EXTENSIBILITY: rtps_types::xtypes::Extensibility = rtps_types::xtypes::Extensibility::Appendable;
+    }
+    impl serde::Serialize for Wrapper {
+        fn serialize<S: serde::Serializer>(
+            &self,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            serializer
+                .serialize_newtype_struct(
+                    rtps_types::xtypes::DELIMITED,
+                    &rtps_types::xtypes::Derived(self),
+                )
+        }
+    }
+    impl<'de> serde::Deserialize<'de> for Wrapper {
+        fn deserialize<D: serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Wrapper, D::Error> {
+            let value = deserializer
+                .deserialize_newtype_struct(
+                    rtps_types::xtypes::DELIMITED,
+                    rtps_types::xtypes::DerivedVisitor::<Wrapper>::default(),
+                )?;
+            Ok(value)
+        }
+    }
+    impl rtps_types::xtypes::SerializeDerived for Wrapper {
+        fn serialize_derived<S: serde::Serializer>(
+            &self,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            Wrapper::serialize(self, serializer)
+        }
+    }
+    impl<'de> rtps_types::xtypes::DeserializeDerived<'de> for Wrapper {
+        fn deserialize_derived<D: serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Wrapper, D::Error> {
+            Wrapper::deserialize(deserializer)
+        }
+    }
+    impl rtps_cdr::CdrEncode for Wrapper {
+        fn encode(&self, ser: &mut rtps_cdr::Serializer<'_>) -> rtps_cdr::Result<()> {
+            ser.delimited(|ser| {
+                rtps_cdr::CdrEncode::encode(&self.origin, ser)?;
+                rtps_cdr::CdrEncode::encode(&self.size, ser)?;
+                Ok(())
+            })
+        }
+    }
+    impl rtps_cdr::CdrDecode for Wrapper {
+        fn decode(de: &mut rtps_cdr::Deserializer<'_>) -> rtps_cdr::Result<Wrapper> {
+            de.delimited(|de| Ok(Wrapper {
+                origin: de.appended()?,
+                size: de.appended()?,
+                _pad0: Default::default(),
+            }))
+        }
+    }
+    impl rtps_cdr::SerializedSize for Wrapper {
+        const MAX_SIZE_XCDR1: Option<usize> = Some(9);
+        const MIN_SIZE_XCDR1: usize = 9;
+        const MAX_SIZE_XCDR2: Option<usize> = Some(13);
+        const MIN_SIZE_XCDR2: usize = 13;
+    }
+    const _: () = {
+        assert!(cfg!(target_endian = "little"), "fixed layouts are little endian");
+        rtps_types::assert_size::<Wrapper>(12);
+        assert!(::std::mem::offset_of!(Wrapper, origin) == 0);
+        rtps_types::assert_size::<Point>(8);
+        assert!(::std::mem::offset_of!(Wrapper, size) == 8);
+        rtps_types::assert_size::<u8>(1);
+    };
+    unsafe impl rtps_types::FixedLayout for Wrapper {
+        fn is_valid(bytes: &[u8]) -> bool {
+            <Point as rtps_types::FixedLayout>::is_valid(&bytes[0..8])
+                && <u8 as rtps_types::FixedLayout>::is_valid(&bytes[8..9])
+        }
+    }
+}
@@ -0,0 +1,29 @@
+#[allow(unused_imports)]
+use std::vec::Vec;
+#[allow(unused_imports)]
+use serde_derive::{Serialize, Deserialize};
+#[allow(non_snake_case)]
+pub mod LongDouble {
+    #[allow(unused_imports)]
+    use serde_derive::{Serialize, Deserialize};
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    pub type Value = rtps_types::LongDouble;
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[derive(Serialize, Deserialize)]
+    #[derive(Clone, Debug, Default)]
+    pub struct Measurement {
+        pub value: Value,
+        pub deviation: rtps_types::LongDouble,
+        pub samples: [rtps_types::LongDouble; 3],
+    }
+    #[allow(dead_code)]
+    #[allow(non_upper_case_globals)]
+    pub const PI: rtps_types::LongDouble = rtps_types::LongDouble::from_f64(
+        3.14159265358979323846,
+    );
+    #[allow(dead_code)]
+    #[allow(non_upper_case_globals)]
+    pub const TWO: rtps_types::LongDouble = rtps_types::LongDouble::from_f64(2.0);
+}
@@ -0,0 +1,203 @@
+#[allow(unused_imports)]
+use std::vec::Vec;
+#[allow(unused_imports)]
+use serde_derive::{Serialize, Deserialize};
+pub mod dds {
+    #[allow(unused_imports)]
+    use serde_derive::{Serialize, Deserialize};
+    pub mod dcps {
+        #[allow(unused_imports)]
+        use serde_derive::{Serialize, Deserialize};
+        #[allow(dead_code)]
+        pub type GuidPrefixT = [u8; 12];
+        #[allow(dead_code)]
+        #[derive(Serialize, Deserialize)]
+        #[derive(Clone, Debug, Default)]
+        #[serde(rename = "EntityId_t")]
+        pub struct EntityIdT {
+            #[serde(rename = "entityKey")]
+            pub entity_key: [u8; 3],
+            #[serde(rename = "entityKind")]
+            pub entity_kind: u8,
+        }
+        #[allow(dead_code)]
+        #[derive(Serialize, Deserialize)]
+        #[derive(Clone, Debug, Default)]
+        #[serde(rename = "GUID_t")]
+        pub struct GuidT {
+            #[serde(rename = "guidPrefix")]
+            pub guid_prefix: GuidPrefixT,
+            #[serde(rename = "entityId")]
+            pub entity_id: EntityIdT,
+            #[serde(rename = "type")]
+            pub r#type: i32,
+        }
+        #[allow(dead_code)]
+        #[derive(Clone, Debug, Default)]
+        #[repr(u32)]
+        pub enum HistoryQosPolicyKind {
+            #[default]
+            KeepLastHistoryQos = 0,
+            KeepAllHistoryQos = 1,
+        }
+        impl HistoryQosPolicyKind {
+            /// The value of the enumerator
+            pub const fn value(&self) -> u32 {
+                match self {
+                    HistoryQosPolicyKind::KeepLastHistoryQos => 0,
+                    HistoryQosPolicyKind::KeepAllHistoryQos => 1,
+                }
+            }
+            /// The IDL name of the enumerator
+            pub const fn as_str(&self) -> &'static str {
+                match self {
+                    HistoryQosPolicyKind::KeepLastHistoryQos => "KEEP_LAST_HISTORY_QOS",
+                    HistoryQosPolicyKind::KeepAllHistoryQos => "KEEP_ALL_HISTORY_QOS",
+                }
+            }
+        }
+        impl ::std::convert::TryFrom<u32> for HistoryQosPolicyKind {
+            type Error = rtps_types::EnumError;
+            fn try_from(
+                value: u32,
+            ) -> Result<HistoryQosPolicyKind, rtps_types::EnumError> {
+                match value {
+                    0 => Ok(HistoryQosPolicyKind::KeepLastHistoryQos),
+                    1 => Ok(HistoryQosPolicyKind::KeepAllHistoryQos),
+                    _ => Err(rtps_types::EnumError::Value(value)),
+                }
+            }
+        }
+        impl From<HistoryQosPolicyKind> for u32 {
+            fn from(value: HistoryQosPolicyKind) -> u32 {
+                value.value()
+            }
+        }
+        impl ::std::fmt::Display for HistoryQosPolicyKind {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+        impl ::std::str::FromStr for HistoryQosPolicyKind {
+            type Err = rtps_types::EnumError;
+            fn from_str(
+                name: &str,
+            ) -> Result<HistoryQosPolicyKind, rtps_types::EnumError> {
+                match name {
+                    "KEEP_LAST_HISTORY_QOS" => {
+                        Ok(HistoryQosPolicyKind::KeepLastHistoryQos)
+                    }
+                    "KEEP_ALL_HISTORY_QOS" => Ok(HistoryQosPolicyKind::KeepAllHistoryQos),
+                    _ => Err(rtps_types::EnumError::Name(name.to_owned())),
+                }
+            }
+        }
+        impl serde::Serialize for HistoryQosPolicyKind {
+            fn serialize<S: serde::Serializer>(
+                &self,
+                serializer: S,
+            ) -> Result<S::Ok, S::Error> {
+                serializer.serialize_u32(self.value())
+            }
+        }
+        impl<'de> serde::Deserialize<'de> for HistoryQosPolicyKind {
+            fn deserialize<D: serde::Deserializer<'de>>(
+                deserializer: D,
+            ) -> Result<HistoryQosPolicyKind, D::Error> {
+                let value = <u32 as serde::Deserialize>::deserialize(deserializer)?;
+                <HistoryQosPolicyKind as ::std::convert::TryFrom<u32>>::try_from(value)
+                    .map_err(serde::de::Error::custom)
+            }
+        }
+        #[allow(dead_code)]
+        #[derive(Serialize, Deserialize)]
+        #[derive(Clone, Debug)]
+        #[serde(remote = "Self")]
+        pub enum Value {
+            MaxSamples { #[serde(rename = "lValue")] l_value: i32 },
+            #[serde(rename = "default")]
+            Default { #[serde(rename = "octetValue")] octet_value: u8 },
+        }
+        impl Default for Value {
+            fn default() -> Value {
+                Value::Default {
+                    octet_value: Default::default(),
+                }
+            }
+        }
+        impl serde::Serialize for Value {
+            fn serialize<S: serde::Serializer>(
+                &self,
+                serializer: S,
+            ) -> Result<S::Ok, S::Error> {
+                if serializer.is_human_readable() {
+                    Value::serialize(self, serializer)
+                } else {
+                    use serde::ser::SerializeTuple;
+                    let mut tuple = serializer.serialize_tuple(2)?;
+                    match self {
+                        Value::MaxSamples { l_value: value } => {
+                            tuple.serialize_element(&8i32)?;
+                            tuple.serialize_element(value)?;
+                        }
+                        Value::Default { octet_value: value } => {
+                            tuple.serialize_element(&0i32)?;
+                            tuple.serialize_element(value)?;
+                        }
+                    }
+                    tuple.end()
+                }
+            }
+        }
+        impl<'de> serde::Deserialize<'de> for Value {
+            fn deserialize<D: serde::Deserializer<'de>>(
+                deserializer: D,
+            ) -> Result<Value, D::Error> {
+                let value = if deserializer.is_human_readable() {
+                    Value::deserialize(deserializer)
+                } else {
+                    struct Discriminated;
+                    impl<'de> serde::de::Visitor<'de> for Discriminated {
+                        type Value = Value;
+                        fn expecting(
+                            &self,
+                            f: &mut ::std::fmt::Formatter,
+                        ) -> ::std::fmt::Result {
+                            f.write_str("union DDS::DCPS::Value")
+                        }
+                        fn visit_seq<A: serde::de::SeqAccess<'de>>(
+                            self,
+                            mut seq: A,
+                        ) -> Result<Value, A::Error> {
+                            let label = seq
+                                .next_element::<i32>()?
+                                .ok_or_else(|| serde::de::Error::invalid_length(0, &self))?;
+                            Ok(
+                                match label {
+                                    8i32 => {
+                                        Value::MaxSamples {
+                                            l_value: seq
+                                                .next_element()?
+                                                .ok_or_else(|| serde::de::Error::invalid_length(1, &self))?,
+                                        }
+                                    }
+                                    _ => {
+                                        Value::Default {
+                                            octet_value: seq
+                                                .next_element()?
+                                                .ok_or_else(|| serde::de::Error::invalid_length(1, &self))?,
+                                        }
+                                    }
+                                },
+                            )
+                        }
+                    }
+                    deserializer.deserialize_tuple(2, Discriminated)
+                }?;
+                Ok(value)
+            }
+        }
+        #[allow(dead_code)]
+        pub const MAX_SAMPLES: i32 = 8;
+    }
+}
@@ -0,0 +1,26 @@
+#[allow(unused_imports)]
+use std::vec::Vec;
+#[allow(unused_imports)]
+use serde_derive::{Serialize, Deserialize};
+#[allow(non_snake_case)]
+pub mod Native {
+    #[allow(unused_imports)]
+    use serde_derive::{Serialize, Deserialize};
+    #[allow(unused_imports)]
+    pub use crate::native::Handle;
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    pub type Timestamp = std::time::SystemTime;
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    pub type HandleSeq = [Handle; 4];
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[derive(Serialize, Deserialize)]
+    #[derive(Clone, Debug, Default)]
+    pub struct Entity {
+        pub handle: Handle,
+        pub created: Timestamp,
+        pub updates: Vec<Timestamp>,
+    }
+}
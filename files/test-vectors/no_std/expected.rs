@@ -0,0 +1,546 @@
+#[allow(unused_imports)]
+use ::alloc::{borrow::ToOwned, boxed::Box, string::String, vec::Vec};
+#[allow(unused_imports)]
+use serde::{Serialize, Deserialize};
+#[allow(non_snake_case)]
+pub mod Embedded {
+    #[allow(unused_imports)]
+    use ::alloc::{borrow::ToOwned, boxed::Box, string::String, vec::Vec};
+    #[allow(unused_imports)]
+    use serde::{Serialize, Deserialize};
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[derive(Clone, Debug, PartialEq, Default)]
+    #[repr(u32)]
+    pub enum State {
+        #[default]
+        IDLE = 0,
+        BUSY = 1,
+    }
+    impl State {
+        /// The value of the enumerator
+        pub const fn value(&self) -> u32 {
+            match self {
+                State::IDLE => 0,
+                State::BUSY => 1,
+            }
+        }
+        /// The IDL name of the enumerator
+        pub const fn as_str(&self) -> &'static str {
+            match self {
+                State::IDLE => "IDLE",
+                State::BUSY => "BUSY",
+            }
+        }
+    }
+    impl ::core::convert::TryFrom<u32> for State {
+        type Error = rtps_types::EnumError;
+        fn try_from(value: u32) -> Result<State, rtps_types::EnumError> {
+            match value {
+                0 => Ok(State::IDLE),
+                1 => Ok(State::BUSY),
+                _ => Err(rtps_types::EnumError::Value(value)),
+            }
+        }
+    }
+    impl From<State> for u32 {
+        fn from(value: State) -> u32 {
+            value.value()
+        }
+    }
+    impl ::core::fmt::Display for State {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+            f.write_str(self.as_str())
+        }
+    }
+    impl ::core::str::FromStr for State {
+        type Err = rtps_types::EnumError;
+        fn from_str(name: &str) -> Result<State, rtps_types::EnumError> {
+            match name {
+                "IDLE" => Ok(State::IDLE),
+                "BUSY" => Ok(State::BUSY),
+                _ => Err(rtps_types::EnumError::Name(name.to_owned())),
+            }
+        }
+    }
+    impl serde::Serialize for State {
+        fn serialize<S: serde::Serializer>(
+            &self,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            serializer.serialize_u32(self.value())
+        }
+    }
+    impl<'de> serde::Deserialize<'de> for State {
+        fn deserialize<D: serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<State, D::Error> {
+            let value = <u32 as serde::Deserialize>::deserialize(deserializer)?;
+            <State as ::core::convert::TryFrom<u32>>::try_from(value)
+                .map_err(serde::de::Error::custom)
+        }
+    }
+    impl rtps_cdr::CdrEncode for State {
+        fn encode(&self, ser: &mut rtps_cdr::Serializer<'_>) -> rtps_cdr::Result<()> {
+            rtps_cdr::CdrEncode::encode(&self.value(), ser)
+        }
+    }
+    impl rtps_cdr::CdrDecode for State {
+        fn decode(de: &mut rtps_cdr::Deserializer<'_>) -> rtps_cdr::Result<State> {
+            let value = <u32 as rtps_cdr::CdrDecode>::decode(de)?;
+            Ok(<State as ::core::convert::TryFrom<u32>>::try_from(value)?)
+        }
+    }
+    impl rtps_cdr::SerializedSize for State {
+        const MAX_SIZE_XCDR1: Option<usize> = Some(4);
+        const MIN_SIZE_XCDR1: usize = 4;
+        const MAX_SIZE_XCDR2: Option<usize> = Some(4);
+        const MIN_SIZE_XCDR2: usize = 4;
+    }
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    pub type Label = rtps_types::FixedString<8>;
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[derive(Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq)]
+    #[serde(remote = "Self")]
+    pub struct Reading {
+        pub name: Label,
+        pub stamps: rtps_types::FixedVec<u64, 4>,
+        pub blob: Vec<u8>,
+        pub note: String,
+        pub state: State,
+    }
+    impl Default for Reading {
+        fn default() -> Reading {
+            Reading {
+                name: ::core::convert::TryFrom::try_from("temp")
+                    .expect("default within the bound"),
+                stamps: Default::default(),
+                blob: Default::default(),
+                note: Default::default(),
+                state: Default::default(),
+            }
+        }
+    }
+    impl rtps_types::Validate for Reading {
+        #[allow(clippy::only_used_in_recursion)]
+        fn validate_into(
+            &self,
+            _path: &str,
+            _violations: &mut Vec<rtps_types::Violation>,
+        ) {}
+    }
+    #[allow(dead_code)]
+    impl Reading {
+        /// Verify the constraints declared in IDL, reporting the path of each violating member
+        pub fn validate(&self) -> Result<(), rtps_types::ValidationError> {
+            rtps_types::Validate::validate(self)
+        }
+    }
+    impl rtps_types::xtypes::Extensible for Reading {
+        const EXTENSIBILITY: rtps_types::xtypes::Extensibility = rtps_types::xtypes::Extensibility::Final;
+    }
+    impl serde::Serialize for Reading {
+        fn serialize<S: serde::Serializer>(
+            &self,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            Reading::serialize(self, serializer)
+        }
+    }
+    impl<'de> serde::Deserialize<'de> for Reading {
+        fn deserialize<D: serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Reading, D::Error> {
+            let value = Reading::deserialize(deserializer)?;
+            rtps_types::Validate::validate(&value).map_err(serde::de::Error::custom)?;
+            Ok(value)
+        }
+    }
+    impl rtps_cdr::CdrEncode for Reading {
+        fn encode(&self, ser: &mut rtps_cdr::Serializer<'_>) -> rtps_cdr::Result<()> {
+            rtps_cdr::CdrEncode::encode(&self.name, ser)?;
+            rtps_cdr::CdrEncode::encode(&self.stamps, ser)?;
+            rtps_cdr::CdrEncode::encode(&self.blob, ser)?;
+            rtps_cdr::CdrEncode::encode(&self.note, ser)?;
+            rtps_cdr::CdrEncode::encode(&self.state, ser)?;
+            Ok(())
+        }
+    }
+    impl rtps_cdr::CdrDecode for Reading {
+        fn decode(de: &mut rtps_cdr::Deserializer<'_>) -> rtps_cdr::Result<Reading> {
+            let value = Reading {
+                name: rtps_cdr::CdrDecode::decode(de)?,
+                stamps: rtps_cdr::CdrDecode::decode(de)?,
+                blob: rtps_cdr::CdrDecode::decode(de)?,
+                note: rtps_cdr::CdrDecode::decode(de)?,
+                state: rtps_cdr::CdrDecode::decode(de)?,
+            };
+            rtps_types::Validate::validate(&value)?;
+            Ok(value)
+        }
+    }
+    impl rtps_cdr::SerializedSize for Reading {
+        const MAX_SIZE_XCDR1: Option<usize> = None;
+        const MIN_SIZE_XCDR1: usize = 28;
+        const MAX_SIZE_XCDR2: Option<usize> = None;
+        const MIN_SIZE_XCDR2: usize = 28;
+    }
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[derive(Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq)]
+    #[serde(remote = "Self")]
+    pub enum Value {
+        number { number: i32 },
+        text { text: Label },
+    }
+    impl Default for Value {
+        fn default() -> Value {
+            Value::number {
+                number: Default::default(),
+            }
+        }
+    }
+    impl rtps_types::Validate for Value {
+        #[allow(clippy::only_used_in_recursion)]
+        fn validate_into(
+            &self,
+            _path: &str,
+            _violations: &mut Vec<rtps_types::Violation>,
+        ) {}
+    }
+    #[allow(dead_code)]
+    impl Value {
+        /// Verify the constraints declared in IDL, reporting the path of each violating member
+        pub fn validate(&self) -> Result<(), rtps_types::ValidationError> {
+            rtps_types::Validate::validate(self)
+        }
+    }
+    impl rtps_types::xtypes::Extensible for Value {
+        const EXTENSIBILITY: rtps_types::xtypes::Extensibility = rtps_types::xtypes::Extensibility::Final;
+    }
+    impl serde::Serialize for Value {
+        fn serialize<S: serde::Serializer>(
+            &self,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            if serializer.is_human_readable() {
+                Value::serialize(self, serializer)
+            } else {
+                use serde::ser::SerializeTuple;
+                let mut tuple = serializer.serialize_tuple(2)?;
+                match self {
+                    Value::number { number: value } => {
+                        tuple.serialize_element(&1i16)?;
+                        tuple.serialize_element(value)?;
+                    }
+                    Value::text { text: value } => {
+                        tuple.serialize_element(&2i16)?;
+                        tuple.serialize_element(value)?;
+                    }
+                }
+                tuple.end()
+            }
+        }
+    }
+    impl<'de> serde::Deserialize<'de> for Value {
+        fn deserialize<D: serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Value, D::Error> {
+            let value = if deserializer.is_human_readable() {
+                Value::deserialize(deserializer)
+            } else {
+                struct Discriminated;
+                impl<'de> serde::de::Visitor<'de> for Discriminated {
+                    type Value = Value;
+                    fn expecting(
+                        &self,
+                        f: &mut ::core::fmt::Formatter,
+                    ) -> ::core::fmt::Result {
+                        f.write_str("union Embedded::Value")
+                    }
+                    fn visit_seq<A: serde::de::SeqAccess<'de>>(
+                        self,
+                        mut seq: A,
+                    ) -> Result<Value, A::Error> {
+                        let label = seq
+                            .next_element::<i16>()?
+                            .ok_or_else(|| serde::de::Error::invalid_length(0, &self))?;
+                        Ok(
+                            match label {
+                                1i16 => {
+                                    Value::number {
+                                        number: seq
+                                            .next_element()?
+                                            .ok_or_else(|| serde::de::Error::invalid_length(1, &self))?,
+                                    }
+                                }
+                                2i16 => {
+                                    Value::text {
+                                        text: seq
+                                            .next_element()?
+                                            .ok_or_else(|| serde::de::Error::invalid_length(1, &self))?,
+                                    }
+                                }
+                                label => {
+                                    return Err(
+                                        serde::de::Error::custom(
+                                            ::core::format_args!(
+                                                "invalid discriminator {} of {}", label,
+                                                "union Embedded::Value"
+                                            ),
+                                        ),
+                                    );
+                                }
+                            },
+                        )
+                    }
+                }
+                deserializer.deserialize_tuple(2, Discriminated)
+            }?;
+            rtps_types::Validate::validate(&value).map_err(serde::de::Error::custom)?;
+            Ok(value)
+        }
+    }
+    impl rtps_cdr::CdrEncode for Value {
+        fn encode(&self, ser: &mut rtps_cdr::Serializer<'_>) -> rtps_cdr::Result<()> {
+            rtps_cdr::CdrEncode::encode(&rtps_cdr::CdrUnion::discriminator(self), ser)?;
+            rtps_cdr::CdrUnion::encode_element(self, ser)
+        }
+    }
+    impl rtps_cdr::CdrDecode for Value {
+        fn decode(de: &mut rtps_cdr::Deserializer<'_>) -> rtps_cdr::Result<Value> {
+            let value = {
+                let discriminator = rtps_cdr::CdrDecode::decode(de)?;
+                rtps_cdr::CdrUnion::decode_element(discriminator, de)
+            }?;
+            rtps_types::Validate::validate(&value)?;
+            Ok(value)
+        }
+    }
+    impl rtps_cdr::CdrUnion for Value {
+        type Discriminator = i16;
+        fn discriminator(&self) -> i16 {
+            match self {
+                Value::number { .. } => 1i16,
+                Value::text { .. } => 2i16,
+            }
+        }
+        fn encode_element(
+            &self,
+            ser: &mut rtps_cdr::Serializer<'_>,
+        ) -> rtps_cdr::Result<()> {
+            match self {
+                Value::number { number: value } => {
+                    rtps_cdr::CdrEncode::encode(value, ser)?;
+                }
+                Value::text { text: value } => {
+                    rtps_cdr::CdrEncode::encode(value, ser)?;
+                }
+            }
+            Ok(())
+        }
+        fn decode_element(
+            discriminator: i16,
+            de: &mut rtps_cdr::Deserializer<'_>,
+        ) -> rtps_cdr::Result<Value> {
+            Ok(
+                match discriminator {
+                    1i16 => {
+                        Value::number {
+                            number: rtps_cdr::CdrDecode::decode(de)?,
+                        }
+                    }
+                    2i16 => {
+                        Value::text {
+                            text: rtps_cdr::CdrDecode::decode(de)?,
+                        }
+                    }
+                    label => {
+                        return Err(
+                            rtps_cdr::Error::InvalidDiscriminator(
+                                i128::from(label),
+                                "union Embedded::Value",
+                            ),
+                        );
+                    }
+                },
+            )
+        }
+    }
+    impl rtps_cdr::SerializedSize for Value {
+        const MAX_SIZE_XCDR1: Option<usize> = Some(17);
+        const MIN_SIZE_XCDR1: usize = 8;
+        const MAX_SIZE_XCDR2: Option<usize> = Some(17);
+        const MIN_SIZE_XCDR2: usize = 8;
+    }
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[derive(Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Default)]
+    #[serde(remote = "Self")]
+    pub struct Status {
+        pub last: Box<Reading>,
+        #[serde(with = "rtps_types::xtypes::delimited")]
+        pub history: rtps_types::FixedVec<Reading, 2>,
+        pub counters: [i32; 4],
+    }
+    impl rtps_types::Validate for Status {
+        #[allow(clippy::only_used_in_recursion)]
+        fn validate_into(
+            &self,
+            path: &str,
+            violations: &mut Vec<rtps_types::Violation>,
+        ) {
+            {
+                let value = &*self.last;
+                rtps_types::Validate::validate_into(
+                    value,
+                    &rtps_types::validate::member(path, "last"),
+                    violations,
+                );
+            }
+            {
+                let value = &self.history;
+                rtps_types::Validate::validate_into(
+                    value,
+                    &rtps_types::validate::member(path, "history"),
+                    violations,
+                );
+            }
+        }
+    }
+    #[allow(dead_code)]
+    impl Status {
+        /// Verify the constraints declared in IDL, reporting the path of each violating member
+        pub fn validate(&self) -> Result<(), rtps_types::ValidationError> {
+            rtps_types::Validate::validate(self)
+        }
+    }
+    impl rtps_types::xtypes::Extensible for Status {
+        const EXTENSIBILITY: rtps_types::xtypes::Extensibility = rtps_types::xtypes::Extensibility::Final;
+    }
+    impl serde::Serialize for Status {
+        fn serialize<S: serde::Serializer>(
+            &self,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            Status::serialize(self, serializer)
+        }
+    }
+    impl<'de> serde::Deserialize<'de> for Status {
+        fn deserialize<D: serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Status, D::Error> {
+            let value = Status::deserialize(deserializer)?;
+            rtps_types::Validate::validate(&value).map_err(serde::de::Error::custom)?;
+            Ok(value)
+        }
+    }
+    impl rtps_cdr::CdrEncode for Status {
+        fn encode(&self, ser: &mut rtps_cdr::Serializer<'_>) -> rtps_cdr::Result<()> {
+            rtps_cdr::CdrEncode::encode(&self.last, ser)?;
+            rtps_cdr::CdrEncode::encode(&rtps_cdr::Delimited(&self.history), ser)?;
+            rtps_cdr::CdrEncode::encode(&self.counters, ser)?;
+            Ok(())
+        }
+    }
+    impl rtps_cdr::CdrDecode for Status {
+        fn decode(de: &mut rtps_cdr::Deserializer<'_>) -> rtps_cdr::Result<Status> {
+            let value = Status {
+                last: rtps_cdr::CdrDecode::decode(de)?,
+                history: <rtps_cdr::Delimited<_> as rtps_cdr::CdrDecode>::decode(de)?.0,
+                counters: rtps_cdr::CdrDecode::decode(de)?,
+            };
+            rtps_types::Validate::validate(&value)?;
+            Ok(value)
+        }
+    }
+    impl rtps_cdr::SerializedSize for Status {
+        const MAX_SIZE_XCDR1: Option<usize> = None;
+        const MIN_SIZE_XCDR1: usize = 48;
+        const MAX_SIZE_XCDR2: Option<usize> = None;
+        const MIN_SIZE_XCDR2: usize = 52;
+    }
+    #[allow(non_snake_case)]
+    pub mod Node {
+        #[allow(unused_imports)]
+        use ::alloc::{borrow::ToOwned, boxed::Box, string::String, vec::Vec};
+        #[allow(unused_imports)]
+        use serde::{Serialize, Deserialize};
+        #[allow(dead_code)]
+        #[allow(non_camel_case_types)]
+        #[derive(Serialize, Deserialize)]
+        #[derive(Clone, Debug, PartialEq, Default)]
+        #[serde(remote = "Self")]
+        pub struct Counter {
+            pub name: rtps_types::FixedString<16>,
+            pub values: rtps_types::FixedVec<i32, 3>,
+        }
+        impl rtps_types::Validate for Counter {
+            #[allow(clippy::only_used_in_recursion)]
+            fn validate_into(
+                &self,
+                _path: &str,
+                _violations: &mut Vec<rtps_types::Violation>,
+            ) {}
+        }
+        #[allow(dead_code)]
+        impl Counter {
+            /// Verify the constraints declared in IDL, reporting the path of each violating member
+            pub fn validate(&self) -> Result<(), rtps_types::ValidationError> {
+                rtps_types::Validate::validate(self)
+            }
+        }
+        impl rtps_types::xtypes::Extensible for Counter {
+            const EXTENSIBILITY: rtps_types::xtypes::Extensibility = rtps_types::xtypes::Extensibility::Final;
+        }
+        impl serde::Serialize for Counter {
+            fn serialize<S: serde::Serializer>(
+                &self,
+                serializer: S,
+            ) -> Result<S::Ok, S::Error> {
+                Counter::serialize(self, serializer)
+            }
+        }
+        impl<'de> serde::Deserialize<'de> for Counter {
+            fn deserialize<D: serde::Deserializer<'de>>(
+                deserializer: D,
+            ) -> Result<Counter, D::Error> {
+                let value = Counter::deserialize(deserializer)?;
+                rtps_types::Validate::validate(&value)
+                    .map_err(serde::de::Error::custom)?;
+                Ok(value)
+            }
+        }
+        impl rtps_cdr::CdrEncode for Counter {
+            fn encode(
+                &self,
+                ser: &mut rtps_cdr::Serializer<'_>,
+            ) -> rtps_cdr::Result<()> {
+                rtps_cdr::CdrEncode::encode(&self.name, ser)?;
+                rtps_cdr::CdrEncode::encode(&self.values, ser)?;
+                Ok(())
+            }
+        }
+        impl rtps_cdr::CdrDecode for Counter {
+            fn decode(de: &mut rtps_cdr::Deserializer<'_>) -> rtps_cdr::Result<Counter> {
+                let value = Counter {
+                    name: rtps_cdr::CdrDecode::decode(de)?,
+                    values: rtps_cdr::CdrDecode::decode(de)?,
+                };
+                rtps_types::Validate::validate(&value)?;
+                Ok(value)
+            }
+        }
+        impl rtps_cdr::SerializedSize for Counter {
+            const MAX_SIZE_XCDR1: Option<usize> = Some(40);
+            const MIN_SIZE_XCDR1: usize = 12;
+            const MAX_SIZE_XCDR2: Option<usize> = Some(40);
+            const MIN_SIZE_XCDR2: usize = 12;
+        }
+    }
+}
@@ -0,0 +1,158 @@
+#[allow(unused_imports)]
+use std::vec::Vec;
+#[allow(unused_imports)]
+use serde_derive::{Serialize, Deserialize};
+#[allow(non_snake_case)]
+pub mod Optional {
+    #[allow(unused_imports)]
+    use serde_derive::{Serialize, Deserialize};
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[derive(Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Default)]
+    pub struct Node {
+        pub value: i32,
+        #[serde(default)]
+        #[serde(
+            serialize_with = "rtps_types::xtypes::optional::serialize::<1, _, _>",
+            deserialize_with = "rtps_types::xtypes::optional::deserialize"
+        )]
+        pub next: Option<Box<Node>>,
+    }
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[derive(Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct Header {
+        pub id: i32,
+        #[serde(default)]
+        #[serde(
+            serialize_with = "rtps_types::xtypes::optional::serialize::<1, _, _>",
+            deserialize_with = "rtps_types::xtypes::optional::deserialize"
+        )]
+        pub name: Option<String>,
+        pub flags: i16,
+        pub count: Box<i32>,
+        pub blob: Box<[u8; 16]>,
+    }
+    impl Default for Header {
+        fn default() -> Header {
+            Header {
+                id: Default::default(),
+                name: Default::default(),
+                flags: Default::default(),
+                count: Box::new(5),
+                blob: Default::default(),
+            }
+        }
+    }
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[derive(Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq)]
+    #[serde(remote = "Self")]
+    pub enum Content {
+        header {
+            #[serde(default)]
+            #[serde(
+                serialize_with = "rtps_types::xtypes::optional::serialize::<1, _, _>",
+                deserialize_with = "rtps_types::xtypes::optional::deserialize"
+            )]
+            header: Option<Header>,
+        },
+        list { list: Box<Node> },
+    }
+    impl Default for Content {
+        fn default() -> Content {
+            Content::header {
+                header: Default::default(),
+            }
+        }
+    }
+    impl serde::Serialize for Content {
+        fn serialize<S: serde::Serializer>(
+            &self,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            if serializer.is_human_readable() {
+                Content::serialize(self, serializer)
+            } else {
+                use serde::ser::SerializeTuple;
+                let mut tuple = serializer.serialize_tuple(2)?;
+                match self {
+                    Content::header { header: value } => {
+                        tuple.serialize_element(&0i32)?;
+                        tuple
+                            .serialize_element(
+                                &rtps_types::xtypes::Optional(1u32, value.as_ref()),
+                            )?;
+                    }
+                    Content::list { list: value } => {
+                        tuple.serialize_element(&1i32)?;
+                        tuple.serialize_element(value)?;
+                    }
+                }
+                tuple.end()
+            }
+        }
+    }
+    impl<'de> serde::Deserialize<'de> for Content {
+        fn deserialize<D: serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Content, D::Error> {
+            let value = if deserializer.is_human_readable() {
+                Content::deserialize(deserializer)
+            } else {
+                struct Discriminated;
+                impl<'de> serde::de::Visitor<'de> for Discriminated {
+                    type Value = Content;
+                    fn expecting(
+                        &self,
+                        f: &mut ::std::fmt::Formatter,
+                    ) -> ::std::fmt::Result {
+                        f.write_str("union Optional::Content")
+                    }
+                    fn visit_seq<A: serde::de::SeqAccess<'de>>(
+                        self,
+                        mut seq: A,
+                    ) -> Result<Content, A::Error> {
+                        let label = seq
+                            .next_element::<i32>()?
+                            .ok_or_else(|| serde::de::Error::invalid_length(0, &self))?;
+                        Ok(
+                            match label {
+                                0i32 => {
+                                    Content::header {
+                                        header: seq
+                                            .next_element::<rtps_types::xtypes::OptionalValue<_>>()?
+                                            .ok_or_else(|| serde::de::Error::invalid_length(1, &self))?
+                                            .0,
+                                    }
+                                }
+                                1i32 => {
+                                    Content::list {
+                                        list: seq
+                                            .next_element()?
+                                            .ok_or_else(|| serde::de::Error::invalid_length(1, &self))?,
+                                    }
+                                }
+                                label => {
+                                    return Err(
+                                        serde::de::Error::custom(
+                                            ::std::format_args!(
+                                                "invalid discriminator {} of {}", label,
+                                                "union Optional::Content"
+                                            ),
+                                        ),
+                                    );
+                                }
+                            },
+                        )
+                    }
+                }
+                deserializer.deserialize_tuple(2, Discriminated)
+            }?;
+            Ok(value)
+        }
+    }
+}
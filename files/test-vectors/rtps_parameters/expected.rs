@@ -0,0 +1,727 @@
+#[allow(unused_imports)]
+use std::vec::Vec;
+#[allow(unused_imports)]
+use serde_derive::{Serialize, Deserialize};
+#[allow(non_snake_case)]
+pub mod DDS {
+    #[allow(unused_imports)]
+    use serde_derive::{Serialize, Deserialize};
+    #[allow(non_snake_case)]
+    pub mod RTPS {
+        #[allow(unused_imports)]
+        use serde_derive::{Serialize, Deserialize};
+        #[allow(dead_code)]
+        #[allow(non_camel_case_types)]
+        pub type ParameterId_t = u16;
+        #[allow(dead_code)]
+        #[allow(non_camel_case_types)]
+        #[derive(Serialize, Deserialize)]
+        #[derive(Clone, Debug, Default)]
+        pub struct Duration_t {
+            pub sec: i32,
+            pub nanosec: u32,
+        }
+        impl rtps_types::xtypes::Extensible for Duration_t {
+            const EXTENSIBILITY: rtps_types::xtypes::Extensibility = rtps_types::xtypes::Extensibility::Final;
+        }
+        impl rtps_cdr::CdrEncode for Duration_t {
+            fn encode(
+                &self,
+                ser: &mut rtps_cdr::Serializer<'_>,
+            ) -> rtps_cdr::Result<()> {
+                rtps_cdr::CdrEncode::encode(&self.sec, ser)?;
+                rtps_cdr::CdrEncode::encode(&self.nanosec, ser)?;
+                Ok(())
+            }
+        }
+        impl rtps_cdr::CdrDecode for Duration_t {
+            fn decode(
+                de: &mut rtps_cdr::Deserializer<'_>,
+            ) -> rtps_cdr::Result<Duration_t> {
+                Ok(Duration_t {
+                    sec: rtps_cdr::CdrDecode::decode(de)?,
+                    nanosec: rtps_cdr::CdrDecode::decode(de)?,
+                })
+            }
+        }
+        impl rtps_cdr::SerializedSize for Duration_t {
+            const MAX_SIZE_XCDR1: Option<usize> = Some(8);
+            const MIN_SIZE_XCDR1: usize = 8;
+            const MAX_SIZE_XCDR2: Option<usize> = Some(8);
+            const MIN_SIZE_XCDR2: usize = 8;
+        }
+        #[allow(dead_code)]
+        #[allow(non_camel_case_types)]
+        #[derive(Clone, Debug, Default)]
+        #[repr(u32)]
+        pub enum DurabilityQosPolicyKind {
+            #[default]
+            VOLATILE_DURABILITY_QOS = 0,
+            TRANSIENT_LOCAL_DURABILITY_QOS = 1,
+            TRANSIENT_DURABILITY_QOS = 2,
+            PERSISTENT_DURABILITY_QOS = 3,
+        }
+        impl DurabilityQosPolicyKind {
+            /// The value of the enumerator
+            pub const fn value(&self) -> u32 {
+                match self {
+                    DurabilityQosPolicyKind::VOLATILE_DURABILITY_QOS => 0,
+                    DurabilityQosPolicyKind::TRANSIENT_LOCAL_DURABILITY_QOS => 1,
+                    DurabilityQosPolicyKind::TRANSIENT_DURABILITY_QOS => 2,
+                    DurabilityQosPolicyKind::PERSISTENT_DURABILITY_QOS => 3,
+                }
+            }
+            /// The IDL name of the enumerator
+            pub const fn as_str(&self) -> &'static str {
+                match self {
+                    DurabilityQosPolicyKind::VOLATILE_DURABILITY_QOS => {
+                        "VOLATILE_DURABILITY_QOS"
+                    }
+                    DurabilityQosPolicyKind::TRANSIENT_LOCAL_DURABILITY_QOS => {
+                        "TRANSIENT_LOCAL_DURABILITY_QOS"
+                    }
+                    DurabilityQosPolicyKind::TRANSIENT_DURABILITY_QOS => {
+                        "TRANSIENT_DURABILITY_QOS"
+                    }
+                    DurabilityQosPolicyKind::PERSISTENT_DURABILITY_QOS => {
+                        "PERSISTENT_DURABILITY_QOS"
+                    }
+                }
+            }
+        }
+        impl ::std::convert::TryFrom<u32> for DurabilityQosPolicyKind {
+            type Error = rtps_types::EnumError;
+            fn try_from(
+                value: u32,
+            ) -> Result<DurabilityQosPolicyKind, rtps_types::EnumError> {
+                match value {
+                    0 => Ok(DurabilityQosPolicyKind::VOLATILE_DURABILITY_QOS),
+                    1 => Ok(DurabilityQosPolicyKind::TRANSIENT_LOCAL_DURABILITY_QOS),
+                    2 => Ok(DurabilityQosPolicyKind::TRANSIENT_DURABILITY_QOS),
+                    3 => Ok(DurabilityQosPolicyKind::PERSISTENT_DURABILITY_QOS),
+                    _ => Err(rtps_types::EnumError::Value(value)),
+                }
+            }
+        }
+        impl From<DurabilityQosPolicyKind> for u32 {
+            fn from(value: DurabilityQosPolicyKind) -> u32 {
+                value.value()
+            }
+        }
+        impl ::std::fmt::Display for DurabilityQosPolicyKind {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+        impl ::std::str::FromStr for DurabilityQosPolicyKind {
+            type Err = rtps_types::EnumError;
+            fn from_str(
+                name: &str,
+            ) -> Result<DurabilityQosPolicyKind, rtps_types::EnumError> {
+                match name {
+                    "VOLATILE_DURABILITY_QOS" => {
+                        Ok(DurabilityQosPolicyKind::VOLATILE_DURABILITY_QOS)
+                    }
+                    "TRANSIENT_LOCAL_DURABILITY_QOS" => {
+                        Ok(DurabilityQosPolicyKind::TRANSIENT_LOCAL_DURABILITY_QOS)
+                    }
+                    "TRANSIENT_DURABILITY_QOS" => {
+                        Ok(DurabilityQosPolicyKind::TRANSIENT_DURABILITY_QOS)
+                    }
+                    "PERSISTENT_DURABILITY_QOS" => {
+                        Ok(DurabilityQosPolicyKind::PERSISTENT_DURABILITY_QOS)
+                    }
+                    _ => Err(rtps_types::EnumError::Name(name.to_owned())),
+                }
+            }
+        }
+        impl serde::Serialize for DurabilityQosPolicyKind {
+            fn serialize<S: serde::Serializer>(
+                &self,
+                serializer: S,
+            ) -> Result<S::Ok, S::Error> {
+                serializer.serialize_u32(self.value())
+            }
+        }
+        impl<'de> serde::Deserialize<'de> for DurabilityQosPolicyKind {
+            fn deserialize<D: serde::Deserializer<'de>>(
+                deserializer: D,
+            ) -> Result<DurabilityQosPolicyKind, D::Error> {
+                let value = <u32 as serde::Deserialize>::deserialize(deserializer)?;
+                <DurabilityQosPolicyKind as ::std::convert::TryFrom<
+                    u32,
+                >>::try_from(value)
+                    .map_err(serde::de::Error::custom)
+            }
+        }
+        impl rtps_cdr::CdrEncode for DurabilityQosPolicyKind {
+            fn encode(
+                &self,
+                ser: &mut rtps_cdr::Serializer<'_>,
+            ) -> rtps_cdr::Result<()> {
+                rtps_cdr::CdrEncode::encode(&self.value(), ser)
+            }
+        }
+        impl rtps_cdr::CdrDecode for DurabilityQosPolicyKind {
+            fn decode(
+                de: &mut rtps_cdr::Deserializer<'_>,
+            ) -> rtps_cdr::Result<DurabilityQosPolicyKind> {
+                let value = <u32 as rtps_cdr::CdrDecode>::decode(de)?;
+                Ok(
+                    <DurabilityQosPolicyKind as ::std::convert::TryFrom<
+                        u32,
+                    >>::try_from(value)?,
+                )
+            }
+        }
+        impl rtps_cdr::SerializedSize for DurabilityQosPolicyKind {
+            const MAX_SIZE_XCDR1: Option<usize> = Some(4);
+            const MIN_SIZE_XCDR1: usize = 4;
+            const MAX_SIZE_XCDR2: Option<usize> = Some(4);
+            const MIN_SIZE_XCDR2: usize = 4;
+        }
+        #[allow(dead_code)]
+        #[allow(non_camel_case_types)]
+        #[derive(Serialize, Deserialize)]
+        #[derive(Clone, Debug, Default)]
+        pub struct DurabilityQosPolicy {
+            pub kind: DurabilityQosPolicyKind,
+        }
+        impl rtps_types::xtypes::Extensible for DurabilityQosPolicy {
+            const EXTENSIBILITY: rtps_types::xtypes::Extensibility = rtps_types::xtypes::Extensibility::Final;
+        }
+        impl rtps_cdr::CdrEncode for DurabilityQosPolicy {
+            fn encode(
+                &self,
+                ser: &mut rtps_cdr::Serializer<'_>,
+            ) -> rtps_cdr::Result<()> {
+                rtps_cdr::CdrEncode::encode(&self.kind, ser)?;
+                Ok(())
+            }
+        }
+        impl rtps_cdr::CdrDecode for DurabilityQosPolicy {
+            fn decode(
+                de: &mut rtps_cdr::Deserializer<'_>,
+            ) -> rtps_cdr::Result<DurabilityQosPolicy> {
+                Ok(DurabilityQosPolicy {
+                    kind: rtps_cdr::CdrDecode::decode(de)?,
+                })
+            }
+        }
+        impl rtps_cdr::SerializedSize for DurabilityQosPolicy {
+            const MAX_SIZE_XCDR1: Option<usize> = Some(4);
+            const MIN_SIZE_XCDR1: usize = 4;
+            const MAX_SIZE_XCDR2: Option<usize> = Some(4);
+            const MIN_SIZE_XCDR2: usize = 4;
+        }
+        #[allow(dead_code)]
+        #[allow(non_camel_case_types)]
+        #[derive(Clone, Debug, Default)]
+        #[repr(u32)]
+        pub enum ReliabilityQosPolicyKind {
+            #[default]
+            BEST_EFFORT_RELIABILITY_QOS = 0,
+            RELIABLE_RELIABILITY_QOS = 1,
+        }
+        impl ReliabilityQosPolicyKind {
+            /// The value of the enumerator
+            pub const fn value(&self) -> u32 {
+                match self {
+                    ReliabilityQosPolicyKind::BEST_EFFORT_RELIABILITY_QOS => 0,
+                    ReliabilityQosPolicyKind::RELIABLE_RELIABILITY_QOS => 1,
+                }
+            }
+            /// The IDL name of the enumerator
+            pub const fn as_str(&self) -> &'static str {
+                match self {
+                    ReliabilityQosPolicyKind::BEST_EFFORT_RELIABILITY_QOS => {
+                        "BEST_EFFORT_RELIABILITY_QOS"
+                    }
+                    ReliabilityQosPolicyKind::RELIABLE_RELIABILITY_QOS => {
+                        "RELIABLE_RELIABILITY_QOS"
+                    }
+                }
+            }
+        }
+        impl ::std::convert::TryFrom<u32> for ReliabilityQosPolicyKind {
+            type Error = rtps_types::EnumError;
+            fn try_from(
+                value: u32,
+            ) -> Result<ReliabilityQosPolicyKind, rtps_types::EnumError> {
+                match value {
+                    0 => Ok(ReliabilityQosPolicyKind::BEST_EFFORT_RELIABILITY_QOS),
+                    1 => Ok(ReliabilityQosPolicyKind::RELIABLE_RELIABILITY_QOS),
+                    _ => Err(rtps_types::EnumError::Value(value)),
+                }
+            }
+        }
+        impl From<ReliabilityQosPolicyKind> for u32 {
+            fn from(value: ReliabilityQosPolicyKind) -> u32 {
+                value.value()
+            }
+        }
+        impl ::std::fmt::Display for ReliabilityQosPolicyKind {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+        impl ::std::str::FromStr for ReliabilityQosPolicyKind {
+            type Err = rtps_types::EnumError;
+            fn from_str(
+                name: &str,
+            ) -> Result<ReliabilityQosPolicyKind, rtps_types::EnumError> {
+                match name {
+                    "BEST_EFFORT_RELIABILITY_QOS" => {
+                        Ok(ReliabilityQosPolicyKind::BEST_EFFORT_RELIABILITY_QOS)
+                    }
+                    "RELIABLE_RELIABILITY_QOS" => {
+                        Ok(ReliabilityQosPolicyKind::RELIABLE_RELIABILITY_QOS)
+                    }
+                    _ => Err(rtps_types::EnumError::Name(name.to_owned())),
+                }
+            }
+        }
+        impl serde::Serialize for ReliabilityQosPolicyKind {
+            fn serialize<S: serde::Serializer>(
+                &self,
+                serializer: S,
+            ) -> Result<S::Ok, S::Error> {
+                serializer.serialize_u32(self.value())
+            }
+        }
+        impl<'de> serde::Deserialize<'de> for ReliabilityQosPolicyKind {
+            fn deserialize<D: serde::Deserializer<'de>>(
+                deserializer: D,
+            ) -> Result<ReliabilityQosPolicyKind, D::Error> {
+                let value = <u32 as serde::Deserialize>::deserialize(deserializer)?;
+                <ReliabilityQosPolicyKind as ::std::convert::TryFrom<
+                    u32,
+                >>::try_from(value)
+                    .map_err(serde::de::Error::custom)
+            }
+        }
+        impl rtps_cdr::CdrEncode for ReliabilityQosPolicyKind {
+            fn encode(
+                &self,
+                ser: &mut rtps_cdr::Serializer<'_>,
+            ) -> rtps_cdr::Result<()> {
+                rtps_cdr::CdrEncode::encode(&self.value(), ser)
+            }
+        }
+        impl rtps_cdr::CdrDecode for ReliabilityQosPolicyKind {
+            fn decode(
+                de: &mut rtps_cdr::Deserializer<'_>,
+            ) -> rtps_cdr::Result<ReliabilityQosPolicyKind> {
+                let value = <u32 as rtps_cdr::CdrDecode>::decode(de)?;
+                Ok(
+                    <ReliabilityQosPolicyKind as ::std::convert::TryFrom<
+                        u32,
+                    >>::try_from(value)?,
+                )
+            }
+        }
+        impl rtps_cdr::SerializedSize for ReliabilityQosPolicyKind {
+            const MAX_SIZE_XCDR1: Option<usize> = Some(4);
+            const MIN_SIZE_XCDR1: usize = 4;
+            const MAX_SIZE_XCDR2: Option<usize> = Some(4);
+            const MIN_SIZE_XCDR2: usize = 4;
+        }
+        #[allow(dead_code)]
+        #[allow(non_camel_case_types)]
+        #[derive(Serialize, Deserialize)]
+        #[derive(Clone, Debug, Default)]
+        pub struct ReliabilityQosPolicy {
+            pub kind: ReliabilityQosPolicyKind,
+            pub max_blocking_time: Duration_t,
+        }
+        impl rtps_types::xtypes::Extensible for ReliabilityQosPolicy {
+            const EXTENSIBILITY: rtps_types::xtypes::Extensibility = rtps_types::xtypes::Extensibility::Final;
+        }
+        impl rtps_cdr::CdrEncode for ReliabilityQosPolicy {
+            fn encode(
+                &self,
+                ser: &mut rtps_cdr::Serializer<'_>,
+            ) -> rtps_cdr::Result<()> {
+                rtps_cdr::CdrEncode::encode(&self.kind, ser)?;
+                rtps_cdr::CdrEncode::encode(&self.max_blocking_time, ser)?;
+                Ok(())
+            }
+        }
+        impl rtps_cdr::CdrDecode for ReliabilityQosPolicy {
+            fn decode(
+                de: &mut rtps_cdr::Deserializer<'_>,
+            ) -> rtps_cdr::Result<ReliabilityQosPolicy> {
+                Ok(ReliabilityQosPolicy {
+                    kind: rtps_cdr::CdrDecode::decode(de)?,
+                    max_blocking_time: rtps_cdr::CdrDecode::decode(de)?,
+                })
+            }
+        }
+        impl rtps_cdr::SerializedSize for ReliabilityQosPolicy {
+            const MAX_SIZE_XCDR1: Option<usize> = Some(12);
+            const MIN_SIZE_XCDR1: usize = 12;
+            const MAX_SIZE_XCDR2: Option<usize> = Some(12);
+            const MIN_SIZE_XCDR2: usize = 12;
+        }
+        #[allow(dead_code)]
+        #[allow(non_camel_case_types)]
+        #[derive(Serialize, Deserialize)]
+        #[derive(Clone, Debug, Default)]
+        pub struct PartitionQosPolicy {
+            #[serde(with = "rtps_types::xtypes::delimited")]
+            pub name: Vec<String>,
+        }
+        impl rtps_types::xtypes::Extensible for PartitionQosPolicy {
+            const EXTENSIBILITY: rtps_types::xtypes::Extensibility = rtps_types::xtypes::Extensibility::Final;
+        }
+        impl rtps_cdr::CdrEncode for PartitionQosPolicy {
+            fn encode(
+                &self,
+                ser: &mut rtps_cdr::Serializer<'_>,
+            ) -> rtps_cdr::Result<()> {
+                rtps_cdr::CdrEncode::encode(&rtps_cdr::Delimited(&self.name), ser)?;
+                Ok(())
+            }
+        }
+        impl rtps_cdr::CdrDecode for PartitionQosPolicy {
+            fn decode(
+                de: &mut rtps_cdr::Deserializer<'_>,
+            ) -> rtps_cdr::Result<PartitionQosPolicy> {
+                Ok(PartitionQosPolicy {
+                    name: <rtps_cdr::Delimited<_> as rtps_cdr::CdrDecode>::decode(de)?.0,
+                })
+            }
+        }
+        impl rtps_cdr::SerializedSize for PartitionQosPolicy {
+            const MAX_SIZE_XCDR1: Option<usize> = None;
+            const MIN_SIZE_XCDR1: usize = 4;
+            const MAX_SIZE_XCDR2: Option<usize> = None;
+            const MIN_SIZE_XCDR2: usize = 8;
+        }
+        #[allow(dead_code)]
+        #[allow(non_camel_case_types)]
+        #[derive(Serialize, Deserialize)]
+        #[derive(Clone, Debug, Default)]
+        pub struct Locator_t {
+            pub kind: i32,
+            pub _port: u32,
+            pub address: [u8; 16],
+        }
+        impl rtps_types::xtypes::Extensible for Locator_t {
+            const EXTENSIBILITY: rtps_types::xtypes::Extensibility = rtps_types::xtypes::Extensibility::Final;
+        }
+        impl rtps_cdr::CdrEncode for Locator_t {
+            fn encode(
+                &self,
+                ser: &mut rtps_cdr::Serializer<'_>,
+            ) -> rtps_cdr::Result<()> {
+                rtps_cdr::CdrEncode::encode(&self.kind, ser)?;
+                rtps_cdr::CdrEncode::encode(&self._port, ser)?;
+                rtps_cdr::CdrEncode::encode(&self.address, ser)?;
+                Ok(())
+            }
+        }
+        impl rtps_cdr::CdrDecode for Locator_t {
+            fn decode(
+                de: &mut rtps_cdr::Deserializer<'_>,
+            ) -> rtps_cdr::Result<Locator_t> {
+                Ok(Locator_t {
+                    kind: rtps_cdr::CdrDecode::decode(de)?,
+                    _port: rtps_cdr::CdrDecode::decode(de)?,
+                    address: rtps_cdr::CdrDecode::decode(de)?,
+                })
+            }
+        }
+        impl rtps_cdr::SerializedSize for Locator_t {
+            const MAX_SIZE_XCDR1: Option<usize> = Some(24);
+            const MIN_SIZE_XCDR1: usize = 24;
+            const MAX_SIZE_XCDR2: Option<usize> = Some(24);
+            const MIN_SIZE_XCDR2: usize = 24;
+        }
+        #[allow(dead_code)]
+        #[allow(non_camel_case_types)]
+        #[derive(Serialize, Deserialize)]
+        #[derive(Clone, Debug)]
+        #[serde(remote = "Self")]
+        pub enum Parameter {
+            #[serde(alias = "PID_TYPE_NAME")]
+            PID_TOPIC_NAME { string_data: rtps_types::BoundedString<256> },
+            PID_DURABILITY { durability: DurabilityQosPolicy },
+            PID_RELIABILITY { reliability: ReliabilityQosPolicy },
+            PID_PARTITION { partition: PartitionQosPolicy },
+            PID_UNICAST_LOCATOR { locator: Locator_t },
+        }
+        impl Default for Parameter {
+            fn default() -> Parameter {
+                Parameter::PID_TOPIC_NAME {
+                    string_data: Default::default(),
+                }
+            }
+        }
+        impl rtps_types::xtypes::Extensible for Parameter {
+            const EXTENSIBILITY: rtps_types::xtypes::Extensibility = rtps_types::xtypes::Extensibility::Final;
+        }
+        impl serde::Serialize for Parameter {
+            fn serialize<S: serde::Serializer>(
+                &self,
+                serializer: S,
+            ) -> Result<S::Ok, S::Error> {
+                if serializer.is_human_readable() {
+                    Parameter::serialize(self, serializer)
+                } else {
+                    use serde::ser::SerializeTuple;
+                    let mut tuple = serializer.serialize_tuple(2)?;
+                    match self {
+                        Parameter::PID_TOPIC_NAME { string_data: value } => {
+                            tuple.serialize_element(&5u16)?;
+                            tuple.serialize_element(value)?;
+                        }
+                        Parameter::PID_DURABILITY { durability: value } => {
+                            tuple.serialize_element(&29u16)?;
+                            tuple.serialize_element(value)?;
+                        }
+                        Parameter::PID_RELIABILITY { reliability: value } => {
+                            tuple.serialize_element(&26u16)?;
+                            tuple.serialize_element(value)?;
+                        }
+                        Parameter::PID_PARTITION { partition: value } => {
+                            tuple.serialize_element(&41u16)?;
+                            tuple.serialize_element(value)?;
+                        }
+                        Parameter::PID_UNICAST_LOCATOR { locator: value } => {
+                            tuple.serialize_element(&47u16)?;
+                            tuple.serialize_element(value)?;
+                        }
+                    }
+                    tuple.end()
+                }
+            }
+        }
+        impl<'de> serde::Deserialize<'de> for Parameter {
+            fn deserialize<D: serde::Deserializer<'de>>(
+                deserializer: D,
+            ) -> Result<Parameter, D::Error> {
+                let value = if deserializer.is_human_readable() {
+                    Parameter::deserialize(deserializer)
+                } else {
+                    struct Discriminated;
+                    impl<'de> serde::de::Visitor<'de> for Discriminated {
+                        type Value = Parameter;
+                        fn expecting(
+                            &self,
+                            f: &mut ::std::fmt::Formatter,
+                        ) -> ::std::fmt::Result {
+                            f.write_str("union DDS::RTPS::Parameter")
+                        }
+                        fn visit_seq<A: serde::de::SeqAccess<'de>>(
+                            self,
+                            mut seq: A,
+                        ) -> Result<Parameter, A::Error> {
+                            let label = seq
+                                .next_element::<u16>()?
+                                .ok_or_else(|| serde::de::Error::invalid_length(0, &self))?;
+                            Ok(
+                                match label {
+                                    5u16 | 7u16 => {
+                                        Parameter::PID_TOPIC_NAME {
+                                            string_data: seq
+                                                .next_element()?
+                                                .ok_or_else(|| serde::de::Error::invalid_length(1, &self))?,
+                                        }
+                                    }
+                                    29u16 => {
+                                        Parameter::PID_DURABILITY {
+                                            durability: seq
+                                                .next_element()?
+                                                .ok_or_else(|| serde::de::Error::invalid_length(1, &self))?,
+                                        }
+                                    }
+                                    26u16 => {
+                                        Parameter::PID_RELIABILITY {
+                                            reliability: seq
+                                                .next_element()?
+                                                .ok_or_else(|| serde::de::Error::invalid_length(1, &self))?,
+                                        }
+                                    }
+                                    41u16 => {
+                                        Parameter::PID_PARTITION {
+                                            partition: seq
+                                                .next_element()?
+                                                .ok_or_else(|| serde::de::Error::invalid_length(1, &self))?,
+                                        }
+                                    }
+                                    47u16 => {
+                                        Parameter::PID_UNICAST_LOCATOR {
+                                            locator: seq
+                                                .next_element()?
+                                                .ok_or_else(|| serde::de::Error::invalid_length(1, &self))?,
+                                        }
+                                    }
+                                    label => {
+                                        return Err(
+                                            serde::de::Error::custom(
+                                                ::std::format_args!(
+                                                    "invalid discriminator {} of {}", label,
+                                                    "union DDS::RTPS::Parameter"
+                                                ),
+                                            ),
+                                        );
+                                    }
+                                },
+                            )
+                        }
+                    }
+                    deserializer.deserialize_tuple(2, Discriminated)
+                }?;
+                Ok(value)
+            }
+        }
+        impl rtps_cdr::CdrEncode for Parameter {
+            fn encode(
+                &self,
+                ser: &mut rtps_cdr::Serializer<'_>,
+            ) -> rtps_cdr::Result<()> {
+                rtps_cdr::CdrEncode::encode(
+                    &rtps_cdr::CdrUnion::discriminator(self),
+                    ser,
+                )?;
+                rtps_cdr::CdrUnion::encode_element(self, ser)
+            }
+        }
+        impl rtps_cdr::CdrDecode for Parameter {
+            fn decode(
+                de: &mut rtps_cdr::Deserializer<'_>,
+            ) -> rtps_cdr::Result<Parameter> {
+                {
+                    let discriminator = rtps_cdr::CdrDecode::decode(de)?;
+                    rtps_cdr::CdrUnion::decode_element(discriminator, de)
+                }
+            }
+        }
+        impl rtps_cdr::CdrUnion for Parameter {
+            type Discriminator = u16;
+            fn discriminator(&self) -> u16 {
+                match self {
+                    Parameter::PID_TOPIC_NAME { .. } => 5u16,
+                    Parameter::PID_DURABILITY { .. } => 29u16,
+                    Parameter::PID_RELIABILITY { .. } => 26u16,
+                    Parameter::PID_PARTITION { .. } => 41u16,
+                    Parameter::PID_UNICAST_LOCATOR { .. } => 47u16,
+                }
+            }
+            fn encode_element(
+                &self,
+                ser: &mut rtps_cdr::Serializer<'_>,
+            ) -> rtps_cdr::Result<()> {
+                match self {
+                    Parameter::PID_TOPIC_NAME { string_data: value } => {
+                        rtps_cdr::CdrEncode::encode(value, ser)?;
+                    }
+                    Parameter::PID_DURABILITY { durability: value } => {
+                        rtps_cdr::CdrEncode::encode(value, ser)?;
+                    }
+                    Parameter::PID_RELIABILITY { reliability: value } => {
+                        rtps_cdr::CdrEncode::encode(value, ser)?;
+                    }
+                    Parameter::PID_PARTITION { partition: value } => {
+                        rtps_cdr::CdrEncode::encode(value, ser)?;
+                    }
+                    Parameter::PID_UNICAST_LOCATOR { locator: value } => {
+                        rtps_cdr::CdrEncode::encode(value, ser)?;
+                    }
+                }
+                Ok(())
+            }
+            fn decode_element(
+                discriminator: u16,
+                de: &mut rtps_cdr::Deserializer<'_>,
+            ) -> rtps_cdr::Result<Parameter> {
+                Ok(
+                    match discriminator {
+                        5u16 | 7u16 => {
+                            Parameter::PID_TOPIC_NAME {
+                                string_data: rtps_cdr::CdrDecode::decode(de)?,
+                            }
+                        }
+                        29u16 => {
+                            Parameter::PID_DURABILITY {
+                                durability: rtps_cdr::CdrDecode::decode(de)?,
+                            }
+                        }
+                        26u16 => {
+                            Parameter::PID_RELIABILITY {
+                                reliability: rtps_cdr::CdrDecode::decode(de)?,
+                            }
+                        }
+                        41u16 => {
+                            Parameter::PID_PARTITION {
+                                partition: rtps_cdr::CdrDecode::decode(de)?,
+                            }
+                        }
+                        47u16 => {
+                            Parameter::PID_UNICAST_LOCATOR {
+                                locator: rtps_cdr::CdrDecode::decode(de)?,
+                            }
+                        }
+                        label => {
+                            return Err(
+                                rtps_cdr::Error::InvalidDiscriminator(
+                                    i128::from(label),
+                                    "union DDS::RTPS::Parameter",
+                                ),
+                            );
+                        }
+                    },
+                )
+            }
+        }
+        impl rtps_cdr::SerializedSize for Parameter {
+            const MAX_SIZE_XCDR1: Option<usize> = None;
+            const MIN_SIZE_XCDR1: usize = 8;
+            const MAX_SIZE_XCDR2: Option<usize> = None;
+            const MIN_SIZE_XCDR2: usize = 8;
+        }
+        #[allow(dead_code)]
+        #[allow(non_upper_case_globals)]
+        pub const PID_SENTINEL: ParameterId_t = 0x0001;
+        #[allow(dead_code)]
+        #[allow(non_upper_case_globals)]
+        pub const PID_USER_DATA: ParameterId_t = 0x002c;
+        #[allow(dead_code)]
+        #[allow(non_upper_case_globals)]
+        pub const PID_TOPIC_NAME: ParameterId_t = 0x0005;
+        #[allow(dead_code)]
+        #[allow(non_upper_case_globals)]
+        pub const PID_TYPE_NAME: ParameterId_t = 0x0007;
+        #[allow(dead_code)]
+        #[allow(non_upper_case_globals)]
+        pub const PID_DURABILITY: ParameterId_t = 0x001d;
+        #[allow(dead_code)]
+        #[allow(non_upper_case_globals)]
+        pub const PID_RELIABILITY: ParameterId_t = 0x001a;
+        #[allow(dead_code)]
+        #[allow(non_upper_case_globals)]
+        pub const PID_PARTITION: ParameterId_t = 0x0029;
+        #[allow(dead_code)]
+        #[allow(non_upper_case_globals)]
+        pub const PID_UNICAST_LOCATOR: ParameterId_t = 0x002f;
+        #[allow(dead_code)]
+        #[allow(non_upper_case_globals)]
+        pub const PID_ENDPOINT_GUID: ParameterId_t = 0x005a;
+        #[allow(dead_code)]
+        #[allow(non_upper_case_globals)]
+        pub const PIDMASK_VENDOR_SPECIFIC: ParameterId_t = 0x8000;
+        #[allow(dead_code)]
+        #[allow(non_upper_case_globals)]
+        pub const PIDMASK_INCOMPATIBLE: ParameterId_t = 0x4000;
+        #[allow(dead_code)]
+        #[allow(non_upper_case_globals)]
+        pub const PID_DDS_BASE: ParameterId_t = PIDMASK_VENDOR_SPECIFIC + 0x3000;
+        #[allow(dead_code)]
+        #[allow(non_upper_case_globals)]
+        pub const PID_DDS_ASSOCIATED_WRITER: ParameterId_t = PID_DDS_BASE + 2;
+        #[allow(dead_code)]
+        #[allow(non_upper_case_globals)]
+        pub const LOCATOR_KIND_UDPv4: i32 = 1;
+    }
+}
@@ -0,0 +1,721 @@
+#[allow(unused_imports)]
+use std::vec::Vec;
+#[allow(unused_imports)]
+use serde_derive::{Serialize, Deserialize};
+#[allow(non_snake_case)]
+pub mod DDS {
+    #[allow(unused_imports)]
+    use serde_derive::{Serialize, Deserialize};
+    #[allow(non_snake_case)]
+    pub mod RTPS {
+        #[allow(unused_imports)]
+        use serde_derive::{Serialize, Deserialize};
+        #[allow(dead_code)]
+        #[allow(non_camel_case_types)]
+        pub type GuidPrefix_t = [u8; 12];
+        #[allow(dead_code)]
+        #[allow(non_camel_case_types)]
+        pub type EntityKey_t = [u8; 3];
+        #[allow(dead_code)]
+        #[allow(non_camel_case_types)]
+        pub type OctetArray16 = [u8; 16];
+        #[allow(dead_code)]
+        #[allow(non_camel_case_types)]
+        #[derive(Serialize, Deserialize)]
+        #[derive(Clone, Debug, Default)]
+        pub struct EntityId_t {
+            pub entityKey: EntityKey_t,
+            pub entityKind: u8,
+        }
+        impl rtps_types::xtypes::Extensible for EntityId_t {
+            const EXTENSIBILITY: rtps_types::xtypes::Extensibility = rtps_types::xtypes::Extensibility::Final;
+        }
+        impl rtps_cdr::CdrEncode for EntityId_t {
+            fn encode(
+                &self,
+                ser: &mut rtps_cdr::Serializer<'_>,
+            ) -> rtps_cdr::Result<()> {
+                rtps_cdr::CdrEncode::encode(&self.entityKey, ser)?;
+                rtps_cdr::CdrEncode::encode(&self.entityKind, ser)?;
+                Ok(())
+            }
+        }
+        impl rtps_cdr::CdrDecode for EntityId_t {
+            fn decode(
+                de: &mut rtps_cdr::Deserializer<'_>,
+            ) -> rtps_cdr::Result<EntityId_t> {
+                Ok(EntityId_t {
+                    entityKey: rtps_cdr::CdrDecode::decode(de)?,
+                    entityKind: rtps_cdr::CdrDecode::decode(de)?,
+                })
+            }
+        }
+        impl rtps_cdr::SerializedSize for EntityId_t {
+            const MAX_SIZE_XCDR1: Option<usize> = Some(4);
+            const MIN_SIZE_XCDR1: usize = 4;
+            const MAX_SIZE_XCDR2: Option<usize> = Some(4);
+            const MIN_SIZE_XCDR2: usize = 4;
+        }
+        #[allow(dead_code)]
+        #[allow(non_camel_case_types)]
+        #[derive(Serialize, Deserialize)]
+        #[derive(Clone, Debug, Default)]
+        pub struct GUID_t {
+            pub guidPrefix: GuidPrefix_t,
+            pub entityId: EntityId_t,
+        }
+        impl rtps_types::xtypes::Extensible for GUID_t {
+            const EXTENSIBILITY: rtps_types::xtypes::Extensibility = rtps_types::xtypes::Extensibility::Final;
+        }
+        impl rtps_cdr::CdrEncode for GUID_t {
+            fn encode(
+                &self,
+                ser: &mut rtps_cdr::Serializer<'_>,
+            ) -> rtps_cdr::Result<()> {
+                rtps_cdr::CdrEncode::encode(&self.guidPrefix, ser)?;
+                rtps_cdr::CdrEncode::encode(&self.entityId, ser)?;
+                Ok(())
+            }
+        }
+        impl rtps_cdr::CdrDecode for GUID_t {
+            fn decode(de: &mut rtps_cdr::Deserializer<'_>) -> rtps_cdr::Result<GUID_t> {
+                Ok(GUID_t {
+                    guidPrefix: rtps_cdr::CdrDecode::decode(de)?,
+                    entityId: rtps_cdr::CdrDecode::decode(de)?,
+                })
+            }
+        }
+        impl rtps_cdr::SerializedSize for GUID_t {
+            const MAX_SIZE_XCDR1: Option<usize> = Some(16);
+            const MIN_SIZE_XCDR1: usize = 16;
+            const MAX_SIZE_XCDR2: Option<usize> = Some(16);
+            const MIN_SIZE_XCDR2: usize = 16;
+        }
+        #[allow(dead_code)]
+        #[allow(non_camel_case_types)]
+        #[derive(Serialize, Deserialize)]
+        #[derive(Clone, Debug, Default)]
+        pub struct Locator_t {
+            pub kind: i32,
+            pub _port: u32,
+            pub address: OctetArray16,
+        }
+        impl rtps_types::xtypes::Extensible for Locator_t {
+            const EXTENSIBILITY: rtps_types::xtypes::Extensibility = rtps_types::xtypes::Extensibility::Final;
+        }
+        impl rtps_cdr::CdrEncode for Locator_t {
+            fn encode(
+                &self,
+                ser: &mut rtps_cdr::Serializer<'_>,
+            ) -> rtps_cdr::Result<()> {
+                rtps_cdr::CdrEncode::encode(&self.kind, ser)?;
+                rtps_cdr::CdrEncode::encode(&self._port, ser)?;
+                rtps_cdr::CdrEncode::encode(&self.address, ser)?;
+                Ok(())
+            }
+        }
+        impl rtps_cdr::CdrDecode for Locator_t {
+            fn decode(
+                de: &mut rtps_cdr::Deserializer<'_>,
+            ) -> rtps_cdr::Result<Locator_t> {
+                Ok(Locator_t {
+                    kind: rtps_cdr::CdrDecode::decode(de)?,
+                    _port: rtps_cdr::CdrDecode::decode(de)?,
+                    address: rtps_cdr::CdrDecode::decode(de)?,
+                })
+            }
+        }
+        impl rtps_cdr::SerializedSize for Locator_t {
+            const MAX_SIZE_XCDR1: Option<usize> = Some(24);
+            const MIN_SIZE_XCDR1: usize = 24;
+            const MAX_SIZE_XCDR2: Option<usize> = Some(24);
+            const MIN_SIZE_XCDR2: usize = 24;
+        }
+        #[allow(dead_code)]
+        #[allow(non_camel_case_types)]
+        pub type OctetArray2 = [u8; 2];
+        #[allow(dead_code)]
+        #[allow(non_camel_case_types)]
+        pub type OctetArray4 = [u8; 4];
+        #[allow(dead_code)]
+        #[allow(non_camel_case_types)]
+        pub type OctetSeq = Vec<u8>;
+        #[allow(dead_code)]
+        #[allow(non_camel_case_types)]
+        #[derive(Serialize, Deserialize)]
+        #[derive(Clone, Debug, Default)]
+        pub struct Count_t {
+            pub value: i32,
+        }
+        impl rtps_types::xtypes::Extensible for Count_t {
+            const EXTENSIBILITY: rtps_types::xtypes::Extensibility = rtps_types::xtypes::Extensibility::Final;
+        }
+        impl rtps_cdr::CdrEncode for Count_t {
+            fn encode(
+                &self,
+                ser: &mut rtps_cdr::Serializer<'_>,
+            ) -> rtps_cdr::Result<()> {
+                rtps_cdr::CdrEncode::encode(&self.value, ser)?;
+                Ok(())
+            }
+        }
+        impl rtps_cdr::CdrDecode for Count_t {
+            fn decode(de: &mut rtps_cdr::Deserializer<'_>) -> rtps_cdr::Result<Count_t> {
+                Ok(Count_t {
+                    value: rtps_cdr::CdrDecode::decode(de)?,
+                })
+            }
+        }
+        impl rtps_cdr::SerializedSize for Count_t {
+            const MAX_SIZE_XCDR1: Option<usize> = Some(4);
+            const MIN_SIZE_XCDR1: usize = 4;
+            const MAX_SIZE_XCDR2: Option<usize> = Some(4);
+            const MIN_SIZE_XCDR2: usize = 4;
+        }
+        #[allow(dead_code)]
+        #[allow(non_camel_case_types)]
+        #[derive(Serialize, Deserialize)]
+        #[derive(Clone, Debug, Default)]
+        pub struct SequenceNumber_t {
+            pub high: i32,
+            pub low: u32,
+        }
+        impl rtps_types::xtypes::Extensible for SequenceNumber_t {
+            const EXTENSIBILITY: rtps_types::xtypes::Extensibility = rtps_types::xtypes::Extensibility::Final;
+        }
+        impl rtps_cdr::CdrEncode for SequenceNumber_t {
+            fn encode(
+                &self,
+                ser: &mut rtps_cdr::Serializer<'_>,
+            ) -> rtps_cdr::Result<()> {
+                rtps_cdr::CdrEncode::encode(&self.high, ser)?;
+                rtps_cdr::CdrEncode::encode(&self.low, ser)?;
+                Ok(())
+            }
+        }
+        impl rtps_cdr::CdrDecode for SequenceNumber_t {
+            fn decode(
+                de: &mut rtps_cdr::Deserializer<'_>,
+            ) -> rtps_cdr::Result<SequenceNumber_t> {
+                Ok(SequenceNumber_t {
+                    high: rtps_cdr::CdrDecode::decode(de)?,
+                    low: rtps_cdr::CdrDecode::decode(de)?,
+                })
+            }
+        }
+        impl rtps_cdr::SerializedSize for SequenceNumber_t {
+            const MAX_SIZE_XCDR1: Option<usize> = Some(8);
+            const MIN_SIZE_XCDR1: usize = 8;
+            const MAX_SIZE_XCDR2: Option<usize> = Some(8);
+            const MIN_SIZE_XCDR2: usize = 8;
+        }
+        #[allow(dead_code)]
+        #[allow(non_camel_case_types)]
+        pub type LongSeq8 = rtps_types::BoundedVec<i32, 8>;
+        #[allow(dead_code)]
+        #[allow(non_camel_case_types)]
+        #[derive(Serialize, Deserialize)]
+        #[derive(Clone, Debug, Default)]
+        pub struct SequenceNumberSet {
+            pub bitmapBase: SequenceNumber_t,
+            pub numBits: u32,
+            pub bitmap: LongSeq8,
+        }
+        impl rtps_types::xtypes::Extensible for SequenceNumberSet {
+            const EXTENSIBILITY: rtps_types::xtypes::Extensibility = rtps_types::xtypes::Extensibility::Final;
+        }
+        impl rtps_cdr::CdrEncode for SequenceNumberSet {
+            fn encode(
+                &self,
+                ser: &mut rtps_cdr::Serializer<'_>,
+            ) -> rtps_cdr::Result<()> {
+                rtps_cdr::CdrEncode::encode(&self.bitmapBase, ser)?;
+                rtps_cdr::CdrEncode::encode(&self.numBits, ser)?;
+                rtps_cdr::CdrEncode::encode(&self.bitmap, ser)?;
+                Ok(())
+            }
+        }
+        impl rtps_cdr::CdrDecode for SequenceNumberSet {
+            fn decode(
+                de: &mut rtps_cdr::Deserializer<'_>,
+            ) -> rtps_cdr::Result<SequenceNumberSet> {
+                Ok(SequenceNumberSet {
+                    bitmapBase: rtps_cdr::CdrDecode::decode(de)?,
+                    numBits: rtps_cdr::CdrDecode::decode(de)?,
+                    bitmap: rtps_cdr::CdrDecode::decode(de)?,
+                })
+            }
+        }
+        impl rtps_cdr::SerializedSize for SequenceNumberSet {
+            const MAX_SIZE_XCDR1: Option<usize> = Some(48);
+            const MIN_SIZE_XCDR1: usize = 16;
+            const MAX_SIZE_XCDR2: Option<usize> = Some(48);
+            const MIN_SIZE_XCDR2: usize = 16;
+        }
+        #[allow(dead_code)]
+        #[allow(non_camel_case_types)]
+        pub type LocatorList = rtps_types::BoundedVec<Locator_t, 8>;
+        #[allow(dead_code)]
+        #[allow(non_camel_case_types)]
+        #[derive(Serialize, Deserialize)]
+        #[derive(Clone, Debug, Default)]
+        pub struct Time_t {
+            pub seconds: i32,
+            pub fraction: u32,
+        }
+        impl rtps_types::xtypes::Extensible for Time_t {
+            const EXTENSIBILITY: rtps_types::xtypes::Extensibility = rtps_types::xtypes::Extensibility::Final;
+        }
+        impl rtps_cdr::CdrEncode for Time_t {
+            fn encode(
+                &self,
+                ser: &mut rtps_cdr::Serializer<'_>,
+            ) -> rtps_cdr::Result<()> {
+                rtps_cdr::CdrEncode::encode(&self.seconds, ser)?;
+                rtps_cdr::CdrEncode::encode(&self.fraction, ser)?;
+                Ok(())
+            }
+        }
+        impl rtps_cdr::CdrDecode for Time_t {
+            fn decode(de: &mut rtps_cdr::Deserializer<'_>) -> rtps_cdr::Result<Time_t> {
+                Ok(Time_t {
+                    seconds: rtps_cdr::CdrDecode::decode(de)?,
+                    fraction: rtps_cdr::CdrDecode::decode(de)?,
+                })
+            }
+        }
+        impl rtps_cdr::SerializedSize for Time_t {
+            const MAX_SIZE_XCDR1: Option<usize> = Some(8);
+            const MIN_SIZE_XCDR1: usize = 8;
+            const MAX_SIZE_XCDR2: Option<usize> = Some(8);
+            const MIN_SIZE_XCDR2: usize = 8;
+        }
+        #[allow(dead_code)]
+        #[allow(non_camel_case_types)]
+        #[derive(Serialize, Deserialize)]
+        #[derive(Clone, Debug, Default)]
+        pub struct ProtocolVersion_t {
+            pub major: u8,
+            pub minor: u8,
+        }
+        impl rtps_types::xtypes::Extensible for ProtocolVersion_t {
+            const EXTENSIBILITY: rtps_types::xtypes::Extensibility = rtps_types::xtypes::Extensibility::Final;
+        }
+        impl rtps_cdr::CdrEncode for ProtocolVersion_t {
+            fn encode(
+                &self,
+                ser: &mut rtps_cdr::Serializer<'_>,
+            ) -> rtps_cdr::Result<()> {
+                rtps_cdr::CdrEncode::encode(&self.major, ser)?;
+                rtps_cdr::CdrEncode::encode(&self.minor, ser)?;
+                Ok(())
+            }
+        }
+        impl rtps_cdr::CdrDecode for ProtocolVersion_t {
+            fn decode(
+                de: &mut rtps_cdr::Deserializer<'_>,
+            ) -> rtps_cdr::Result<ProtocolVersion_t> {
+                Ok(ProtocolVersion_t {
+                    major: rtps_cdr::CdrDecode::decode(de)?,
+                    minor: rtps_cdr::CdrDecode::decode(de)?,
+                })
+            }
+        }
+        impl rtps_cdr::SerializedSize for ProtocolVersion_t {
+            const MAX_SIZE_XCDR1: Option<usize> = Some(2);
+            const MIN_SIZE_XCDR1: usize = 2;
+            const MAX_SIZE_XCDR2: Option<usize> = Some(2);
+            const MIN_SIZE_XCDR2: usize = 2;
+        }
+        #[allow(dead_code)]
+        #[allow(non_camel_case_types)]
+        #[derive(Serialize, Deserialize)]
+        #[derive(Clone, Debug, Default)]
+        pub struct VendorId_t {
+            pub vendorId: OctetArray2,
+        }
+        impl rtps_types::xtypes::Extensible for VendorId_t {
+            const EXTENSIBILITY: rtps_types::xtypes::Extensibility = rtps_types::xtypes::Extensibility::Final;
+        }
+        impl rtps_cdr::CdrEncode for VendorId_t {
+            fn encode(
+                &self,
+                ser: &mut rtps_cdr::Serializer<'_>,
+            ) -> rtps_cdr::Result<()> {
+                rtps_cdr::CdrEncode::encode(&self.vendorId, ser)?;
+                Ok(())
+            }
+        }
+        impl rtps_cdr::CdrDecode for VendorId_t {
+            fn decode(
+                de: &mut rtps_cdr::Deserializer<'_>,
+            ) -> rtps_cdr::Result<VendorId_t> {
+                Ok(VendorId_t {
+                    vendorId: rtps_cdr::CdrDecode::decode(de)?,
+                })
+            }
+        }
+        impl rtps_cdr::SerializedSize for VendorId_t {
+            const MAX_SIZE_XCDR1: Option<usize> = Some(2);
+            const MIN_SIZE_XCDR1: usize = 2;
+            const MAX_SIZE_XCDR2: Option<usize> = Some(2);
+            const MIN_SIZE_XCDR2: usize = 2;
+        }
+        #[allow(dead_code)]
+        #[allow(non_camel_case_types)]
+        #[derive(Serialize, Deserialize)]
+        #[derive(Clone, Debug, Default)]
+        pub struct SubmessageHeader {
+            pub submessageId: u8,
+            pub flags: u8,
+            pub submessageLength: u16,
+        }
+        impl rtps_types::xtypes::Extensible for SubmessageHeader {
+            const EXTENSIBILITY: rtps_types::xtypes::Extensibility = rtps_types::xtypes::Extensibility::Final;
+        }
+        impl rtps_cdr::CdrEncode for SubmessageHeader {
+            fn encode(
+                &self,
+                ser: &mut rtps_cdr::Serializer<'_>,
+            ) -> rtps_cdr::Result<()> {
+                rtps_cdr::CdrEncode::encode(&self.submessageId, ser)?;
+                rtps_cdr::CdrEncode::encode(&self.flags, ser)?;
+                rtps_cdr::CdrEncode::encode(&self.submessageLength, ser)?;
+                Ok(())
+            }
+        }
+        impl rtps_cdr::CdrDecode for SubmessageHeader {
+            fn decode(
+                de: &mut rtps_cdr::Deserializer<'_>,
+            ) -> rtps_cdr::Result<SubmessageHeader> {
+                Ok(SubmessageHeader {
+                    submessageId: rtps_cdr::CdrDecode::decode(de)?,
+                    flags: rtps_cdr::CdrDecode::decode(de)?,
+                    submessageLength: rtps_cdr::CdrDecode::decode(de)?,
+                })
+            }
+        }
+        impl rtps_cdr::SerializedSize for SubmessageHeader {
+            const MAX_SIZE_XCDR1: Option<usize> = Some(4);
+            const MIN_SIZE_XCDR1: usize = 4;
+            const MAX_SIZE_XCDR2: Option<usize> = Some(4);
+            const MIN_SIZE_XCDR2: usize = 4;
+        }
+        #[allow(dead_code)]
+        #[allow(non_camel_case_types)]
+        #[derive(Serialize, Deserialize)]
+        #[derive(Clone, Debug, Default)]
+        pub struct Header {
+            pub prefix: OctetArray4,
+            pub version: ProtocolVersion_t,
+            pub vendorId: VendorId_t,
+            pub guidPrefix: GuidPrefix_t,
+        }
+        impl rtps_types::xtypes::Extensible for Header {
+            const EXTENSIBILITY: rtps_types::xtypes::Extensibility = rtps_types::xtypes::Extensibility::Final;
+        }
+        impl rtps_cdr::CdrEncode for Header {
+            fn encode(
+                &self,
+                ser: &mut rtps_cdr::Serializer<'_>,
+            ) -> rtps_cdr::Result<()> {
+                rtps_cdr::CdrEncode::encode(&self.prefix, ser)?;
+                rtps_cdr::CdrEncode::encode(&self.version, ser)?;
+                rtps_cdr::CdrEncode::encode(&self.vendorId, ser)?;
+                rtps_cdr::CdrEncode::encode(&self.guidPrefix, ser)?;
+                Ok(())
+            }
+        }
+        impl rtps_cdr::CdrDecode for Header {
+            fn decode(de: &mut rtps_cdr::Deserializer<'_>) -> rtps_cdr::Result<Header> {
+                Ok(Header {
+                    prefix: rtps_cdr::CdrDecode::decode(de)?,
+                    version: rtps_cdr::CdrDecode::decode(de)?,
+                    vendorId: rtps_cdr::CdrDecode::decode(de)?,
+                    guidPrefix: rtps_cdr::CdrDecode::decode(de)?,
+                })
+            }
+        }
+        impl rtps_cdr::SerializedSize for Header {
+            const MAX_SIZE_XCDR1: Option<usize> = Some(20);
+            const MIN_SIZE_XCDR1: usize = 20;
+            const MAX_SIZE_XCDR2: Option<usize> = Some(20);
+            const MIN_SIZE_XCDR2: usize = 20;
+        }
+        #[allow(dead_code)]
+        #[allow(non_camel_case_types)]
+        #[derive(Serialize, Deserialize)]
+        #[derive(Clone, Debug, Default)]
+        pub struct AckNackSubmessage {
+            pub smHeader: SubmessageHeader,
+            pub readerId: EntityId_t,
+            pub writerId: EntityId_t,
+            pub readerSNState: SequenceNumberSet,
+            pub count: Count_t,
+        }
+        impl rtps_types::xtypes::Extensible for AckNackSubmessage {
+            const EXTENSIBILITY: rtps_types::xtypes::Extensibility = rtps_types::xtypes::Extensibility::Final;
+        }
+        impl rtps_cdr::CdrEncode for AckNackSubmessage {
+            fn encode(
+                &self,
+                ser: &mut rtps_cdr::Serializer<'_>,
+            ) -> rtps_cdr::Result<()> {
+                rtps_cdr::CdrEncode::encode(&self.smHeader, ser)?;
+                rtps_cdr::CdrEncode::encode(&self.readerId, ser)?;
+                rtps_cdr::CdrEncode::encode(&self.writerId, ser)?;
+                rtps_cdr::CdrEncode::encode(&self.readerSNState, ser)?;
+                rtps_cdr::CdrEncode::encode(&self.count, ser)?;
+                Ok(())
+            }
+        }
+        impl rtps_cdr::CdrDecode for AckNackSubmessage {
+            fn decode(
+                de: &mut rtps_cdr::Deserializer<'_>,
+            ) -> rtps_cdr::Result<AckNackSubmessage> {
+                Ok(AckNackSubmessage {
+                    smHeader: rtps_cdr::CdrDecode::decode(de)?,
+                    readerId: rtps_cdr::CdrDecode::decode(de)?,
+                    writerId: rtps_cdr::CdrDecode::decode(de)?,
+                    readerSNState: rtps_cdr::CdrDecode::decode(de)?,
+                    count: rtps_cdr::CdrDecode::decode(de)?,
+                })
+            }
+        }
+        impl rtps_cdr::SerializedSize for AckNackSubmessage {
+            const MAX_SIZE_XCDR1: Option<usize> = Some(64);
+            const MIN_SIZE_XCDR1: usize = 32;
+            const MAX_SIZE_XCDR2: Option<usize> = Some(64);
+            const MIN_SIZE_XCDR2: usize = 32;
+        }
+        #[allow(dead_code)]
+        #[allow(non_camel_case_types)]
+        #[derive(Serialize, Deserialize)]
+        #[derive(Clone, Debug, Default)]
+        pub struct HeartBeatSubmessage {
+            pub smHeader: SubmessageHeader,
+            pub readerId: EntityId_t,
+            pub writerId: EntityId_t,
+            pub firstSN: SequenceNumber_t,
+            pub lastSN: SequenceNumber_t,
+            pub count: Count_t,
+        }
+        impl rtps_types::xtypes::Extensible for HeartBeatSubmessage {
+            const EXTENSIBILITY: rtps_types::xtypes::Extensibility = rtps_types::xtypes::Extensibility::Final;
+        }
+        impl rtps_cdr::CdrEncode for HeartBeatSubmessage {
+            fn encode(
+                &self,
+                ser: &mut rtps_cdr::Serializer<'_>,
+            ) -> rtps_cdr::Result<()> {
+                rtps_cdr::CdrEncode::encode(&self.smHeader, ser)?;
+                rtps_cdr::CdrEncode::encode(&self.readerId, ser)?;
+                rtps_cdr::CdrEncode::encode(&self.writerId, ser)?;
+                rtps_cdr::CdrEncode::encode(&self.firstSN, ser)?;
+                rtps_cdr::CdrEncode::encode(&self.lastSN, ser)?;
+                rtps_cdr::CdrEncode::encode(&self.count, ser)?;
+                Ok(())
+            }
+        }
+        impl rtps_cdr::CdrDecode for HeartBeatSubmessage {
+            fn decode(
+                de: &mut rtps_cdr::Deserializer<'_>,
+            ) -> rtps_cdr::Result<HeartBeatSubmessage> {
+                Ok(HeartBeatSubmessage {
+                    smHeader: rtps_cdr::CdrDecode::decode(de)?,
+                    readerId: rtps_cdr::CdrDecode::decode(de)?,
+                    writerId: rtps_cdr::CdrDecode::decode(de)?,
+                    firstSN: rtps_cdr::CdrDecode::decode(de)?,
+                    lastSN: rtps_cdr::CdrDecode::decode(de)?,
+                    count: rtps_cdr::CdrDecode::decode(de)?,
+                })
+            }
+        }
+        impl rtps_cdr::SerializedSize for HeartBeatSubmessage {
+            const MAX_SIZE_XCDR1: Option<usize> = Some(32);
+            const MIN_SIZE_XCDR1: usize = 32;
+            const MAX_SIZE_XCDR2: Option<usize> = Some(32);
+            const MIN_SIZE_XCDR2: usize = 32;
+        }
+        #[allow(dead_code)]
+        #[allow(non_camel_case_types)]
+        #[derive(Serialize, Deserialize)]
+        #[derive(Clone, Debug, Default)]
+        pub struct InfoReplySubmessage {
+            pub smHeader: SubmessageHeader,
+            #[serde(with = "rtps_types::xtypes::delimited")]
+            pub unicastLocatorList: LocatorList,
+            #[serde(with = "rtps_types::xtypes::delimited")]
+            pub multicastLocatorList: LocatorList,
+        }
+        impl rtps_types::xtypes::Extensible for InfoReplySubmessage {
+            const EXTENSIBILITY: rtps_types::xtypes::Extensibility = rtps_types::xtypes::Extensibility::Final;
+        }
+        impl rtps_cdr::CdrEncode for InfoReplySubmessage {
+            fn encode(
+                &self,
+                ser: &mut rtps_cdr::Serializer<'_>,
+            ) -> rtps_cdr::Result<()> {
+                rtps_cdr::CdrEncode::encode(&self.smHeader, ser)?;
+                rtps_cdr::CdrEncode::encode(
+                    &rtps_cdr::Delimited(&self.unicastLocatorList),
+                    ser,
+                )?;
+                rtps_cdr::CdrEncode::encode(
+                    &rtps_cdr::Delimited(&self.multicastLocatorList),
+                    ser,
+                )?;
+                Ok(())
+            }
+        }
+        impl rtps_cdr::CdrDecode for InfoReplySubmessage {
+            fn decode(
+                de: &mut rtps_cdr::Deserializer<'_>,
+            ) -> rtps_cdr::Result<InfoReplySubmessage> {
+                Ok(InfoReplySubmessage {
+                    smHeader: rtps_cdr::CdrDecode::decode(de)?,
+                    unicastLocatorList: <rtps_cdr::Delimited<
+                        _,
+                    > as rtps_cdr::CdrDecode>::decode(de)?
+                        .0,
+                    multicastLocatorList: <rtps_cdr::Delimited<
+                        _,
+                    > as rtps_cdr::CdrDecode>::decode(de)?
+                        .0,
+                })
+            }
+        }
+        impl rtps_cdr::SerializedSize for InfoReplySubmessage {
+            const MAX_SIZE_XCDR1: Option<usize> = Some(396);
+            const MIN_SIZE_XCDR1: usize = 12;
+            const MAX_SIZE_XCDR2: Option<usize> = Some(404);
+            const MIN_SIZE_XCDR2: usize = 20;
+        }
+        #[allow(dead_code)]
+        #[allow(non_camel_case_types)]
+        #[derive(Serialize, Deserialize)]
+        #[derive(Clone, Debug, Default)]
+        pub struct InfoTimestampSubmessage {
+            pub smHeader: SubmessageHeader,
+            pub timestamp: Time_t,
+        }
+        impl rtps_types::xtypes::Extensible for InfoTimestampSubmessage {
+            const EXTENSIBILITY: rtps_types::xtypes::Extensibility = rtps_types::xtypes::Extensibility::Final;
+        }
+        impl rtps_cdr::CdrEncode for InfoTimestampSubmessage {
+            fn encode(
+                &self,
+                ser: &mut rtps_cdr::Serializer<'_>,
+            ) -> rtps_cdr::Result<()> {
+                rtps_cdr::CdrEncode::encode(&self.smHeader, ser)?;
+                rtps_cdr::CdrEncode::encode(&self.timestamp, ser)?;
+                Ok(())
+            }
+        }
+        impl rtps_cdr::CdrDecode for InfoTimestampSubmessage {
+            fn decode(
+                de: &mut rtps_cdr::Deserializer<'_>,
+            ) -> rtps_cdr::Result<InfoTimestampSubmessage> {
+                Ok(InfoTimestampSubmessage {
+                    smHeader: rtps_cdr::CdrDecode::decode(de)?,
+                    timestamp: rtps_cdr::CdrDecode::decode(de)?,
+                })
+            }
+        }
+        impl rtps_cdr::SerializedSize for InfoTimestampSubmessage {
+            const MAX_SIZE_XCDR1: Option<usize> = Some(12);
+            const MIN_SIZE_XCDR1: usize = 12;
+            const MAX_SIZE_XCDR2: Option<usize> = Some(12);
+            const MIN_SIZE_XCDR2: usize = 12;
+        }
+        #[allow(dead_code)]
+        #[allow(non_camel_case_types)]
+        #[derive(Serialize, Deserialize)]
+        #[derive(Clone, Debug, Default)]
+        pub struct DataSubmessage {
+            pub smHeader: SubmessageHeader,
+            pub extraFlags: u16,
+            pub octetsToInlineQos: u16,
+            pub readerId: EntityId_t,
+            pub writerId: EntityId_t,
+            pub writerSN: SequenceNumber_t,
+            pub inlineQos: OctetSeq,
+        }
+        impl rtps_types::xtypes::Extensible for DataSubmessage {
+            const EXTENSIBILITY: rtps_types::xtypes::Extensibility = rtps_types::xtypes::Extensibility::Final;
+        }
+        impl rtps_cdr::CdrEncode for DataSubmessage {
+            fn encode(
+                &self,
+                ser: &mut rtps_cdr::Serializer<'_>,
+            ) -> rtps_cdr::Result<()> {
+                rtps_cdr::CdrEncode::encode(&self.smHeader, ser)?;
+                rtps_cdr::CdrEncode::encode(&self.extraFlags, ser)?;
+                rtps_cdr::CdrEncode::encode(&self.octetsToInlineQos, ser)?;
+                rtps_cdr::CdrEncode::encode(&self.readerId, ser)?;
+                rtps_cdr::CdrEncode::encode(&self.writerId, ser)?;
+                rtps_cdr::CdrEncode::encode(&self.writerSN, ser)?;
+                rtps_cdr::CdrEncode::encode(&self.inlineQos, ser)?;
+                Ok(())
+            }
+        }
+        impl rtps_cdr::CdrDecode for DataSubmessage {
+            fn decode(
+                de: &mut rtps_cdr::Deserializer<'_>,
+            ) -> rtps_cdr::Result<DataSubmessage> {
+                Ok(DataSubmessage {
+                    smHeader: rtps_cdr::CdrDecode::decode(de)?,
+                    extraFlags: rtps_cdr::CdrDecode::decode(de)?,
+                    octetsToInlineQos: rtps_cdr::CdrDecode::decode(de)?,
+                    readerId: rtps_cdr::CdrDecode::decode(de)?,
+                    writerId: rtps_cdr::CdrDecode::decode(de)?,
+                    writerSN: rtps_cdr::CdrDecode::decode(de)?,
+                    inlineQos: rtps_cdr::CdrDecode::decode(de)?,
+                })
+            }
+        }
+        impl rtps_cdr::SerializedSize for DataSubmessage {
+            const MAX_SIZE_XCDR1: Option<usize> = None;
+            const MIN_SIZE_XCDR1: usize = 28;
+            const MAX_SIZE_XCDR2: Option<usize> = None;
+            const MIN_SIZE_XCDR2: usize = 28;
+        }
+        #[allow(dead_code)]
+        #[allow(non_camel_case_types)]
+        #[derive(Serialize, Deserialize)]
+        #[derive(Clone, Debug, Default)]
+        pub struct ParticipantMessageData {
+            pub participantGuid: GUID_t,
+            pub data: OctetSeq,
+        }
+        impl rtps_types::xtypes::Extensible for ParticipantMessageData {
+            const EXTENSIBILITY: rtps_types::xtypes::Extensibility = rtps_types::xtypes::Extensibility::Final;
+        }
+        impl rtps_cdr::CdrEncode for ParticipantMessageData {
+            fn encode(
+                &self,
+                ser: &mut rtps_cdr::Serializer<'_>,
+            ) -> rtps_cdr::Result<()> {
+                rtps_cdr::CdrEncode::encode(&self.participantGuid, ser)?;
+                rtps_cdr::CdrEncode::encode(&self.data, ser)?;
+                Ok(())
+            }
+        }
+        impl rtps_cdr::CdrDecode for ParticipantMessageData {
+            fn decode(
+                de: &mut rtps_cdr::Deserializer<'_>,
+            ) -> rtps_cdr::Result<ParticipantMessageData> {
+                Ok(ParticipantMessageData {
+                    participantGuid: rtps_cdr::CdrDecode::decode(de)?,
+                    data: rtps_cdr::CdrDecode::decode(de)?,
+                })
+            }
+        }
+        impl rtps_cdr::SerializedSize for ParticipantMessageData {
+            const MAX_SIZE_XCDR1: Option<usize> = None;
+            const MIN_SIZE_XCDR1: usize = 20;
+            const MAX_SIZE_XCDR2: Option<usize> = None;
+            const MIN_SIZE_XCDR2: usize = 20;
+        }
+    }
+}
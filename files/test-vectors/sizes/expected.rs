@@ -0,0 +1,819 @@
+#[allow(unused_imports)]
+use std::vec::Vec;
+#[allow(unused_imports)]
+use serde_derive::{Serialize, Deserialize};
+#[allow(non_snake_case)]
+pub mod Sizes {
+    #[allow(unused_imports)]
+    use serde_derive::{Serialize, Deserialize};
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[derive(Clone, Debug, Default)]
+    #[repr(u8)]
+    pub enum Small {
+        #[default]
+        LOW = 0,
+        HIGH = 1,
+    }
+    impl Small {
+        /// The value of the enumerator
+        pub const fn value(&self) -> u8 {
+            match self {
+                Small::LOW => 0,
+                Small::HIGH => 1,
+            }
+        }
+        /// The IDL name of the enumerator
+        pub const fn as_str(&self) -> &'static str {
+            match self {
+                Small::LOW => "LOW",
+                Small::HIGH => "HIGH",
+            }
+        }
+    }
+    impl ::std::convert::TryFrom<u32> for Small {
+        type Error = rtps_types::EnumError;
+        fn try_from(value: u32) -> Result<Small, rtps_types::EnumError> {
+            match value {
+                0 => Ok(Small::LOW),
+                1 => Ok(Small::HIGH),
+                _ => Err(rtps_types::EnumError::Value(value)),
+            }
+        }
+    }
+    impl From<Small> for u32 {
+        fn from(value: Small) -> u32 {
+            u32::from(value.value())
+        }
+    }
+    impl From<Small> for u8 {
+        fn from(value: Small) -> u8 {
+            value.value()
+        }
+    }
+    impl ::std::convert::TryFrom<u8> for Small {
+        type Error = rtps_types::EnumError;
+        fn try_from(value: u8) -> Result<Small, rtps_types::EnumError> {
+            <Small as ::std::convert::TryFrom<u32>>::try_from(u32::from(value))
+        }
+    }
+    impl ::std::fmt::Display for Small {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            f.write_str(self.as_str())
+        }
+    }
+    impl ::std::str::FromStr for Small {
+        type Err = rtps_types::EnumError;
+        fn from_str(name: &str) -> Result<Small, rtps_types::EnumError> {
+            match name {
+                "LOW" => Ok(Small::LOW),
+                "HIGH" => Ok(Small::HIGH),
+                _ => Err(rtps_types::EnumError::Name(name.to_owned())),
+            }
+        }
+    }
+    impl serde::Serialize for Small {
+        fn serialize<S: serde::Serializer>(
+            &self,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            serializer.serialize_u8(self.value())
+        }
+    }
+    impl<'de> serde::Deserialize<'de> for Small {
+        fn deserialize<D: serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Small, D::Error> {
+            let value = <u8 as serde::Deserialize>::deserialize(deserializer)?;
+            <Small as ::std::convert::TryFrom<u8>>::try_from(value)
+                .map_err(serde::de::Error::custom)
+        }
+    }
+    impl rtps_cdr::CdrEncode for Small {
+        fn encode(&self, ser: &mut rtps_cdr::Serializer<'_>) -> rtps_cdr::Result<()> {
+            rtps_cdr::CdrEncode::encode(&self.value(), ser)
+        }
+    }
+    impl rtps_cdr::CdrDecode for Small {
+        fn decode(de: &mut rtps_cdr::Deserializer<'_>) -> rtps_cdr::Result<Small> {
+            let value = <u8 as rtps_cdr::CdrDecode>::decode(de)?;
+            Ok(<Small as ::std::convert::TryFrom<u8>>::try_from(value)?)
+        }
+    }
+    impl rtps_cdr::SerializedSize for Small {
+        const MAX_SIZE_XCDR1: Option<usize> = Some(1);
+        const MIN_SIZE_XCDR1: usize = 1;
+        const MAX_SIZE_XCDR2: Option<usize> = Some(1);
+        const MIN_SIZE_XCDR2: usize = 1;
+    }
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[derive(Serialize, Deserialize)]
+    #[derive(Clone, Debug, Default)]
+    pub struct Aligned {
+        pub flag: u8,
+        pub value: f64,
+        pub small: Small,
+    }
+    impl rtps_types::xtypes::Extensible for Aligned {
+        const EXTENSIBILITY: rtps_types::xtypes::Extensibility = rtps_types::xtypes::Extensibility::Final;
+    }
+    impl rtps_cdr::CdrEncode for Aligned {
+        fn encode(&self, ser: &mut rtps_cdr::Serializer<'_>) -> rtps_cdr::Result<()> {
+            rtps_cdr::CdrEncode::encode(&self.flag, ser)?;
+            rtps_cdr::CdrEncode::encode(&self.value, ser)?;
+            rtps_cdr::CdrEncode::encode(&self.small, ser)?;
+            Ok(())
+        }
+    }
+    impl rtps_cdr::CdrDecode for Aligned {
+        fn decode(de: &mut rtps_cdr::Deserializer<'_>) -> rtps_cdr::Result<Aligned> {
+            Ok(Aligned {
+                flag: rtps_cdr::CdrDecode::decode(de)?,
+                value: rtps_cdr::CdrDecode::decode(de)?,
+                small: rtps_cdr::CdrDecode::decode(de)?,
+            })
+        }
+    }
+    impl rtps_cdr::SerializedSize for Aligned {
+        const MAX_SIZE_XCDR1: Option<usize> = Some(17);
+        const MIN_SIZE_XCDR1: usize = 17;
+        const MAX_SIZE_XCDR2: Option<usize> = Some(13);
+        const MIN_SIZE_XCDR2: usize = 13;
+    }
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[derive(Serialize, Deserialize)]
+    #[derive(Clone, Debug, Default)]
+    pub struct Arrays {
+        #[serde(with = "rtps_types::xtypes::delimited")]
+        pub items: [Aligned; 3],
+        pub shorts: [[i16; 3]; 2],
+        pub wide: rtps_types::LongDouble,
+    }
+    impl rtps_types::xtypes::Extensible for Arrays {
+        const EXTENSIBILITY: rtps_types::xtypes::Extensibility = rtps_types::xtypes::Extensibility::Final;
+    }
+    impl rtps_cdr::CdrEncode for Arrays {
+        fn encode(&self, ser: &mut rtps_cdr::Serializer<'_>) -> rtps_cdr::Result<()> {
+            rtps_cdr::CdrEncode::encode(&rtps_cdr::Delimited(&self.items), ser)?;
+            rtps_cdr::CdrEncode::encode(&self.shorts, ser)?;
+            rtps_cdr::CdrEncode::encode(&self.wide, ser)?;
+            Ok(())
+        }
+    }
+    impl rtps_cdr::CdrDecode for Arrays {
+        fn decode(de: &mut rtps_cdr::Deserializer<'_>) -> rtps_cdr::Result<Arrays> {
+            Ok(Arrays {
+                items: <rtps_cdr::Delimited<_> as rtps_cdr::CdrDecode>::decode(de)?.0,
+                shorts: rtps_cdr::CdrDecode::decode(de)?,
+                wide: rtps_cdr::CdrDecode::decode(de)?,
+            })
+        }
+    }
+    impl rtps_cdr::SerializedSize for Arrays {
+        const MAX_SIZE_XCDR1: Option<usize> = Some(80);
+        const MIN_SIZE_XCDR1: usize = 80;
+        const MAX_SIZE_XCDR2: Option<usize> = Some(72);
+        const MIN_SIZE_XCDR2: usize = 72;
+    }
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[derive(Serialize, Deserialize)]
+    #[derive(Clone, Debug, Default)]
+    pub struct Bounded {
+        pub name: rtps_types::BoundedString<8>,
+        pub wname: rtps_types::BoundedWideString<4>,
+        #[serde(with = "rtps_types::xtypes::delimited")]
+        pub items: rtps_types::BoundedVec<Aligned, 5>,
+        pub bytes: rtps_types::BoundedVec<u8, 100>,
+    }
+    impl rtps_types::xtypes::Extensible for Bounded {
+        const EXTENSIBILITY: rtps_types::xtypes::Extensibility = rtps_types::xtypes::Extensibility::Final;
+    }
+    impl rtps_cdr::CdrEncode for Bounded {
+        fn encode(&self, ser: &mut rtps_cdr::Serializer<'_>) -> rtps_cdr::Result<()> {
+            rtps_cdr::CdrEncode::encode(&self.name, ser)?;
+            rtps_cdr::CdrEncode::encode(&self.wname, ser)?;
+            rtps_cdr::CdrEncode::encode(&rtps_cdr::Delimited(&self.items), ser)?;
+            rtps_cdr::CdrEncode::encode(&self.bytes, ser)?;
+            Ok(())
+        }
+    }
+    impl rtps_cdr::CdrDecode for Bounded {
+        fn decode(de: &mut rtps_cdr::Deserializer<'_>) -> rtps_cdr::Result<Bounded> {
+            Ok(Bounded {
+                name: rtps_cdr::CdrDecode::decode(de)?,
+                wname: rtps_cdr::CdrDecode::decode(de)?,
+                items: <rtps_cdr::Delimited<_> as rtps_cdr::CdrDecode>::decode(de)?.0,
+                bytes: rtps_cdr::CdrDecode::decode(de)?,
+            })
+        }
+    }
+    impl rtps_cdr::SerializedSize for Bounded {
+        const MAX_SIZE_XCDR1: Option<usize> = Some(228);
+        const MIN_SIZE_XCDR1: usize = 24;
+        const MAX_SIZE_XCDR2: Option<usize> = Some(216);
+        const MIN_SIZE_XCDR2: usize = 28;
+    }
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[derive(Serialize, Deserialize)]
+    #[derive(Clone, Debug, Default)]
+    #[serde(remote = "Self")]
+    #[serde(default)]
+    pub struct Versioned {
+        pub tag: u8,
+        #[serde(with = "rtps_types::xtypes::delimited")]
+        pub items: rtps_types::BoundedVec<Aligned, 2>,
+        #[serde(default)]
+        #[serde(
+            serialize_with = "rtps_types::xtypes::optional::serialize::<2, _, _>",
+            deserialize_with = "rtps_types::xtypes::optional::deserialize"
+        )]
+        pub extra: Option<f64>,
+    }
+    impl rtps_types::xtypes::Extensible for Versioned {
+        const EXTENSIBILITY: rtps_types::xtypes::Extensibility = rtps_types::xtypes::Extensibility::Appendable;
+    }
+    impl serde::Serialize for Versioned {
+        fn serialize<S: serde::Serializer>(
+            &self,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            serializer
+                .serialize_newtype_struct(
+                    rtps_types::xtypes::DELIMITED,
+                    &rtps_types::xtypes::Derived(self),
+                )
+        }
+    }
+    impl<'de> serde::Deserialize<'de> for Versioned {
+        fn deserialize<D: serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Versioned, D::Error> {
+            let value = deserializer
+                .deserialize_newtype_struct(
+                    rtps_types::xtypes::DELIMITED,
+                    rtps_types::xtypes::DerivedVisitor::<Versioned>::default(),
+                )?;
+            Ok(value)
+        }
+    }
+    impl rtps_types::xtypes::SerializeDerived for Versioned {
+        fn serialize_derived<S: serde::Serializer>(
+            &self,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            Versioned::serialize(self, serializer)
+        }
+    }
+    impl<'de> rtps_types::xtypes::DeserializeDerived<'de> for Versioned {
+        fn deserialize_derived<D: serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Versioned, D::Error> {
+            Versioned::deserialize(deserializer)
+        }
+    }
+    impl rtps_cdr::CdrEncode for Versioned {
+        fn encode(&self, ser: &mut rtps_cdr::Serializer<'_>) -> rtps_cdr::Result<()> {
+            ser.delimited(|ser| {
+                rtps_cdr::CdrEncode::encode(&self.tag, ser)?;
+                rtps_cdr::CdrEncode::encode(&rtps_cdr::Delimited(&self.items), ser)?;
+                ser.optional(2u32, self.extra.as_ref())?;
+                Ok(())
+            })
+        }
+    }
+    impl rtps_cdr::CdrDecode for Versioned {
+        fn decode(de: &mut rtps_cdr::Deserializer<'_>) -> rtps_cdr::Result<Versioned> {
+            de.delimited(|de| Ok(Versioned {
+                tag: de.appended()?,
+                items: de.appended::<rtps_cdr::Delimited<_>>()?.0,
+                extra: de.appended_with(|de| de.optional())?,
+            }))
+        }
+    }
+    impl rtps_cdr::SerializedSize for Versioned {
+        const MAX_SIZE_XCDR1: Option<usize> = Some(56);
+        const MIN_SIZE_XCDR1: usize = 12;
+        const MAX_SIZE_XCDR2: Option<usize> = Some(52);
+        const MIN_SIZE_XCDR2: usize = 17;
+    }
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[derive(Serialize, Deserialize)]
+    #[derive(Clone, Debug, Default)]
+    #[serde(remote = "Self")]
+    pub struct Params {
+        pub small: u8,
+        pub far: i32,
+        pub big: rtps_types::BoundedVec<u8, 70000>,
+        #[serde(default)]
+        #[serde(
+            serialize_with = "rtps_types::xtypes::optional::serialize::<16386, _, _>",
+            deserialize_with = "rtps_types::xtypes::optional::deserialize"
+        )]
+        pub note: Option<rtps_types::BoundedString<3>>,
+    }
+    impl rtps_types::xtypes::Extensible for Params {
+        const EXTENSIBILITY: rtps_types::xtypes::Extensibility = rtps_types::xtypes::Extensibility::Mutable;
+    }
+    impl serde::Serialize for Params {
+        fn serialize<S: serde::Serializer>(
+            &self,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            if serializer.is_human_readable() {
+                Params::serialize(self, serializer)
+            } else {
+                serializer
+                    .serialize_newtype_struct(
+                        rtps_types::xtypes::MUTABLE,
+                        &rtps_types::xtypes::Members(self),
+                    )
+            }
+        }
+    }
+    impl<'de> serde::Deserialize<'de> for Params {
+        fn deserialize<D: serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Params, D::Error> {
+            let value = if deserializer.is_human_readable() {
+                Params::deserialize(deserializer)?
+            } else {
+                deserializer
+                    .deserialize_newtype_struct(
+                        rtps_types::xtypes::MUTABLE,
+                        rtps_types::xtypes::MembersVisitor::<Params>::default(),
+                    )?
+            };
+            Ok(value)
+        }
+    }
+    impl rtps_types::xtypes::SerializeMembers for Params {
+        fn member_count(&self) -> usize {
+            3 + usize::from(self.note.is_some())
+        }
+        fn serialize_members<M: serde::ser::SerializeMap>(
+            &self,
+            map: &mut M,
+        ) -> Result<(), M::Error> {
+            map.serialize_entry(&1u32, &self.small)?;
+            map.serialize_entry(&16384u32, &self.far)?;
+            map.serialize_entry(&16385u32, &self.big)?;
+            if let Some(value) = &self.note {
+                map.serialize_entry(&16386u32, value)?;
+            }
+            Ok(())
+        }
+    }
+    impl<'de> rtps_types::xtypes::DeserializeMembers<'de> for Params {
+        fn deserialize_members<A: serde::de::MapAccess<'de>>(
+            mut map: A,
+        ) -> Result<Params, A::Error> {
+            let mut value = Params::default();
+            while let Some(header) = map.next_key::<u32>()? {
+                match header & rtps_types::xtypes::MEMBER_ID_MASK {
+                    1 => value.small = map.next_value()?,
+                    16384 => value.far = map.next_value()?,
+                    16385 => value.big = map.next_value()?,
+                    16386 => value.note = Some(map.next_value()?),
+                    _ => rtps_types::xtypes::skip_member(&mut map, header)?,
+                }
+            }
+            Ok(value)
+        }
+    }
+    impl rtps_cdr::CdrEncode for Params {
+        fn encode(&self, ser: &mut rtps_cdr::Serializer<'_>) -> rtps_cdr::Result<()> {
+            ser.members(|ser| {
+                ser.member(1u32, |ser| rtps_cdr::CdrEncode::encode(&self.small, ser))?;
+                ser.member(16384u32, |ser| rtps_cdr::CdrEncode::encode(&self.far, ser))?;
+                ser.member(16385u32, |ser| rtps_cdr::CdrEncode::encode(&self.big, ser))?;
+                if let Some(value) = &self.note {
+                    ser.member(16386u32, |ser| rtps_cdr::CdrEncode::encode(value, ser))?;
+                }
+                Ok(())
+            })
+        }
+    }
+    impl rtps_cdr::CdrDecode for Params {
+        fn decode(de: &mut rtps_cdr::Deserializer<'_>) -> rtps_cdr::Result<Params> {
+            let mut value = Params::default();
+            de.members(|de, header| {
+                match header & rtps_types::xtypes::MEMBER_ID_MASK {
+                    1 => value.small = rtps_cdr::CdrDecode::decode(de)?,
+                    16384 => value.far = rtps_cdr::CdrDecode::decode(de)?,
+                    16385 => value.big = rtps_cdr::CdrDecode::decode(de)?,
+                    16386 => value.note = Some(rtps_cdr::CdrDecode::decode(de)?),
+                    _ => return Ok(false),
+                }
+                Ok(true)
+            })?;
+            Ok(value)
+        }
+    }
+    impl rtps_cdr::SerializedSize for Params {
+        const MAX_SIZE_XCDR1: Option<usize> = Some(70064);
+        const MIN_SIZE_XCDR1: usize = 44;
+        const MAX_SIZE_XCDR2: Option<usize> = Some(70056);
+        const MIN_SIZE_XCDR2: usize = 40;
+    }
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[derive(Serialize, Deserialize)]
+    #[derive(Clone, Debug)]
+    #[serde(remote = "Self")]
+    pub enum Choice {
+        small { small: u8 },
+        large { large: Aligned },
+    }
+    impl Default for Choice {
+        fn default() -> Choice {
+            Choice::small {
+                small: Default::default(),
+            }
+        }
+    }
+    impl rtps_types::xtypes::Extensible for Choice {
+        const EXTENSIBILITY: rtps_types::xtypes::Extensibility = rtps_types::xtypes::Extensibility::Final;
+    }
+    impl serde::Serialize for Choice {
+        fn serialize<S: serde::Serializer>(
+            &self,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            if serializer.is_human_readable() {
+                Choice::serialize(self, serializer)
+            } else {
+                use serde::ser::SerializeTuple;
+                let mut tuple = serializer.serialize_tuple(2)?;
+                match self {
+                    Choice::small { small: value } => {
+                        tuple.serialize_element(&0i32)?;
+                        tuple.serialize_element(value)?;
+                    }
+                    Choice::large { large: value } => {
+                        tuple.serialize_element(&1i32)?;
+                        tuple.serialize_element(value)?;
+                    }
+                }
+                tuple.end()
+            }
+        }
+    }
+    impl<'de> serde::Deserialize<'de> for Choice {
+        fn deserialize<D: serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Choice, D::Error> {
+            let value = if deserializer.is_human_readable() {
+                Choice::deserialize(deserializer)
+            } else {
+                struct Discriminated;
+                impl<'de> serde::de::Visitor<'de> for Discriminated {
+                    type Value = Choice;
+                    fn expecting(
+                        &self,
+                        f: &mut ::std::fmt::Formatter,
+                    ) -> ::std::fmt::Result {
+                        f.write_str("union Sizes::Choice")
+                    }
+                    fn visit_seq<A: serde::de::SeqAccess<'de>>(
+                        self,
+                        mut seq: A,
+                    ) -> Result<Choice, A::Error> {
+                        let label = seq
+                            .next_element::<i32>()?
+                            .ok_or_else(|| serde::de::Error::invalid_length(0, &self))?;
+                        Ok(
+                            match label {
+                                0i32 => {
+                                    Choice::small {
+                                        small: seq
+                                            .next_element()?
+                                            .ok_or_else(|| serde::de::Error::invalid_length(1, &self))?,
+                                    }
+                                }
+                                1i32 => {
+                                    Choice::large {
+                                        large: seq
+                                            .next_element()?
+                                            .ok_or_else(|| serde::de::Error::invalid_length(1, &self))?,
+                                    }
+                                }
+                                label => {
+                                    return Err(
+                                        serde::de::Error::custom(
+                                            ::std::format_args!(
+                                                "invalid discriminator {} of {}", label,
+                                                "union Sizes::Choice"
+                                            ),
+                                        ),
+                                    );
+                                }
+                            },
+                        )
+                    }
+                }
+                deserializer.deserialize_tuple(2, Discriminated)
+            }?;
+            Ok(value)
+        }
+    }
+    impl rtps_cdr::CdrEncode for Choice {
+        fn encode(&self, ser: &mut rtps_cdr::Serializer<'_>) -> rtps_cdr::Result<()> {
+            rtps_cdr::CdrEncode::encode(&rtps_cdr::CdrUnion::discriminator(self), ser)?;
+            rtps_cdr::CdrUnion::encode_element(self, ser)
+        }
+    }
+    impl rtps_cdr::CdrDecode for Choice {
+        fn decode(de: &mut rtps_cdr::Deserializer<'_>) -> rtps_cdr::Result<Choice> {
+            {
+                let discriminator = rtps_cdr::CdrDecode::decode(de)?;
+                rtps_cdr::CdrUnion::decode_element(discriminator, de)
+            }
+        }
+    }
+    impl rtps_cdr::CdrUnion for Choice {
+        type Discriminator = i32;
+        fn discriminator(&self) -> i32 {
+            match self {
+                Choice::small { .. } => 0i32,
+                Choice::large { .. } => 1i32,
+            }
+        }
+        fn encode_element(
+            &self,
+            ser: &mut rtps_cdr::Serializer<'_>,
+        ) -> rtps_cdr::Result<()> {
+            match self {
+                Choice::small { small: value } => {
+                    rtps_cdr::CdrEncode::encode(value, ser)?;
+                }
+                Choice::large { large: value } => {
+                    rtps_cdr::CdrEncode::encode(value, ser)?;
+                }
+            }
+            Ok(())
+        }
+        fn decode_element(
+            discriminator: i32,
+            de: &mut rtps_cdr::Deserializer<'_>,
+        ) -> rtps_cdr::Result<Choice> {
+            Ok(
+                match discriminator {
+                    0i32 => {
+                        Choice::small {
+                            small: rtps_cdr::CdrDecode::decode(de)?,
+                        }
+                    }
+                    1i32 => {
+                        Choice::large {
+                            large: rtps_cdr::CdrDecode::decode(de)?,
+                        }
+                    }
+                    label => {
+                        return Err(
+                            rtps_cdr::Error::InvalidDiscriminator(
+                                i128::from(label),
+                                "union Sizes::Choice",
+                            ),
+                        );
+                    }
+                },
+            )
+        }
+    }
+    impl rtps_cdr::SerializedSize for Choice {
+        const MAX_SIZE_XCDR1: Option<usize> = Some(17);
+        const MIN_SIZE_XCDR1: usize = 5;
+        const MAX_SIZE_XCDR2: Option<usize> = Some(17);
+        const MIN_SIZE_XCDR2: usize = 5;
+    }
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[derive(Serialize, Deserialize)]
+    #[derive(Clone, Debug)]
+    #[serde(remote = "Self")]
+    pub enum Selection {
+        small { small: u8 },
+        large { large: Bounded },
+    }
+    impl Default for Selection {
+        fn default() -> Selection {
+            Selection::small {
+                small: Default::default(),
+            }
+        }
+    }
+    impl rtps_types::xtypes::Extensible for Selection {
+        const EXTENSIBILITY: rtps_types::xtypes::Extensibility = rtps_types::xtypes::Extensibility::Mutable;
+    }
+    impl serde::Serialize for Selection {
+        fn serialize<S: serde::Serializer>(
+            &self,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            if serializer.is_human_readable() {
+                Selection::serialize(self, serializer)
+            } else {
+                serializer
+                    .serialize_newtype_struct(
+                        rtps_types::xtypes::MUTABLE,
+                        &rtps_types::xtypes::Members(self),
+                    )
+            }
+        }
+    }
+    impl<'de> serde::Deserialize<'de> for Selection {
+        fn deserialize<D: serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Selection, D::Error> {
+            let value = if deserializer.is_human_readable() {
+                Selection::deserialize(deserializer)?
+            } else {
+                deserializer
+                    .deserialize_newtype_struct(
+                        rtps_types::xtypes::MUTABLE,
+                        rtps_types::xtypes::MembersVisitor::<Selection>::default(),
+                    )?
+            };
+            Ok(value)
+        }
+    }
+    impl rtps_types::xtypes::SerializeMembers for Selection {
+        fn member_count(&self) -> usize {
+            match self {
+                Selection::small { .. } => 2,
+                Selection::large { .. } => 2,
+            }
+        }
+        fn serialize_members<M: serde::ser::SerializeMap>(
+            &self,
+            map: &mut M,
+        ) -> Result<(), M::Error> {
+            match self {
+                Selection::small { small: value } => {
+                    map.serialize_entry(&rtps_types::xtypes::MUST_UNDERSTAND, &0i32)?;
+                    map.serialize_entry(&1u32, value)?;
+                }
+                Selection::large { large: value } => {
+                    map.serialize_entry(&rtps_types::xtypes::MUST_UNDERSTAND, &1i32)?;
+                    map.serialize_entry(&2u32, value)?;
+                }
+            }
+            Ok(())
+        }
+    }
+    impl<'de> rtps_types::xtypes::DeserializeMembers<'de> for Selection {
+        fn deserialize_members<A: serde::de::MapAccess<'de>>(
+            mut map: A,
+        ) -> Result<Selection, A::Error> {
+            let mut value = Selection::default();
+            while let Some(header) = map.next_key::<u32>()? {
+                match header & rtps_types::xtypes::MEMBER_ID_MASK {
+                    0 => {
+                        map.next_value::<i32>()?;
+                    }
+                    1 => {
+                        value = Selection::small {
+                            small: map.next_value()?,
+                        };
+                    }
+                    2 => {
+                        value = Selection::large {
+                            large: map.next_value()?,
+                        };
+                    }
+                    _ => rtps_types::xtypes::skip_member(&mut map, header)?,
+                }
+            }
+            Ok(value)
+        }
+    }
+    impl rtps_cdr::CdrEncode for Selection {
+        fn encode(&self, ser: &mut rtps_cdr::Serializer<'_>) -> rtps_cdr::Result<()> {
+            ser.members(|ser| {
+                match self {
+                    Selection::small { small: value } => {
+                        ser.member(
+                            rtps_types::xtypes::MUST_UNDERSTAND,
+                            |ser| rtps_cdr::CdrEncode::encode(&0i32, ser),
+                        )?;
+                        ser.member(1u32, |ser| rtps_cdr::CdrEncode::encode(value, ser))?;
+                    }
+                    Selection::large { large: value } => {
+                        ser.member(
+                            rtps_types::xtypes::MUST_UNDERSTAND,
+                            |ser| rtps_cdr::CdrEncode::encode(&1i32, ser),
+                        )?;
+                        ser.member(2u32, |ser| rtps_cdr::CdrEncode::encode(value, ser))?;
+                    }
+                }
+                Ok(())
+            })
+        }
+    }
+    impl rtps_cdr::CdrDecode for Selection {
+        fn decode(de: &mut rtps_cdr::Deserializer<'_>) -> rtps_cdr::Result<Selection> {
+            let mut value = Selection::default();
+            de.members(|de, header| {
+                match header & rtps_types::xtypes::MEMBER_ID_MASK {
+                    0 => {
+                        <i32 as rtps_cdr::CdrDecode>::decode(de)?;
+                    }
+                    1 => {
+                        value = Selection::small {
+                            small: rtps_cdr::CdrDecode::decode(de)?,
+                        };
+                    }
+                    2 => {
+                        value = Selection::large {
+                            large: rtps_cdr::CdrDecode::decode(de)?,
+                        };
+                    }
+                    _ => return Ok(false),
+                }
+                Ok(true)
+            })?;
+            Ok(value)
+        }
+    }
+    impl rtps_cdr::SerializedSize for Selection {
+        const MAX_SIZE_XCDR1: Option<usize> = Some(244);
+        const MIN_SIZE_XCDR1: usize = 20;
+        const MAX_SIZE_XCDR2: Option<usize> = Some(240);
+        const MIN_SIZE_XCDR2: usize = 25;
+    }
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[derive(Serialize, Deserialize)]
+    #[derive(Clone, Debug, Default)]
+    pub struct Node {
+        pub value: i32,
+        #[serde(default)]
+        #[serde(
+            serialize_with = "rtps_types::xtypes::optional::serialize::<1, _, _>",
+            deserialize_with = "rtps_types::xtypes::optional::deserialize"
+        )]
+        pub next: Option<Box<Node>>,
+    }
+    impl rtps_types::xtypes::Extensible for Node {
+        const EXTENSIBILITY: rtps_types::xtypes::Extensibility = rtps_types::xtypes::Extensibility::Final;
+    }
+    impl rtps_cdr::CdrEncode for Node {
+        fn encode(&self, ser: &mut rtps_cdr::Serializer<'_>) -> rtps_cdr::Result<()> {
+            rtps_cdr::CdrEncode::encode(&self.value, ser)?;
+            ser.optional(1u32, self.next.as_ref())?;
+            Ok(())
+        }
+    }
+    impl rtps_cdr::CdrDecode for Node {
+        fn decode(de: &mut rtps_cdr::Deserializer<'_>) -> rtps_cdr::Result<Node> {
+            Ok(Node {
+                value: rtps_cdr::CdrDecode::decode(de)?,
+                next: de.optional()?,
+            })
+        }
+    }
+    impl rtps_cdr::SerializedSize for Node {
+        const MAX_SIZE_XCDR1: Option<usize> = None;
+        const MIN_SIZE_XCDR1: usize = 8;
+        const MAX_SIZE_XCDR2: Option<usize> = None;
+        const MIN_SIZE_XCDR2: usize = 5;
+    }
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[derive(Serialize, Deserialize)]
+    #[derive(Clone, Debug, Default)]
+    pub struct Unbounded {
+        pub name: String,
+        #[serde(with = "rtps_types::xtypes::delimited")]
+        pub items: Vec<Aligned>,
+    }
+    impl rtps_types::xtypes::Extensible for Unbounded {
+        const EXTENSIBILITY: rtps_types::xtypes::Extensibility = rtps_types::xtypes::Extensibility::Final;
+    }
+    impl rtps_cdr::CdrEncode for Unbounded {
+        fn encode(&self, ser: &mut rtps_cdr::Serializer<'_>) -> rtps_cdr::Result<()> {
+            rtps_cdr::CdrEncode::encode(&self.name, ser)?;
+            rtps_cdr::CdrEncode::encode(&rtps_cdr::Delimited(&self.items), ser)?;
+            Ok(())
+        }
+    }
+    impl rtps_cdr::CdrDecode for Unbounded {
+        fn decode(de: &mut rtps_cdr::Deserializer<'_>) -> rtps_cdr::Result<Unbounded> {
+            Ok(Unbounded {
+                name: rtps_cdr::CdrDecode::decode(de)?,
+                items: <rtps_cdr::Delimited<_> as rtps_cdr::CdrDecode>::decode(de)?.0,
+            })
+        }
+    }
+    impl rtps_cdr::SerializedSize for Unbounded {
+        const MAX_SIZE_XCDR1: Option<usize> = None;
+        const MIN_SIZE_XCDR1: usize = 12;
+        const MAX_SIZE_XCDR2: Option<usize> = None;
+        const MIN_SIZE_XCDR2: usize = 16;
+    }
+}
@@ -2,13 +2,10 @@
 use std::vec::Vec;
 #[allow(unused_imports)]
 use serde_derive::{Serialize, Deserialize};
-
-//
-//
 #[allow(dead_code)]
 #[allow(non_camel_case_types)]
 #[derive(Serialize, Deserialize)]
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Default)]
 pub struct Foo {
     pub m_l1: i32,
     pub m_l2: i32,
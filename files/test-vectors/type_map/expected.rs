@@ -0,0 +1,128 @@
+#[allow(unused_imports)]
+use std::vec::Vec;
+#[allow(unused_imports)]
+use serde_derive::{Serialize, Deserialize};
+#[allow(non_snake_case)]
+pub mod TypeMap {
+    #[allow(unused_imports)]
+    use serde_derive::{Serialize, Deserialize};
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    pub type Time_t = std::time::Duration;
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    pub type Payload = bytes::Bytes;
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[derive(Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Default)]
+    pub struct Sample {
+        pub timestamp: Time_t,
+        pub payload: Payload,
+        pub key: bytes::Bytes,
+        pub topic: ::std::borrow::Cow<'static, str>,
+        pub sequence_number: std::num::Wrapping<u64>,
+        pub value: f64,
+    }
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[derive(Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    #[serde(remote = "Self")]
+    pub enum Event {
+        at { at: Time_t },
+        reason { reason: String },
+    }
+    impl Default for Event {
+        fn default() -> Event {
+            Event::at {
+                at: Default::default(),
+            }
+        }
+    }
+    impl serde::Serialize for Event {
+        fn serialize<S: serde::Serializer>(
+            &self,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            if serializer.is_human_readable() {
+                Event::serialize(self, serializer)
+            } else {
+                use serde::ser::SerializeTuple;
+                let mut tuple = serializer.serialize_tuple(2)?;
+                match self {
+                    Event::at { at: value } => {
+                        tuple.serialize_element(&1i32)?;
+                        tuple.serialize_element(value)?;
+                    }
+                    Event::reason { reason: value } => {
+                        tuple.serialize_element(&2i32)?;
+                        tuple.serialize_element(value)?;
+                    }
+                }
+                tuple.end()
+            }
+        }
+    }
+    impl<'de> serde::Deserialize<'de> for Event {
+        fn deserialize<D: serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Event, D::Error> {
+            let value = if deserializer.is_human_readable() {
+                Event::deserialize(deserializer)
+            } else {
+                struct Discriminated;
+                impl<'de> serde::de::Visitor<'de> for Discriminated {
+                    type Value = Event;
+                    fn expecting(
+                        &self,
+                        f: &mut ::std::fmt::Formatter,
+                    ) -> ::std::fmt::Result {
+                        f.write_str("union TypeMap::Event")
+                    }
+                    fn visit_seq<A: serde::de::SeqAccess<'de>>(
+                        self,
+                        mut seq: A,
+                    ) -> Result<Event, A::Error> {
+                        let label = seq
+                            .next_element::<i32>()?
+                            .ok_or_else(|| serde::de::Error::invalid_length(0, &self))?;
+                        Ok(
+                            match label {
+                                1i32 => {
+                                    Event::at {
+                                        at: seq
+                                            .next_element()?
+                                            .ok_or_else(|| serde::de::Error::invalid_length(1, &self))?,
+                                    }
+                                }
+                                2i32 => {
+                                    Event::reason {
+                                        reason: seq
+                                            .next_element()?
+                                            .ok_or_else(|| serde::de::Error::invalid_length(1, &self))?,
+                                    }
+                                }
+                                label => {
+                                    return Err(
+                                        serde::de::Error::custom(
+                                            ::std::format_args!(
+                                                "invalid discriminator {} of {}", label,
+                                                "union TypeMap::Event"
+                                            ),
+                                        ),
+                                    );
+                                }
+                            },
+                        )
+                    }
+                }
+                deserializer.deserialize_tuple(2, Discriminated)
+            }?;
+            Ok(value)
+        }
+    }
+    #[allow(dead_code)]
+    #[allow(non_upper_case_globals)]
+    pub const MAX_SEQUENCE_NUMBER: u64 = 1000;
+}
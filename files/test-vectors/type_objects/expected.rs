@@ -0,0 +1,443 @@
+#[allow(unused_imports)]
+use std::vec::Vec;
+#[allow(unused_imports)]
+use serde_derive::{Serialize, Deserialize};
+#[allow(non_snake_case)]
+pub mod Shapes {
+    #[allow(unused_imports)]
+    use serde_derive::{Serialize, Deserialize};
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[derive(Clone, Debug, Default)]
+    #[repr(u32)]
+    pub enum Color {
+        RED = 0,
+        GREEN = 4,
+        #[default]
+        BLUE = 5,
+    }
+    impl Color {
+        /// The value of the enumerator
+        pub const fn value(&self) -> u32 {
+            match self {
+                Color::RED => 0,
+                Color::GREEN => 4,
+                Color::BLUE => 5,
+            }
+        }
+        /// The IDL name of the enumerator
+        pub const fn as_str(&self) -> &'static str {
+            match self {
+                Color::RED => "RED",
+                Color::GREEN => "GREEN",
+                Color::BLUE => "BLUE",
+            }
+        }
+    }
+    impl ::std::convert::TryFrom<u32> for Color {
+        type Error = rtps_types::EnumError;
+        fn try_from(value: u32) -> Result<Color, rtps_types::EnumError> {
+            match value {
+                0 => Ok(Color::RED),
+                4 => Ok(Color::GREEN),
+                5 => Ok(Color::BLUE),
+                _ => Err(rtps_types::EnumError::Value(value)),
+            }
+        }
+    }
+    impl From<Color> for u32 {
+        fn from(value: Color) -> u32 {
+            value.value()
+        }
+    }
+    impl ::std::fmt::Display for Color {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            f.write_str(self.as_str())
+        }
+    }
+    impl ::std::str::FromStr for Color {
+        type Err = rtps_types::EnumError;
+        fn from_str(name: &str) -> Result<Color, rtps_types::EnumError> {
+            match name {
+                "RED" => Ok(Color::RED),
+                "GREEN" => Ok(Color::GREEN),
+                "BLUE" => Ok(Color::BLUE),
+                _ => Err(rtps_types::EnumError::Name(name.to_owned())),
+            }
+        }
+    }
+    impl serde::Serialize for Color {
+        fn serialize<S: serde::Serializer>(
+            &self,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            serializer.serialize_u32(self.value())
+        }
+    }
+    impl<'de> serde::Deserialize<'de> for Color {
+        fn deserialize<D: serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Color, D::Error> {
+            let value = <u32 as serde::Deserialize>::deserialize(deserializer)?;
+            <Color as ::std::convert::TryFrom<u32>>::try_from(value)
+                .map_err(serde::de::Error::custom)
+        }
+    }
+    impl rtps_types::Discoverable for Color {
+        const TYPE_OBJECTS: rtps_types::TypeObjects = rtps_types::TypeObjects {
+            minimal: rtps_types::TypeObject {
+                hash: *b"\xD6\x90\xF8\xDA\xFE;4\x94\x91\xFC#\xB0\xA3\xFE",
+                bytes: b"B\0\0\0\xF1@\0\0\x02\0\0\0 \0\0\x002\0\0\0\x03\0\0\0\n\0\0\0\0\0\0\0\0\0\xA2\xD9T{\0\0\n\0\0\0\x04\0\0\0\0\0\x9D\xE0\xE5\xDD\0\0\n\0\0\0\x05\0\0\0@\0\x1B>\x1E\xE9",
+            },
+            complete: rtps_types::TypeObject {
+                hash: *b"R\x8Ca\t\xFB\xC7\xE4f\xB5\x90h\xC2NK",
+                bytes: b"o\0\0\0\xF2@\0\0\x16\0\0\0 \0\0\0\x0E\0\0\0Shapes::Color\0\0\0K\0\0\0\x03\0\0\0\x12\0\0\0\0\0\0\0\0\0\0\0\x04\0\0\0RED\0\0\0\0\0\x14\0\0\0\x04\0\0\0\0\0\0\0\x06\0\0\0GREEN\0\0\0\x13\0\0\0\x05\0\0\0@\0\0\0\x05\0\0\0BLUE\0\0\0",
+            },
+        };
+        const DEPENDENCIES: &'static [rtps_types::TypeObjects] = &[];
+    }
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    pub type Name = rtps_types::BoundedString<32>;
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[derive(Serialize, Deserialize)]
+    #[derive(Clone, Debug)]
+    #[serde(remote = "Self")]
+    pub enum Fill {
+        #[serde(alias = "GREEN")]
+        RED { level: u8 },
+        default {
+            #[serde(with = "rtps_types::xtypes::delimited")]
+            pattern: Vec<Geo::Point>,
+        },
+    }
+    impl Default for Fill {
+        fn default() -> Fill {
+            Fill::default {
+                pattern: Default::default(),
+            }
+        }
+    }
+    impl serde::Serialize for Fill {
+        fn serialize<S: serde::Serializer>(
+            &self,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            if serializer.is_human_readable() {
+                Fill::serialize(self, serializer)
+            } else {
+                use serde::ser::SerializeTuple;
+                let mut tuple = serializer.serialize_tuple(2)?;
+                match self {
+                    Fill::RED { level: value } => {
+                        tuple.serialize_element(&0u32)?;
+                        tuple.serialize_element(value)?;
+                    }
+                    Fill::default { pattern: value } => {
+                        tuple.serialize_element(&5u32)?;
+                        tuple.serialize_element(&rtps_types::xtypes::Delimited(value))?;
+                    }
+                }
+                tuple.end()
+            }
+        }
+    }
+    impl<'de> serde::Deserialize<'de> for Fill {
+        fn deserialize<D: serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Fill, D::Error> {
+            let value = if deserializer.is_human_readable() {
+                Fill::deserialize(deserializer)
+            } else {
+                struct Discriminated;
+                impl<'de> serde::de::Visitor<'de> for Discriminated {
+                    type Value = Fill;
+                    fn expecting(
+                        &self,
+                        f: &mut ::std::fmt::Formatter,
+                    ) -> ::std::fmt::Result {
+                        f.write_str("union Shapes::Fill")
+                    }
+                    fn visit_seq<A: serde::de::SeqAccess<'de>>(
+                        self,
+                        mut seq: A,
+                    ) -> Result<Fill, A::Error> {
+                        let label = seq
+                            .next_element::<u32>()?
+                            .ok_or_else(|| serde::de::Error::invalid_length(0, &self))?;
+                        Ok(
+                            match label {
+                                0u32 | 4u32 => {
+                                    Fill::RED {
+                                        level: seq
+                                            .next_element()?
+                                            .ok_or_else(|| serde::de::Error::invalid_length(1, &self))?,
+                                    }
+                                }
+                                _ => {
+                                    Fill::default {
+                                        pattern: seq
+                                            .next_element::<rtps_types::xtypes::DelimitedValue<_>>()?
+                                            .ok_or_else(|| serde::de::Error::invalid_length(1, &self))?
+                                            .0,
+                                    }
+                                }
+                            },
+                        )
+                    }
+                }
+                deserializer.deserialize_tuple(2, Discriminated)
+            }?;
+            Ok(value)
+        }
+    }
+    impl rtps_types::Discoverable for Fill {
+        const TYPE_OBJECTS: rtps_types::TypeObjects = rtps_types::TypeObjects {
+            minimal: rtps_types::TypeObject {
+                hash: *b"\x8D\x14a,N\xF7\xC8q\xADj*Z\xFDa",
+                bytes: b"l\0\0\0\xF1R\x01\0\0\0\0\0\x11\0\0\0\x01\0\xF1\xD6\x90\xF8\xDA\xFE;4\x94\x91\xFC#\xB0\xA3\xFE\0\0\0H\0\0\0\x02\0\0\0\x18\0\0\0\x01\0\0\0\x01\0\x02\0\x02\0\0\0\0\0\0\0\x04\0\0\0\xC9\xE9\xA8H$\0\0\0\x02\0\0\0A\0\x80\xF1\x01\0\0\xF1\x11\xFD\xA2\xA3\x97\xC6MfVMvh\xB9}\0\0\0\0\0\0$\x0B\xF0\"",
+            },
+            complete: rtps_types::TypeObject {
+                hash: *b"\xB2\xCE\xA6\xA0\xD9\x8A\x0E;\xAB\xB3$O\xD2\xEA",
+                bytes: b"\x96\0\0\0\xF2R\x01\0\x15\0\0\0\0\0\0\0\r\0\0\0Shapes::Fill\0\0\0\0\x13\0\0\0\x01\0\xF2R\x8Ca\t\xFB\xC7\xE4f\xB5\x90h\xC2NK\0\0\0Z\0\0\0\x02\0\0\0 \0\0\0\x01\0\0\0\x01\0\x02\0\x02\0\0\0\0\0\0\0\x04\0\0\0\x06\0\0\0level\0\0\0.\0\0\0\x02\0\0\0A\0\x80\xF2\x01\0\0\xF2\xFDa\x99\xEA\x05m\xE6\x7F&Zz5q\x13\0\0\0\0\0\0\x08\0\0\0pattern\0\0\0",
+            },
+        };
+        const DEPENDENCIES: &'static [rtps_types::TypeObjects] = &[
+            rtps_types::TypeObjects {
+                minimal: rtps_types::TypeObject {
+                    hash: *b"\xD6\x90\xF8\xDA\xFE;4\x94\x91\xFC#\xB0\xA3\xFE",
+                    bytes: b"B\0\0\0\xF1@\0\0\x02\0\0\0 \0\0\x002\0\0\0\x03\0\0\0\n\0\0\0\0\0\0\0\0\0\xA2\xD9T{\0\0\n\0\0\0\x04\0\0\0\0\0\x9D\xE0\xE5\xDD\0\0\n\0\0\0\x05\0\0\0@\0\x1B>\x1E\xE9",
+                },
+                complete: rtps_types::TypeObject {
+                    hash: *b"R\x8Ca\t\xFB\xC7\xE4f\xB5\x90h\xC2NK",
+                    bytes: b"o\0\0\0\xF2@\0\0\x16\0\0\0 \0\0\0\x0E\0\0\0Shapes::Color\0\0\0K\0\0\0\x03\0\0\0\x12\0\0\0\0\0\0\0\0\0\0\0\x04\0\0\0RED\0\0\0\0\0\x14\0\0\0\x04\0\0\0\0\0\0\0\x06\0\0\0GREEN\0\0\0\x13\0\0\0\x05\0\0\0@\0\0\0\x05\0\0\0BLUE\0\0\0",
+                },
+            },
+            rtps_types::TypeObjects {
+                minimal: rtps_types::TypeObject {
+                    hash: *b"\x11\xFD\xA2\xA3\x97\xC6MfVMvh\xB9}",
+                    bytes: b"3\0\0\0\xF1Q\n\0\x01\0\0\0\0\0\0\0#\0\0\0\x02\0\0\0\x0B\0\0\0\0\0\0\0\x01\0\x04\x9D\xD4\xE4a\0\x0B\0\0\0\x01\0\0\0\x01\0\x04AR\x90v",
+                },
+                complete: rtps_types::TypeObject {
+                    hash: *b"\xFDa\x99\xEA\x05m\xE6\x7F&Zz5q\x13",
+                    bytes: b"T\0\0\0\xF2Q\n\0\x1B\0\0\0\0\0\0\0\x13\0\0\0Shapes::Geo::Point\0\0,\0\0\0\x02\0\0\0\x10\0\0\0\0\0\0\0\x01\0\x04\0\x02\0\0\0x\0\0\0\x10\0\0\0\x01\0\0\0\x01\0\x04\0\x02\0\0\0y\0\0\0",
+                },
+            },
+        ];
+    }
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[derive(Serialize, Deserialize)]
+    #[derive(Clone, Debug, Default)]
+    #[serde(remote = "Self")]
+    pub struct Shape {
+        pub name: Name,
+        pub color: Color,
+        pub origin: Geo::Point,
+        #[serde(default)]
+        #[serde(
+            serialize_with = "rtps_types::xtypes::optional::serialize::<3, _, _>",
+            deserialize_with = "rtps_types::xtypes::optional::deserialize"
+        )]
+        pub fill: Option<Fill>,
+        pub scale: [[f64; 3]; 2],
+        #[serde(with = "rtps_types::xtypes::delimited")]
+        pub tags: rtps_types::BoundedVec<String, 300>,
+    }
+    impl serde::Serialize for Shape {
+        fn serialize<S: serde::Serializer>(
+            &self,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            if serializer.is_human_readable() {
+                Shape::serialize(self, serializer)
+            } else {
+                serializer
+                    .serialize_newtype_struct(
+                        rtps_types::xtypes::MUTABLE,
+                        &rtps_types::xtypes::Members(self),
+                    )
+            }
+        }
+    }
+    impl<'de> serde::Deserialize<'de> for Shape {
+        fn deserialize<D: serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Shape, D::Error> {
+            let value = if deserializer.is_human_readable() {
+                Shape::deserialize(deserializer)?
+            } else {
+                deserializer
+                    .deserialize_newtype_struct(
+                        rtps_types::xtypes::MUTABLE,
+                        rtps_types::xtypes::MembersVisitor::<Shape>::default(),
+                    )?
+            };
+            Ok(value)
+        }
+    }
+    impl rtps_types::xtypes::SerializeMembers for Shape {
+        fn member_count(&self) -> usize {
+            5 + usize::from(self.fill.is_some())
+        }
+        fn serialize_members<M: serde::ser::SerializeMap>(
+            &self,
+            map: &mut M,
+        ) -> Result<(), M::Error> {
+            map.serialize_entry(&rtps_types::xtypes::MUST_UNDERSTAND, &self.name)?;
+            map.serialize_entry(&1u32, &self.color)?;
+            map.serialize_entry(&2u32, &self.origin)?;
+            if let Some(value) = &self.fill {
+                map.serialize_entry(&3u32, value)?;
+            }
+            map.serialize_entry(&4u32, &self.scale)?;
+            map.serialize_entry(&5u32, &rtps_types::xtypes::Delimited(&self.tags))?;
+            Ok(())
+        }
+    }
+    impl<'de> rtps_types::xtypes::DeserializeMembers<'de> for Shape {
+        fn deserialize_members<A: serde::de::MapAccess<'de>>(
+            mut map: A,
+        ) -> Result<Shape, A::Error> {
+            let mut value = Shape::default();
+            while let Some(header) = map.next_key::<u32>()? {
+                match header & rtps_types::xtypes::MEMBER_ID_MASK {
+                    0 => value.name = map.next_value()?,
+                    1 => value.color = map.next_value()?,
+                    2 => value.origin = map.next_value()?,
+                    3 => value.fill = Some(map.next_value()?),
+                    4 => value.scale = map.next_value()?,
+                    5 => {
+                        value.tags = map
+                            .next_value::<rtps_types::xtypes::DelimitedValue<_>>()?
+                            .0;
+                    }
+                    _ => rtps_types::xtypes::skip_member(&mut map, header)?,
+                }
+            }
+            Ok(value)
+        }
+    }
+    impl rtps_types::Discoverable for Shape {
+        const TYPE_OBJECTS: rtps_types::TypeObjects = rtps_types::TypeObjects {
+            minimal: rtps_types::TypeObject {
+                hash: *b"zmOAUG-\xF7\x06:(`j\xA8",
+                bytes: b"\xCA\0\0\0\xF1Q\x04\0\x01\0\0\0\0\0\0\0\xBA\0\0\0\x06\0\0\0\x19\0\0\0\0\0\0\x001\0\xF1\xF9C\xF8x\xD8\xCFd|\x99\x93hY\xCBJ\xB0h\x93\x1C\0\0\0\x19\0\0\0\x01\0\0\0\x01\0\xF1\xD6\x90\xF8\xDA\xFE;4\x94\x91\xFC#\xB0\xA3\xFEp\xDD\xA5\xDF\0\0\0\x19\0\0\0\x02\0\0\0\x01\0\xF1\x11\xFD\xA2\xA3\x97\xC6MfVMvh\xB9}|I\xB1S\0\0\0\x19\0\0\0\x03\0\0\0\t\0\xF1\x8D\x14a,N\xF7\xC8q\xADj*Z\xFDa\xA5R\xC7G\0\0\0\x17\0\0\0\x04\0\0\0\x01\0\x90\xF3\x01\0\0\0\x02\0\0\0\x02\x03\n\x0C\xB4z\xEB\0\x16\0\0\0\x05\0\0\0\x01\0\x81\xF3\x01\0\0\0,\x01\0\0p\0\xD5z\xC4R",
+            },
+            complete: rtps_types::TypeObject {
+                hash: *b"\xB7\xED+gJ9\x07O{\xEF\xEAS5\x81",
+                bytes: b"\x13\x01\0\0\xF2Q\x04\0\x16\0\0\0\0\0\0\0\x0E\0\0\0Shapes::Shape\0\0\0\xEF\0\0\0\x06\0\0\0#\0\0\0\0\0\0\x001\0\xF2>4{\xB2 rY-d\xC8caL\x99\0\0\0\x05\0\0\0name\0\0\0\0$\0\0\0\x01\0\0\0\x01\0\xF2R\x8Ca\t\xFB\xC7\xE4f\xB5\x90h\xC2NK\0\0\0\x06\0\0\0color\0\0\0%\0\0\0\x02\0\0\0\x01\0\xF2\xFDa\x99\xEA\x05m\xE6\x7F&Zz5q\x13\0\0\0\x07\0\0\0origin\0\0\0\0\0\0#\0\0\0\x03\0\0\0\t\0\xF2\xB2\xCE\xA6\xA0\xD9\x8A\x0E;\xAB\xB3$O\xD2\xEA\0\0\0\x05\0\0\0fill\0\0\0\0 \0\0\0\x04\0\0\0\x01\0\x90\xF3\x01\0\0\0\x02\0\0\0\x02\x03\n\0\x06\0\0\0scale\0\0\0\x1F\0\0\0\x05\0\0\0\x01\0\x81\xF3\x01\0\0\0,\x01\0\0p\0\0\0\x05\0\0\0tags\0\0\0",
+            },
+        };
+        const DEPENDENCIES: &'static [rtps_types::TypeObjects] = &[
+            rtps_types::TypeObjects {
+                minimal: rtps_types::TypeObject {
+                    hash: *b"\xF9C\xF8x\xD8\xCFd|\x99\x93hY\xCBJ",
+                    bytes: b"\x10\0\0\0\xF10\0\0\0\0\0\0\x04\0\0\0\0\0p ",
+                },
+                complete: rtps_types::TypeObject {
+                    hash: *b">4{\xB2 rY-d\xC8caL\x99",
+                    bytes: b"*\0\0\0\xF20\0\0\x15\0\0\0\0\0\0\0\r\0\0\0Shapes::Name\0\0\0\0\x06\0\0\0\0\0p \0\0",
+                },
+            },
+            rtps_types::TypeObjects {
+                minimal: rtps_types::TypeObject {
+                    hash: *b"\xD6\x90\xF8\xDA\xFE;4\x94\x91\xFC#\xB0\xA3\xFE",
+                    bytes: b"B\0\0\0\xF1@\0\0\x02\0\0\0 \0\0\x002\0\0\0\x03\0\0\0\n\0\0\0\0\0\0\0\0\0\xA2\xD9T{\0\0\n\0\0\0\x04\0\0\0\0\0\x9D\xE0\xE5\xDD\0\0\n\0\0\0\x05\0\0\0@\0\x1B>\x1E\xE9",
+                },
+                complete: rtps_types::TypeObject {
+                    hash: *b"R\x8Ca\t\xFB\xC7\xE4f\xB5\x90h\xC2NK",
+                    bytes: b"o\0\0\0\xF2@\0\0\x16\0\0\0 \0\0\0\x0E\0\0\0Shapes::Color\0\0\0K\0\0\0\x03\0\0\0\x12\0\0\0\0\0\0\0\0\0\0\0\x04\0\0\0RED\0\0\0\0\0\x14\0\0\0\x04\0\0\0\0\0\0\0\x06\0\0\0GREEN\0\0\0\x13\0\0\0\x05\0\0\0@\0\0\0\x05\0\0\0BLUE\0\0\0",
+                },
+            },
+            rtps_types::TypeObjects {
+                minimal: rtps_types::TypeObject {
+                    hash: *b"\x11\xFD\xA2\xA3\x97\xC6MfVMvh\xB9}",
+                    bytes: b"3\0\0\0\xF1Q\n\0\x01\0\0\0\0\0\0\0#\0\0\0\x02\0\0\0\x0B\0\0\0\0\0\0\0\x01\0\x04\x9D\xD4\xE4a\0\x0B\0\0\0\x01\0\0\0\x01\0\x04AR\x90v",
+                },
+                complete: rtps_types::TypeObject {
+                    hash: *b"\xFDa\x99\xEA\x05m\xE6\x7F&Zz5q\x13",
+                    bytes: b"T\0\0\0\xF2Q\n\0\x1B\0\0\0\0\0\0\0\x13\0\0\0Shapes::Geo::Point\0\0,\0\0\0\x02\0\0\0\x10\0\0\0\0\0\0\0\x01\0\x04\0\x02\0\0\0x\0\0\0\x10\0\0\0\x01\0\0\0\x01\0\x04\0\x02\0\0\0y\0\0\0",
+                },
+            },
+            rtps_types::TypeObjects {
+                minimal: rtps_types::TypeObject {
+                    hash: *b"\x8D\x14a,N\xF7\xC8q\xADj*Z\xFDa",
+                    bytes: b"l\0\0\0\xF1R\x01\0\0\0\0\0\x11\0\0\0\x01\0\xF1\xD6\x90\xF8\xDA\xFE;4\x94\x91\xFC#\xB0\xA3\xFE\0\0\0H\0\0\0\x02\0\0\0\x18\0\0\0\x01\0\0\0\x01\0\x02\0\x02\0\0\0\0\0\0\0\x04\0\0\0\xC9\xE9\xA8H$\0\0\0\x02\0\0\0A\0\x80\xF1\x01\0\0\xF1\x11\xFD\xA2\xA3\x97\xC6MfVMvh\xB9}\0\0\0\0\0\0$\x0B\xF0\"",
+                },
+                complete: rtps_types::TypeObject {
+                    hash: *b"\xB2\xCE\xA6\xA0\xD9\x8A\x0E;\xAB\xB3$O\xD2\xEA",
+                    bytes: b"\x96\0\0\0\xF2R\x01\0\x15\0\0\0\0\0\0\0\r\0\0\0Shapes::Fill\0\0\0\0\x13\0\0\0\x01\0\xF2R\x8Ca\t\xFB\xC7\xE4f\xB5\x90h\xC2NK\0\0\0Z\0\0\0\x02\0\0\0 \0\0\0\x01\0\0\0\x01\0\x02\0\x02\0\0\0\0\0\0\0\x04\0\0\0\x06\0\0\0level\0\0\0.\0\0\0\x02\0\0\0A\0\x80\xF2\x01\0\0\xF2\xFDa\x99\xEA\x05m\xE6\x7F&Zz5q\x13\0\0\0\0\0\0\x08\0\0\0pattern\0\0\0",
+                },
+            },
+        ];
+    }
+    #[allow(non_snake_case)]
+    pub mod Geo {
+        #[allow(unused_imports)]
+        use serde_derive::{Serialize, Deserialize};
+        #[allow(dead_code)]
+        #[allow(non_camel_case_types)]
+        #[derive(Serialize, Deserialize)]
+        #[derive(Clone, Debug, Default)]
+        #[serde(remote = "Self")]
+        #[serde(default)]
+        pub struct Point {
+            pub x: i32,
+            pub y: i32,
+        }
+        impl serde::Serialize for Point {
+            fn serialize<S: serde::Serializer>(
+                &self,
+                serializer: S,
+            ) -> Result<S::Ok, S::Error> {
+                serializer
+                    .serialize_newtype_struct(
+                        rtps_types::xtypes::DELIMITED,
+                        &rtps_types::xtypes::Derived(self),
+                    )
+            }
+        }
+        impl<'de> serde::Deserialize<'de> for Point {
+            fn deserialize<D: serde::Deserializer<'de>>(
+                deserializer: D,
+            ) -> Result<Point, D::Error> {
+                let value = deserializer
+                    .deserialize_newtype_struct(
+                        rtps_types::xtypes::DELIMITED,
+                        rtps_types::xtypes::DerivedVisitor::<Point>::default(),
+                    )?;
+                Ok(value)
+            }
+        }
+        impl rtps_types::xtypes::SerializeDerived for Point {
+            fn serialize_derived<S: serde::Serializer>(
+                &self,
+                serializer: S,
+            ) -> Result<S::Ok, S::Error> {
+                Point::serialize(self, serializer)
+            }
+        }
+        impl<'de> rtps_types::xtypes::DeserializeDerived<'de> for Point {
+            fn deserialize_derived<D: serde::Deserializer<'de>>(
+                deserializer: D,
+            ) -> Result<Point, D::Error> {
+                Point::deserialize(deserializer)
+            }
+        }
+        impl rtps_types::Discoverable for Point {
+            const TYPE_OBJECTS: rtps_types::TypeObjects = rtps_types::TypeObjects {
+                minimal: rtps_types::TypeObject {
+                    hash: *b"\x11\xFD\xA2\xA3\x97\xC6MfVMvh\xB9}",
+                    bytes: b"3\0\0\0\xF1Q\n\0\x01\0\0\0\0\0\0\0#\0\0\0\x02\0\0\0\x0B\0\0\0\0\0\0\0\x01\0\x04\x9D\xD4\xE4a\0\x0B\0\0\0\x01\0\0\0\x01\0\x04AR\x90v",
+                },
+                complete: rtps_types::TypeObject {
+                    hash: *b"\xFDa\x99\xEA\x05m\xE6\x7F&Zz5q\x13",
+                    bytes: b"T\0\0\0\xF2Q\n\0\x1B\0\0\0\0\0\0\0\x13\0\0\0Shapes::Geo::Point\0\0,\0\0\0\x02\0\0\0\x10\0\0\0\0\0\0\0\x01\0\x04\0\x02\0\0\0x\0\0\0\x10\0\0\0\x01\0\0\0\x01\0\x04\0\x02\0\0\0y\0\0\0",
+                },
+            };
+            const DEPENDENCIES: &'static [rtps_types::TypeObjects] = &[];
+        }
+    }
+}
@@ -2,9 +2,6 @@
 use std::vec::Vec;
 #[allow(unused_imports)]
 use serde_derive::{Serialize, Deserialize};
-
-//
-//
 #[allow(dead_code)]
 #[allow(non_camel_case_types)]
 pub type Foo = char;
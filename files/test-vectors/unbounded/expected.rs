@@ -0,0 +1,31 @@
+#[allow(unused_imports)]
+use std::vec::Vec;
+#[allow(unused_imports)]
+use serde_derive::{Serialize, Deserialize};
+#[allow(non_snake_case)]
+pub mod Bounded {
+    #[allow(unused_imports)]
+    use serde_derive::{Serialize, Deserialize};
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    pub type Name = String;
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    pub type Payload = Vec<u8>;
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[derive(Serialize, Deserialize)]
+    #[derive(Clone, Debug, Default)]
+    pub struct Sample {
+        pub name: Name,
+        pub description: String,
+        #[serde(with = "rtps_types::xtypes::delimited")]
+        pub aliases: Vec<Name>,
+        #[serde(with = "rtps_types::xtypes::delimited")]
+        pub matrix: Vec<Vec<i32>>,
+        pub payload: Payload,
+    }
+    #[allow(dead_code)]
+    #[allow(non_upper_case_globals)]
+    pub const MAX_NAME: u32 = 16;
+}
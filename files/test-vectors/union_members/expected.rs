@@ -2,19 +2,98 @@
 use std::vec::Vec;
 #[allow(unused_imports)]
 use serde_derive::{Serialize, Deserialize};
-
-//
-//
 #[allow(dead_code)]
 #[allow(non_camel_case_types)]
 #[derive(Serialize, Deserialize)]
 #[derive(Clone, Debug)]
+#[serde(remote = "Self")]
 pub enum Foo {
-    LABEL0{l: i32,},
-    LABEL1{s: i16,},
-    LABEL2{s: i16,},
-    default{o: u8,},
+    l { l: i32 },
+    s { s: i16 },
+    default { o: u8 },
+}
+impl Default for Foo {
+    fn default() -> Foo {
+        Foo::l { l: Default::default() }
+    }
+}
+impl serde::Serialize for Foo {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            Foo::serialize(self, serializer)
+        } else {
+            use serde::ser::SerializeTuple;
+            let mut tuple = serializer.serialize_tuple(2)?;
+            match self {
+                Foo::l { l: value } => {
+                    tuple.serialize_element(&0i32)?;
+                    tuple.serialize_element(value)?;
+                }
+                Foo::s { s: value } => {
+                    tuple.serialize_element(&1i32)?;
+                    tuple.serialize_element(value)?;
+                }
+                Foo::default { o: value } => {
+                    tuple.serialize_element(&3i32)?;
+                    tuple.serialize_element(value)?;
+                }
+            }
+            tuple.end()
+        }
+    }
+}
+impl<'de> serde::Deserialize<'de> for Foo {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Foo, D::Error> {
+        let value = if deserializer.is_human_readable() {
+            Foo::deserialize(deserializer)
+        } else {
+            struct Discriminated;
+            impl<'de> serde::de::Visitor<'de> for Discriminated {
+                type Value = Foo;
+                fn expecting(
+                    &self,
+                    f: &mut ::std::fmt::Formatter,
+                ) -> ::std::fmt::Result {
+                    f.write_str("union Foo")
+                }
+                fn visit_seq<A: serde::de::SeqAccess<'de>>(
+                    self,
+                    mut seq: A,
+                ) -> Result<Foo, A::Error> {
+                    let label = seq
+                        .next_element::<i32>()?
+                        .ok_or_else(|| serde::de::Error::invalid_length(0, &self))?;
+                    Ok(
+                        match label {
+                            0i32 => {
+                                Foo::l {
+                                    l: seq
+                                        .next_element()?
+                                        .ok_or_else(|| serde::de::Error::invalid_length(1, &self))?,
+                                }
+                            }
+                            1i32 | 2i32 => {
+                                Foo::s {
+                                    s: seq
+                                        .next_element()?
+                                        .ok_or_else(|| serde::de::Error::invalid_length(1, &self))?,
+                                }
+                            }
+                            _ => {
+                                Foo::default {
+                                    o: seq
+                                        .next_element()?
+                                        .ok_or_else(|| serde::de::Error::invalid_length(1, &self))?,
+                                }
+                            }
+                        },
+                    )
+                }
+            }
+            deserializer.deserialize_tuple(2, Discriminated)
+        }?;
+        Ok(value)
+    }
 }
-//
-// TODO custom de-/serializer
-//
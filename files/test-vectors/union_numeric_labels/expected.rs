@@ -0,0 +1,113 @@
+#[allow(unused_imports)]
+use std::vec::Vec;
+#[allow(unused_imports)]
+use serde_derive::{Serialize, Deserialize};
+#[allow(dead_code)]
+#[allow(non_camel_case_types)]
+#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug)]
+#[serde(remote = "Self")]
+pub enum Value {
+    lValue { lValue: i32 },
+    dValue { dValue: f64 },
+    code { code: u8 },
+}
+impl Default for Value {
+    fn default() -> Value {
+        Value::lValue {
+            lValue: Default::default(),
+        }
+    }
+}
+impl serde::Serialize for Value {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            Value::serialize(self, serializer)
+        } else {
+            use serde::ser::SerializeTuple;
+            let mut tuple = serializer.serialize_tuple(2)?;
+            match self {
+                Value::lValue { lValue: value } => {
+                    tuple.serialize_element(&1i16)?;
+                    tuple.serialize_element(value)?;
+                }
+                Value::dValue { dValue: value } => {
+                    tuple.serialize_element(&2i16)?;
+                    tuple.serialize_element(value)?;
+                }
+                Value::code { code: value } => {
+                    tuple.serialize_element(&-1i16)?;
+                    tuple.serialize_element(value)?;
+                }
+            }
+            tuple.end()
+        }
+    }
+}
+impl<'de> serde::Deserialize<'de> for Value {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Value, D::Error> {
+        let value = if deserializer.is_human_readable() {
+            Value::deserialize(deserializer)
+        } else {
+            struct Discriminated;
+            impl<'de> serde::de::Visitor<'de> for Discriminated {
+                type Value = Value;
+                fn expecting(
+                    &self,
+                    f: &mut ::std::fmt::Formatter,
+                ) -> ::std::fmt::Result {
+                    f.write_str("union Value")
+                }
+                fn visit_seq<A: serde::de::SeqAccess<'de>>(
+                    self,
+                    mut seq: A,
+                ) -> Result<Value, A::Error> {
+                    let label = seq
+                        .next_element::<i16>()?
+                        .ok_or_else(|| serde::de::Error::invalid_length(0, &self))?;
+                    Ok(
+                        match label {
+                            1i16 => {
+                                Value::lValue {
+                                    lValue: seq
+                                        .next_element()?
+                                        .ok_or_else(|| serde::de::Error::invalid_length(1, &self))?,
+                                }
+                            }
+                            2i16 | 3i16 => {
+                                Value::dValue {
+                                    dValue: seq
+                                        .next_element()?
+                                        .ok_or_else(|| serde::de::Error::invalid_length(1, &self))?,
+                                }
+                            }
+                            -1i16 => {
+                                Value::code {
+                                    code: seq
+                                        .next_element()?
+                                        .ok_or_else(|| serde::de::Error::invalid_length(1, &self))?,
+                                }
+                            }
+                            label => {
+                                return Err(
+                                    serde::de::Error::custom(
+                                        ::std::format_args!(
+                                            "invalid discriminator {} of {}", label, "union Value"
+                                        ),
+                                    ),
+                                );
+                            }
+                        },
+                    )
+                }
+            }
+            deserializer.deserialize_tuple(2, Discriminated)
+        }?;
+        Ok(value)
+    }
+}
+#[allow(dead_code)]
+#[allow(non_upper_case_globals)]
+pub const Mask: i32 = !0x0F & 0o17;
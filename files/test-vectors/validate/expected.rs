@@ -0,0 +1,443 @@
+#[allow(unused_imports)]
+use std::vec::Vec;
+#[allow(unused_imports)]
+use serde_derive::{Serialize, Deserialize};
+#[allow(non_snake_case)]
+pub mod Limits {
+    #[allow(unused_imports)]
+    use serde_derive::{Serialize, Deserialize};
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[derive(Serialize, Deserialize)]
+    #[derive(Clone, Debug, Default)]
+    #[serde(remote = "Self")]
+    pub struct Reading {
+        pub speed: i32,
+        pub ratio: f64,
+        pub grade: char,
+        pub label: String,
+    }
+    impl rtps_types::Validate for Reading {
+        #[allow(clippy::only_used_in_recursion)]
+        fn validate_into(
+            &self,
+            path: &str,
+            violations: &mut Vec<rtps_types::Violation>,
+        ) {
+            {
+                let value = &self.speed;
+                rtps_types::validate::min(value, 0, path, "speed", violations);
+                rtps_types::validate::max(value, MAX_SPEED, path, "speed", violations);
+            }
+            {
+                let value = &self.ratio;
+                rtps_types::validate::min(value, 0.5, path, "ratio", violations);
+            }
+            {
+                let value = &self.grade;
+                rtps_types::validate::max(value, 'z', path, "grade", violations);
+            }
+            {
+                let value = &self.label;
+                rtps_types::validate::bound(value.len(), 8, path, "label", violations);
+            }
+        }
+    }
+    #[allow(dead_code)]
+    impl Reading {
+        /// Verify the constraints declared in IDL, reporting the path of each violating member
+        pub fn validate(&self) -> Result<(), rtps_types::ValidationError> {
+            rtps_types::Validate::validate(self)
+        }
+    }
+    impl serde::Serialize for Reading {
+        fn serialize<S: serde::Serializer>(
+            &self,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            Reading::serialize(self, serializer)
+        }
+    }
+    impl<'de> serde::Deserialize<'de> for Reading {
+        fn deserialize<D: serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Reading, D::Error> {
+            let value = Reading::deserialize(deserializer)?;
+            rtps_types::Validate::validate(&value).map_err(serde::de::Error::custom)?;
+            Ok(value)
+        }
+    }
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[derive(Serialize, Deserialize)]
+    #[derive(Clone, Debug, Default)]
+    #[serde(remote = "Self")]
+    pub struct Sensor {
+        pub id: u32,
+        pub current: Reading,
+        #[serde(with = "rtps_types::xtypes::delimited")]
+        pub history: Vec<Reading>,
+        #[serde(default)]
+        #[serde(
+            serialize_with = "rtps_types::xtypes::optional::serialize::<3, _, _>",
+            deserialize_with = "rtps_types::xtypes::optional::deserialize"
+        )]
+        pub last: Option<Reading>,
+        pub calibration: Box<Reading>,
+        #[serde(default)]
+        #[serde(
+            serialize_with = "rtps_types::xtypes::optional::serialize::<5, _, _>",
+            deserialize_with = "rtps_types::xtypes::optional::deserialize"
+        )]
+        pub level: Option<u8>,
+        #[serde(with = "rtps_types::xtypes::delimited")]
+        pub slots: [Reading; 2],
+    }
+    impl rtps_types::Validate for Sensor {
+        #[allow(clippy::only_used_in_recursion)]
+        fn validate_into(
+            &self,
+            path: &str,
+            violations: &mut Vec<rtps_types::Violation>,
+        ) {
+            {
+                let value = &self.current;
+                rtps_types::Validate::validate_into(
+                    value,
+                    &rtps_types::validate::member(path, "current"),
+                    violations,
+                );
+            }
+            {
+                let value = &self.history;
+                rtps_types::validate::bound(value.len(), 4, path, "history", violations);
+                rtps_types::Validate::validate_into(
+                    value,
+                    &rtps_types::validate::member(path, "history"),
+                    violations,
+                );
+            }
+            if let Some(value) = self.last.as_ref() {
+                rtps_types::Validate::validate_into(
+                    value,
+                    &rtps_types::validate::member(path, "last"),
+                    violations,
+                );
+            }
+            {
+                let value = &*self.calibration;
+                rtps_types::Validate::validate_into(
+                    value,
+                    &rtps_types::validate::member(path, "calibration"),
+                    violations,
+                );
+            }
+            if let Some(value) = self.level.as_ref() {
+                rtps_types::validate::max(value, 10, path, "level", violations);
+            }
+            {
+                let value = &self.slots;
+                rtps_types::Validate::validate_into(
+                    value,
+                    &rtps_types::validate::member(path, "slots"),
+                    violations,
+                );
+            }
+        }
+    }
+    #[allow(dead_code)]
+    impl Sensor {
+        /// Verify the constraints declared in IDL, reporting the path of each violating member
+        pub fn validate(&self) -> Result<(), rtps_types::ValidationError> {
+            rtps_types::Validate::validate(self)
+        }
+    }
+    impl serde::Serialize for Sensor {
+        fn serialize<S: serde::Serializer>(
+            &self,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            Sensor::serialize(self, serializer)
+        }
+    }
+    impl<'de> serde::Deserialize<'de> for Sensor {
+        fn deserialize<D: serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Sensor, D::Error> {
+            let value = Sensor::deserialize(deserializer)?;
+            rtps_types::Validate::validate(&value).map_err(serde::de::Error::custom)?;
+            Ok(value)
+        }
+    }
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[derive(Serialize, Deserialize)]
+    #[derive(Clone, Debug)]
+    #[serde(remote = "Self")]
+    pub enum Sample {
+        digit { digit: i32 },
+        reading { reading: Reading },
+        text { text: String },
+    }
+    impl Default for Sample {
+        fn default() -> Sample {
+            Sample::digit {
+                digit: Default::default(),
+            }
+        }
+    }
+    impl rtps_types::Validate for Sample {
+        #[allow(clippy::only_used_in_recursion)]
+        fn validate_into(
+            &self,
+            path: &str,
+            violations: &mut Vec<rtps_types::Violation>,
+        ) {
+            match self {
+                Sample::digit { digit } => {
+                    let value = digit;
+                    rtps_types::validate::min(value, 1, path, "digit", violations);
+                    rtps_types::validate::max(value, 9, path, "digit", violations);
+                }
+                Sample::reading { reading } => {
+                    let value = reading;
+                    rtps_types::Validate::validate_into(
+                        value,
+                        &rtps_types::validate::member(path, "reading"),
+                        violations,
+                    );
+                }
+                _ => {}
+            }
+        }
+    }
+    #[allow(dead_code)]
+    impl Sample {
+        /// Verify the constraints declared in IDL, reporting the path of each violating member
+        pub fn validate(&self) -> Result<(), rtps_types::ValidationError> {
+            rtps_types::Validate::validate(self)
+        }
+    }
+    impl serde::Serialize for Sample {
+        fn serialize<S: serde::Serializer>(
+            &self,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            if serializer.is_human_readable() {
+                Sample::serialize(self, serializer)
+            } else {
+                use serde::ser::SerializeTuple;
+                let mut tuple = serializer.serialize_tuple(2)?;
+                match self {
+                    Sample::digit { digit: value } => {
+                        tuple.serialize_element(&0i32)?;
+                        tuple.serialize_element(value)?;
+                    }
+                    Sample::reading { reading: value } => {
+                        tuple.serialize_element(&1i32)?;
+                        tuple.serialize_element(value)?;
+                    }
+                    Sample::text { text: value } => {
+                        tuple.serialize_element(&2i32)?;
+                        tuple.serialize_element(value)?;
+                    }
+                }
+                tuple.end()
+            }
+        }
+    }
+    impl<'de> serde::Deserialize<'de> for Sample {
+        fn deserialize<D: serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Sample, D::Error> {
+            let value = if deserializer.is_human_readable() {
+                Sample::deserialize(deserializer)
+            } else {
+                struct Discriminated;
+                impl<'de> serde::de::Visitor<'de> for Discriminated {
+                    type Value = Sample;
+                    fn expecting(
+                        &self,
+                        f: &mut ::std::fmt::Formatter,
+                    ) -> ::std::fmt::Result {
+                        f.write_str("union Limits::Sample")
+                    }
+                    fn visit_seq<A: serde::de::SeqAccess<'de>>(
+                        self,
+                        mut seq: A,
+                    ) -> Result<Sample, A::Error> {
+                        let label = seq
+                            .next_element::<i32>()?
+                            .ok_or_else(|| serde::de::Error::invalid_length(0, &self))?;
+                        Ok(
+                            match label {
+                                0i32 => {
+                                    Sample::digit {
+                                        digit: seq
+                                            .next_element()?
+                                            .ok_or_else(|| serde::de::Error::invalid_length(1, &self))?,
+                                    }
+                                }
+                                1i32 => {
+                                    Sample::reading {
+                                        reading: seq
+                                            .next_element()?
+                                            .ok_or_else(|| serde::de::Error::invalid_length(1, &self))?,
+                                    }
+                                }
+                                2i32 => {
+                                    Sample::text {
+                                        text: seq
+                                            .next_element()?
+                                            .ok_or_else(|| serde::de::Error::invalid_length(1, &self))?,
+                                    }
+                                }
+                                label => {
+                                    return Err(
+                                        serde::de::Error::custom(
+                                            ::std::format_args!(
+                                                "invalid discriminator {} of {}", label,
+                                                "union Limits::Sample"
+                                            ),
+                                        ),
+                                    );
+                                }
+                            },
+                        )
+                    }
+                }
+                deserializer.deserialize_tuple(2, Discriminated)
+            }?;
+            rtps_types::Validate::validate(&value).map_err(serde::de::Error::custom)?;
+            Ok(value)
+        }
+    }
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[derive(Serialize, Deserialize)]
+    #[derive(Clone, Debug)]
+    #[serde(remote = "Self")]
+    pub enum Pair {
+        low { low: i32 },
+        high { high: i32 },
+    }
+    impl Default for Pair {
+        fn default() -> Pair {
+            Pair::low {
+                low: Default::default(),
+            }
+        }
+    }
+    impl rtps_types::Validate for Pair {
+        #[allow(clippy::only_used_in_recursion)]
+        fn validate_into(
+            &self,
+            path: &str,
+            violations: &mut Vec<rtps_types::Violation>,
+        ) {
+            match self {
+                Pair::low { low } => {
+                    let value = low;
+                    rtps_types::validate::min(value, 1, path, "low", violations);
+                }
+                Pair::high { high } => {
+                    let value = high;
+                    rtps_types::validate::max(value, 1000, path, "high", violations);
+                }
+            }
+        }
+    }
+    #[allow(dead_code)]
+    impl Pair {
+        /// Verify the constraints declared in IDL, reporting the path of each violating member
+        pub fn validate(&self) -> Result<(), rtps_types::ValidationError> {
+            rtps_types::Validate::validate(self)
+        }
+    }
+    impl serde::Serialize for Pair {
+        fn serialize<S: serde::Serializer>(
+            &self,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            if serializer.is_human_readable() {
+                Pair::serialize(self, serializer)
+            } else {
+                use serde::ser::SerializeTuple;
+                let mut tuple = serializer.serialize_tuple(2)?;
+                match self {
+                    Pair::low { low: value } => {
+                        tuple.serialize_element(&0i16)?;
+                        tuple.serialize_element(value)?;
+                    }
+                    Pair::high { high: value } => {
+                        tuple.serialize_element(&1i16)?;
+                        tuple.serialize_element(value)?;
+                    }
+                }
+                tuple.end()
+            }
+        }
+    }
+    impl<'de> serde::Deserialize<'de> for Pair {
+        fn deserialize<D: serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Pair, D::Error> {
+            let value = if deserializer.is_human_readable() {
+                Pair::deserialize(deserializer)
+            } else {
+                struct Discriminated;
+                impl<'de> serde::de::Visitor<'de> for Discriminated {
+                    type Value = Pair;
+                    fn expecting(
+                        &self,
+                        f: &mut ::std::fmt::Formatter,
+                    ) -> ::std::fmt::Result {
+                        f.write_str("union Limits::Pair")
+                    }
+                    fn visit_seq<A: serde::de::SeqAccess<'de>>(
+                        self,
+                        mut seq: A,
+                    ) -> Result<Pair, A::Error> {
+                        let label = seq
+                            .next_element::<i16>()?
+                            .ok_or_else(|| serde::de::Error::invalid_length(0, &self))?;
+                        Ok(
+                            match label {
+                                0i16 => {
+                                    Pair::low {
+                                        low: seq
+                                            .next_element()?
+                                            .ok_or_else(|| serde::de::Error::invalid_length(1, &self))?,
+                                    }
+                                }
+                                1i16 => {
+                                    Pair::high {
+                                        high: seq
+                                            .next_element()?
+                                            .ok_or_else(|| serde::de::Error::invalid_length(1, &self))?,
+                                    }
+                                }
+                                label => {
+                                    return Err(
+                                        serde::de::Error::custom(
+                                            ::std::format_args!(
+                                                "invalid discriminator {} of {}", label,
+                                                "union Limits::Pair"
+                                            ),
+                                        ),
+                                    );
+                                }
+                            },
+                        )
+                    }
+                }
+                deserializer.deserialize_tuple(2, Discriminated)
+            }?;
+            rtps_types::Validate::validate(&value).map_err(serde::de::Error::custom)?;
+            Ok(value)
+        }
+    }
+    #[allow(dead_code)]
+    #[allow(non_upper_case_globals)]
+    pub const MAX_SPEED: i32 = 300;
+}
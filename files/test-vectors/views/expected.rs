@@ -0,0 +1,566 @@
+#[allow(unused_imports)]
+use std::vec::Vec;
+#[allow(unused_imports)]
+use serde_derive::{Serialize, Deserialize};
+#[allow(non_snake_case)]
+pub mod Views {
+    #[allow(unused_imports)]
+    use serde_derive::{Serialize, Deserialize};
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[derive(Clone, Debug, PartialEq, Default)]
+    #[repr(u32)]
+    pub enum Color {
+        #[default]
+        RED = 0,
+        GREEN = 1,
+        BLUE = 2,
+    }
+    impl Color {
+        /// The value of the enumerator
+        pub const fn value(&self) -> u32 {
+            match self {
+                Color::RED => 0,
+                Color::GREEN => 1,
+                Color::BLUE => 2,
+            }
+        }
+        /// The IDL name of the enumerator
+        pub const fn as_str(&self) -> &'static str {
+            match self {
+                Color::RED => "RED",
+                Color::GREEN => "GREEN",
+                Color::BLUE => "BLUE",
+            }
+        }
+    }
+    impl ::std::convert::TryFrom<u32> for Color {
+        type Error = rtps_types::EnumError;
+        fn try_from(value: u32) -> Result<Color, rtps_types::EnumError> {
+            match value {
+                0 => Ok(Color::RED),
+                1 => Ok(Color::GREEN),
+                2 => Ok(Color::BLUE),
+                _ => Err(rtps_types::EnumError::Value(value)),
+            }
+        }
+    }
+    impl From<Color> for u32 {
+        fn from(value: Color) -> u32 {
+            value.value()
+        }
+    }
+    impl ::std::fmt::Display for Color {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            f.write_str(self.as_str())
+        }
+    }
+    impl ::std::str::FromStr for Color {
+        type Err = rtps_types::EnumError;
+        fn from_str(name: &str) -> Result<Color, rtps_types::EnumError> {
+            match name {
+                "RED" => Ok(Color::RED),
+                "GREEN" => Ok(Color::GREEN),
+                "BLUE" => Ok(Color::BLUE),
+                _ => Err(rtps_types::EnumError::Name(name.to_owned())),
+            }
+        }
+    }
+    impl serde::Serialize for Color {
+        fn serialize<S: serde::Serializer>(
+            &self,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            serializer.serialize_u32(self.value())
+        }
+    }
+    impl<'de> serde::Deserialize<'de> for Color {
+        fn deserialize<D: serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Color, D::Error> {
+            let value = <u32 as serde::Deserialize>::deserialize(deserializer)?;
+            <Color as ::std::convert::TryFrom<u32>>::try_from(value)
+                .map_err(serde::de::Error::custom)
+        }
+    }
+    impl rtps_cdr::CdrEncode for Color {
+        fn encode(&self, ser: &mut rtps_cdr::Serializer<'_>) -> rtps_cdr::Result<()> {
+            rtps_cdr::CdrEncode::encode(&self.value(), ser)
+        }
+    }
+    impl rtps_cdr::CdrDecode for Color {
+        fn decode(de: &mut rtps_cdr::Deserializer<'_>) -> rtps_cdr::Result<Color> {
+            let value = <u32 as rtps_cdr::CdrDecode>::decode(de)?;
+            Ok(<Color as ::std::convert::TryFrom<u32>>::try_from(value)?)
+        }
+    }
+    impl rtps_cdr::SerializedSize for Color {
+        const MAX_SIZE_XCDR1: Option<usize> = Some(4);
+        const MIN_SIZE_XCDR1: usize = 4;
+        const MAX_SIZE_XCDR2: Option<usize> = Some(4);
+        const MIN_SIZE_XCDR2: usize = 4;
+    }
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[derive(Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Default)]
+    pub struct Point {
+        pub x: i32,
+        pub y: i32,
+    }
+    impl rtps_types::xtypes::Extensible for Point {
+        const EXTENSIBILITY: rtps_types::xtypes::Extensibility = rtps_types::xtypes::Extensibility::Final;
+    }
+    impl rtps_cdr::CdrEncode for Point {
+        fn encode(&self, ser: &mut rtps_cdr::Serializer<'_>) -> rtps_cdr::Result<()> {
+            rtps_cdr::CdrEncode::encode(&self.x, ser)?;
+            rtps_cdr::CdrEncode::encode(&self.y, ser)?;
+            Ok(())
+        }
+    }
+    impl rtps_cdr::CdrDecode for Point {
+        fn decode(de: &mut rtps_cdr::Deserializer<'_>) -> rtps_cdr::Result<Point> {
+            Ok(Point {
+                x: rtps_cdr::CdrDecode::decode(de)?,
+                y: rtps_cdr::CdrDecode::decode(de)?,
+            })
+        }
+    }
+    impl rtps_cdr::SerializedSize for Point {
+        const MAX_SIZE_XCDR1: Option<usize> = Some(8);
+        const MIN_SIZE_XCDR1: usize = 8;
+        const MAX_SIZE_XCDR2: Option<usize> = Some(8);
+        const MIN_SIZE_XCDR2: usize = 8;
+    }
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[derive(Clone, Debug)]
+    pub struct PointView<'a> {
+        members: rtps_cdr::ViewMembers<'a, 2>,
+    }
+    #[allow(dead_code)]
+    impl<'a> PointView<'a> {
+        /// View the XCDR1 encoding in the byte order, the members being decoded when accessed
+        pub fn new(
+            bytes: &'a [u8],
+            endianness: rtps_cdr::Endianness,
+        ) -> rtps_cdr::Result<PointView<'a>> {
+            rtps_cdr::CdrView::view(&mut rtps_cdr::Deserializer::new(bytes, endianness))
+        }
+        pub fn x(&self) -> rtps_cdr::Result<i32> {
+            <i32 as rtps_cdr::CdrDecode>::decode(&mut self.members.member(0))
+        }
+        pub fn y(&self) -> rtps_cdr::Result<i32> {
+            <i32 as rtps_cdr::CdrDecode>::decode(&mut self.members.member(1))
+        }
+        /// Decode the struct viewed
+        pub fn to_owned(&self) -> rtps_cdr::Result<Point> {
+            rtps_cdr::CdrDecode::decode(&mut self.members.start())
+        }
+    }
+    impl<'a> rtps_cdr::CdrView<'a> for PointView<'a> {
+        fn view(de: &mut rtps_cdr::Deserializer<'a>) -> rtps_cdr::Result<PointView<'a>> {
+            let mut members = rtps_cdr::ViewMembers::begin(de)?;
+            members.skip_decoded::<i32>(de)?;
+            members.skip_decoded::<i32>(de)?;
+            Ok(PointView { members })
+        }
+    }
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    pub type Name = String;
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[derive(Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Default)]
+    #[serde(remote = "Self")]
+    #[serde(default)]
+    pub struct Label {
+        pub text: Name,
+        pub color: Color,
+    }
+    impl rtps_types::xtypes::Extensible for Label {
+        const EXTENSIBILITY: rtps_types::xtypes::Extensibility = rtps_types::xtypes::Extensibility::Appendable;
+    }
+    impl serde::Serialize for Label {
+        fn serialize<S: serde::Serializer>(
+            &self,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            serializer
+                .serialize_newtype_struct(
+                    rtps_types::xtypes::DELIMITED,
+                    &rtps_types::xtypes::Derived(self),
+                )
+        }
+    }
+    impl<'de> serde::Deserialize<'de> for Label {
+        fn deserialize<D: serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Label, D::Error> {
+            let value = deserializer
+                .deserialize_newtype_struct(
+                    rtps_types::xtypes::DELIMITED,
+                    rtps_types::xtypes::DerivedVisitor::<Label>::default(),
+                )?;
+            Ok(value)
+        }
+    }
+    impl rtps_types::xtypes::SerializeDerived for Label {
+        fn serialize_derived<S: serde::Serializer>(
+            &self,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            Label::serialize(self, serializer)
+        }
+    }
+    impl<'de> rtps_types::xtypes::DeserializeDerived<'de> for Label {
+        fn deserialize_derived<D: serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Label, D::Error> {
+            Label::deserialize(deserializer)
+        }
+    }
+    impl rtps_cdr::CdrEncode for Label {
+        fn encode(&self, ser: &mut rtps_cdr::Serializer<'_>) -> rtps_cdr::Result<()> {
+            ser.delimited(|ser| {
+                rtps_cdr::CdrEncode::encode(&self.text, ser)?;
+                rtps_cdr::CdrEncode::encode(&self.color, ser)?;
+                Ok(())
+            })
+        }
+    }
+    impl rtps_cdr::CdrDecode for Label {
+        fn decode(de: &mut rtps_cdr::Deserializer<'_>) -> rtps_cdr::Result<Label> {
+            de.delimited(|de| Ok(Label {
+                text: de.appended()?,
+                color: de.appended()?,
+            }))
+        }
+    }
+    impl rtps_cdr::SerializedSize for Label {
+        const MAX_SIZE_XCDR1: Option<usize> = None;
+        const MIN_SIZE_XCDR1: usize = 12;
+        const MAX_SIZE_XCDR2: Option<usize> = None;
+        const MIN_SIZE_XCDR2: usize = 16;
+    }
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[derive(Clone, Debug)]
+    pub struct LabelView<'a> {
+        members: rtps_cdr::ViewMembers<'a, 2>,
+    }
+    #[allow(dead_code)]
+    impl<'a> LabelView<'a> {
+        /// View the XCDR1 encoding in the byte order, the members being decoded when accessed
+        pub fn new(
+            bytes: &'a [u8],
+            endianness: rtps_cdr::Endianness,
+        ) -> rtps_cdr::Result<LabelView<'a>> {
+            rtps_cdr::CdrView::view(&mut rtps_cdr::Deserializer::new(bytes, endianness))
+        }
+        pub fn text(&self) -> rtps_cdr::Result<&'a str> {
+            <&'a str as rtps_cdr::CdrView<'a>>::view(&mut self.members.member(0))
+        }
+        pub fn color(&self) -> rtps_cdr::Result<Color> {
+            <Color as rtps_cdr::CdrDecode>::decode(&mut self.members.member(1))
+        }
+        /// Decode the struct viewed
+        pub fn to_owned(&self) -> rtps_cdr::Result<Label> {
+            rtps_cdr::CdrDecode::decode(&mut self.members.start())
+        }
+    }
+    impl<'a> rtps_cdr::CdrView<'a> for LabelView<'a> {
+        fn view(de: &mut rtps_cdr::Deserializer<'a>) -> rtps_cdr::Result<LabelView<'a>> {
+            let mut members = rtps_cdr::ViewMembers::begin(de)?;
+            members.skip_view::<&'a str>(de)?;
+            members.skip_decoded::<Color>(de)?;
+            Ok(LabelView { members })
+        }
+    }
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[derive(Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Default)]
+    pub struct Shape {
+        pub name: String,
+        pub origin: Point,
+        pub data: Vec<u8>,
+        #[serde(with = "rtps_types::xtypes::delimited")]
+        pub points: Vec<Point>,
+        pub area: f64,
+        #[serde(default)]
+        #[serde(
+            serialize_with = "rtps_types::xtypes::optional::serialize::<5, _, _>",
+            deserialize_with = "rtps_types::xtypes::optional::deserialize"
+        )]
+        pub label: Option<Label>,
+        pub caption: Label,
+        pub tag: rtps_types::BoundedString<8>,
+        pub counts: [i16; 3],
+    }
+    impl rtps_types::xtypes::Extensible for Shape {
+        const EXTENSIBILITY: rtps_types::xtypes::Extensibility = rtps_types::xtypes::Extensibility::Final;
+    }
+    impl rtps_cdr::CdrEncode for Shape {
+        fn encode(&self, ser: &mut rtps_cdr::Serializer<'_>) -> rtps_cdr::Result<()> {
+            rtps_cdr::CdrEncode::encode(&self.name, ser)?;
+            rtps_cdr::CdrEncode::encode(&self.origin, ser)?;
+            rtps_cdr::CdrEncode::encode(&self.data, ser)?;
+            rtps_cdr::CdrEncode::encode(&rtps_cdr::Delimited(&self.points), ser)?;
+            rtps_cdr::CdrEncode::encode(&self.area, ser)?;
+            ser.optional(5u32, self.label.as_ref())?;
+            rtps_cdr::CdrEncode::encode(&self.caption, ser)?;
+            rtps_cdr::CdrEncode::encode(&self.tag, ser)?;
+            rtps_cdr::CdrEncode::encode(&self.counts, ser)?;
+            Ok(())
+        }
+    }
+    impl rtps_cdr::CdrDecode for Shape {
+        fn decode(de: &mut rtps_cdr::Deserializer<'_>) -> rtps_cdr::Result<Shape> {
+            Ok(Shape {
+                name: rtps_cdr::CdrDecode::decode(de)?,
+                origin: rtps_cdr::CdrDecode::decode(de)?,
+                data: rtps_cdr::CdrDecode::decode(de)?,
+                points: <rtps_cdr::Delimited<_> as rtps_cdr::CdrDecode>::decode(de)?.0,
+                area: rtps_cdr::CdrDecode::decode(de)?,
+                label: de.optional()?,
+                caption: rtps_cdr::CdrDecode::decode(de)?,
+                tag: rtps_cdr::CdrDecode::decode(de)?,
+                counts: rtps_cdr::CdrDecode::decode(de)?,
+            })
+        }
+    }
+    impl rtps_cdr::SerializedSize for Shape {
+        const MAX_SIZE_XCDR1: Option<usize> = None;
+        const MIN_SIZE_XCDR1: usize = 60;
+        const MAX_SIZE_XCDR2: Option<usize> = None;
+        const MIN_SIZE_XCDR2: usize = 68;
+    }
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[derive(Clone, Debug)]
+    pub struct ShapeView<'a> {
+        members: rtps_cdr::ViewMembers<'a, 9>,
+    }
+    #[allow(dead_code)]
+    impl<'a> ShapeView<'a> {
+        /// View the XCDR1 encoding in the byte order, the members being decoded when accessed
+        pub fn new(
+            bytes: &'a [u8],
+            endianness: rtps_cdr::Endianness,
+        ) -> rtps_cdr::Result<ShapeView<'a>> {
+            rtps_cdr::CdrView::view(&mut rtps_cdr::Deserializer::new(bytes, endianness))
+        }
+        pub fn name(&self) -> rtps_cdr::Result<&'a str> {
+            <&'a str as rtps_cdr::CdrView<'a>>::view(&mut self.members.member(0))
+        }
+        pub fn origin(&self) -> rtps_cdr::Result<PointView<'a>> {
+            <PointView<'a> as rtps_cdr::CdrView<'a>>::view(&mut self.members.member(1))
+        }
+        pub fn data(&self) -> rtps_cdr::Result<&'a [u8]> {
+            <&'a [u8] as rtps_cdr::CdrView<'a>>::view(&mut self.members.member(2))
+        }
+        pub fn points(&self) -> rtps_cdr::Result<Vec<Point>> {
+            <Vec<Point> as rtps_cdr::CdrDecode>::decode(&mut self.members.member(3))
+        }
+        pub fn area(&self) -> rtps_cdr::Result<f64> {
+            <f64 as rtps_cdr::CdrDecode>::decode(&mut self.members.member(4))
+        }
+        pub fn label(&self) -> rtps_cdr::Result<Option<Label>> {
+            rtps_cdr::Deserializer::optional::<Label>(&mut self.members.member(5))
+        }
+        pub fn caption(&self) -> rtps_cdr::Result<LabelView<'a>> {
+            <LabelView<'a> as rtps_cdr::CdrView<'a>>::view(&mut self.members.member(6))
+        }
+        pub fn tag(&self) -> rtps_cdr::Result<&'a str> {
+            <&'a str as rtps_cdr::CdrView<'a>>::view(&mut self.members.member(7))
+        }
+        pub fn counts(&self) -> rtps_cdr::Result<[i16; 3]> {
+            <[i16; 3] as rtps_cdr::CdrDecode>::decode(&mut self.members.member(8))
+        }
+        /// Decode the struct viewed
+        pub fn to_owned(&self) -> rtps_cdr::Result<Shape> {
+            rtps_cdr::CdrDecode::decode(&mut self.members.start())
+        }
+    }
+    impl<'a> rtps_cdr::CdrView<'a> for ShapeView<'a> {
+        fn view(de: &mut rtps_cdr::Deserializer<'a>) -> rtps_cdr::Result<ShapeView<'a>> {
+            let mut members = rtps_cdr::ViewMembers::begin(de)?;
+            members.skip_view::<&'a str>(de)?;
+            members.skip_view::<PointView<'a>>(de)?;
+            members.skip_view::<&'a [u8]>(de)?;
+            members.skip_decoded::<Vec<Point>>(de)?;
+            members.skip_decoded::<f64>(de)?;
+            members.skip_optional::<Label>(de)?;
+            members.skip_view::<LabelView<'a>>(de)?;
+            members.skip_view::<&'a str>(de)?;
+            members.skip_decoded::<[i16; 3]>(de)?;
+            Ok(ShapeView { members })
+        }
+    }
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[derive(Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Default)]
+    #[serde(remote = "Self")]
+    pub struct Settings {
+        pub level: i32,
+    }
+    impl rtps_types::xtypes::Extensible for Settings {
+        const EXTENSIBILITY: rtps_types::xtypes::Extensibility = rtps_types::xtypes::Extensibility::Mutable;
+    }
+    impl serde::Serialize for Settings {
+        fn serialize<S: serde::Serializer>(
+            &self,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            if serializer.is_human_readable() {
+                Settings::serialize(self, serializer)
+            } else {
+                serializer
+                    .serialize_newtype_struct(
+                        rtps_types::xtypes::MUTABLE,
+                        &rtps_types::xtypes::Members(self),
+                    )
+            }
+        }
+    }
+    impl<'de> serde::Deserialize<'de> for Settings {
+        fn deserialize<D: serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Settings, D::Error> {
+            let value = if deserializer.is_human_readable() {
+                Settings::deserialize(deserializer)?
+            } else {
+                deserializer
+                    .deserialize_newtype_struct(
+                        rtps_types::xtypes::MUTABLE,
+                        rtps_types::xtypes::MembersVisitor::<Settings>::default(),
+                    )?
+            };
+            Ok(value)
+        }
+    }
+    impl rtps_types::xtypes::SerializeMembers for Settings {
+        fn member_count(&self) -> usize {
+            1
+        }
+        fn serialize_members<M: serde::ser::SerializeMap>(
+            &self,
+            map: &mut M,
+        ) -> Result<(), M::Error> {
+            map.serialize_entry(&0u32, &self.level)?;
+            Ok(())
+        }
+    }
+    impl<'de> rtps_types::xtypes::DeserializeMembers<'de> for Settings {
+        fn deserialize_members<A: serde::de::MapAccess<'de>>(
+            mut map: A,
+        ) -> Result<Settings, A::Error> {
+            let mut value = Settings::default();
+            while let Some(header) = map.next_key::<u32>()? {
+                match header & rtps_types::xtypes::MEMBER_ID_MASK {
+                    0 => value.level = map.next_value()?,
+                    _ => rtps_types::xtypes::skip_member(&mut map, header)?,
+                }
+            }
+            Ok(value)
+        }
+    }
+    impl rtps_cdr::CdrEncode for Settings {
+        fn encode(&self, ser: &mut rtps_cdr::Serializer<'_>) -> rtps_cdr::Result<()> {
+            ser.members(|ser| {
+                ser.member(0u32, |ser| rtps_cdr::CdrEncode::encode(&self.level, ser))?;
+                Ok(())
+            })
+        }
+    }
+    impl rtps_cdr::CdrDecode for Settings {
+        fn decode(de: &mut rtps_cdr::Deserializer<'_>) -> rtps_cdr::Result<Settings> {
+            let mut value = Settings::default();
+            de.members(|de, header| {
+                match header & rtps_types::xtypes::MEMBER_ID_MASK {
+                    0 => value.level = rtps_cdr::CdrDecode::decode(de)?,
+                    _ => return Ok(false),
+                }
+                Ok(true)
+            })?;
+            Ok(value)
+        }
+    }
+    impl rtps_cdr::SerializedSize for Settings {
+        const MAX_SIZE_XCDR1: Option<usize> = Some(12);
+        const MIN_SIZE_XCDR1: usize = 12;
+        const MAX_SIZE_XCDR2: Option<usize> = Some(16);
+        const MIN_SIZE_XCDR2: usize = 16;
+    }
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[derive(Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Default)]
+    pub struct Config {
+        pub settings: Settings,
+        pub flag: u8,
+    }
+    impl rtps_types::xtypes::Extensible for Config {
+        const EXTENSIBILITY: rtps_types::xtypes::Extensibility = rtps_types::xtypes::Extensibility::Final;
+    }
+    impl rtps_cdr::CdrEncode for Config {
+        fn encode(&self, ser: &mut rtps_cdr::Serializer<'_>) -> rtps_cdr::Result<()> {
+            rtps_cdr::CdrEncode::encode(&self.settings, ser)?;
+            rtps_cdr::CdrEncode::encode(&self.flag, ser)?;
+            Ok(())
+        }
+    }
+    impl rtps_cdr::CdrDecode for Config {
+        fn decode(de: &mut rtps_cdr::Deserializer<'_>) -> rtps_cdr::Result<Config> {
+            Ok(Config {
+                settings: rtps_cdr::CdrDecode::decode(de)?,
+                flag: rtps_cdr::CdrDecode::decode(de)?,
+            })
+        }
+    }
+    impl rtps_cdr::SerializedSize for Config {
+        const MAX_SIZE_XCDR1: Option<usize> = Some(13);
+        const MIN_SIZE_XCDR1: usize = 13;
+        const MAX_SIZE_XCDR2: Option<usize> = Some(17);
+        const MIN_SIZE_XCDR2: usize = 17;
+    }
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[derive(Clone, Debug)]
+    pub struct ConfigView<'a> {
+        members: rtps_cdr::ViewMembers<'a, 2>,
+    }
+    #[allow(dead_code)]
+    impl<'a> ConfigView<'a> {
+        /// View the XCDR1 encoding in the byte order, the members being decoded when accessed
+        pub fn new(
+            bytes: &'a [u8],
+            endianness: rtps_cdr::Endianness,
+        ) -> rtps_cdr::Result<ConfigView<'a>> {
+            rtps_cdr::CdrView::view(&mut rtps_cdr::Deserializer::new(bytes, endianness))
+        }
+        pub fn settings(&self) -> rtps_cdr::Result<Settings> {
+            <Settings as rtps_cdr::CdrDecode>::decode(&mut self.members.member(0))
+        }
+        pub fn flag(&self) -> rtps_cdr::Result<u8> {
+            <u8 as rtps_cdr::CdrDecode>::decode(&mut self.members.member(1))
+        }
+        /// Decode the struct viewed
+        pub fn to_owned(&self) -> rtps_cdr::Result<Config> {
+            rtps_cdr::CdrDecode::decode(&mut self.members.start())
+        }
+    }
+    impl<'a> rtps_cdr::CdrView<'a> for ConfigView<'a> {
+        fn view(
+            de: &mut rtps_cdr::Deserializer<'a>,
+        ) -> rtps_cdr::Result<ConfigView<'a>> {
+            let mut members = rtps_cdr::ViewMembers::begin(de)?;
+            members.skip_decoded::<Settings>(de)?;
+            members.skip_decoded::<u8>(de)?;
+            Ok(ConfigView { members })
+        }
+    }
+}
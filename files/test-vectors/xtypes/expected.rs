@@ -0,0 +1,563 @@
+#[allow(unused_imports)]
+use std::vec::Vec;
+#[allow(unused_imports)]
+use serde_derive::{Serialize, Deserialize};
+#[allow(non_snake_case)]
+pub mod Shapes {
+    #[allow(unused_imports)]
+    use serde_derive::{Serialize, Deserialize};
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[derive(Clone, Debug, Default)]
+    #[repr(u32)]
+    pub enum Color {
+        #[default]
+        RED = 0,
+        GREEN = 1,
+        BLUE = 2,
+    }
+    impl Color {
+        /// The value of the enumerator
+        pub const fn value(&self) -> u32 {
+            match self {
+                Color::RED => 0,
+                Color::GREEN => 1,
+                Color::BLUE => 2,
+            }
+        }
+        /// The IDL name of the enumerator
+        pub const fn as_str(&self) -> &'static str {
+            match self {
+                Color::RED => "RED",
+                Color::GREEN => "GREEN",
+                Color::BLUE => "BLUE",
+            }
+        }
+    }
+    impl ::std::convert::TryFrom<u32> for Color {
+        type Error = rtps_types::EnumError;
+        fn try_from(value: u32) -> Result<Color, rtps_types::EnumError> {
+            match value {
+                0 => Ok(Color::RED),
+                1 => Ok(Color::GREEN),
+                2 => Ok(Color::BLUE),
+                _ => Err(rtps_types::EnumError::Value(value)),
+            }
+        }
+    }
+    impl From<Color> for u32 {
+        fn from(value: Color) -> u32 {
+            value.value()
+        }
+    }
+    impl ::std::fmt::Display for Color {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            f.write_str(self.as_str())
+        }
+    }
+    impl ::std::str::FromStr for Color {
+        type Err = rtps_types::EnumError;
+        fn from_str(name: &str) -> Result<Color, rtps_types::EnumError> {
+            match name {
+                "RED" => Ok(Color::RED),
+                "GREEN" => Ok(Color::GREEN),
+                "BLUE" => Ok(Color::BLUE),
+                _ => Err(rtps_types::EnumError::Name(name.to_owned())),
+            }
+        }
+    }
+    impl serde::Serialize for Color {
+        fn serialize<S: serde::Serializer>(
+            &self,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            serializer.serialize_u32(self.value())
+        }
+    }
+    impl<'de> serde::Deserialize<'de> for Color {
+        fn deserialize<D: serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Color, D::Error> {
+            let value = <u32 as serde::Deserialize>::deserialize(deserializer)?;
+            <Color as ::std::convert::TryFrom<u32>>::try_from(value)
+                .map_err(serde::de::Error::custom)
+        }
+    }
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[derive(Serialize, Deserialize)]
+    #[derive(Clone, Debug, Default)]
+    #[serde(remote = "Self")]
+    #[serde(default)]
+    pub struct Point {
+        pub x: i32,
+        pub y: i32,
+    }
+    impl serde::Serialize for Point {
+        fn serialize<S: serde::Serializer>(
+            &self,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            serializer
+                .serialize_newtype_struct(
+                    rtps_types::xtypes::DELIMITED,
+                    &rtps_types::xtypes::Derived(self),
+                )
+        }
+    }
+    impl<'de> serde::Deserialize<'de> for Point {
+        fn deserialize<D: serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Point, D::Error> {
+            let value = deserializer
+                .deserialize_newtype_struct(
+                    rtps_types::xtypes::DELIMITED,
+                    rtps_types::xtypes::DerivedVisitor::<Point>::default(),
+                )?;
+            Ok(value)
+        }
+    }
+    impl rtps_types::xtypes::SerializeDerived for Point {
+        fn serialize_derived<S: serde::Serializer>(
+            &self,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            Point::serialize(self, serializer)
+        }
+    }
+    impl<'de> rtps_types::xtypes::DeserializeDerived<'de> for Point {
+        fn deserialize_derived<D: serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Point, D::Error> {
+            Point::deserialize(deserializer)
+        }
+    }
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[derive(Serialize, Deserialize)]
+    #[derive(Clone, Debug, Default)]
+    #[serde(remote = "Self")]
+    #[serde(default)]
+    pub struct Point3 {
+        pub x: i32,
+        pub y: i32,
+        pub z: i32,
+    }
+    impl serde::Serialize for Point3 {
+        fn serialize<S: serde::Serializer>(
+            &self,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            serializer
+                .serialize_newtype_struct(
+                    rtps_types::xtypes::DELIMITED,
+                    &rtps_types::xtypes::Derived(self),
+                )
+        }
+    }
+    impl<'de> serde::Deserialize<'de> for Point3 {
+        fn deserialize<D: serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Point3, D::Error> {
+            let value = deserializer
+                .deserialize_newtype_struct(
+                    rtps_types::xtypes::DELIMITED,
+                    rtps_types::xtypes::DerivedVisitor::<Point3>::default(),
+                )?;
+            Ok(value)
+        }
+    }
+    impl rtps_types::xtypes::SerializeDerived for Point3 {
+        fn serialize_derived<S: serde::Serializer>(
+            &self,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            Point3::serialize(self, serializer)
+        }
+    }
+    impl<'de> rtps_types::xtypes::DeserializeDerived<'de> for Point3 {
+        fn deserialize_derived<D: serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Point3, D::Error> {
+            Point3::deserialize(deserializer)
+        }
+    }
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[derive(Serialize, Deserialize)]
+    #[derive(Clone, Debug, Default)]
+    pub struct Segment {
+        pub from: Point,
+        pub to: Point,
+    }
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[derive(Serialize, Deserialize)]
+    #[derive(Clone, Debug)]
+    #[serde(remote = "Self")]
+    pub enum Fill {
+        solid { solid: Color },
+        gradient { gradient: Vec<Color> },
+    }
+    impl Default for Fill {
+        fn default() -> Fill {
+            Fill::solid {
+                solid: Default::default(),
+            }
+        }
+    }
+    impl serde::Serialize for Fill {
+        fn serialize<S: serde::Serializer>(
+            &self,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            serializer
+                .serialize_newtype_struct(
+                    rtps_types::xtypes::DELIMITED,
+                    &rtps_types::xtypes::Derived(self),
+                )
+        }
+    }
+    impl<'de> serde::Deserialize<'de> for Fill {
+        fn deserialize<D: serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Fill, D::Error> {
+            let value = deserializer
+                .deserialize_newtype_struct(
+                    rtps_types::xtypes::DELIMITED,
+                    rtps_types::xtypes::DerivedVisitor::<Fill>::default(),
+                )?;
+            Ok(value)
+        }
+    }
+    impl rtps_types::xtypes::SerializeDerived for Fill {
+        fn serialize_derived<S: serde::Serializer>(
+            &self,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            if serializer.is_human_readable() {
+                Fill::serialize(self, serializer)
+            } else {
+                use serde::ser::SerializeTuple;
+                let mut tuple = serializer.serialize_tuple(2)?;
+                match self {
+                    Fill::solid { solid: value } => {
+                        tuple.serialize_element(&0i32)?;
+                        tuple.serialize_element(value)?;
+                    }
+                    Fill::gradient { gradient: value } => {
+                        tuple.serialize_element(&1i32)?;
+                        tuple.serialize_element(value)?;
+                    }
+                }
+                tuple.end()
+            }
+        }
+    }
+    impl<'de> rtps_types::xtypes::DeserializeDerived<'de> for Fill {
+        fn deserialize_derived<D: serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Fill, D::Error> {
+            if deserializer.is_human_readable() {
+                Fill::deserialize(deserializer)
+            } else {
+                struct Discriminated;
+                impl<'de> serde::de::Visitor<'de> for Discriminated {
+                    type Value = Fill;
+                    fn expecting(
+                        &self,
+                        f: &mut ::std::fmt::Formatter,
+                    ) -> ::std::fmt::Result {
+                        f.write_str("union Shapes::Fill")
+                    }
+                    fn visit_seq<A: serde::de::SeqAccess<'de>>(
+                        self,
+                        mut seq: A,
+                    ) -> Result<Fill, A::Error> {
+                        let label = seq
+                            .next_element::<i32>()?
+                            .ok_or_else(|| serde::de::Error::invalid_length(0, &self))?;
+                        Ok(
+                            match label {
+                                0i32 => {
+                                    Fill::solid {
+                                        solid: seq
+                                            .next_element()?
+                                            .ok_or_else(|| serde::de::Error::invalid_length(1, &self))?,
+                                    }
+                                }
+                                1i32 => {
+                                    Fill::gradient {
+                                        gradient: seq
+                                            .next_element()?
+                                            .ok_or_else(|| serde::de::Error::invalid_length(1, &self))?,
+                                    }
+                                }
+                                label => {
+                                    return Err(
+                                        serde::de::Error::custom(
+                                            ::std::format_args!(
+                                                "invalid discriminator {} of {}", label,
+                                                "union Shapes::Fill"
+                                            ),
+                                        ),
+                                    );
+                                }
+                            },
+                        )
+                    }
+                }
+                deserializer.deserialize_tuple(2, Discriminated)
+            }
+        }
+    }
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[derive(Serialize, Deserialize)]
+    #[derive(Clone, Debug, Default)]
+    #[serde(remote = "Self")]
+    pub struct Shape {
+        pub name: String,
+        pub color: Color,
+        #[serde(with = "rtps_types::xtypes::delimited")]
+        pub outline: Vec<Point>,
+        #[serde(default)]
+        #[serde(
+            serialize_with = "rtps_types::xtypes::optional::serialize::<7, _, _>",
+            deserialize_with = "rtps_types::xtypes::optional::deserialize"
+        )]
+        pub fill: Option<Fill>,
+        #[serde(default)]
+        #[serde(
+            serialize_with = "rtps_types::xtypes::optional::serialize::<2147483656, _, _>",
+            deserialize_with = "rtps_types::xtypes::optional::deserialize"
+        )]
+        pub layer: Option<i32>,
+    }
+    impl serde::Serialize for Shape {
+        fn serialize<S: serde::Serializer>(
+            &self,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            if serializer.is_human_readable() {
+                Shape::serialize(self, serializer)
+            } else {
+                serializer
+                    .serialize_newtype_struct(
+                        rtps_types::xtypes::MUTABLE,
+                        &rtps_types::xtypes::Members(self),
+                    )
+            }
+        }
+    }
+    impl<'de> serde::Deserialize<'de> for Shape {
+        fn deserialize<D: serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Shape, D::Error> {
+            let value = if deserializer.is_human_readable() {
+                Shape::deserialize(deserializer)?
+            } else {
+                deserializer
+                    .deserialize_newtype_struct(
+                        rtps_types::xtypes::MUTABLE,
+                        rtps_types::xtypes::MembersVisitor::<Shape>::default(),
+                    )?
+            };
+            Ok(value)
+        }
+    }
+    impl rtps_types::xtypes::SerializeMembers for Shape {
+        fn member_count(&self) -> usize {
+            3 + usize::from(self.fill.is_some()) + usize::from(self.layer.is_some())
+        }
+        fn serialize_members<M: serde::ser::SerializeMap>(
+            &self,
+            map: &mut M,
+        ) -> Result<(), M::Error> {
+            map.serialize_entry(&rtps_types::xtypes::MUST_UNDERSTAND, &self.name)?;
+            map.serialize_entry(&5u32, &self.color)?;
+            map.serialize_entry(&6u32, &rtps_types::xtypes::Delimited(&self.outline))?;
+            if let Some(value) = &self.fill {
+                map.serialize_entry(&7u32, value)?;
+            }
+            if let Some(value) = &self.layer {
+                map.serialize_entry(
+                    &(rtps_types::xtypes::MUST_UNDERSTAND | 8u32),
+                    value,
+                )?;
+            }
+            Ok(())
+        }
+    }
+    impl<'de> rtps_types::xtypes::DeserializeMembers<'de> for Shape {
+        fn deserialize_members<A: serde::de::MapAccess<'de>>(
+            mut map: A,
+        ) -> Result<Shape, A::Error> {
+            let mut value = Shape::default();
+            while let Some(header) = map.next_key::<u32>()? {
+                match header & rtps_types::xtypes::MEMBER_ID_MASK {
+                    0 => value.name = map.next_value()?,
+                    5 => value.color = map.next_value()?,
+                    6 => {
+                        value.outline = map
+                            .next_value::<rtps_types::xtypes::DelimitedValue<_>>()?
+                            .0;
+                    }
+                    7 => value.fill = Some(map.next_value()?),
+                    8 => value.layer = Some(map.next_value()?),
+                    _ => rtps_types::xtypes::skip_member(&mut map, header)?,
+                }
+            }
+            Ok(value)
+        }
+    }
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[derive(Serialize, Deserialize)]
+    #[derive(Clone, Debug, Default)]
+    #[serde(remote = "Self")]
+    pub struct ShapeName {
+        pub name: String,
+        pub color: Color,
+    }
+    impl serde::Serialize for ShapeName {
+        fn serialize<S: serde::Serializer>(
+            &self,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            if serializer.is_human_readable() {
+                ShapeName::serialize(self, serializer)
+            } else {
+                serializer
+                    .serialize_newtype_struct(
+                        rtps_types::xtypes::MUTABLE,
+                        &rtps_types::xtypes::Members(self),
+                    )
+            }
+        }
+    }
+    impl<'de> serde::Deserialize<'de> for ShapeName {
+        fn deserialize<D: serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<ShapeName, D::Error> {
+            let value = if deserializer.is_human_readable() {
+                ShapeName::deserialize(deserializer)?
+            } else {
+                deserializer
+                    .deserialize_newtype_struct(
+                        rtps_types::xtypes::MUTABLE,
+                        rtps_types::xtypes::MembersVisitor::<ShapeName>::default(),
+                    )?
+            };
+            Ok(value)
+        }
+    }
+    impl rtps_types::xtypes::SerializeMembers for ShapeName {
+        fn member_count(&self) -> usize {
+            2
+        }
+        fn serialize_members<M: serde::ser::SerializeMap>(
+            &self,
+            map: &mut M,
+        ) -> Result<(), M::Error> {
+            map.serialize_entry(&rtps_types::xtypes::MUST_UNDERSTAND, &self.name)?;
+            map.serialize_entry(&5u32, &self.color)?;
+            Ok(())
+        }
+    }
+    impl<'de> rtps_types::xtypes::DeserializeMembers<'de> for ShapeName {
+        fn deserialize_members<A: serde::de::MapAccess<'de>>(
+            mut map: A,
+        ) -> Result<ShapeName, A::Error> {
+            let mut value = ShapeName::default();
+            while let Some(header) = map.next_key::<u32>()? {
+                match header & rtps_types::xtypes::MEMBER_ID_MASK {
+                    0 => value.name = map.next_value()?,
+                    5 => value.color = map.next_value()?,
+                    _ => rtps_types::xtypes::skip_member(&mut map, header)?,
+                }
+            }
+            Ok(value)
+        }
+    }
+    #[allow(dead_code)]
+    #[allow(non_camel_case_types)]
+    #[derive(Serialize, Deserialize)]
+    #[derive(Clone, Debug, Default)]
+    #[serde(remote = "Self")]
+    pub struct Label {
+        pub text: String,
+        pub font_size: i16,
+        #[serde(with = "rtps_types::xtypes::delimited")]
+        pub tags: Vec<String>,
+    }
+    impl serde::Serialize for Label {
+        fn serialize<S: serde::Serializer>(
+            &self,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            if serializer.is_human_readable() {
+                Label::serialize(self, serializer)
+            } else {
+                serializer
+                    .serialize_newtype_struct(
+                        rtps_types::xtypes::MUTABLE,
+                        &rtps_types::xtypes::Members(self),
+                    )
+            }
+        }
+    }
+    impl<'de> serde::Deserialize<'de> for Label {
+        fn deserialize<D: serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Label, D::Error> {
+            let value = if deserializer.is_human_readable() {
+                Label::deserialize(deserializer)?
+            } else {
+                deserializer
+                    .deserialize_newtype_struct(
+                        rtps_types::xtypes::MUTABLE,
+                        rtps_types::xtypes::MembersVisitor::<Label>::default(),
+                    )?
+            };
+            Ok(value)
+        }
+    }
+    impl rtps_types::xtypes::SerializeMembers for Label {
+        fn member_count(&self) -> usize {
+            3
+        }
+        fn serialize_members<M: serde::ser::SerializeMap>(
+            &self,
+            map: &mut M,
+        ) -> Result<(), M::Error> {
+            map.serialize_entry(
+                &(rtps_types::xtypes::MUST_UNDERSTAND | 206680604u32),
+                &self.text,
+            )?;
+            map.serialize_entry(&123780599u32, &self.font_size)?;
+            map.serialize_entry(&100u32, &rtps_types::xtypes::Delimited(&self.tags))?;
+            Ok(())
+        }
+    }
+    impl<'de> rtps_types::xtypes::DeserializeMembers<'de> for Label {
+        fn deserialize_members<A: serde::de::MapAccess<'de>>(
+            mut map: A,
+        ) -> Result<Label, A::Error> {
+            let mut value = Label::default();
+            while let Some(header) = map.next_key::<u32>()? {
+                match header & rtps_types::xtypes::MEMBER_ID_MASK {
+                    206680604 => value.text = map.next_value()?,
+                    123780599 => value.font_size = map.next_value()?,
+                    100 => {
+                        value.tags = map
+                            .next_value::<rtps_types::xtypes::DelimitedValue<_>>()?
+                            .0;
+                    }
+                    _ => rtps_types::xtypes::skip_member(&mut map, header)?,
+                }
+            }
+            Ok(value)
+        }
+    }
+}
@@ -1,7 +1,8 @@
 extern crate rtps_idl;
 extern crate getopts;
 
-use rtps_idl::{IdlLoader, Configuration, generate_with_loader};
+use rtps_idl::{IdlLoader, CharMapping, Configuration, ExternalMapping, NamingConvention, TypeRule, generate_with_generator};
+use rtps_idl::generator;
 use std::io::{Error, ErrorKind};
 use std::io::{self, Read};
 use std::fs::File;
@@ -53,6 +54,15 @@ fn print_usage(program: &str, opts: Options) -> Result<(), std::io::Error> {
 }
 
 
+// comma separated list, omitting empty entries
+fn split_list(list: &str) -> Vec<String> {
+    list.split(',')
+        .map(|item| item.trim())
+        .filter(|item| !item.is_empty())
+        .map(|item| item.to_owned())
+        .collect()
+}
+
 fn main() -> Result<(), std::io::Error> {
     let mut opts = Options::new();
     opts.optmulti("I", "",
@@ -63,6 +73,61 @@ fn main() -> Result<(), std::io::Error> {
                 "Write output to 'outfile'.", "outfile");
     opts.optflag("v", "",
                  "Verbose output for debugging'.");
+    opts.optopt("", "config",
+                "Read the generator configuration from the TOML 'file'; further options extend or override it.",
+                "file");
+    opts.optopt("", "keyword-suffix",
+                "Append 'suffix' to identifiers colliding with Rust keywords, instead of emitting raw identifiers.",
+                "suffix");
+    opts.optopt("", "chars",
+                "Mapping of char, wchar and wstring, 'rust' (char and String, default) or 'wire' (octets and UTF-16 code units).",
+                "mapping");
+    opts.optopt("", "external",
+                "Pointer type of @external members, 'box' (default) or 'arc'.",
+                "pointer");
+    opts.optopt("", "native-path",
+                "Module 'path' providing the Rust types of native declarations lacking a type mapping.",
+                "path");
+    opts.optopt("", "naming",
+                "Naming convention of generated items, 'idl' (verbatim, default) or 'rust'.",
+                "convention");
+    opts.optmulti("", "derive",
+                  "Derive the comma separated 'traits' for all structs, unions and enums, besides Clone and Debug.",
+                  "traits");
+    opts.optmulti("", "type-derive",
+                  "Derive the 'traits' for types whose scoped name matches 'pattern' ('*' matching any sequence).",
+                  "pattern=traits");
+    opts.optmulti("", "attribute",
+                  "Add the outer 'attribute' to all structs, unions and enums.",
+                  "attribute");
+    opts.optmulti("", "type-attribute",
+                  "Add the outer 'attribute' to types whose scoped name matches 'pattern'.",
+                  "pattern=attribute");
+    opts.optflag("", "no-serde",
+                 "Do not derive serde's Serialize and Deserialize.");
+    opts.optflag("", "bounded",
+                 "Emit bounded strings and sequences as rtps-types' bounded containers, instead of String and Vec.");
+    opts.optflag("", "validate",
+                 "Implement validate() checking @range, @min, @max and the bounds, and validate deserialized values.");
+    opts.optflag("", "cdr",
+                 "Implement rtps-cdr's CdrEncode and CdrDecode, encoding CDR without serde.");
+    opts.optflag("", "views",
+                 "Emit zero-copy views of the structs, reading XCDR1 in place; implies --cdr.");
+    opts.optflag("", "fixed-layout",
+                 "Emit #[repr(C)] structs laid out as their plain CDR encoding, for shared memory.");
+    opts.optflag("", "no-std",
+                 "Emit core and alloc paths only, for no_std crates declaring 'extern crate alloc'.");
+    opts.optflag("", "heapless",
+                 "Emit bounded strings and sequences as rtps-types' FixedString and FixedVec, not allocating.");
+    opts.optflag("", "type-objects",
+                 "Implement rtps-types' Discoverable, holding the XTypes TypeObjects of each type.");
+    opts.optopt("", "lang",
+                &format!("Backend generating the output, one of: {} (default 'rust').",
+                         generator::GENERATORS.join(", ")),
+                "backend");
+    opts.optopt("", "backend",
+                "Same as --lang.",
+                "backend");
     opts.optflag("h", "help", "print this help menu");
     let args: Vec<_> = env::args().collect();
     let program = args[0].clone();
@@ -99,14 +164,115 @@ fn main() -> Result<(), std::io::Error> {
         load_from(&env::current_dir().unwrap(), &infile)
             .map_err(|_| Error::new(ErrorKind::NotFound, ""))?;
 
-    let config = Configuration::new(defs, matches.opt_present("v"));
+    let mut config = match matches.opt_str("config") {
+        Some(config_file) => {
+            let text = load_from(&env::current_dir().unwrap(), &config_file)
+                .map_err(|_| Error::new(ErrorKind::NotFound, config_file.clone()))?;
+            Configuration::from_toml(&text).map_err(|err| {
+                eprint!("config error {:?}", err);
+                Error::new(ErrorKind::InvalidData, "config error")
+            })?
+        }
+        None => Configuration::default(),
+    };
+    config.definition = defs;
+    config.verbose = matches.opt_present("v");
+    if let Some(suffix) = matches.opt_str("keyword-suffix") {
+        generator::rust::check_keyword_suffix(&suffix).map_err(|err| {
+            eprint!("config error {:?}", err);
+            Error::new(ErrorKind::InvalidInput, "config error")
+        })?;
+        config.keyword_suffix = Some(suffix);
+    }
+    match matches.opt_str("chars").as_deref() {
+        None => (),
+        Some("rust") => config.chars = CharMapping::Rust,
+        Some("wire") => config.chars = CharMapping::Wire,
+        Some(_) => return print_usage(&program, opts),
+    };
+    match matches.opt_str("external").as_deref() {
+        None => (),
+        Some("box") => config.external = ExternalMapping::Box,
+        Some("arc") => config.external = ExternalMapping::Arc,
+        Some(_) => return print_usage(&program, opts),
+    };
+    if let Some(native_path) = matches.opt_str("native-path") {
+        config.native_path = Some(native_path);
+    }
+    match matches.opt_str("naming").as_deref() {
+        None => (),
+        Some("idl") => config.naming = NamingConvention::Idl,
+        Some("rust") => config.naming = NamingConvention::Rust,
+        Some(_) => return print_usage(&program, opts),
+    };
+
+    for derives in matches.opt_strs("derive") {
+        config.derives.extend(split_list(&derives));
+    }
+    if matches.opt_present("no-serde") {
+        config.serde = false;
+    }
+    if matches.opt_present("bounded") {
+        config.bounded = true;
+    }
+    if matches.opt_present("validate") {
+        config.validate = true;
+    }
+    if matches.opt_present("cdr") {
+        config.cdr = true;
+    }
+    if matches.opt_present("views") {
+        config.cdr = true;
+        config.views = true;
+    }
+    if matches.opt_present("fixed-layout") {
+        config.fixed_layout = true;
+    }
+    if matches.opt_present("no-std") {
+        config.no_std = true;
+    }
+    if matches.opt_present("heapless") {
+        config.heapless = true;
+    }
+    if matches.opt_present("type-objects") {
+        config.type_objects = true;
+    }
+    config.attributes.extend(matches.opt_strs("attribute"));
+    for rule in matches.opt_strs("type-derive") {
+        match rule.split_once('=') {
+            Some((pattern, derives)) => config.type_rules.push(TypeRule {
+                pattern: pattern.to_owned(),
+                derives: split_list(derives),
+                ..TypeRule::default()
+            }),
+            None => return print_usage(&program, opts),
+        }
+    }
+    for rule in matches.opt_strs("type-attribute") {
+        match rule.split_once('=') {
+            Some((pattern, attribute)) => config.type_rules.push(TypeRule {
+                pattern: pattern.to_owned(),
+                attributes: vec![attribute.to_owned()],
+                ..TypeRule::default()
+            }),
+            None => return print_usage(&program, opts),
+        }
+    }
+
+    let lang = matches.opt_str("lang")
+        .or_else(|| matches.opt_str("backend"))
+        .unwrap_or_else(|| "rust".to_owned());
+    let backend = match generator::by_name(&lang) {
+        Some(backend) => backend,
+        None => return print_usage(&program, opts),
+    };
 
     let result = match matches.opt_str("o") {
         Some(outfile) => {
             let mut of = File::create(std::path::Path::new(&outfile))?;
-            generate_with_loader(&mut of, &mut loader, &config, &data)
+            generate_with_generator(&mut of, &mut loader, backend.as_ref(), &config, &data)
         }
-        _ => generate_with_loader(&mut io::stdout(), &mut loader, &config, &data),
+        _ => generate_with_generator(&mut io::stdout(), &mut loader, backend.as_ref(), &config, &data),
     };
 
     match result {
@@ -121,7 +287,7 @@ fn main() -> Result<(), std::io::Error> {
 
 #[cfg(test)]
 mod tests {
-    use rtps_idl::{generate_with_search_path, Configuration};
+    use rtps_idl::{generate_with_search_path, CharMapping, Configuration, ExternalMapping, IdlError, NamingConvention, TypeRule};
     use super::Loader;
     use std::io::Cursor;
     use std::str;
@@ -270,6 +436,15 @@ mod tests {
         testvector_verify("files/test-vectors/const_op_mod");
     }
 
+    #[test]
+    fn const_precedence() {
+        let config = Configuration {
+            bounded: true,
+            ..Configuration::default()
+        };
+        testvector_verify_with_config("files/test-vectors/const_precedence", &config);
+    }
+
     #[test]
     fn include_directive() {
         testvector_verify("files/test-vectors/include_directive/");
@@ -280,7 +455,343 @@ mod tests {
         testvector_verify("files/test-vectors/union_members");
     }
 
+    #[test]
+    fn annotations() {
+        testvector_verify("files/test-vectors/annotations");
+    }
+
+    #[test]
+    fn union_numeric_labels() {
+        testvector_verify("files/test-vectors/union_numeric_labels");
+    }
+
+    #[test]
+    fn keyword_identifiers() {
+        testvector_verify("files/test-vectors/keyword_identifiers");
+    }
+
+    #[test]
+    fn keyword_identifiers_suffix() {
+        let config = Configuration {
+            keyword_suffix: Some("_".to_owned()),
+            ..Configuration::default()
+        };
+        testvector_verify_with_config("files/test-vectors/keyword_identifiers_suffix", &config);
+    }
+
+    #[test]
+    fn naming_rust() {
+        let config = Configuration {
+            naming: NamingConvention::Rust,
+            ..Configuration::default()
+        };
+        testvector_verify_with_config("files/test-vectors/naming_rust", &config);
+    }
+
+    #[test]
+    fn derives() {
+        let config = Configuration {
+            derives: derive_list(&["Clone", "Debug", "PartialEq", "Eq", "Hash", "Default", "Copy"]),
+            serde: false,
+            attributes: vec!["doc = \" Generated from IDL\"".to_owned()],
+            type_rules: vec![
+                TypeRule {
+                    pattern: "Derives::*".to_owned(),
+                    derives: vec!["PartialOrd".to_owned()],
+                    ..TypeRule::default()
+                },
+                TypeRule {
+                    pattern: "Derives::Point".to_owned(),
+                    attributes: vec!["non_exhaustive".to_owned()],
+                    ..TypeRule::default()
+                },
+            ],
+            ..Configuration::default()
+        };
+        testvector_verify_with_config("files/test-vectors/derives", &config);
+    }
+
+    #[test]
+    fn type_map() {
+        let mut config_file = File::open("files/test-vectors/type_map/config.toml").unwrap();
+        let mut text = String::new();
+        assert!(config_file.read_to_string(&mut text).is_ok());
+        let config = Configuration::from_toml(&text).unwrap();
+        testvector_verify_with_config("files/test-vectors/type_map", &config);
+    }
+
+    #[test]
+    fn native() {
+        let mut config_file = File::open("files/test-vectors/native/config.toml").unwrap();
+        let mut text = String::new();
+        assert!(config_file.read_to_string(&mut text).is_ok());
+        let config = Configuration::from_toml(&text).unwrap();
+        testvector_verify_with_config("files/test-vectors/native", &config);
+    }
+
+    #[test]
+    fn bounded() {
+        let config = Configuration {
+            bounded: true,
+            ..Configuration::default()
+        };
+        testvector_verify_with_config("files/test-vectors/bounded", &config);
+    }
+
+    #[test]
+    fn unbounded() {
+        let config = Configuration {
+            bounded: false,
+            ..Configuration::default()
+        };
+        testvector_verify_with_config("files/test-vectors/unbounded", &config);
+    }
+
+    #[test]
+    fn chars() {
+        let config = Configuration {
+            bounded: true,
+            ..Configuration::default()
+        };
+        testvector_verify_with_config("files/test-vectors/chars", &config);
+    }
+
+    #[test]
+    fn chars_wire() {
+        let config = Configuration {
+            bounded: true,
+            chars: CharMapping::Wire,
+            ..Configuration::default()
+        };
+        testvector_verify_with_config("files/test-vectors/chars_wire", &config);
+    }
+
+    #[test]
+    fn long_double() {
+        testvector_verify("files/test-vectors/long_double");
+    }
+
+    #[test]
+    fn enums() {
+        let config = Configuration {
+            derives: derive_list(&["Clone", "Debug", "PartialEq", "Eq", "Default", "Copy"]),
+            ..Configuration::default()
+        };
+        testvector_verify_with_config("files/test-vectors/enums", &config);
+    }
+
+    #[test]
+    fn defaults() {
+        let config = Configuration {
+            derives: derive_list(&["Clone", "Debug", "PartialEq"]),
+            bounded: true,
+            serde: false,
+            ..Configuration::default()
+        };
+        testvector_verify_with_config("files/test-vectors/defaults", &config);
+    }
+
+    #[test]
+    fn defaults_invalid() {
+        // the length of a default is checked against the bound of a bounded string
+        let bounded = Configuration { bounded: true, ..Configuration::default() };
+        assert!(generate_with_config("struct S { @default(\"abcd\") string<3> s; };", &bounded).is_err());
+        assert!(generate("enum E { A, B }; struct S { @default(C) E e; };").is_err());
+        assert!(generate("struct S { @default(1) sequence<long> s; };").is_err());
+        assert!(generate("enum E { A, B }; struct S { @default(B) E e; };").is_ok());
+    }
+
+    #[test]
+    fn optional_external() {
+        let config = Configuration {
+            derives: derive_list(&["Clone", "Debug", "PartialEq", "Copy"]),
+            ..Configuration::default()
+        };
+        testvector_verify_with_config("files/test-vectors/optional_external", &config);
+    }
+
+    #[test]
+    fn external_arc() {
+        let config = Configuration {
+            external: ExternalMapping::Arc,
+            serde: false,
+            ..Configuration::default()
+        };
+        testvector_verify_with_config("files/test-vectors/external_arc", &config);
+    }
+
+    #[test]
+    fn validate() {
+        let config = Configuration {
+            bounded: false,
+            validate: true,
+            ..Configuration::default()
+        };
+        testvector_verify_with_config("files/test-vectors/validate", &config);
+    }
+
+    #[test]
+    fn validate_invalid() {
+        let config = Configuration {
+            validate: true,
+            ..Configuration::default()
+        };
+        let generate = |input: &str| generate_with_config(input, &config);
+        assert!(generate("struct S { @range(min = 0, max = 9) string s; };").is_err());
+        assert!(generate("struct T { long x; }; struct S { @min(0) T t; };").is_err());
+        assert!(generate("struct S { @max(1.5) long x; };").is_err());
+        assert!(generate("struct S { @range(min = 0, max = 9) unsigned short x; };").is_ok());
+    }
+
+    #[test]
+    fn xtypes() {
+        testvector_verify("files/test-vectors/xtypes");
+    }
+
+    #[test]
+    fn xtypes_invalid() {
+        assert!(generate("@final @mutable struct S { long x; };").is_err());
+        assert!(generate("@extensibility(OPEN) struct S { long x; };").is_err());
+        assert!(generate("@mutable struct S { @id(1) long x; @id(1) long y; };").is_err());
+        assert!(generate("@mutable struct S { @id(1) long x; @id(0) long y; long z; };").is_err());
+        assert!(generate("@mutable struct S { @id(0x10000000) long x; };").is_err());
+        assert!(generate("@mutable @autoid(RANDOM) struct S { long x; };").is_err());
+        assert!(generate("@mutable union U switch (long) { case 0: @id(0) long x; };").is_err());
+        assert!(generate("@mutable struct S { @id(1) long x; @id(0) long y; };").is_ok());
+    }
+
+    #[test]
+    fn cdr() {
+        let config = Configuration {
+            derives: derive_list(&["Clone", "Debug", "PartialEq"]),
+            bounded: true,
+            validate: true,
+            cdr: true,
+            ..Configuration::default()
+        };
+        testvector_verify_with_config("files/test-vectors/cdr", &config);
+    }
+
+    #[test]
+    fn rtps_submessages() {
+        let config = Configuration {
+            bounded: true,
+            cdr: true,
+            ..Configuration::default()
+        };
+        testvector_verify_with_config("files/test-vectors/rtps_submessages", &config);
+    }
+
+    #[test]
+    fn xcdr1() {
+        let config = Configuration {
+            derives: derive_list(&["Clone", "Debug", "PartialEq"]),
+            ..Configuration::default()
+        };
+        testvector_verify_with_config("files/test-vectors/xcdr1", &config);
+    }
+
+    #[test]
+    fn sizes() {
+        let config = Configuration {
+            bounded: true,
+            cdr: true,
+            ..Configuration::default()
+        };
+        testvector_verify_with_config("files/test-vectors/sizes", &config);
+    }
+
+    #[test]
+    fn views() {
+        let config = Configuration {
+            derives: derive_list(&["Clone", "Debug", "PartialEq"]),
+            bounded: true,
+            cdr: true,
+            views: true,
+            ..Configuration::default()
+        };
+        testvector_verify_with_config("files/test-vectors/views", &config);
+    }
+
+    #[test]
+    fn fixed_layout() {
+        let config = Configuration {
+            derives: derive_list(&["Clone", "Debug", "PartialEq"]),
+            cdr: true,
+            fixed_layout: true,
+            ..Configuration::default()
+        };
+        testvector_verify_with_config("files/test-vectors/layout", &config);
+    }
+
+    #[test]
+    fn no_std() {
+        let config = Configuration {
+            derives: derive_list(&["Clone", "Debug", "PartialEq"]),
+            validate: true,
+            cdr: true,
+            no_std: true,
+            heapless: true,
+            ..Configuration::default()
+        };
+        testvector_verify_with_config("files/test-vectors/no_std", &config);
+    }
+
+    #[test]
+    fn type_objects() {
+        let config = Configuration {
+            bounded: true,
+            type_objects: true,
+            ..Configuration::default()
+        };
+        testvector_verify_with_config("files/test-vectors/type_objects", &config);
+    }
+
+    #[test]
+    fn rtps_parameters() {
+        let config = Configuration {
+            bounded: true,
+            cdr: true,
+            ..Configuration::default()
+        };
+        testvector_verify_with_config("files/test-vectors/rtps_parameters", &config);
+    }
+
+    #[test]
+    fn union_labels_invalid() {
+        assert!(generate("union U switch (long) { case 1: long a; case 1: short b; };").is_err());
+        assert!(generate("union U switch (octet) { case 256: long a; };").is_err());
+        assert!(generate("union U switch (long) { case X: long a; };").is_err());
+        assert!(generate("union U switch (boolean) { case TRUE: long a; case FALSE: short b; default: octet c; };")
+            .is_err());
+        assert!(generate("union U switch (long) { case 7: long a; case 42: string b; };").is_ok());
+    }
+
+    #[test]
+    fn enum_values_invalid() {
+        assert!(generate("enum E { A, @value(0) B };").is_err());
+        assert!(generate("@bit_bound(4) enum E { @value(16) A };").is_err());
+        assert!(generate("@bit_bound(33) enum E { A };").is_err());
+        assert!(generate("@bit_bound(4) enum E { @value(15) A, B };").is_err());
+        assert!(generate("@bit_bound(4) enum E { @value(14) A, B };").is_ok());
+    }
+
+    #[test]
+    fn naming_rust_collision() {
+        let config = Configuration {
+            naming: NamingConvention::Rust,
+            ..Configuration::default()
+        };
+        let input = "struct Foo { long fooBar; short foo_bar; };";
+        let mut out = Cursor::new(Vec::new());
+        assert!(generate_with_search_path(&mut out, vec![], &config, input).is_err());
+    }
+
     fn testvector_verify(testvector: &str) {
+        testvector_verify_with_config(testvector, &Configuration::default())
+    }
+
+    fn testvector_verify_with_config(testvector: &str, config: &Configuration) {
         let input_path = Path::new(testvector).join("input.idl");
         let expected_path = Path::new(testvector).join("expected.rs");
 
@@ -304,12 +815,11 @@ mod tests {
         let mut expected = String::new();
         assert!(expected_file.read_to_string(&mut expected).is_ok());
 
-        let config = Configuration::default();
         let search_path = vec![testvector.to_owned()];
 
         // Create fake "file"
         let mut out = Cursor::new(Vec::new());
-        match generate_with_search_path(&mut out, search_path, &config, &input) {
+        match generate_with_search_path(&mut out, search_path, config, &input) {
             Ok(_) => (),
             Err(err) => {
                 eprint!("parse error {:?}", err);
@@ -321,6 +831,22 @@ mod tests {
         assert_eq!(expected_bytes, out.get_ref().as_slice());
     }
 
+    /// Generate from the IDL text with the default configuration, discarding the output
+    fn generate(input: &str) -> Result<(), IdlError> {
+        generate_with_config(input, &Configuration::default())
+    }
+
+    /// Generate from the IDL text, discarding the output
+    fn generate_with_config(input: &str, config: &Configuration) -> Result<(), IdlError> {
+        let mut out = Cursor::new(Vec::new());
+        generate_with_search_path(&mut out, vec![], config, input)
+    }
+
+    /// The derive list of a configuration
+    fn derive_list(names: &[&str]) -> Vec<String> {
+        names.iter().map(|d| d.to_string()).collect()
+    }
+
     fn print_buffer(buf: &Vec<u8>) {
         let content = str::from_utf8(&buf).unwrap();
 
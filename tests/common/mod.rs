@@ -0,0 +1,12 @@
+//! Scaffolding shared by the integration tests
+
+/// Declares the module `generated` holding the Rust code generated for a test vector
+macro_rules! generated {
+    ($vector:literal) => {
+        // IDL naming keeps the IDL identifiers, such as upper case enumerators
+        #[allow(clippy::upper_case_acronyms)]
+        mod generated {
+            include!(concat!(env!("CARGO_MANIFEST_DIR"), "/files/test-vectors/", $vector, "/expected.rs"));
+        }
+    };
+}
@@ -0,0 +1,126 @@
+extern crate rtps_idl;
+
+use rtps_idl::{Configuration, parse_with_search_path};
+use rtps_idl::ast::*;
+use rtps_idl::visit::{self, Visitor, VisitorMut};
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+
+const IDL_DIR: &str = "files/";
+const IDL_ANNOTATED: &str = "files/test-vectors/annotations/input.idl";
+
+fn parse_file(path: &str) -> Specification {
+    let config = Configuration::default();
+    let mut data = String::new();
+
+    File::open(Path::new(path))
+        .and_then(|mut inf| inf.read_to_string(&mut data))
+        .unwrap();
+
+    parse_with_search_path(vec![IDL_DIR.to_owned()], &config, &data).unwrap()
+}
+
+#[derive(Default)]
+struct Collector {
+    types: Vec<String>,
+    members: Vec<String>,
+    annotations: Vec<String>,
+}
+
+impl<'ast> Visitor<'ast> for Collector {
+    fn visit_type_dcl(&mut self, type_dcl: &'ast IdlTypeDcl) {
+        self.types.push(type_dcl.kind.id().unwrap().to_owned());
+        visit::walk_type_dcl(self, type_dcl)
+    }
+
+    fn visit_struct_member(&mut self, member: &'ast IdlStructMember) {
+        self.members.push(member.id.clone());
+        visit::walk_struct_member(self, member)
+    }
+
+    fn visit_annotation(&mut self, annotation: &'ast IdlAnnotation) {
+        self.annotations.push(annotation.id().to_owned());
+    }
+}
+
+#[test]
+fn visit_annotations() {
+    let spec = parse_file(IDL_ANNOTATED);
+    let mut collector = Collector::default();
+    collector.visit_specification(&spec);
+
+    assert_eq!(collector.types, vec!["Sample", "Color", "Choice"]);
+    assert_eq!(collector.members, vec!["id", "level", "count"]);
+    assert_eq!(collector.annotations,
+               vec!["final", "key", "optional", "range", "default",
+                    "appendable", "value", "default_literal",
+                    "extensibility", "external", "verbatim"]);
+}
+
+#[test]
+fn annotation_params_and_spans() {
+    let spec = parse_file(IDL_ANNOTATED);
+    let module = &spec.root.modules["Annotated"];
+    let sample = &module.types["Sample"];
+
+    assert_eq!(sample.span.line, 3);
+    assert!(find_annotation(&sample.annotations, "final").is_some());
+
+    match sample.kind {
+        IdlTypeDclKind::StructDcl(_, ref members) => {
+            let range = find_annotation(&members[1].annotations, "range").unwrap();
+            match range.param("max") {
+                Some(IdlValueExpr::DecLiteral(ref max)) => assert_eq!(max, "100"),
+                other => panic!("unexpected {:?}", other),
+            }
+            let default = find_annotation(&members[2].annotations, "default").unwrap();
+            match default.value() {
+                Some(IdlValueExpr::DecLiteral(ref value)) => assert_eq!(value, "7"),
+                other => panic!("unexpected {:?}", other),
+            }
+            assert_eq!(members[2].span.line, 6);
+        }
+        ref other => panic!("unexpected {:?}", other),
+    }
+
+    let max = &module.constants["MAX"];
+    assert!(find_annotation(&max.annotations, "verbatim").is_some());
+}
+
+#[test]
+fn spans_of_included_files() {
+    let config = Configuration::default();
+    let search_path = vec!["files/test-vectors/include_directive".to_owned()];
+    let spec = parse_with_search_path(search_path, &config,
+                                      "#include<simple.idl>\nstruct Outer { long a; };").unwrap();
+    let module = &spec.root.modules["ModuleA"];
+    let seq_long = &module.types["seq_long"];
+
+    assert_eq!(seq_long.span.file, Some("simple.idl".to_owned()));
+    assert_eq!(seq_long.span.line, 6);
+    assert_eq!(module.span.file, Some("simple.idl".to_owned()));
+
+    let outer = &spec.root.types["Outer"];
+    assert_eq!(outer.span.file, None);
+    assert_eq!(outer.span.line, 2);
+}
+
+struct Renamer;
+
+impl VisitorMut for Renamer {
+    fn visit_struct_member_mut(&mut self, member: &mut IdlStructMember) {
+        member.id = member.id.to_uppercase();
+        visit::walk_struct_member_mut(self, member)
+    }
+}
+
+#[test]
+fn visit_mut_rename() {
+    let mut spec = parse_file(IDL_ANNOTATED);
+    Renamer.visit_specification_mut(&mut spec);
+
+    let mut collector = Collector::default();
+    collector.visit_specification(&spec);
+    assert_eq!(collector.members, vec!["ID", "LEVEL", "COUNT"]);
+}
@@ -0,0 +1,38 @@
+extern crate rtps_types;
+extern crate serde_json;
+
+#[macro_use]
+mod common;
+
+generated!("bounded");
+
+use std::convert::TryFrom;
+use generated::Bounded::{Name, Payload, Sample};
+
+fn sample(name: &str) -> Sample {
+    Sample {
+        name: Name::try_from(name).unwrap(),
+        description: Default::default(),
+        aliases: Default::default(),
+        matrix: vec![TryFrom::try_from(vec![1, 2, 3]).unwrap()],
+        payload: Payload::try_from(vec![0u8; 64]).unwrap(),
+    }
+}
+
+#[test]
+fn bounded_members() {
+    assert!(Name::try_from("a name beyond 16 bytes").is_err());
+    let mut sample = sample("participant");
+    assert!(sample.payload.push(0).is_err());
+    assert!(sample.aliases.push(sample.name.clone()).is_ok());
+    assert_eq!(generated::Bounded::MAX_NAME as usize, Name::BOUND);
+}
+
+#[test]
+fn oversize_data_is_rejected() {
+    let json = serde_json::to_string(&sample("participant")).unwrap();
+    assert!(serde_json::from_str::<Sample>(&json).is_ok());
+
+    let oversize = json.replace("participant", "participant-00001");
+    assert!(serde_json::from_str::<Sample>(&oversize).is_err());
+}
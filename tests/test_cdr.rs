@@ -0,0 +1,101 @@
+extern crate rtps_cdr;
+extern crate rtps_elements;
+extern crate serde_json;
+
+#[macro_use]
+mod common;
+
+generated!("xcdr1");
+
+use rtps_cdr::{decode_from_slice, encode_to_vec, from_slice, to_vec, Encoding, Endianness};
+use rtps_elements::DDS::{self, DCPS};
+use generated::Wire::{Code, Reading};
+
+#[test]
+fn guid_on_the_wire() {
+    let guid = DCPS::GUID_t {
+        guidPrefix: [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12],
+        entityId: DCPS::EntityId_t { entityKey: [0, 0, 1], entityKind: DCPS::ENTITYKIND_BUILTIN_PARTICIPANT },
+    };
+    let bytes = to_vec(&guid, Endianness::Big).unwrap();
+    assert_eq!(bytes, [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 0, 0, 1, 0xc1]);
+    let decoded: DCPS::GUID_t = from_slice(&bytes, Endianness::Big).unwrap();
+    assert_eq!(decoded.guidPrefix, guid.guidPrefix);
+    assert_eq!(decoded.entityId.entityKind, 0xc1);
+}
+
+#[test]
+fn builtin_topic_data_round_trip() {
+    let mut data = DDS::PublicationBuiltinTopicData {
+        topic_name: "Square".to_owned(),
+        type_name: "ShapeType".to_owned(),
+        ..Default::default()
+    };
+    data.reliability.kind = DDS::ReliabilityQosPolicyKind::RELIABLE_RELIABILITY_QOS;
+    data.partition.name = vec!["a".to_owned(), "bc".to_owned()];
+    data.user_data.value = vec![1, 2, 3];
+    for endianness in [Endianness::Big, Endianness::Little] {
+        let bytes = to_vec(&data, endianness).unwrap();
+        let decoded: DDS::PublicationBuiltinTopicData = from_slice(&bytes, endianness).unwrap();
+        assert_eq!(decoded.topic_name, "Square");
+        assert_eq!(decoded.partition.name, data.partition.name);
+        assert_eq!(to_vec(&decoded, endianness).unwrap(), bytes);
+    }
+}
+
+#[test]
+fn union_labels_on_the_wire() {
+    // the discriminator is the case label, not the index of the case
+    let number = Code::number { number: 3 };
+    let bytes = to_vec(&number, Endianness::Big).unwrap();
+    assert_eq!(bytes, [0, 0, 0, 7, 0, 0, 0, 3]);
+    assert_eq!(from_slice::<Code>(&bytes, Endianness::Big).unwrap(), number);
+
+    let text = Code::text { text: "ab".to_owned() };
+    let bytes = to_vec(&text, Endianness::Little).unwrap();
+    assert_eq!(bytes, [42, 0, 0, 0, 3, 0, 0, 0, b'a', b'b', 0]);
+    assert_eq!(from_slice::<Code>(&bytes, Endianness::Little).unwrap(), text);
+
+    assert!(from_slice::<Code>(&[0, 0, 0, 1, 0, 0, 0, 3], Endianness::Big).is_err());
+    // human readable formats keep the derived representation
+    assert_eq!(serde_json::to_string(&text).unwrap(), "{\"text\":{\"text\":\"ab\"}}");
+}
+
+#[test]
+fn optional_members_on_the_wire() {
+    let absent = Reading { id: 1, value: None, code: Code::number { number: 3 } };
+    let bytes = to_vec(&absent, Endianness::Big).unwrap();
+    assert_eq!(bytes, [
+        1, 0, 0, 0, // id, padding up to 4
+        0, 1, 0, 0, // parameter header of value, member ID 1, length 0
+        0, 0, 0, 7, 0, 0, 0, 3, // code
+    ]);
+    assert_eq!(from_slice::<Reading>(&bytes, Endianness::Big).unwrap(), absent);
+
+    let present = Reading { value: Some(9), ..absent };
+    let bytes = to_vec(&present, Endianness::Big).unwrap();
+    assert_eq!(bytes, [
+        1, 0, 0, 0, // id, padding up to 4
+        0, 1, 0, 8, // parameter header of value, member ID 1, length 8
+        0, 0, 0, 0, 0, 0, 0, 9, // value, aligned relative to its first byte
+        0, 0, 0, 7, 0, 0, 0, 3, // code
+    ]);
+    assert_eq!(from_slice::<Reading>(&bytes, Endianness::Big).unwrap(), present);
+}
+
+#[test]
+fn builtin_topic_data_codec() {
+    let mut data = DDS::SubscriptionBuiltinTopicData {
+        topic_name: "Circle".to_owned(),
+        ..Default::default()
+    };
+    data.partition.name = vec!["a".to_owned()];
+    data.user_data.value = vec![4, 5];
+    for endianness in [Endianness::Big, Endianness::Little] {
+        let bytes = encode_to_vec(&data, endianness, Encoding::Xcdr1).unwrap();
+        assert_eq!(bytes, to_vec(&data, endianness).unwrap());
+        let decoded: DDS::SubscriptionBuiltinTopicData = decode_from_slice(&bytes, endianness, Encoding::Xcdr1).unwrap();
+        assert_eq!(decoded.topic_name, "Circle");
+        assert_eq!(decoded.user_data.value, data.user_data.value);
+    }
+}
@@ -0,0 +1,35 @@
+extern crate rtps_types;
+extern crate serde_json;
+
+#[macro_use]
+mod common;
+
+generated!("chars_wire");
+
+use std::convert::TryFrom;
+use generated::Chars::{Text, EURO, SEPARATOR, WIDE_GREETING};
+use rtps_types::{BoundedWString, Char, WChar, WString};
+
+#[test]
+fn wire_chars() {
+    let text = Text {
+        initial: SEPARATOR,
+        symbol: EURO,
+        name: "name".to_owned(),
+        label: WString::from(WIDE_GREETING),
+        code: BoundedWString::try_from("€uro").unwrap(),
+        letters: [Char(b'a'); 4],
+        symbols: vec![WChar::try_from('ß').unwrap()],
+    };
+    assert_eq!(text.label.to_utf8().unwrap(), WIDE_GREETING);
+
+    // chars are octets, wide chars and strings UTF-16 code units
+    let json = serde_json::to_string(&text).unwrap();
+    assert!(json.starts_with("{\"initial\":58,\"symbol\":8364,"));
+    assert!(json.contains("\"code\":[8364,117,114,111]"));
+    assert!(json.contains("\"symbols\":[223]"));
+
+    let decoded: Text = serde_json::from_str(&json).unwrap();
+    assert_eq!(decoded.code, text.code);
+    assert!(BoundedWString::<8>::try_from("more than 8").is_err());
+}
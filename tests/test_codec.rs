@@ -0,0 +1,256 @@
+extern crate rtps_cdr;
+
+#[macro_use]
+mod common;
+
+generated!("cdr");
+
+use std::convert::TryFrom;
+use rtps_cdr::{decode_from_slice, decode_payload, encode_payload, encode_to_vec, from_payload, from_slice_with_encoding,
+               to_payload, to_vec_with_encoding, CdrDecode, CdrEncode, Encoding, Endianness};
+use rtps_types::xtypes::Extensible;
+use generated::Telemetry::{App, Code, Command, Config, ConfigName, Empty, Frame, Header, Nothing, Range, Reading, Selection,
+                           Setting, Status, Window};
+
+const ENDIANNESSES: [Endianness; 2] = [Endianness::Big, Endianness::Little];
+const ENCODINGS: [Encoding; 2] = [Encoding::Xcdr1, Encoding::Xcdr2];
+
+/// Verify the generated codec encodes and decodes the value as the serde impls do
+fn round_trip<T>(value: &T)
+    where T: CdrEncode + CdrDecode + serde::Serialize + serde::de::DeserializeOwned + PartialEq + std::fmt::Debug
+{
+    for &endianness in &ENDIANNESSES {
+        for &encoding in &ENCODINGS {
+            let bytes = encode_to_vec(value, endianness, encoding).unwrap();
+            assert_eq!(bytes, to_vec_with_encoding(value, endianness, encoding).unwrap());
+            assert_eq!(&decode_from_slice::<T>(&bytes, endianness, encoding).unwrap(), value);
+            assert_eq!(&from_slice_with_encoding::<T>(&bytes, endianness, encoding).unwrap(), value);
+        }
+    }
+}
+
+fn header() -> Header {
+    Header { stamp: 7, quality: 80, status: Status::ACTIVE, source: TryFrom::try_from("probe").unwrap() }
+}
+
+fn config() -> Config {
+    Config {
+        name: "a".to_owned(),
+        status: Status::FAILED,
+        ranges: vec![Range { low: 1, high: 2 }],
+        window: Some(Window { range: Range { low: 0, high: 9 }, gaps: vec![], limit: None }),
+        level: Some(3),
+        reading: Reading::text { text: "x".to_owned() },
+    }
+}
+
+#[test]
+fn final_types() {
+    let frame = Frame {
+        header: header(),
+        samples: vec![0.5, -1.0, 2.25],
+        counters: TryFrom::try_from(vec![1i16, -2, 3]).unwrap(),
+        matrix: [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]],
+        tags: vec!["x".to_owned(), String::new()],
+        history: Some(vec![header(), Header::default()]),
+        offset: Some(-4),
+        marker: Empty {},
+    };
+    round_trip(&frame);
+    round_trip(&Frame::default());
+    round_trip(&Reading::value { value: 1.5 });
+    round_trip(&Reading::ranges { ranges: vec![Range { low: 3, high: 4 }] });
+}
+
+#[test]
+fn optional_members() {
+    // XCDR1 precedes an optional member by a parameter header of its member ID, of length 0
+    // if absent, XCDR2 by a boolean
+    let app = App { x: 3, s: Some("hi".to_owned()) };
+    let absent = App { x: 3, s: None };
+    let vectors: [(&App, Encoding, &[u8]); 4] = [
+        (&app, Encoding::Xcdr1, &[3, 0, 0, 0, 1, 0, 8, 0, 3, 0, 0, 0, b'h', b'i', 0, 0]),
+        (&absent, Encoding::Xcdr1, &[3, 0, 0, 0, 1, 0, 0, 0]),
+        (&app, Encoding::Xcdr2, &[3, 0, 0, 0, 1, 0, 0, 0, 3, 0, 0, 0, b'h', b'i', 0]),
+        (&absent, Encoding::Xcdr2, &[3, 0, 0, 0, 0]),
+    ];
+    for (value, encoding, bytes) in vectors {
+        assert_eq!(encode_to_vec(value, Endianness::Little, encoding).unwrap(), bytes);
+        assert_eq!(to_vec_with_encoding(value, Endianness::Little, encoding).unwrap(), bytes);
+        assert_eq!(&decode_from_slice::<App>(bytes, Endianness::Little, encoding).unwrap(), value);
+        assert_eq!(&from_slice_with_encoding::<App>(bytes, Endianness::Little, encoding).unwrap(), value);
+    }
+    round_trip(&app);
+    round_trip(&absent);
+}
+
+#[test]
+fn union_labels() {
+    // the discriminator is the case label at the width of the switch type, `default` being
+    // encoded by a value no case lists
+    let vectors: [(Code, &[u8]); 3] = [
+        (Code::a { a: 1 }, &[0, 0, 0, 7, 0, 0, 0, 1]),
+        (Code::b { b: 2 }, &[0, 0, 0, 42, 2]),
+        (Code::default { other: 3 }, &[0, 0, 0, 0, 0, 3]),
+    ];
+    for (value, bytes) in &vectors {
+        assert_eq!(encode_to_vec(value, Endianness::Big, Encoding::Xcdr1).unwrap(), *bytes);
+        assert_eq!(to_vec_with_encoding(value, Endianness::Big, Encoding::Xcdr1).unwrap(), *bytes);
+        assert_eq!(&decode_from_slice::<Code>(bytes, Endianness::Big, Encoding::Xcdr1).unwrap(), value);
+        assert_eq!(&from_slice_with_encoding::<Code>(bytes, Endianness::Big, Encoding::Xcdr1).unwrap(), value);
+        round_trip(value);
+    }
+    let bytes = [0, 0, 0, 43, 4];
+    assert_eq!(decode_from_slice::<Code>(&bytes, Endianness::Big, Encoding::Xcdr1).unwrap(), Code::b { b: 4 });
+    assert_eq!(from_slice_with_encoding::<Code>(&bytes, Endianness::Big, Encoding::Xcdr1).unwrap(), Code::b { b: 4 });
+    let bytes = [0, 0, 0, 9, 0, 5];
+    assert_eq!(decode_from_slice::<Code>(&bytes, Endianness::Big, Encoding::Xcdr1).unwrap(),
+               Code::default { other: 5 });
+    assert_eq!(from_slice_with_encoding::<Code>(&bytes, Endianness::Big, Encoding::Xcdr1).unwrap(),
+               Code::default { other: 5 });
+
+    // enumerators are given by their value
+    let selection = Selection::K_B { b: 1 };
+    let bytes = [0, 0, 0, 5, 0, 0, 0, 1];
+    assert_eq!(encode_to_vec(&selection, Endianness::Big, Encoding::Xcdr1).unwrap(), bytes);
+    assert_eq!(to_vec_with_encoding(&selection, Endianness::Big, Encoding::Xcdr1).unwrap(), bytes);
+    assert_eq!(decode_from_slice::<Selection>(&bytes, Endianness::Big, Encoding::Xcdr1).unwrap(), selection);
+    assert_eq!(from_slice_with_encoding::<Selection>(&bytes, Endianness::Big, Encoding::Xcdr1).unwrap(), selection);
+    round_trip(&selection);
+    round_trip(&Selection::K_C { c: "c".to_owned() });
+    let bytes = [0, 0, 0, 0, 0, 0, 0, 1];
+    let err = decode_from_slice::<Selection>(&bytes, Endianness::Big, Encoding::Xcdr1).unwrap_err();
+    assert_eq!(err.to_string(), "invalid discriminator 0 of union Telemetry::Selection");
+    let err = from_slice_with_encoding::<Selection>(&bytes, Endianness::Big, Encoding::Xcdr1).unwrap_err();
+    assert_eq!(err.to_string(), "invalid discriminator 0 of union Telemetry::Selection");
+
+    // a discriminator of type short is encoded as such, in mutable unions as member 0
+    let bytes = [0, 0, 0, 3, 0, 0, 1];
+    assert_eq!(encode_to_vec(&Setting::enabled { enabled: true }, Endianness::Big, Encoding::Xcdr2).unwrap(), bytes);
+    let bytes = encode_to_vec(&Command::stop { stop: vec![] }, Endianness::Big, Encoding::Xcdr2).unwrap();
+    assert_eq!(bytes[..16], [0, 0, 0, 28, 0xc0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 1]);
+}
+
+#[test]
+fn appendable_types() {
+    let window = Window {
+        range: Range { low: 1, high: 2 },
+        gaps: vec![Range { low: 3, high: 4 }],
+        limit: Some(Range { low: 0, high: 10 }),
+    };
+    round_trip(&window);
+    round_trip(&Setting::enabled { enabled: true });
+    round_trip(&Setting::range { range: Range { low: -1, high: 1 } });
+
+    // members not encoded are read as default
+    let bytes = encode_to_vec(&0u32, Endianness::Little, Encoding::Xcdr2).unwrap();
+    let range = decode_from_slice::<Range>(&bytes, Endianness::Little, Encoding::Xcdr2).unwrap();
+    assert_eq!(range, Range::default());
+}
+
+#[test]
+fn mutable_types() {
+    round_trip(&config());
+    round_trip(&Config { window: None, level: None, ..config() });
+    round_trip(&Command::start { start: "now".to_owned() });
+    round_trip(&Command::stop { stop: vec![Range { low: 5, high: 6 }] });
+    round_trip(&Command::configure { configure: config() });
+    round_trip(&Nothing {});
+
+    // unknown members are skipped unless these must be understood
+    for &encoding in &ENCODINGS {
+        let bytes = encode_to_vec(&Config { level: None, ..config() }, Endianness::Big, encoding).unwrap();
+        let name = decode_from_slice::<ConfigName>(&bytes, Endianness::Big, encoding).unwrap();
+        assert_eq!(name, ConfigName { name: "a".to_owned(), status: Status::FAILED });
+        let bytes = encode_to_vec(&config(), Endianness::Big, encoding).unwrap();
+        let err = decode_from_slice::<ConfigName>(&bytes, Endianness::Big, encoding).unwrap_err();
+        assert_eq!(err.to_string(), "unknown member 13 must be understood");
+
+        // a later version reads the members not encoded as default
+        let bytes = encode_to_vec(&name, Endianness::Big, encoding).unwrap();
+        let config = decode_from_slice::<Config>(&bytes, Endianness::Big, encoding).unwrap();
+        assert_eq!(config, Config { name: "a".to_owned(), status: Status::FAILED, ..Config::default() });
+    }
+}
+
+#[test]
+fn invalid_input() {
+    let decode = |bytes: &[u8]| decode_from_slice::<Header>(bytes, Endianness::Big, Encoding::Xcdr1);
+    let bytes = encode_to_vec(&Header { quality: 101, ..header() }, Endianness::Big, Encoding::Xcdr1).unwrap();
+    assert!(decode(&bytes).is_err());
+    let mut bytes = encode_to_vec(&header(), Endianness::Big, Encoding::Xcdr1).unwrap();
+    bytes[12] = 2;
+    assert!(decode(&bytes).is_err());
+
+    let bytes = encode_to_vec(&3u32, Endianness::Big, Encoding::Xcdr1).unwrap();
+    let err = decode_from_slice::<Reading>(&bytes, Endianness::Big, Encoding::Xcdr1).unwrap_err();
+    assert_eq!(err.to_string(), "invalid discriminator 3 of union Telemetry::Reading");
+}
+
+/// Verify the generated codec encodes the value as `bytes` and decodes these
+fn wire<T: CdrEncode + CdrDecode + PartialEq + std::fmt::Debug>(value: &T, endianness: Endianness, encoding: Encoding,
+                                                              bytes: &[u8]) {
+    assert_eq!(encode_to_vec(value, endianness, encoding).unwrap(), bytes);
+    assert_eq!(&decode_from_slice::<T>(bytes, endianness, encoding).unwrap(), value);
+}
+
+#[test]
+fn wire_bytes() {
+    // the codec on its own, as the other tests compare it with the serde impls
+    let header_bytes = [
+        0, 0, 0, 0, 0, 0, 0, 7, // stamp
+        80, 0, 0, 0, // quality, padding up to 4
+        0, 0, 0, 1, // status
+        0, 0, 0, 6, b'p', b'r', b'o', b'b', b'e', 0, // source
+    ];
+    wire(&header(), Endianness::Big, Encoding::Xcdr1, &header_bytes);
+    wire(&header(), Endianness::Big, Encoding::Xcdr2, &header_bytes);
+
+    let range = Range { low: 1, high: 2 };
+    wire(&range, Endianness::Little, Encoding::Xcdr1, &[1, 0, 0, 0, 2, 0, 0, 0]);
+    wire(&range, Endianness::Little, Encoding::Xcdr2, &[8, 0, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0]);
+
+    let name = ConfigName { name: "a".to_owned(), status: Status::FAILED };
+    wire(&name, Endianness::Big, Encoding::Xcdr1, &[
+        0x40, 0, 0, 8, 0, 0, 0, 2, b'a', 0, 0, 0, // name, a must-understand key
+        0, 10, 0, 4, 0, 0, 0, 8, // status
+        0x7f, 0x02, 0, 0, // PID_LIST_END
+    ]);
+    wire(&name, Endianness::Big, Encoding::Xcdr2, &[
+        0, 0, 0, 28, // DHEADER
+        0xc0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 2, b'a', 0, 0, 0, // EMHEADER and NEXTINT of name, padding up to 4
+        0x40, 0, 0, 10, 0, 0, 0, 4, 0, 0, 0, 8, // status
+    ]);
+    wire(&Nothing {}, Endianness::Big, Encoding::Xcdr1, &[0x7f, 0x02, 0, 0]);
+    wire(&Nothing {}, Endianness::Big, Encoding::Xcdr2, &[0, 0, 0, 0]);
+}
+
+/// Verify both encode the payload with the encapsulation identifier `id` in big endian
+fn payload<T>(value: &T, encoding: Encoding, id: u8)
+    where T: CdrEncode + CdrDecode + Extensible + serde::Serialize + serde::de::DeserializeOwned + PartialEq
+             + std::fmt::Debug
+{
+    for &endianness in &ENDIANNESSES {
+        let payload = encode_payload(value, endianness, encoding).unwrap();
+        assert_eq!(payload, to_payload(value, endianness, encoding).unwrap());
+        assert_eq!(payload[..2], [0, id | (endianness == Endianness::Little) as u8]);
+        assert_eq!(payload.len() % 4, 0);
+        assert_eq!(&decode_payload::<T>(&payload).unwrap(), value);
+        assert_eq!(&from_payload::<T>(&payload).unwrap(), value);
+    }
+}
+
+#[test]
+fn payloads() {
+    payload(&header(), Encoding::Xcdr1, 0x00);
+    payload(&header(), Encoding::Xcdr2, 0x10);
+    payload(&Range { low: 1, high: 2 }, Encoding::Xcdr1, 0x00);
+    payload(&Range { low: 1, high: 2 }, Encoding::Xcdr2, 0x14);
+    payload(&config(), Encoding::Xcdr1, 0x02);
+    payload(&config(), Encoding::Xcdr2, 0x12);
+    payload(&Setting::enabled { enabled: true }, Encoding::Xcdr2, 0x14);
+
+    // the header of a final type does not encode a mutable one
+    let bytes = encode_payload(&header(), Endianness::Little, Encoding::Xcdr1).unwrap();
+    assert!(decode_payload::<Config>(&bytes).is_err());
+}
@@ -0,0 +1,36 @@
+extern crate rtps_types;
+
+// IDL naming keeps the enumerators upper case
+#[macro_use]
+mod common;
+
+generated!("defaults");
+
+use generated::Defaults::{ByMode, ByNumber, Config, Mode, Plain};
+
+#[test]
+fn struct_defaults() {
+    let config = Config::default();
+    assert_eq!(config.count, 42);
+    assert_eq!(config.ratio, 1.0);
+    assert!(config.enabled);
+    assert_eq!(config.tag, 'x');
+    assert_eq!(config.name, "main");
+    assert_eq!(config.label, "abc");
+    assert_eq!(config.mode, Mode::STOPPED);
+    assert_eq!(config.stamp, 0);
+    assert_eq!(config.block, [0u8; 64]);
+    assert_eq!(config.table, [[0i16; 40]; 4]);
+
+    // the first enumerator, unless annotated by @default_literal
+    let plain = Plain::default();
+    assert_eq!(plain.mode, Mode::RUNNING);
+    assert_eq!(plain.values, [0; 8]);
+}
+
+#[test]
+fn union_defaults() {
+    // the branch of the default discriminator
+    assert_eq!(ByMode::default(), ByMode::RUNNING { running: 3 });
+    assert_eq!(ByNumber::default(), ByNumber::zero { zero: [0; 64] });
+}
@@ -0,0 +1,43 @@
+extern crate rtps_types;
+extern crate serde_json;
+
+#[macro_use]
+mod common;
+
+generated!("enums");
+
+use std::convert::TryFrom;
+use generated::Enums::{Color, Flag, Level, Setting};
+use rtps_types::EnumError;
+
+#[test]
+fn enum_values() {
+    assert_eq!(Level::LOW.value(), 10);
+    assert_eq!(Level::MEDIUM as u8, 11);
+    assert_eq!(u32::from(Level::EXTREME), 100);
+    assert_eq!(u16::from(Flag::TOP), 0x800);
+    assert_eq!(Level::try_from(12u8), Ok(Level::HIGH));
+    assert_eq!(Color::try_from(3u32), Err(EnumError::Value(3)));
+    assert_eq!(std::mem::size_of::<Level>(), 1);
+    assert_eq!(std::mem::size_of::<Flag>(), 2);
+
+    // the first enumerator, unless annotated by @default_literal
+    assert_eq!(Color::default(), Color::RED);
+    assert_eq!(Level::default(), Level::HIGH);
+}
+
+#[test]
+fn enum_names() {
+    assert_eq!(Level::MEDIUM.to_string(), "MEDIUM");
+    assert_eq!("BLUE".parse::<Color>(), Ok(Color::BLUE));
+    assert_eq!("PURPLE".parse::<Color>(), Err(EnumError::Name("PURPLE".to_owned())));
+}
+
+#[test]
+fn enum_serde() {
+    let setting = Setting { color: Color::GREEN, level: Level::EXTREME };
+    let json = serde_json::to_string(&setting).unwrap();
+    assert_eq!(json, "{\"color\":1,\"level\":100}");
+    assert_eq!(serde_json::from_str::<Setting>(&json).unwrap(), setting);
+    assert!(serde_json::from_str::<Setting>("{\"color\":1,\"level\":99}").is_err());
+}
@@ -0,0 +1,205 @@
+extern crate rtps_idl;
+
+use rtps_idl::{Configuration, ExternalMapping, IdlError, IdlLoader, generate_with_generator};
+use rtps_idl::ast::{IdlTypeDcl, Specification};
+use rtps_idl::generator::{self, Generator};
+use rtps_idl::visit::{self, Visitor};
+use std::io::{Error, ErrorKind, Write};
+
+struct NoIncludes;
+
+impl IdlLoader for NoIncludes {
+    fn load(&self, _filename: &str) -> Result<String, Error> {
+        Err(Error::from(ErrorKind::NotFound))
+    }
+}
+
+/// Backend listing the declared types, one per line
+struct TypeList;
+
+struct TypeNames(Vec<String>);
+
+impl<'ast> Visitor<'ast> for TypeNames {
+    fn visit_type_dcl(&mut self, type_dcl: &'ast IdlTypeDcl) {
+        self.0.push(type_dcl.kind.id().unwrap().to_owned());
+        visit::walk_type_dcl(self, type_dcl)
+    }
+}
+
+impl Generator for TypeList {
+    fn name(&self) -> &str {
+        "type-list"
+    }
+
+    fn generate(&self, out: &mut dyn Write, spec: &Specification, _config: &Configuration)
+                -> Result<(), IdlError> {
+        let mut names = TypeNames(Vec::new());
+        names.visit_specification(spec);
+        for name in names.0 {
+            writeln!(out, "{}", name).map_err(|_| IdlError::InternalError)?;
+        }
+        Ok(())
+    }
+}
+
+const IDL: &str = "module M { struct A { long a; }; typedef sequence<A> ASeq; }; enum E { X, Y };";
+
+#[test]
+fn custom_generator() {
+    let mut out = Vec::new();
+    generate_with_generator(&mut out, &mut NoIncludes, &TypeList, &Configuration::default(), IDL)
+        .unwrap();
+
+    assert_eq!(String::from_utf8(out).unwrap(), "E\nA\nASeq\n");
+}
+
+#[test]
+fn rust_generator_by_name() {
+    let rust = generator::by_name("rust").unwrap();
+    assert_eq!(rust.name(), "rust");
+    assert!(generator::by_name("cobol").is_none());
+
+    let mut out = Vec::new();
+    generate_with_generator(&mut out, &mut NoIncludes, rust.as_ref(), &Configuration::default(), IDL)
+        .unwrap();
+
+    let mut expected = Vec::new();
+    rtps_idl::generate_with_loader(&mut expected, &mut NoIncludes, &Configuration::default(), IDL)
+        .unwrap();
+    assert_eq!(out, expected);
+    assert!(String::from_utf8(out).unwrap().contains("pub struct A {"));
+}
+
+#[test]
+fn rust_generator_tokens() {
+    let spec = rtps_idl::parse_with_loader(&mut NoIncludes, &Configuration::default(), IDL).unwrap();
+    let tokens = generator::rust::generate_tokens(&spec, &Configuration::default()).unwrap();
+
+    // the token stream forms a valid Rust source file
+    let file = syn::parse2::<syn::File>(tokens).unwrap();
+    let decls = file.items.iter().filter(|item| !matches!(item, syn::Item::Impl(_)));
+    assert_eq!(decls.count(), 4);
+}
+
+#[test]
+fn type_map_validation() {
+    let generate = |toml: &str| {
+        let config = Configuration::from_toml(toml)?;
+        let spec = rtps_idl::parse_with_loader(&mut NoIncludes, &config, IDL)?;
+        generator::rust::generate_tokens(&spec, &config)
+    };
+
+    assert!(generate("[types]\n\"M::A\" = \"my::A\"\n\"sequence<M::A>\" = \"my::ASeq\"").is_ok());
+    assert!(generate("[types]\n\"::E\" = \"my::E\"").is_ok());
+
+    // the IDL type must be declared and the Rust type well-formed
+    match generate("[types]\n\"M::B\" = \"my::B\"") {
+        Err(IdlError::ErrorMesg(mesg)) => assert!(mesg.contains("'M::B' does not name a type")),
+        other => panic!("unexpected {:?}", other.map(|_| ())),
+    }
+    assert!(generate("[types]\n\"sequence<\" = \"Vec<u8>\"").is_err());
+    assert!(generate("[types]\n\"M::A\" = \"my A\"").is_err());
+
+    // unknown keys and naming conventions are rejected
+    assert!(Configuration::from_toml("derive = [\"Hash\"]").is_err());
+    assert!(Configuration::from_toml("naming = \"camel\"").is_err());
+    assert!(Configuration::from_toml("external = \"rc\"").is_err());
+    assert_eq!(Configuration::from_toml("external = \"arc\"").unwrap().external, ExternalMapping::Arc);
+    assert!(Configuration::from_toml("validate = true").unwrap().validate);
+    let config = Configuration::from_toml("views = true").unwrap();
+    assert!(config.views && config.cdr);
+    assert!(Configuration::from_toml("fixed_layout = true").unwrap().fixed_layout);
+    let config = Configuration::from_toml("no_std = true\nheapless = true").unwrap();
+    assert!(config.no_std && config.heapless);
+    assert!(Configuration::from_toml("type_objects = true").unwrap().type_objects);
+
+    // the keyword suffix must form identifiers which are no keywords
+    assert!(Configuration::from_toml("keyword_suffix = \"_\"").is_ok());
+    assert!(Configuration::from_toml("keyword_suffix = \"-\"").is_err());
+    assert!(Configuration::from_toml("keyword_suffix = \"of\"").is_err());
+    let config = Configuration { keyword_suffix: Some(String::new()), ..Configuration::default() };
+    let spec = rtps_idl::parse_with_loader(&mut NoIncludes, &config, IDL).unwrap();
+    assert!(generator::rust::generate_tokens(&spec, &config).is_err());
+}
+
+#[test]
+fn native_requires_mapping() {
+    const NATIVE_IDL: &str = "module M { native H; struct A { H h; }; };";
+    let generate = |config: &Configuration| {
+        let spec = rtps_idl::parse_with_loader(&mut NoIncludes, config, NATIVE_IDL)?;
+        generator::rust::generate_file(&spec, config)
+    };
+
+    match generate(&Configuration::default()) {
+        Err(IdlError::ErrorMesg(mesg)) => assert!(mesg.contains("native type 'M::H' requires a type mapping")),
+        other => panic!("unexpected {:?}", other),
+    }
+
+    let mut config = Configuration::default();
+    config.type_map.insert("M::H".to_owned(), "my::Handle".to_owned());
+    assert!(generate(&config).unwrap().contains("pub type H = my::Handle;"));
+
+    let config = Configuration { native_path: Some("crate::ffi".to_owned()), ..Configuration::default() };
+    assert!(generate(&config).unwrap().contains("pub use crate::ffi::H;"));
+}
+
+#[test]
+fn view_methods() {
+    const VIEW_IDL: &str = "module M { struct A { long new; }; };";
+    let config = Configuration { cdr: true, views: true, ..Configuration::default() };
+    let spec = rtps_idl::parse_with_loader(&mut NoIncludes, &config, VIEW_IDL).unwrap();
+    match generator::rust::generate_file(&spec, &config) {
+        Err(IdlError::ErrorMesg(mesg)) => assert!(mesg.contains("member 'new' of 'M::A' collides with a method of its view")),
+        other => panic!("unexpected {:?}", other),
+    }
+}
+
+#[test]
+fn fixed_layout_rejects() {
+    let config = Configuration { fixed_layout: true, ..Configuration::default() };
+    let generate = |idl: &str| {
+        let spec = rtps_idl::parse_with_loader(&mut NoIncludes, &config, idl).unwrap();
+        match generator::rust::generate_file(&spec, &config) {
+            Err(IdlError::ErrorMesg(mesg)) => mesg,
+            other => panic!("unexpected {:?}", other),
+        }
+    };
+
+    assert_eq!(generate("module M { struct A { string s; }; };"),
+               "'M::A' has no fixed layout, member 's': unbounded string");
+    assert_eq!(generate("module M { typedef sequence<long> L; };"),
+               "'M::L' has no fixed layout, unbounded sequence");
+    assert_eq!(generate("module M { union U switch (long) { case 1: long a; }; };"),
+               "'M::U' has no fixed layout, being a union");
+    assert_eq!(generate("module M { struct A { @optional long a; }; };"),
+               "'M::A' has no fixed layout, member 'a': @optional");
+
+    // CDR does not align a struct, and places the next member within its trailing padding
+    assert_eq!(generate("module M { struct In { octet a; long b; }; struct A { octet c; In i; }; };"),
+               "'M::A' has no fixed layout, member 'i': at CDR offset 1, not aligned to 4 in memory");
+    assert_eq!(generate("module M { struct In { long b; octet a; }; struct A { In i; octet c; }; };"),
+               "'M::A' has no fixed layout, member 'c': at CDR offset 5, within the padding of the preceding member");
+    assert_eq!(generate("module M { struct A { sequence<string<2>, 2> s; }; };"),
+               "'M::A' has no fixed layout, member 's': elements 8 bytes apart in CDR, 7 in memory");
+}
+
+#[test]
+fn type_objects_reject() {
+    let config = Configuration {
+        type_objects: true,
+        native_path: Some("crate::ffi".to_owned()),
+        ..Configuration::default()
+    };
+    let generate = |idl: &str| {
+        let spec = rtps_idl::parse_with_loader(&mut NoIncludes, &config, idl).unwrap();
+        match generator::rust::generate_file(&spec, &config) {
+            Err(IdlError::ErrorMesg(mesg)) => mesg,
+            other => panic!("unexpected {:?}", other),
+        }
+    };
+
+    assert_eq!(generate("module M { native H; struct A { H h; }; };"),
+               "'M::A' has no type object, member 'h': native type 'M::H'");
+    assert_eq!(generate("module M { struct A { sequence<A> children; }; };"),
+               "'M::A' has no type object, member 'children': recursive type 'M::A'");
+}
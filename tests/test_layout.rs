@@ -0,0 +1,109 @@
+extern crate rtps_cdr;
+
+#[macro_use]
+mod common;
+
+generated!("layout");
+
+use std::convert::TryFrom;
+use std::mem;
+use rtps_cdr::{encode_to_vec, CdrDecode, Deserializer, Encoding, Endianness, SerializedSize};
+use rtps_types::{Char, FixedLayout, FixedVec, LayoutError, WChar};
+use generated::Layout::{Level, Mode, Point, Sample, Wrapper};
+
+/// A sample with its strings and sequences at their bound
+fn sample() -> Sample {
+    Sample {
+        flag: 1,
+        value: 2.5,
+        mode: Mode::ON,
+        level: Level::HIGH,
+        name: TryFrom::try_from("seven..").unwrap(),
+        points: FixedVec::try_from(vec![Point { x: 1, y: 2 }, Point { x: 3, y: 4 }]).unwrap(),
+        counts: [5, 6, 7],
+        tag: Char::from_char('t'),
+        ok: true,
+        stamps: FixedVec::try_from(vec![8, 9]).unwrap(),
+        wide: WChar::from_char('w'),
+        ..Sample::default()
+    }
+}
+
+/// Bytes aligned to 8, as shared memory is
+fn aligned(bytes: &[u8]) -> Vec<u64> {
+    let mut words = vec![0u64; bytes.len().div_ceil(8)];
+    let len = bytes.len();
+    // SAFETY: the words hold at least the bytes
+    unsafe { std::slice::from_raw_parts_mut(words.as_mut_ptr() as *mut u8, len) }.copy_from_slice(bytes);
+    words
+}
+
+fn as_bytes(words: &[u64], len: usize) -> &[u8] {
+    // SAFETY: the words hold at least `len` bytes
+    unsafe { std::slice::from_raw_parts(words.as_ptr() as *const u8, len) }
+}
+
+#[test]
+fn layout_is_cdr() {
+    let sample = sample();
+    let encoded = encode_to_vec(&sample, Endianness::Little, Encoding::Xcdr1).unwrap();
+    assert_eq!(Some(encoded.len()), Sample::MAX_SIZE_XCDR1);
+    assert_eq!(mem::size_of::<Sample>(), 96);
+
+    // the bytes in memory are the encoding, followed by the padding to the alignment
+    let bytes = sample.as_bytes();
+    assert_eq!(&bytes[..encoded.len()], &encoded[..]);
+    assert_eq!(&bytes[encoded.len()..], [0; 6]);
+
+    let wrapper = Wrapper { origin: Point { x: -1, y: 1 }, ..Wrapper::default() };
+    let encoded = encode_to_vec(&wrapper, Endianness::Little, Encoding::Xcdr1).unwrap();
+    assert_eq!(&wrapper.as_bytes()[..9], &encoded[..]);
+}
+
+#[test]
+fn cast_from_bytes() {
+    let sample = sample();
+    let words = aligned(sample.as_bytes());
+    let bytes = as_bytes(&words, mem::size_of::<Sample>());
+    let view = Sample::from_bytes(bytes).unwrap();
+    assert_eq!(*view, sample);
+    assert_eq!(view.name.as_str(), "seven..");
+
+    // the encoding of full strings and sequences decodes from memory
+    let mut de = Deserializer::new(bytes, Endianness::Little);
+    assert_eq!(Sample::decode(&mut de).unwrap(), sample);
+
+    // shorter ones keep the positions of the members
+    let mut words = words.clone();
+    let len = mem::size_of::<Sample>();
+    let bytes = unsafe { std::slice::from_raw_parts_mut(words.as_mut_ptr() as *mut u8, len) };
+    let shared = Sample::from_bytes_mut(bytes).unwrap();
+    shared.points.pop();
+    shared.name.set("one").unwrap();
+    let copy = Sample::read_bytes(&bytes[..]).unwrap();
+    assert_eq!((copy.points.len(), copy.name.as_str()), (1, "one"));
+    assert_eq!((copy.counts, copy.wide), ([5, 6, 7], WChar::from_char('w')));
+}
+
+#[test]
+fn invalid_bytes() {
+    let sample = sample();
+    let words = aligned(sample.as_bytes());
+    let bytes = as_bytes(&words, mem::size_of::<Sample>());
+    assert_eq!(Sample::from_bytes(&bytes[..95]).unwrap_err(), LayoutError::Size { expected: 96, actual: 95 });
+    let unaligned = as_bytes(&words, 97);
+    assert_eq!(Sample::from_bytes(&unaligned[1..]).unwrap_err(), LayoutError::Align(8));
+    assert_eq!(Sample::read_bytes(&unaligned[..96]).unwrap(), sample);
+
+    // booleans, enumerators, lengths and UTF-8 are verified
+    for &(offset, byte) in &[(63, 2), (16, 5), (20, 2), (36, 3), (28, 0xff), (64, 3)] {
+        let mut invalid = bytes.to_vec();
+        invalid[offset] = byte;
+        assert_eq!(Sample::read_bytes(&invalid).unwrap_err(), LayoutError::Invalid, "offset {}", offset);
+    }
+
+    // padding is not
+    let mut padded = bytes.to_vec();
+    padded[1] = 0xff;
+    assert_eq!(Sample::read_bytes(&padded).unwrap(), sample);
+}
@@ -0,0 +1,57 @@
+// the generated code resolves without the prelude of std
+#![no_std]
+extern crate alloc;
+extern crate std;
+
+#[macro_use]
+mod common;
+
+generated!("no_std");
+
+use alloc::boxed::Box;
+use alloc::vec;
+use core::convert::TryFrom;
+use core::mem;
+use rtps_cdr::{decode_from_slice, encode_to_vec, Encoding, Endianness};
+use rtps_types::{FixedString, FixedVec};
+use generated::Embedded::Node::Counter;
+use generated::Embedded::{Reading, State, Status, Value};
+
+#[test]
+fn bounded_in_place() {
+    let reading = Reading {
+        stamps: FixedVec::try_from(vec![1, 2]).unwrap(),
+        state: State::BUSY,
+        ..Reading::default()
+    };
+    assert_eq!(reading.name, "temp");
+    assert_eq!(mem::size_of::<FixedString<8>>(), 13);
+    assert!(FixedVec::<u64, 4>::try_from(vec![1, 2, 3, 4, 5]).is_err());
+
+    let status = Status {
+        last: Box::new(reading.clone()),
+        history: FixedVec::try_from(vec![reading]).unwrap(),
+        counters: [3; 4],
+    };
+    for &encoding in &[Encoding::Xcdr1, Encoding::Xcdr2] {
+        let bytes = encode_to_vec(&status, Endianness::Little, encoding).unwrap();
+        assert_eq!(decode_from_slice::<Status>(&bytes, Endianness::Little, encoding).unwrap(), status);
+    }
+    let counter = Counter {
+        name: FixedString::try_from("ticks").unwrap(),
+        values: FixedVec::try_from(vec![7]).unwrap(),
+    };
+    let bytes = encode_to_vec(&counter, Endianness::Little, Encoding::Xcdr1).unwrap();
+    assert_eq!(decode_from_slice::<Counter>(&bytes, Endianness::Little, Encoding::Xcdr1).unwrap(), counter);
+    let text = Value::text { text: FixedString::try_from("hello").unwrap() };
+    let bytes = encode_to_vec(&text, Endianness::Big, Encoding::Xcdr1).unwrap();
+    assert_eq!(decode_from_slice::<Value>(&bytes, Endianness::Big, Encoding::Xcdr1).unwrap(), text);
+}
+
+#[test]
+fn bound_exceeded() {
+    // the encoding of a longer string fails to decode into the bounded one
+    let bytes = encode_to_vec("ninechars", Endianness::Little, Encoding::Xcdr1).unwrap();
+    assert!(decode_from_slice::<FixedString<8>>(&bytes, Endianness::Little, Encoding::Xcdr1).is_err());
+    assert!(decode_from_slice::<FixedString<9>>(&bytes, Endianness::Little, Encoding::Xcdr1).is_ok());
+}
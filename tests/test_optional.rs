@@ -0,0 +1,33 @@
+extern crate serde_json;
+
+#[macro_use]
+mod common;
+
+generated!("optional_external");
+
+use generated::Optional::{Content, Header, Node};
+
+#[test]
+fn optional_members() {
+    let list = Node { value: 1, next: Some(Box::new(Node { value: 2, next: None })) };
+    let json = serde_json::to_string(&list).unwrap();
+    assert_eq!(json, "{\"value\":1,\"next\":{\"value\":2,\"next\":null}}");
+    assert_eq!(serde_json::from_str::<Node>(&json).unwrap(), list);
+
+    // absent optional members deserialize as None
+    let node: Node = serde_json::from_str("{\"value\":3}").unwrap();
+    assert_eq!(node, Node { value: 3, next: None });
+}
+
+#[test]
+fn external_members() {
+    let header = Header::default();
+    assert_eq!(header.name, None);
+    assert_eq!(*header.count, 5);
+    assert_eq!(*header.blob, [0; 16]);
+
+    let content = Content::list { list: Box::new(Node::default()) };
+    let json = serde_json::to_string(&content).unwrap();
+    assert_eq!(serde_json::from_str::<Content>(&json).unwrap(), content);
+    assert_eq!(Content::default(), Content::header { header: None });
+}
@@ -0,0 +1,105 @@
+extern crate rtps_cdr;
+extern crate rtps_elements;
+
+#[macro_use]
+mod common;
+
+generated!("rtps_parameters");
+
+use std::convert::TryFrom;
+use rtps_cdr::{Endianness, ParameterList, ParameterListWriter};
+use rtps_elements::DDS::{self, DCPS};
+use generated::DDS::RTPS::{self, Parameter, LOCATOR_KIND_UDPv4, PID_DDS_ASSOCIATED_WRITER, PID_DURABILITY,
+                           PID_ENDPOINT_GUID, PID_PARTITION, PID_RELIABILITY, PID_SENTINEL, PID_TOPIC_NAME,
+                           PID_TYPE_NAME, PID_UNICAST_LOCATOR, PID_USER_DATA};
+
+fn guid(kind: u8) -> DCPS::GUID_t {
+    DCPS::GUID_t { guidPrefix: [3; 12], entityId: DCPS::EntityId_t { entityKey: [0, 0, 2], entityKind: kind } }
+}
+
+fn locator(port: u32) -> RTPS::Locator_t {
+    RTPS::Locator_t { kind: LOCATOR_KIND_UDPv4, _port: port, address: [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 127, 0, 0, 1] }
+}
+
+/// Encode the subscription as the parameter list of SEDP, the values typed by the union
+/// where it lists them
+fn write_subscription(data: &DDS::SubscriptionBuiltinTopicData, endianness: Endianness) -> Vec<u8> {
+    let mut writer = ParameterListWriter::new(endianness);
+    writer.put(PID_ENDPOINT_GUID, &guid(DCPS::ENTITYKIND_USER_READER_WITH_KEY)).unwrap();
+    let string_data = TryFrom::try_from(data.topic_name.as_str()).unwrap();
+    writer.put_typed(&Parameter::PID_TOPIC_NAME { string_data }).unwrap();
+    // the type name shares its case with the topic name, written by the first label
+    writer.put(PID_TYPE_NAME, &data.type_name).unwrap();
+    let durability = RTPS::DurabilityQosPolicy { kind: RTPS::DurabilityQosPolicyKind::TRANSIENT_LOCAL_DURABILITY_QOS };
+    writer.put_typed(&Parameter::PID_DURABILITY { durability }).unwrap();
+    writer.put(PID_RELIABILITY, &data.reliability).unwrap();
+    writer.put(PID_PARTITION, &data.partition).unwrap();
+    writer.put(PID_USER_DATA, &data.user_data).unwrap();
+    writer.put_typed(&Parameter::PID_UNICAST_LOCATOR { locator: locator(7411) }).unwrap();
+    writer.put_typed(&Parameter::PID_UNICAST_LOCATOR { locator: locator(7413) }).unwrap();
+    writer.put(PID_DDS_ASSOCIATED_WRITER, &guid(DCPS::ENTITYKIND_USER_WRITER_WITH_KEY)).unwrap();
+    writer.into_payload()
+}
+
+#[test]
+fn discovery_data() {
+    let mut data = DDS::SubscriptionBuiltinTopicData {
+        topic_name: "Circle".to_owned(),
+        type_name: "ShapeType".to_owned(),
+        ..Default::default()
+    };
+    data.reliability.kind = DDS::ReliabilityQosPolicyKind::RELIABLE_RELIABILITY_QOS;
+    data.reliability.max_blocking_time = DDS::Duration_t { sec: 1, nanosec: 500 };
+    data.partition.name = vec!["a".to_owned(), "bc".to_owned()];
+    data.user_data.value = vec![1, 2, 3];
+
+    for &endianness in &[Endianness::Big, Endianness::Little] {
+        let payload = write_subscription(&data, endianness);
+        assert_eq!(payload.len() % 4, 0);
+        let list = ParameterList::from_payload(&payload).unwrap();
+        assert!(!list.contains(PID_SENTINEL));
+        assert!(list.check(|id| id == PID_ENDPOINT_GUID).is_ok());
+
+        // values written typed by the union read untyped, and the other way round
+        let topic_name: String = list.get(PID_TOPIC_NAME).unwrap().unwrap();
+        assert_eq!(topic_name, "Circle");
+        match list.get_typed(PID_TYPE_NAME).unwrap() {
+            Some(Parameter::PID_TOPIC_NAME { string_data }) => assert_eq!(string_data, "ShapeType"),
+            _ => panic!("unexpected type name"),
+        }
+        let durability: DDS::DurabilityQosPolicy = list.get(PID_DURABILITY).unwrap().unwrap();
+        assert!(matches!(durability.kind, DDS::DurabilityQosPolicyKind::TRANSIENT_LOCAL_DURABILITY_QOS));
+        match list.get_typed(PID_RELIABILITY).unwrap() {
+            Some(Parameter::PID_RELIABILITY { reliability }) => {
+                assert!(matches!(reliability.kind, RTPS::ReliabilityQosPolicyKind::RELIABLE_RELIABILITY_QOS));
+                assert_eq!((reliability.max_blocking_time.sec, reliability.max_blocking_time.nanosec), (1, 500));
+            }
+            _ => panic!("unexpected reliability"),
+        }
+        match list.get_typed(PID_PARTITION).unwrap() {
+            Some(Parameter::PID_PARTITION { partition }) => assert_eq!(partition.name, data.partition.name),
+            _ => panic!("unexpected partition"),
+        }
+        let user_data: DDS::UserDataQosPolicy = list.get(PID_USER_DATA).unwrap().unwrap();
+        assert_eq!(user_data.value, data.user_data.value);
+
+        let ports: Vec<u32> = list
+            .get_all_typed(PID_UNICAST_LOCATOR)
+            .unwrap()
+            .into_iter()
+            .map(|parameter| match parameter {
+                Parameter::PID_UNICAST_LOCATOR { locator } => locator._port,
+                _ => panic!("unexpected locator"),
+            })
+            .collect();
+        assert_eq!(ports, [7411, 7413]);
+
+        // the union does not list the vendor-specific parameter, known to the reader by its
+        // identifier only
+        assert!(list.get_typed::<Parameter>(PID_DDS_ASSOCIATED_WRITER).is_err());
+        let parameter = list.parameters().last().unwrap();
+        assert!(parameter.is_vendor_specific() && !parameter.is_incompatible());
+        let writer: DCPS::GUID_t = list.get(PID_DDS_ASSOCIATED_WRITER).unwrap().unwrap();
+        assert_eq!(writer.entityId.entityKind, DCPS::ENTITYKIND_USER_WRITER_WITH_KEY);
+    }
+}
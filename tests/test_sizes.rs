@@ -0,0 +1,78 @@
+extern crate rtps_cdr;
+
+#[macro_use]
+mod common;
+
+generated!("sizes");
+
+use std::convert::TryFrom;
+use rtps_cdr::{encode_to_vec, CdrEncode, Encoding, Endianness, SerializedSize};
+use rtps_types::{BoundedString, BoundedVec, LongDouble};
+use generated::Sizes::{Aligned, Arrays, Bounded, Choice, Params, Selection, Small, Versioned};
+
+const ENCODINGS: [Encoding; 2] = [Encoding::Xcdr1, Encoding::Xcdr2];
+
+fn len<T: CdrEncode>(value: &T, encoding: Encoding) -> usize {
+    encode_to_vec(value, Endianness::Little, encoding).unwrap().len()
+}
+
+/// Verify the values encode at the largest and the smallest size of the type
+fn extremes<T: CdrEncode + SerializedSize>(largest: &T, smallest: &T) {
+    for &encoding in &ENCODINGS {
+        assert_eq!(Some(len(largest, encoding)), T::max_size(encoding), "{:?}", encoding);
+        assert_eq!(len(smallest, encoding), T::min_size(encoding), "{:?}", encoding);
+    }
+}
+
+fn aligned() -> Aligned {
+    Aligned { flag: 1, value: 2.5, small: Small::HIGH }
+}
+
+fn bounded() -> Bounded {
+    Bounded {
+        name: TryFrom::try_from("abcdefgh").unwrap(),
+        // four characters of four octets each
+        wname: TryFrom::try_from("\u{1d11e}".repeat(4)).unwrap(),
+        items: TryFrom::try_from(vec![aligned(); 5]).unwrap(),
+        bytes: TryFrom::try_from(vec![7; 100]).unwrap(),
+    }
+}
+
+#[test]
+fn fixed_sizes() {
+    extremes(&Small::LOW, &Small::HIGH);
+    extremes(&aligned(), &Aligned::default());
+    let arrays = Arrays { items: [aligned(), aligned(), aligned()], shorts: [[1; 3]; 2], wide: LongDouble::from_f64(1.0) };
+    extremes(&arrays, &Arrays::default());
+    assert_eq!((Arrays::MAX_SIZE_XCDR1, Arrays::MIN_SIZE_XCDR2), (Some(80), 72));
+}
+
+#[test]
+fn bounded_sizes() {
+    extremes(&bounded(), &Bounded::default());
+    let versioned = Versioned { tag: 1, items: TryFrom::try_from(vec![aligned(); 2]).unwrap(), extra: Some(0.5) };
+    extremes(&versioned, &Versioned::default());
+    extremes(&Choice::large { large: aligned() }, &Choice::small { small: 3 });
+    extremes(&Selection::large { large: bounded() }, &Selection::small { small: 3 });
+}
+
+#[test]
+fn parameter_sizes() {
+    // the member ID and the length of the sequence require extended parameter headers
+    let params = Params {
+        small: 1,
+        far: 2,
+        big: BoundedVec::try_from(vec![0; 70000]).unwrap(),
+        note: Some(BoundedString::try_from("abc").unwrap()),
+    };
+    extremes(&params, &Params::default());
+}
+
+#[test]
+fn unbounded_sizes() {
+    use generated::Sizes::{Node, Unbounded};
+    assert_eq!((Node::MAX_SIZE_XCDR1, Node::MIN_SIZE_XCDR1), (None, 8));
+    assert_eq!(len(&Node::default(), Encoding::Xcdr1), 8);
+    assert_eq!((Unbounded::MAX_SIZE_XCDR2, Unbounded::MIN_SIZE_XCDR2), (None, 16));
+    assert_eq!(len(&Unbounded::default(), Encoding::Xcdr2), 16);
+}
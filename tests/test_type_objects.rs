@@ -0,0 +1,151 @@
+#[macro_use]
+mod common;
+
+generated!("type_objects");
+
+use rtps_cdr::{from_slice_with_encoding, to_vec_with_encoding, Encoding, Endianness};
+use rtps_types::{Discoverable, TypeIdentifierWithDependencies, TypeIdentifierWithSize, TypeInformation, TypeObject,
+                 TypeObjects, EK_COMPLETE, EK_MINIMAL};
+use generated::Shapes::Geo::Point;
+use generated::Shapes::{Color, Fill, Shape};
+
+fn contains(bytes: &[u8], part: &[u8]) -> bool {
+    bytes.windows(part.len()).any(|window| window == part)
+}
+
+fn verify(objects: &TypeObjects) {
+    for (object, kind) in [(objects.minimal, EK_MINIMAL), (objects.complete, EK_COMPLETE)] {
+        // the DHEADER of the TypeObject delimits it, followed by the equivalence kind
+        let len = u32::from_le_bytes([object.bytes[0], object.bytes[1], object.bytes[2], object.bytes[3]]);
+        assert_eq!(len as usize, object.bytes.len() - 4);
+        assert_eq!(object.equivalence_kind(), kind);
+        assert_eq!(object.hash[..], md5::compute(object.bytes)[..14]);
+        assert_eq!(object.type_identifier()[0], kind);
+        assert_eq!(object.type_identifier()[1..], object.hash);
+    }
+}
+
+#[test]
+fn equivalence_hashes() {
+    for objects in Shape::DEPENDENCIES.iter().chain(Some(&Shape::TYPE_OBJECTS)) {
+        verify(objects);
+    }
+    // complete TypeObjects hold the names, minimal ones their hashes
+    let complete = Point::TYPE_OBJECTS.complete.bytes;
+    assert!(contains(complete, b"Shapes::Geo::Point\0"));
+    assert!(contains(complete, b"x\0"));
+    let minimal = Point::TYPE_OBJECTS.minimal.bytes;
+    assert!(!contains(minimal, b"Point"));
+    assert!(contains(minimal, &md5::compute("x")[..4]));
+}
+
+#[test]
+fn minimal_type_object_encoding() {
+    // the minimal TypeObject of Point laid out by hand as section 7.3.4 of XTypes 1.3
+    // specifies it, independent of the generator and of the md5 crate
+    let expected = [
+        // DHEADER of the appendable TypeObject, EK_MINIMAL, TK_STRUCTURE and the flags
+        // IS_APPENDABLE | IS_NESTED
+        0x33, 0x00, 0x00, 0x00, 0xf1, 0x51, 0x0a, 0x00,
+        // DHEADER of the appendable header, the base type TK_NONE and padding
+        0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        // DHEADER and length of the member sequence
+        0x23, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00,
+        // DHEADER, member ID 0, TRY_CONSTRUCT1, TK_INT32 and the name hash of x
+        0x0b, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x04, 0x9d, 0xd4, 0xe4, 0x61,
+        // padding and the same for member ID 1 named y
+        0x00, 0x0b, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x04, 0x41, 0x52, 0x90, 0x76,
+    ];
+    let minimal = Point::TYPE_OBJECTS.minimal;
+    assert_eq!(minimal.bytes, expected);
+    // the first 14 octets of the MD5 digest of these bytes
+    let hash = [0x11, 0xfd, 0xa2, 0xa3, 0x97, 0xc6, 0x4d, 0x66, 0x56, 0x4d, 0x76, 0x68, 0xb9, 0x7d];
+    assert_eq!(minimal.hash, hash);
+    assert_eq!(minimal.type_identifier()[..], [&[EK_MINIMAL][..], &hash[..]].concat()[..]);
+}
+
+#[test]
+fn dependencies() {
+    let listed = |dependencies: &[TypeObjects], objects: TypeObjects| dependencies.contains(&objects);
+    assert!(Color::DEPENDENCIES.is_empty() && Point::DEPENDENCIES.is_empty());
+    assert_eq!(Fill::DEPENDENCIES, [Color::TYPE_OBJECTS, Point::TYPE_OBJECTS]);
+    // the typedef Name, Color, Point and Fill, each listed once
+    assert_eq!(Shape::DEPENDENCIES.len(), 4);
+    for objects in [Color::TYPE_OBJECTS, Point::TYPE_OBJECTS, Fill::TYPE_OBJECTS] {
+        assert!(listed(Shape::DEPENDENCIES, objects));
+    }
+    // members refer to the hash of the same equivalence kind
+    let refers = |object: TypeObject, to: TypeObject| contains(object.bytes, &to.type_identifier());
+    assert!(refers(Shape::TYPE_OBJECTS.minimal, Point::TYPE_OBJECTS.minimal));
+    assert!(refers(Shape::TYPE_OBJECTS.complete, Point::TYPE_OBJECTS.complete));
+    assert!(!refers(Shape::TYPE_OBJECTS.minimal, Point::TYPE_OBJECTS.complete));
+}
+
+#[test]
+fn type_information() {
+    let information = TypeInformation::of::<Shape>();
+    assert_eq!(information.minimal.typeid_with_size, Shape::TYPE_OBJECTS.minimal.with_size());
+    assert_eq!(information.complete.dependent_typeid_count, 4);
+    assert_eq!(information.complete.dependent_typeids[0], Shape::DEPENDENCIES[0].complete.with_size());
+    assert!(information.matches(&TypeInformation::of::<Shape>()));
+    assert!(!information.matches(&TypeInformation::of::<Point>()));
+
+    // advertised by SEDP as mutable struct in XCDR2
+    let bytes = to_vec_with_encoding(&information, Endianness::Little, Encoding::Xcdr2).unwrap();
+    assert_eq!(bytes[4..8], [0x01, 0x10, 0x00, 0x40]);
+    let decoded: TypeInformation = from_slice_with_encoding(&bytes, Endianness::Little, Encoding::Xcdr2).unwrap();
+    assert_eq!(decoded, information);
+}
+
+fn with_size(kind: u8, first: u8, size: u32) -> TypeIdentifierWithSize {
+    let mut type_id = [kind; 15];
+    for (i, octet) in type_id[1..].iter_mut().enumerate() {
+        *octet = first + i as u8;
+    }
+    TypeIdentifierWithSize { type_id, typeobject_serialized_size: size }
+}
+
+#[test]
+fn type_information_encoding() {
+    let information = TypeInformation {
+        minimal: TypeIdentifierWithDependencies {
+            typeid_with_size: with_size(EK_MINIMAL, 0x01, 0x40),
+            dependent_typeid_count: 0,
+            dependent_typeids: vec![],
+        },
+        complete: TypeIdentifierWithDependencies {
+            typeid_with_size: with_size(EK_COMPLETE, 0x11, 0x80),
+            dependent_typeid_count: 1,
+            dependent_typeids: vec![with_size(EK_COMPLETE, 0x21, 0x20)],
+        },
+    };
+    let expected = [
+        // DHEADER of the mutable TypeInformation
+        0x78, 0x00, 0x00, 0x00,
+        // EMHEADER of member 0x1001 and its length
+        0x01, 0x10, 0x00, 0x40, 0x28, 0x00, 0x00, 0x00,
+        // DHEADER of the appendable TypeIdentifierWithDependencies
+        0x24, 0x00, 0x00, 0x00,
+        // DHEADER of the appendable TypeIdentifierWithSize, the TypeIdentifier, padding and size
+        0x14, 0x00, 0x00, 0x00,
+        0xf1, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x00,
+        0x40, 0x00, 0x00, 0x00,
+        // dependent_typeid_count, the DHEADER and length of the empty dependent_typeids
+        0x00, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        // EMHEADER of member 0x1002 and its length
+        0x02, 0x10, 0x00, 0x40, 0x40, 0x00, 0x00, 0x00,
+        0x3c, 0x00, 0x00, 0x00,
+        0x14, 0x00, 0x00, 0x00,
+        0xf2, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1a, 0x1b, 0x1c, 0x1d, 0x1e, 0x00,
+        0x80, 0x00, 0x00, 0x00,
+        // dependent_typeid_count, the DHEADER and length of dependent_typeids, its element
+        0x01, 0x00, 0x00, 0x00, 0x1c, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00,
+        0x14, 0x00, 0x00, 0x00,
+        0xf2, 0x21, 0x22, 0x23, 0x24, 0x25, 0x26, 0x27, 0x28, 0x29, 0x2a, 0x2b, 0x2c, 0x2d, 0x2e, 0x00,
+        0x20, 0x00, 0x00, 0x00,
+    ];
+    let bytes = to_vec_with_encoding(&information, Endianness::Little, Encoding::Xcdr2).unwrap();
+    assert_eq!(bytes, expected);
+    let decoded: TypeInformation = from_slice_with_encoding(&expected, Endianness::Little, Encoding::Xcdr2).unwrap();
+    assert_eq!(decoded, information);
+}
@@ -0,0 +1,60 @@
+extern crate serde_json;
+
+#[macro_use]
+mod common;
+
+generated!("validate");
+
+use generated::Limits::{Pair, Reading, Sample, Sensor};
+
+fn reading(speed: i32) -> Reading {
+    Reading { speed, ratio: 1.0, grade: 'a', label: "ok".to_owned() }
+}
+
+#[test]
+fn valid_values() {
+    let sensor = Sensor {
+        current: reading(100),
+        history: vec![reading(0), reading(300)],
+        level: Some(10),
+        slots: [reading(1), reading(2)],
+        calibration: Box::new(reading(50)),
+        ..Sensor::default()
+    };
+    assert_eq!(sensor.validate(), Ok(()));
+    assert_eq!(Sample::digit { digit: 9 }.validate(), Ok(()));
+}
+
+#[test]
+fn violation_paths() {
+    let sensor = Sensor {
+        current: Reading { ratio: f64::NAN, ..reading(301) },
+        history: vec![reading(0); 5],
+        last: Some(Reading { grade: '~', ..reading(1) }),
+        level: Some(11),
+        slots: [reading(1), Reading { label: "too long!".to_owned(), ..reading(-1) }],
+        calibration: Box::new(reading(50)),
+        ..Sensor::default()
+    };
+    let paths = sensor.validate().unwrap_err().violations.into_iter()
+        .map(|violation| violation.path)
+        .collect::<Vec<_>>();
+    assert_eq!(paths, ["current.speed", "current.ratio", "history", "last.grade", "level",
+                       "slots[1].speed", "slots[1].label"]);
+
+    let err = Sample::reading { reading: reading(-1) }.validate().unwrap_err();
+    assert_eq!(err.to_string(), "reading.speed: -1 is below the minimum 0");
+    assert!(Pair::high { high: 1001 }.validate().is_err());
+    assert_eq!(Pair::high { high: 0 }.validate(), Ok(()));
+}
+
+#[test]
+fn validating_deserializer() {
+    let json = serde_json::to_string(&reading(400)).unwrap();
+    let err = serde_json::from_str::<Reading>(&json).unwrap_err();
+    assert!(err.to_string().starts_with("speed: 400 is above the maximum 300"));
+
+    let json = serde_json::to_string(&reading(200)).unwrap();
+    assert_eq!(serde_json::from_str::<Reading>(&json).unwrap().speed, 200);
+    assert!(serde_json::from_str::<Sample>("{\"digit\":{\"digit\":0}}").is_err());
+}
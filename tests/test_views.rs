@@ -0,0 +1,87 @@
+extern crate rtps_cdr;
+
+#[macro_use]
+mod common;
+
+generated!("views");
+
+use std::convert::TryFrom;
+use rtps_cdr::{encode_to_vec, CdrView, Deserializer, Encoding, Endianness, Error};
+use generated::Views::{Color, Config, ConfigView, Label, Point, Settings, Shape, ShapeView};
+
+fn shape() -> Shape {
+    Shape {
+        name: "square".to_owned(),
+        origin: Point { x: -1, y: 2 },
+        data: vec![1, 2, 3],
+        points: vec![Point { x: 3, y: 4 }],
+        area: 1.5,
+        label: Some(Label { text: "big".to_owned(), color: Color::GREEN }),
+        caption: Label { text: "caption".to_owned(), color: Color::BLUE },
+        tag: TryFrom::try_from("t").unwrap(),
+        counts: [7, 8, 9],
+    }
+}
+
+#[test]
+fn members_in_place() {
+    let shape = shape();
+    for &endianness in &[Endianness::Big, Endianness::Little] {
+        let bytes = encode_to_vec(&shape, endianness, Encoding::Xcdr1).unwrap();
+        let view = ShapeView::new(&bytes, endianness).unwrap();
+
+        // strings and octets borrow the encoded bytes
+        let name = view.name().unwrap();
+        assert_eq!(name, "square");
+        assert!(bytes.as_ptr_range().contains(&name.as_ptr()));
+        assert_eq!(view.data().unwrap(), [1, 2, 3]);
+        assert_eq!(view.tag().unwrap(), "t");
+
+        let origin = view.origin().unwrap();
+        assert_eq!((origin.x().unwrap(), origin.y().unwrap()), (-1, 2));
+        assert_eq!(origin.to_owned().unwrap(), shape.origin);
+        let caption = view.caption().unwrap();
+        assert_eq!((caption.text().unwrap(), caption.color().unwrap()), ("caption", Color::BLUE));
+
+        assert_eq!(view.points().unwrap(), shape.points);
+        assert_eq!(view.area().unwrap(), 1.5);
+        assert_eq!(view.label().unwrap(), shape.label);
+        assert_eq!(view.counts().unwrap(), [7, 8, 9]);
+        assert_eq!(view.to_owned().unwrap(), shape);
+    }
+}
+
+#[test]
+fn nested_and_mutable() {
+    // the view of a struct within a buffer aligns relative to the buffer's start
+    let config = Config { settings: Settings { level: 3 }, flag: 1 };
+    let mut bytes = encode_to_vec(&(7u8, config.clone()), Endianness::Little, Encoding::Xcdr1).unwrap();
+    bytes.extend_from_slice(&[0xff; 3]);
+    let mut de = Deserializer::new(&bytes, Endianness::Little);
+    assert_eq!(rtps_cdr::CdrDecode::decode(&mut de).ok(), Some(7u8));
+    let view = ConfigView::view(&mut de).unwrap();
+    assert_eq!(de.remaining(), [0xff; 3]);
+    assert_eq!(view.settings().unwrap(), config.settings);
+    assert_eq!(view.flag().unwrap(), 1);
+    assert_eq!(view.to_owned().unwrap(), config);
+}
+
+#[test]
+fn invalid_data() {
+    let bytes = encode_to_vec(&shape(), Endianness::Little, Encoding::Xcdr1).unwrap();
+
+    // members past the end are detected when viewing
+    let err = ShapeView::new(&bytes[..bytes.len() - 1], Endianness::Little).unwrap_err();
+    assert!(matches!(err, Error::Eof));
+
+    // the contents of a member are verified when accessed
+    let mut invalid = bytes.clone();
+    invalid[4] = 0xff;
+    let view = ShapeView::new(&invalid, Endianness::Little).unwrap();
+    assert!(matches!(view.name(), Err(Error::InvalidUtf8)));
+    assert_eq!(view.data().unwrap(), [1, 2, 3]);
+    assert!(view.to_owned().is_err());
+
+    let mut de = Deserializer::with_encoding(&bytes, Endianness::Little, Encoding::Xcdr2);
+    assert!(matches!(ShapeView::view(&mut de), Err(Error::ViewEncoding(Encoding::Xcdr2))));
+}
@@ -0,0 +1,101 @@
+extern crate rtps_cdr;
+extern crate serde_json;
+
+#[macro_use]
+mod common;
+
+generated!("xtypes");
+
+use rtps_cdr::{from_slice_with_encoding, to_vec_with_encoding, Encoding, Endianness};
+use generated::Shapes::{Color, Fill, Label, Point, Point3, Segment, Shape, ShapeName};
+
+fn encode<T: serde::Serialize>(value: &T, encoding: Encoding) -> Vec<u8> {
+    to_vec_with_encoding(value, Endianness::Little, encoding).unwrap()
+}
+
+fn decode<'de, T: serde::Deserialize<'de>>(bytes: &'de [u8], encoding: Encoding) -> rtps_cdr::Result<T> {
+    from_slice_with_encoding(bytes, Endianness::Little, encoding)
+}
+
+fn shape() -> Shape {
+    Shape {
+        name: "a".to_owned(),
+        color: Color::BLUE,
+        outline: vec![Point { x: 1, y: 2 }, Point { x: 3, y: 4 }],
+        fill: Some(Fill::gradient { gradient: vec![Color::RED, Color::GREEN] }),
+        layer: None,
+    }
+}
+
+#[test]
+fn appendable_on_the_wire() {
+    let point = Point { x: 1, y: 2 };
+    assert_eq!(encode(&point, Encoding::Xcdr2), [8, 0, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0]);
+    assert_eq!(encode(&point, Encoding::Xcdr1), [1, 0, 0, 0, 2, 0, 0, 0]);
+
+    // a later version reads the members not encoded as default, an earlier one skips these
+    let bytes = encode(&point, Encoding::Xcdr2);
+    let point3: Point3 = decode(&bytes, Encoding::Xcdr2).unwrap();
+    assert_eq!((point3.x, point3.y, point3.z), (1, 2, 0));
+    let segment = Segment { from: point.clone(), to: point };
+    let bytes = encode(&(Point3 { x: 5, y: 6, z: 7 }, segment), Encoding::Xcdr2);
+    let (point, segment): (Point, Segment) = decode(&bytes, Encoding::Xcdr2).unwrap();
+    assert_eq!((point.x, point.y, segment.to.y), (5, 6, 2));
+}
+
+#[test]
+fn mutable_on_the_wire() {
+    let name = ShapeName { name: "a".to_owned(), color: Color::BLUE };
+    assert_eq!(encode(&name, Encoding::Xcdr2), [
+        28, 0, 0, 0, // DHEADER
+        0, 0, 0, 0xc0, 6, 0, 0, 0, 2, 0, 0, 0, b'a', 0, 0, 0, // must understand, id 0
+        5, 0, 0, 0x40, 4, 0, 0, 0, 2, 0, 0, 0, // id 5
+    ]);
+    assert_eq!(encode(&name, Encoding::Xcdr1), [
+        0, 0x40, 8, 0, 2, 0, 0, 0, b'a', 0, 0, 0, // must understand, id 0
+        5, 0, 4, 0, 2, 0, 0, 0, // id 5
+        2, 0x7f, 0, 0, // PID_LIST_END
+    ]);
+}
+
+#[test]
+fn mutable_round_trip() {
+    for &encoding in &[Encoding::Xcdr1, Encoding::Xcdr2] {
+        let bytes = encode(&shape(), encoding);
+        let decoded: Shape = decode(&bytes, encoding).unwrap();
+        assert_eq!(format!("{:?}", decoded), format!("{:?}", shape()));
+
+        let label = Label { text: "b".to_owned(), font_size: 12, tags: vec!["x".to_owned(), String::new()] };
+        let decoded: Label = decode(&encode(&label, encoding), encoding).unwrap();
+        assert_eq!(format!("{:?}", decoded), format!("{:?}", label));
+    }
+}
+
+#[test]
+fn mutable_unknown_members() {
+    for &encoding in &[Encoding::Xcdr1, Encoding::Xcdr2] {
+        // an earlier version skips the members it does not know
+        let bytes = encode(&shape(), encoding);
+        let name: ShapeName = decode(&bytes, encoding).unwrap();
+        assert_eq!(name.name, "a");
+        assert_eq!(name.color.value(), Color::BLUE.value());
+
+        // unless these must be understood
+        let bytes = encode(&Shape { layer: Some(1), ..shape() }, encoding);
+        let err = decode::<ShapeName>(&bytes, encoding).unwrap_err();
+        assert_eq!(err.to_string(), "unknown member 8 must be understood");
+
+        // a later version reads the members not encoded as default
+        let bytes = encode(&ShapeName { name: "c".to_owned(), color: Color::GREEN }, encoding);
+        let shape: Shape = decode(&bytes, encoding).unwrap();
+        assert_eq!((shape.name.as_str(), shape.outline.len(), shape.fill.is_none()), ("c", 0, true));
+    }
+}
+
+#[test]
+fn human_readable() {
+    let json = serde_json::to_string(&ShapeName { name: "a".to_owned(), color: Color::BLUE }).unwrap();
+    assert_eq!(json, r#"{"name":"a","color":2}"#);
+    let point: Point = serde_json::from_str(r#"{"x":1}"#).unwrap();
+    assert_eq!((point.x, point.y), (1, 0));
+}